@@ -1,5 +1,19 @@
-use crate::database::schema::JobApplication;
+use crate::{
+    categorize::Suggestion,
+    components::field_editor::InlineField,
+    config::{ConfirmationLevel, DestructiveAction},
+    database::{
+        schema::{
+            ApplicationStatus, Conflict, Contact, Draft, Expense, Interview, JobApplication,
+            PositionCategory, Question, Reminder, Resolution, StatusChange,
+        },
+        worker::{DbRequest, DbResponse, RequestId},
+    },
+    import::ImportSummary,
+    postings::Board,
+};
 use serde::{Deserialize, Serialize};
+use std::{collections::HashSet, path::PathBuf};
 use strum::Display;
 
 #[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
@@ -10,23 +24,315 @@ pub enum Action {
     Suspend,
     Resume,
     Quit,
+    // Quit even with changes left unsaved, once that's been confirmed
+    QuitWithoutSaving,
     ClearScreen,
     Error(String),
+    // A failure that was worked around, e.g. a bad theme colour or a copy that didn't happen,
+    // shown in the status bar rather than the error popup
+    Warning(String),
+    // Non-error feedback shown in the status bar
+    Notify(String),
     Help,
+    ShowHelp(crate::app::Mode),
+    ToggleTutorial,
+    TogglePrivacy,
+    SetPrivacy(bool),
+    // Query the current view again, past any caches, as something else may have written
+    Refresh,
+    // Show or hide frame rate, input latency, queued actions and per-component draw times
+    TogglePerfOverlay,
+    // Hide everything behind the PIN prompt, and go back to where the app was once it's entered
+    Lock,
+    Unlock,
+    // Handed to the database task, which answers with a `DbResponse` carrying the same id
+    DbRequest(RequestId, DbRequest),
+    DbResponse(RequestId, DbResponse),
     //
-    DispatchJobSearch,
-    JobResults(Vec<JobApplication>),
+    JobChanged(JobChange),
+    NewJob,
+    // Log an application from just its company, position, link and date
+    QuickAdd,
+    ShowQuickAdd,
+    SaveJob(JobApplication),
+    // Save without checking the per-company limit, once past it has been confirmed
+    SaveJobUnchecked(JobApplication),
+    // A new application and the stored ones it looks like, to warn before adding a duplicate
+    ShowDuplicates(JobApplication, Vec<JobApplication>),
+    // Save a new application despite the ones it looks like, still checking the company limit
+    SaveDuplicate(JobApplication),
+    // What a write the last run didn't finish was doing, to ask whether to run it again
+    ShowInterrupted(String),
+    ResumeInterrupted,
+    // Leave the unfinished write, clearing it from the journal
+    DiscardInterrupted,
+    // Ask for a note on the application's new status before saving it, then save the two
+    PromptStatusNote(JobApplication),
+    // Pick a new status for the selected application without opening the edit form
+    ChangeStatus,
+    PickStatus(JobApplication),
+    // Move the selected application to the built-in status at this index, typed as a count
+    QuickStatus(usize),
+    // Change one field of the application from the detail view
+    EditField(JobApplication, InlineField),
+    SaveStatusChange(JobApplication, String),
+    DeleteJob(i32),
+    ViewJob,
+    OpenJobDetail(JobApplication),
+    // Open the detail view of the application with this UUID or id, if there is one
+    OpenJob(String),
+    ShowJobDetail(JobApplication, Vec<StatusChange>),
+    ScheduleInterview,
+    OpenInterviewForm(i32),
+    // The interview being scheduled, plus every existing interview and its company
+    PopulateInterviewForm(Interview, Vec<(Interview, String)>),
+    SaveInterview(Interview),
+    ShowReminders,
+    ReminderResults(Vec<(Reminder, String)>),
+    NewReminder,
+    OpenReminderForm(i32),
+    PopulateReminderForm(Reminder),
+    SaveReminder(Reminder),
+    DeleteReminder(i32),
+    ExportReminders,
+    // The bank of interview questions across every application
+    ShowQuestions,
+    QuestionResults(Vec<(Question, String)>),
+    // Log a question asked in an interview for the selected application
+    NewQuestion,
+    // The question to add or edit, and the company it was asked at
+    OpenQuestionForm(Question, String),
+    PopulateQuestionForm(Question, String),
+    SaveQuestion(Question),
+    DeleteQuestion(i32),
+    // Upcoming interviews and deadlines as an .ics file in the data directory
+    ExportCalendar,
+    // Fuzzy-find a command or application to run or open
+    OpenPalette,
+    PopulatePalette(Vec<JobApplication>),
+    ShowTags,
+    TagResults(Vec<(String, u32)>),
+    RenameTag(String, String),
+    DeleteTag(String),
+    ShowConflicts,
+    ConflictResults(Vec<Conflict>),
+    ResolveConflict(i32, Resolution),
+    OpenContactForm(Contact),
+    PopulateContactForm(Contact),
+    SaveContact(Contact),
+    DeleteContact(Contact),
+    ShowCategorySuggestions,
+    // Snooze the follow-up needing attention by the configured days, or mark it done
+    SnoozeFollowUp,
+    FollowUpDone,
+    SetFollowUp(i32, Option<u32>),
+    // Close every stale application as rejected or ghosted
+    RejectStale,
+    GhostStale,
+    SetStatus(Vec<i32>, ApplicationStatus),
+    // Switch the job list between its cards and a table
+    ToggleTable,
+    // Step the job list through its orders: added, priority, date applied and company
+    CycleSort,
+    // List saved postings with the applications, apart from them, or leave them out
+    ToggleSaved,
+    // Switch the job list to the next or previous view from the config
+    NextView,
+    PreviousView,
+    // Open the search bar, then filter the job list by its query
+    Search,
+    ApplySearch(String),
+    CategorySuggestions(Vec<Suggestion>),
+    // File the applications under the category
+    Recategorize(Vec<i32>, PositionCategory),
+    ShowStats,
+    DispatchStats,
+    CompareProfiles,
+    ShowTimeline,
+    DispatchTimeline,
+    ShowCalendar,
+    DispatchCalendar,
+    NewExpense,
+    // Open the expense form, linked to the given application if any
+    OpenExpenseForm(Option<i32>),
+    PopulateExpenseForm(Expense),
+    SaveExpense(Expense),
+    DeleteExpense(i32),
+    Export,
+    OpenExternal(String),
+    // Copy the selected job's URL, or the whole job as Markdown
+    CopyUrl,
+    CopyJob,
+    CopyToClipboard(String),
+    // Fill in one of the configured templates for the selected job, to copy
+    ShowTemplates,
+    PickTemplate(JobApplication),
+    ExportCsv(PathBuf),
+    Digest,
+    ImportFile(PathBuf),
+    // Ask for a job posting's link, then fetch it to fill in a new application
+    ImportUrl,
+    ImportFromUrl(String),
+    ImportSummary(ImportSummary),
+    // Dump the whole database to JSON in the data directory
+    Backup,
+    Restore(PathBuf),
+    ExportPreset(String),
+    ImportPreset(PathBuf),
+    PresetPreview(PathBuf, Vec<String>),
+    ApplyPreset(PathBuf),
     //
     IndexNext,
     IndexPrevious,
     FocusNext,
     FocusPrevious,
     UnFocusField,
+    Save,
+    Delete,
     ChangeMode(crate::app::Mode),
+    // Sent by the app once the mode has actually changed, including when leaving a popup
+    ModeChanged(crate::app::Mode),
+    // What a mode is showing, for the terminal title, e.g. "Acme, Backend Dev"
+    TitleContext(crate::app::Mode, Option<String>),
+    // Download the logo of the site with this host into the cache
+    #[cfg(feature = "logos")]
+    FetchLogo(String),
+    // Cached logos to show and the cells to show them in, replacing any shown before
+    #[cfg(feature = "logos")]
+    PlaceLogos(Vec<(ratatui::layout::Rect, std::path::PathBuf)>),
     PopulateEditJobForm(JobApplication),
+    // Put a draft left from an earlier edit back in the form
+    RestoreDraft(Draft),
+    // Suggest spellings for the misspelled word at the cursor, then put the one picked in
+    // place of it
+    SuggestSpelling,
+    ShowSpelling(String, Vec<String>),
+    ReplaceWord(String, String),
+    // A word kept in the personal word list, for every spell-checker to accept
+    WordAdded(String),
+    // Ask the boards whether these applications' postings are still up, answered with the
+    // ids of those taken down
+    CheckPostings(Vec<(i32, Board)>),
+    PostingsClosed(HashSet<i32>),
 
     EnterPopup(&'static str),
     ExitPopup,
-    DispatchNotesPopupData(&'static str),
-    NotesPopupData(&'static str),
+    // Open the notes editor on the selected job, then load the job into it
+    EditNotes,
+    // Show the selected job's details beside the list, resize them and scroll them
+    ToggleNotesPreview,
+    WidenNotesPreview,
+    NarrowNotesPreview,
+    ScrollNotesUp,
+    ScrollNotesDown,
+    // The selected job, for the details beside the list
+    PreviewJob(Option<JobApplication>),
+    DispatchNotesPopupData(JobApplication),
+    // Open the paste-update popup on the selected job, then load the job into it
+    PasteUpdate,
+    PromptPasteUpdate(JobApplication),
+    // Open the file picker on the documents in the edit form, which it sends back once picked
+    PickFiles,
+    PromptFilePicker(crate::database::schema::Files),
+    FilesPicked(crate::database::schema::Files),
+    // Ask a yes/no question, running `on_yes` if the answer is yes
+    Confirm {
+        title: String,
+        message: String,
+        on_yes: Box<Action>,
+    },
+    // Run the wrapped action once confirmed at the configured level for its kind
+    ConfirmDestructive(DestructiveAction, Box<Action>),
+    ShowConfirmation(ConfirmationLevel, String, String, Box<Action>),
+    // Ask for a line of text, then run `on_submit` with it in place of its own; what
+    // `on_submit` already holds is filled in to start from
+    Prompt {
+        title: String,
+        placeholder: String,
+        on_submit: Box<Action>,
+    },
+    ShowPrompt(String, String, Box<Action>),
+}
+
+/// A single write made through the database, pushed to subscribed components so they can
+/// update in place instead of refetching everything.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum JobChange {
+    Created(JobApplication),
+    Updated(JobApplication),
+    Deleted(i32),
+}
+
+impl Action {
+    /// Whether a count typed before the key bound to the action, e.g. `5j`, runs it that
+    /// many times. Only moves are, so a count can't repeat anything that changes data.
+    pub fn repeats(&self) -> bool {
+        matches!(
+            self,
+            Action::IndexNext
+                | Action::IndexPrevious
+                | Action::FocusNext
+                | Action::FocusPrevious
+                | Action::NextView
+                | Action::PreviousView
+        )
+    }
+
+    /// The text an action taking a line of input holds, for `Prompt` to start from.
+    pub fn input(&self) -> Option<String> {
+        match self {
+            Action::ImportFromUrl(text) | Action::ExportPreset(text) => Some(text.clone()),
+            Action::ExportCsv(path)
+            | Action::ImportFile(path)
+            | Action::Restore(path)
+            | Action::ImportPreset(path) => Some(path.display().to_string()),
+            _ => None,
+        }
+    }
+
+    /// The action with `input` in place of the text it holds, or `None` if it takes none.
+    pub fn with_input(self, input: String) -> Option<Action> {
+        Some(match self {
+            Action::ImportFromUrl(_) => Action::ImportFromUrl(input),
+            Action::ExportPreset(_) => Action::ExportPreset(input),
+            Action::ExportCsv(_) => Action::ExportCsv(input.into()),
+            Action::ImportFile(_) => Action::ImportFile(input.into()),
+            Action::Restore(_) => Action::Restore(input.into()),
+            Action::ImportPreset(_) => Action::ImportPreset(input.into()),
+            _ => return None,
+        })
+    }
+}
+
+impl JobChange {
+    pub fn id(&self) -> i32 {
+        match self {
+            JobChange::Created(job) | JobChange::Updated(job) => job.id,
+            JobChange::Deleted(id) => *id,
+        }
+    }
+}
+
+/// Notification topics a component can subscribe to. Subscribed components receive the
+/// matching actions even when they are not part of the current mode.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum Subscription {
+    Jobs,
+    Privacy,
+    /// Every `DbResponse`, for components that send their own requests.
+    Db,
+    /// Words added to the dictionary, for components that check spelling.
+    Spelling,
+}
+
+impl Subscription {
+    pub fn of(action: &Action) -> Option<Self> {
+        match action {
+            Action::JobChanged(_) => Some(Subscription::Jobs),
+            Action::SetPrivacy(_) => Some(Subscription::Privacy),
+            Action::DbResponse(..) => Some(Subscription::Db),
+            Action::WordAdded(_) => Some(Subscription::Spelling),
+            _ => None,
+        }
+    }
 }
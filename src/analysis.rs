@@ -0,0 +1,149 @@
+use std::fmt;
+
+/// Quick triage figures pulled out of a job description.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct DescriptionSummary {
+    pub must_have: usize,
+    pub nice_to_have: usize,
+    /// Highest "N+ years" mentioned.
+    pub years: Option<u32>,
+    pub seniority: Option<&'static str>,
+    /// Notable conditions such as on-call or travel.
+    pub signals: Vec<&'static str>,
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Section {
+    Other,
+    MustHave,
+    NiceToHave,
+}
+
+const NICE_TO_HAVE_HEADINGS: [&str; 4] = ["nice to have", "nice-to-have", "preferred", "bonus"];
+const MUST_HAVE_HEADINGS: [&str; 6] = [
+    "requirement",
+    "required",
+    "must have",
+    "must-have",
+    "qualifications",
+    "what you'll need",
+];
+// checked in order, so the more specific levels come first
+const SENIORITY: [(&str, &[&str]); 6] = [
+    ("principal", &["principal"]),
+    ("staff", &["staff engineer", "staff software"]),
+    (
+        "lead",
+        &["tech lead", "team lead", "lead engineer", "lead developer"],
+    ),
+    ("senior", &["senior", "sr."]),
+    (
+        "junior",
+        &["junior", "jr.", "entry level", "entry-level", "graduate"],
+    ),
+    ("mid-level", &["mid-level", "mid level", "intermediate"]),
+];
+const SIGNALS: [(&str, &[&str]); 5] = [
+    ("on-call", &["on-call", "on call", "pager"]),
+    ("travel", &["travel"]),
+    ("relocation", &["relocat"]),
+    ("clearance", &["clearance"]),
+    ("weekends", &["weekend"]),
+];
+
+pub fn analyze(description: &str) -> DescriptionSummary {
+    let text = description.to_lowercase();
+    let mut summary = DescriptionSummary::default();
+
+    let mut section = Section::Other;
+    for line in text.lines().map(str::trim).filter(|line| !line.is_empty()) {
+        match bullet_text(line) {
+            Some(item) => match section {
+                Section::MustHave if !is_nice_to_have(item) => summary.must_have += 1,
+                Section::MustHave | Section::NiceToHave => summary.nice_to_have += 1,
+                Section::Other if item.contains("required") => summary.must_have += 1,
+                Section::Other => {}
+            },
+            // any other line starts a new section, named by its heading if it has one
+            None => {
+                section = if NICE_TO_HAVE_HEADINGS.iter().any(|h| line.contains(h)) {
+                    Section::NiceToHave
+                } else if MUST_HAVE_HEADINGS.iter().any(|h| line.contains(h)) {
+                    Section::MustHave
+                } else {
+                    Section::Other
+                };
+            }
+        }
+    }
+
+    summary.years = years_of_experience(&text);
+    summary.seniority = SENIORITY
+        .iter()
+        .find(|(_, words)| words.iter().any(|w| text.contains(w)))
+        .map(|(level, _)| *level);
+    summary.signals = SIGNALS
+        .iter()
+        .filter(|(_, words)| words.iter().any(|w| text.contains(w)))
+        .map(|(signal, _)| *signal)
+        .collect();
+    summary
+}
+
+impl DescriptionSummary {
+    pub fn requirements(&self) -> usize {
+        self.must_have + self.nice_to_have
+    }
+}
+
+impl fmt::Display for DescriptionSummary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut parts = vec![match (self.must_have, self.nice_to_have) {
+            (_, 0) | (0, _) => format!("{} requirements", self.requirements()),
+            (must, nice) => format!(
+                "{} requirements ({must} must-have, {nice} nice-to-have)",
+                self.requirements()
+            ),
+        }];
+        if let Some(seniority) = self.seniority {
+            parts.push(seniority.to_string());
+        }
+        if let Some(years) = self.years {
+            parts.push(format!("mentions {years}+ yrs"));
+        }
+        parts.extend(self.signals.iter().map(|s| format!("mentions {s}")));
+        write!(f, "{}", parts.join(", "))
+    }
+}
+
+fn bullet_text(line: &str) -> Option<&str> {
+    if let Some(rest) = line.strip_prefix(['-', '*', '•', '·']) {
+        return Some(rest.trim());
+    }
+    // numbered lists: "1." or "1)"
+    let digits = line.len() - line.trim_start_matches(|c: char| c.is_ascii_digit()).len();
+    if digits > 0 {
+        return line[digits..].strip_prefix(['.', ')']).map(str::trim);
+    }
+    None
+}
+
+fn is_nice_to_have(item: &str) -> bool {
+    ["preferred", "nice to have", "a plus", "bonus"]
+        .iter()
+        .any(|w| item.contains(w))
+}
+
+// "5+ years", "3-5 years", "5 yrs" etc, keeping the largest lower bound found.
+fn years_of_experience(text: &str) -> Option<u32> {
+    let words = text.split_whitespace().collect::<Vec<_>>();
+    words
+        .windows(2)
+        .filter(|pair| pair[1].starts_with("year") || pair[1].starts_with("yr"))
+        .filter_map(|pair| {
+            let number = pair[0].trim_start_matches('(').split(['-', '–']).next()?;
+            number.trim_end_matches('+').parse::<u32>().ok()
+        })
+        .filter(|years| *years <= 30)
+        .max()
+}
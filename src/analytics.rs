@@ -0,0 +1,86 @@
+//! Insights into how the tracker itself is used, worked out from the local activity log.
+//! Nothing here leaves the machine.
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::database::{db::Database, query};
+
+// applications listed as most edited
+const MOST_EDITED: usize = 5;
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Insights {
+    /// Writes made on each day of the week, Monday first.
+    pub by_weekday: [u32; 7],
+    /// `(YYYY-MM-DD, writes)` for the days with the most writes, busiest first.
+    pub busiest_days: Vec<(String, u32)>,
+    /// `(id, company, position, edits)` for the applications edited most, most first.
+    pub most_edited: Vec<(i32, String, String, u32)>,
+    pub edits: u32,
+    /// Applications still in the tracker that have been created or edited.
+    pub jobs: u32,
+}
+
+impl Insights {
+    pub const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+
+    pub fn collect(db: &Database) -> Self {
+        let jobs = query::get_all_applications(db)
+            .into_iter()
+            .map(|job| (job.id, job))
+            .collect::<HashMap<_, _>>();
+        let mut insights = Insights::default();
+        let mut per_day = HashMap::<String, u32>::new();
+        let mut per_job = HashMap::<i32, u32>::new();
+        for activity in query::get_activity(db) {
+            // SQLite counts weekdays from Sunday
+            insights.by_weekday[(activity.weekday as usize + 6) % 7] += 1;
+            *per_day.entry(activity.day).or_default() += 1;
+            if !jobs.contains_key(&activity.job_id) {
+                continue;
+            }
+            let edits = per_job.entry(activity.job_id).or_default();
+            if activity.kind == "updated" {
+                *edits += 1;
+                insights.edits += 1;
+            }
+        }
+        insights.jobs = per_job.len() as u32;
+
+        let mut days = per_day.into_iter().collect::<Vec<_>>();
+        days.sort_by(|(a_day, a), (b_day, b)| b.cmp(a).then_with(|| b_day.cmp(a_day)));
+        days.truncate(3);
+        insights.busiest_days = days;
+
+        let mut edited = per_job
+            .into_iter()
+            .filter(|(_, edits)| *edits > 0)
+            .collect::<Vec<_>>();
+        edited.sort_by(|(a_id, a), (b_id, b)| b.cmp(a).then(a_id.cmp(b_id)));
+        insights.most_edited = edited
+            .into_iter()
+            .take(MOST_EDITED)
+            .map(|(id, edits)| {
+                let job = &jobs[&id];
+                (id, job.company_name.clone(), job.position.clone(), edits)
+            })
+            .collect();
+        insights
+    }
+
+    pub fn average_edits_per_job(&self) -> Option<f64> {
+        (self.jobs > 0).then(|| self.edits as f64 / self.jobs as f64)
+    }
+
+    /// The weekday with the most writes, if anything has been written.
+    pub fn busiest_weekday(&self) -> Option<&'static str> {
+        let (idx, count) = self
+            .by_weekday
+            .iter()
+            .enumerate()
+            .max_by(|(a_idx, a), (b_idx, b)| a.cmp(b).then(b_idx.cmp(a_idx)))?;
+        (*count > 0).then(|| Self::WEEKDAYS[idx])
+    }
+}
@@ -1,7 +1,18 @@
-use color_eyre::Result;
-use crossterm::event::KeyEvent;
+use std::{
+    collections::{HashMap, HashSet},
+    io::stdout,
+    time::{Duration, Instant},
+};
+
+use color_eyre::{Result, eyre::eyre};
+use crossterm::{
+    event::{KeyCode, KeyEvent, MouseButton, MouseEvent, MouseEventKind},
+    execute,
+    terminal::SetTitle,
+};
 use ratatui::{
-    buffer::Buffer,
+    Frame,
+    layout::{Position, Size},
     prelude::Rect,
     style::{Color, Style},
     widgets::Block,
@@ -11,14 +22,42 @@ use tokio::sync::mpsc;
 use tracing::{debug, info};
 
 use crate::{
-    action::Action,
+    action::{Action, Subscription},
+    clipboard,
     components::{
-        component::Component, edit_job::EditJob, job_list::JobList, notes_popup::NotesPopup,
-        search::Home,
+        calendar::CalendarView, component::Component, confirm_popup::ConfirmPopup,
+        conflicts::ConflictInbox, contact_form::ContactForm, duplicate_warning::DuplicateWarning,
+        edit_job::EditJob, error_popup::ErrorPopup, expense_form::ExpenseForm,
+        field_editor::FieldEditor, file_picker::FilePicker, help_popup::HelpPopup,
+        import_summary::ImportSummaryPopup, input_prompt::InputPrompt,
+        interview_form::InterviewForm, job_detail::JobDetail, job_list::JobList,
+        lock_screen::LockScreen, notes_popup::NotesPopup, notes_preview::NotesPreview,
+        palette::CommandPalette, paste_update::PasteUpdatePopup,
+        preset_preview::PresetPreviewPopup, question_form::QuestionForm, questions::QuestionBank,
+        quick_add::QuickAdd, recategorize::RecategorizeAssistant, recovery::RecoveryPrompt,
+        reminder_form::ReminderForm, reminders::RemindersPanel, search::Home,
+        search_bar::SearchBar, spelling_popup::SpellingPopup, stats::StatsView,
+        status_bar::StatusBar, status_note::StatusNotePopup, status_picker::StatusPicker,
+        tag_browser::TagBrowser, template_popup::TemplatePopup, timeline::TimelineView,
+        tutorial::Tutorial, util,
+    },
+    config::{Config, ConfirmationLevel, DestructiveAction},
+    database::{
+        db::Database,
+        journal::{self, Journal},
+        schema::{Expense, Interview, JobApplication, Reminder, Resolution},
+        worker::{self, DbRequest, DbResponse, RequestId},
     },
-    config::Config,
-    database::{db::Database, schema::JobApplication},
+    export,
+    import::posting,
+    opener,
+    perf::Perf,
+    preset::{self, Preset},
+    privacy::Privacy,
+    session::{Entry, Recorder, Replay},
+    statuses,
     tui::{Event, Tui},
+    unlock,
 };
 
 pub struct App {
@@ -27,63 +66,304 @@ pub struct App {
     frame_rate: f64,
     components: Vec<Box<dyn Component>>,
     current_mode_components: Vec<usize>, // store indices of components relevant to current mode
+    global_components: Vec<usize>,       // components active in every mode, drawn on top
     should_quit: bool,
     should_suspend: bool,
     mode: Mode,
     last_tick_key_events: Vec<KeyEvent>,
+    // digits typed so far as a count for the next key, e.g. the 5 of 5j
+    count: Option<usize>,
     action_tx: mpsc::UnboundedSender<Action>,
     action_rx: mpsc::UnboundedReceiver<Action>,
-    database: Database,
-    last_buffer: Buffer,
-    last_mode: Mode,
+    db_tx: mpsc::UnboundedSender<(RequestId, DbRequest)>,
+    // requests sent by the app itself, whose responses it handles
+    pending_requests: HashSet<RequestId>,
+    // where writes are recorded until committed, and the write a previous run didn't finish,
+    // until it's run again or left
+    journal: Option<Journal>,
+    interrupted: Option<DbRequest>,
+    // the view under any popups, and the popups open over it, bottom first; `mode` is the
+    // topmost, which takes all input
+    base_mode: Mode,
+    popups: Vec<Mode>,
+    last_reminder_check: Option<Instant>,
+    // when a key was last pressed or the mouse used, for locking after a while without
+    last_input: Instant,
+    // the view and popups the app was showing when locked, to go back to on unlocking
+    locked_from: Option<(Mode, Vec<Mode>)>,
+    // timings for the performance overlay, collected while it's shown
+    perf: Option<Perf>,
+    // where each component drew a popup in the last frame, so a click outside it can close it
+    popup_areas: HashMap<usize, Rect>,
+    // something changed since the last frame; idle ticks leave the screen as it is
+    dirty: bool,
+    // what each mode last reported showing, and the terminal title last written from it
+    title_contexts: HashMap<Mode, String>,
+    title: String,
+    // logos to show over the job list, kept while other modes are up
+    #[cfg(feature = "logos")]
+    logos: Vec<(Rect, std::path::PathBuf)>,
+    // components are only initialised once their mode is first entered
+    initialized: Vec<bool>,
+    size: Size,
+    // where the session is being recorded, and the recording being played back instead of
+    // taking input
+    recorder: Option<Recorder>,
+    replay: Option<Replay>,
+    focus: FocusManager,
 }
 
-#[derive(Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+/// Which pane keys go to, in modes showing more than one that can take focus, e.g. the job
+/// list and the notes beside it. Tab and Shift+Tab move between them; focus is kept per mode,
+/// so it's still where it was after a popup closes.
+#[derive(Default)]
+struct FocusManager {
+    // the index into the components of each mode's focused pane
+    focused: HashMap<Mode, usize>,
+}
+
+impl FocusManager {
+    /// The panes among `current`, in order, when there's more than one to choose between.
+    fn panes(components: &[Box<dyn Component>], current: &[usize]) -> Vec<usize> {
+        let panes = current
+            .iter()
+            .copied()
+            .filter(|idx| components[*idx].focusable())
+            .collect::<Vec<_>>();
+        if panes.len() > 1 { panes } else { Vec::new() }
+    }
+
+    /// The focused pane, the first until another is focused.
+    fn focused(&self, mode: Mode, panes: &[usize]) -> Option<usize> {
+        self.focused
+            .get(&mode)
+            .filter(|idx| panes.contains(idx))
+            .or(panes.first())
+            .copied()
+    }
+
+    /// Whether a pane other than the mode's main one, the first, has focus.
+    fn aside(&self, mode: Mode, panes: &[usize]) -> bool {
+        self.focused(mode, panes) != panes.first().copied()
+    }
+
+    /// Move focus to the next pane, or the previous one, wrapping around.
+    fn cycle(&mut self, mode: Mode, panes: &[usize], forward: bool) {
+        let Some(focused) = self.focused(mode, panes) else {
+            return;
+        };
+        let at = panes.iter().position(|idx| *idx == focused).unwrap_or(0);
+        let next = if forward {
+            (at + 1) % panes.len()
+        } else {
+            (at + panes.len() - 1) % panes.len()
+        };
+        self.focused.insert(mode, panes[next]);
+    }
+}
+
+/// The largest count a key can be given, so a stray run of digits can't flood the queue.
+const MAX_COUNT: usize = 999;
+
+/// How often due reminders are looked for.
+const REMINDER_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+
+#[derive(
+    Default, Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize, strum::EnumString,
+)]
+#[strum(ascii_case_insensitive)]
 pub enum Mode {
     #[default]
     Home,
     EditJob,
+    // also accepts the old name so existing keybinding files keep working
+    #[strum(serialize = "ViewJob", serialize = "JobDetail")]
+    ViewJob,
+    Stats,
+    Timeline,
+    Calendar,
+    #[strum(disabled)]
     Popup(&'static str),
+    /// Not a mode that is entered: components in `Global` are active in every mode and
+    /// keybindings in `Global` apply wherever a mode doesn't bind the key itself.
+    Global,
+}
+impl Mode {
+    /// Whether digits typed here are a count for the next key rather than text or a choice.
+    pub fn takes_counts(self) -> bool {
+        matches!(
+            self,
+            Mode::Home | Mode::ViewJob | Mode::Stats | Mode::Timeline | Mode::Calendar
+        )
+    }
 }
 
 impl App {
-    pub fn new(tick_rate: f64, frame_rate: f64) -> Result<Self> {
+    /// Rates left as `None` are taken from the config. An encrypted database is unlocked
+    /// first.
+    pub async fn new(tick_rate: Option<f64>, frame_rate: Option<f64>) -> Result<Self> {
+        let mut database = Database::default();
+        unlock::prompt(&mut database, &Config::new()?).await?;
+        Self::with_database(tick_rate, frame_rate, database)
+    }
+
+    pub fn with_database(
+        tick_rate: Option<f64>,
+        frame_rate: Option<f64>,
+        mut database: Database,
+    ) -> Result<Self> {
+        let config = Config::new()?;
+        let tick_rate = tick_rate.unwrap_or(config.config.tick_rate);
+        let frame_rate = frame_rate.unwrap_or(config.config.frame_rate);
+        if tick_rate <= 0.0 || frame_rate <= 0.0 {
+            return Err(eyre!("The tick and frame rates must be above 0"));
+        }
         let (action_tx, action_rx) = mpsc::unbounded_channel();
 
         let mode = Mode::default();
         let components: Vec<Box<dyn Component>> = vec![
             Box::new(Home::new()),
             Box::new(JobList::new()),
+            Box::new(NotesPreview::new()),
             Box::new(EditJob::new()),
+            Box::new(JobDetail::new()),
+            Box::new(StatsView::new()),
+            Box::new(TimelineView::new()),
+            Box::new(CalendarView::new()),
+            Box::new(ExpenseForm::new()),
+            Box::new(InterviewForm::new()),
             Box::new(NotesPopup::new()),
+            Box::new(ConfirmPopup::new()),
+            Box::new(ErrorPopup::new()),
+            Box::new(HelpPopup::new()),
+            Box::new(ImportSummaryPopup::new()),
+            Box::new(PresetPreviewPopup::new()),
+            Box::new(RemindersPanel::new()),
+            Box::new(QuestionBank::new()),
+            Box::new(ConflictInbox::new()),
+            Box::new(RecategorizeAssistant::new()),
+            Box::new(SearchBar::new()),
+            Box::new(StatusNotePopup::new()),
+            Box::new(StatusPicker::new()),
+            Box::new(FieldEditor::new()),
+            Box::new(PasteUpdatePopup::new()),
+            Box::new(TemplatePopup::new()),
+            Box::new(InputPrompt::new()),
+            Box::new(QuickAdd::new()),
+            Box::new(SpellingPopup::new()),
+            Box::new(FilePicker::new()),
+            Box::new(LockScreen::new()),
+            Box::new(TagBrowser::new()),
+            Box::new(CommandPalette::new()),
+            Box::new(DuplicateWarning::new()),
+            Box::new(RecoveryPrompt::new()),
+            Box::new(ContactForm::new()),
+            Box::new(ReminderForm::new()),
+            Box::new(QuestionForm::new()),
+            Box::new(Tutorial::new()),
+            Box::new(StatusBar::new()),
         ];
         let mut current_mode_components = Vec::new();
+        let mut global_components = Vec::new();
         for (idx, component) in components.iter().enumerate() {
             if component.mode() == mode {
                 current_mode_components.push(idx);
+            } else if component.mode() == Mode::Global {
+                global_components.push(idx);
             }
         }
+        database.create()?;
+        statuses::load(&database);
+        database.register_change_handler(action_tx.clone());
+        // its requests would keep application details beside the database in plain text
+        let journal = Journal::for_database(&database).filter(|_| !config.encryption.enabled);
+        let interrupted = journal.as_ref().and_then(Journal::pending);
+        if let Some(request) = &interrupted {
+            action_tx.send(Action::ChangeMode(Mode::Popup("recovery")))?;
+            action_tx.send(Action::ShowInterrupted(journal::describe(request)))?;
+        }
+        let db_tx = worker::spawn(database, journal.clone(), action_tx.clone());
         Ok(Self {
             tick_rate,
             frame_rate,
-            components,
             current_mode_components,
+            global_components,
             should_quit: false,
             should_suspend: false,
-            config: Config::new()?,
+            config,
             mode: Mode::Home,
             last_tick_key_events: Vec::new(),
+            count: None,
             action_tx,
             action_rx,
-            database: Database::default(),
-            last_buffer: Buffer::empty(Rect::new(0, 0, 0, 0)),
-            last_mode: Mode::Home,
+            db_tx,
+            pending_requests: HashSet::new(),
+            journal,
+            interrupted,
+            base_mode: Mode::Home,
+            popups: Vec::new(),
+            last_reminder_check: None,
+            last_input: Instant::now(),
+            locked_from: None,
+            perf: None,
+            popup_areas: HashMap::new(),
+            dirty: true,
+            title_contexts: HashMap::new(),
+            title: String::new(),
+            #[cfg(feature = "logos")]
+            logos: Vec::new(),
+            initialized: vec![false; components.len()],
+            size: Size::default(),
+            recorder: None,
+            replay: None,
+            focus: FocusManager::default(),
+            components,
         })
     }
 
+    /// Record the session to `path` from here on, for replaying with `--replay`.
+    pub fn record_to(&mut self, path: &std::path::Path) -> Result<()> {
+        self.recorder = Some(Recorder::create(path)?);
+        Ok(())
+    }
+
+    /// Play back a recording instead of taking input. Space pauses, `n` steps through it one
+    /// entry at a time while paused, and `q` quits; input is handed back once it has played.
+    pub fn replay(&mut self, entries: Vec<Entry>) {
+        self.replay = Some(Replay::new(entries));
+    }
+
+    /// Merge a preset into the running config, persist it so it is loaded on the next start
+    /// and hand the new config to every component.
+    fn apply_preset(&mut self, path: &std::path::Path) -> Result<()> {
+        let preset = Preset::load(path)?;
+        preset.apply(&mut self.config);
+        preset.save(&preset::active_preset_path(&self.config))?;
+        for component in self.components.iter_mut() {
+            component.register_config_handler(self.config.clone())?;
+        }
+        for warning in self.config.theme_warnings.iter() {
+            self.action_tx.send(Action::Warning(warning.clone()))?;
+        }
+        Ok(())
+    }
+
+    /// Queue an action to be handled once the app is running.
+    pub fn dispatch(&mut self, action: Action) -> Result<()> {
+        // a recording already holds the actions its session was started with
+        if self.replay.is_some() {
+            return Ok(());
+        }
+        if let Some(recorder) = &mut self.recorder {
+            recorder.record(&Entry::Action(Box::new(action.clone())))?;
+        }
+        self.action_tx.send(action)?;
+        Ok(())
+    }
+
     pub async fn run(&mut self) -> Result<()> {
         let mut tui = Tui::new()?
-            // .mouse(true) // uncomment this line to enable mouse support
+            .mouse(self.config.config.mouse)
             .tick_rate(self.tick_rate)
             .frame_rate(self.frame_rate);
         tui.enter()?;
@@ -94,10 +374,17 @@ impl App {
         for component in self.components.iter_mut() {
             component.register_config_handler(self.config.clone())?;
         }
-        for component in self.components.iter_mut() {
-            component.init(tui.size()?)?;
+        self.size = tui.size()?;
+        self.init_components(self.mode)?;
+        self.init_components(Mode::Global)?;
+        for warning in self.config.theme_warnings.iter() {
+            self.action_tx.send(Action::Warning(warning.clone()))?;
         }
 
+        // draw straight away instead of waiting for the first render tick; the job list fills
+        // in once the database task answers
+        self.render(&mut tui)?;
+
         let action_tx = self.action_tx.clone();
         loop {
             self.handle_events(&mut tui).await?;
@@ -117,18 +404,206 @@ impl App {
         Ok(())
     }
 
-    fn handle_job_search(&mut self) -> Result<()> {
-        let test_results = vec![
-            JobApplication::test(0),
-            JobApplication::test(1),
-            JobApplication::test(2),
-            JobApplication::test(3),
-            JobApplication::test(4),
-            JobApplication::test(5),
-        ];
-        let action_tx = self.action_tx.clone();
-        action_tx.send(Action::JobResults(test_results));
+    fn request(&mut self, request: DbRequest) -> Result<()> {
+        let req_id = RequestId::next();
+        self.pending_requests.insert(req_id);
+        self.action_tx.send(Action::DbRequest(req_id, request))?;
+        Ok(())
+    }
 
+    /// Add a new application, once it's known not to be a duplicate, checking the company limit
+    /// first when there is one.
+    fn save_new(&mut self, job: JobApplication) -> Result<()> {
+        if job.is_active && self.config.config.company_application_limit > 0 {
+            self.request(DbRequest::ActiveAtCompany(job))
+        } else {
+            self.request(DbRequest::SaveJob(job))
+        }
+    }
+
+    /// Turn a response from the database task into the actions the rest of the app expects.
+    fn handle_db_response(&mut self, response: DbResponse) -> Result<()> {
+        match response {
+            DbResponse::StatusHistory(job, history) => {
+                self.action_tx.send(Action::ChangeMode(Mode::ViewJob))?;
+                self.action_tx.send(Action::ShowJobDetail(job, history))?;
+            }
+            DbResponse::Interviews(job_id, existing) => {
+                self.action_tx
+                    .send(Action::ChangeMode(Mode::Popup("interview_form")))?;
+                self.action_tx.send(Action::PopulateInterviewForm(
+                    Interview {
+                        job_id,
+                        ..Interview::default()
+                    },
+                    existing,
+                ))?;
+            }
+            DbResponse::SimilarApplications(job, similar) => {
+                if similar.is_empty() {
+                    self.save_new(job)?;
+                } else {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("duplicate_warning")))?;
+                    self.action_tx.send(Action::ShowDuplicates(job, similar))?;
+                }
+            }
+            DbResponse::ActiveAtCompany(job, count) => {
+                let limit = self.config.config.company_application_limit as usize;
+                if count + 1 >= limit {
+                    self.action_tx.send(Action::Notify(format!(
+                        "{count} active application(s) at {} already",
+                        job.company_name
+                    )))?;
+                    self.action_tx.send(Action::ConfirmDestructive(
+                        DestructiveAction::ExceedCompanyLimit,
+                        Box::new(Action::SaveJobUnchecked(job)),
+                    ))?;
+                } else {
+                    self.request(DbRequest::SaveJob(job))?;
+                }
+            }
+            DbResponse::JobSaved(job) => self
+                .action_tx
+                .send(Action::Notify(format!("Saved {}", job.company_name)))?,
+            DbResponse::JobDeleted(_) => {}
+            DbResponse::InterviewSaved(interview) => self.action_tx.send(Action::Notify(
+                format!("Interview scheduled for {}", interview.starts_at),
+            ))?,
+            DbResponse::ExpenseSaved(_) => {
+                self.action_tx
+                    .send(Action::Notify("Expense saved".into()))?;
+                self.action_tx.send(Action::DispatchStats)?;
+            }
+            DbResponse::ExpenseDeleted(_) => self.action_tx.send(Action::DispatchStats)?,
+            DbResponse::Imported(summary) => {
+                self.action_tx
+                    .send(Action::ChangeMode(Mode::Popup("import_summary")))?;
+                self.action_tx.send(Action::ImportSummary(summary))?;
+            }
+            DbResponse::Exported(path, count) => self.action_tx.send(Action::Notify(format!(
+                "Exported {count} application(s) to {}",
+                path.display()
+            )))?,
+            DbResponse::FollowUpSet(_, days) => {
+                self.action_tx.send(Action::Notify(match days {
+                    Some(days) => format!("Follow-up snoozed for {days} day(s)"),
+                    None => "Marked as followed up".to_string(),
+                }))?
+            }
+            DbResponse::BackedUp(path, count) => self.action_tx.send(Action::Notify(format!(
+                "Backed up {count} application(s) to {}",
+                path.display()
+            )))?,
+            DbResponse::Digest(digest) => {
+                let data_dir = &self.config.config.data_dir;
+                match export::digest::deliver(&digest, &self.config.digest, data_dir) {
+                    Ok(destination) => self
+                        .action_tx
+                        .send(Action::Notify(format!("Weekly digest {destination}")))?,
+                    Err(err) => self
+                        .action_tx
+                        .send(Action::Error(format!("Failed to deliver digest: {err}")))?,
+                }
+            }
+            DbResponse::Reminders(reminders) => {
+                self.action_tx.send(Action::ReminderResults(reminders))?
+            }
+            DbResponse::ReminderSaved(reminder) => self.action_tx.send(Action::Notify(format!(
+                "Reminder set for {}",
+                reminder.due_at
+            )))?,
+            DbResponse::ReminderDeleted(_) => self.request(DbRequest::Reminders)?,
+            DbResponse::Questions(questions) => {
+                self.action_tx.send(Action::QuestionResults(questions))?
+            }
+            DbResponse::QuestionSaved(_) => {
+                self.action_tx
+                    .send(Action::Notify("Question saved".into()))?;
+                self.request(DbRequest::Questions)?;
+            }
+            DbResponse::QuestionDeleted(_) => self.request(DbRequest::Questions)?,
+            DbResponse::Tags(tags) => self.action_tx.send(Action::TagResults(tags))?,
+            DbResponse::AllJobs(jobs) => self.action_tx.send(Action::PopulatePalette(jobs))?,
+            DbResponse::TagsChanged(message) => {
+                self.action_tx.send(Action::Notify(message))?;
+                self.request(DbRequest::Tags)?;
+            }
+            DbResponse::RemindersDue(due) => {
+                let privacy = Privacy::new(self.config.config.privacy_mode);
+                for (reminder, company) in due {
+                    let company = privacy.text(&company, &format!("Company #{}", reminder.job_id));
+                    if self.config.reminders.desktop_notifications
+                        && let Err(err) = notify_desktop(&company, &reminder.message)
+                    {
+                        self.action_tx.send(Action::Error(format!(
+                            "Failed to show desktop notification: {err}"
+                        )))?;
+                    }
+                    self.action_tx.send(Action::Notify(format!(
+                        "Reminder: {company} — {}",
+                        reminder.message
+                    )))?;
+                }
+            }
+            DbResponse::RemindersExported(path, count) => self.action_tx.send(Action::Notify(
+                format!("Exported {count} reminder(s) to {}", path.display()),
+            ))?,
+            DbResponse::CalendarExported(path, count) => {
+                self.action_tx.send(Action::Notify(format!(
+                    "Exported {count} interview(s) and deadline(s) to {}",
+                    path.display()
+                )))?
+            }
+            DbResponse::ContactSaved(contact) => self
+                .action_tx
+                .send(Action::Notify(format!("Saved contact {}", contact.name)))?,
+            DbResponse::ContactDeleted(contact) => self
+                .action_tx
+                .send(Action::Notify(format!("Removed contact {}", contact.name)))?,
+            DbResponse::Conflicts(conflicts) => {
+                self.action_tx.send(Action::ConflictResults(conflicts))?
+            }
+            DbResponse::ConflictResolved(_, resolution) => {
+                let message = match resolution {
+                    Resolution::KeepBoth => "Kept both applications",
+                    Resolution::Merge(_) => "Merged into the existing application",
+                    Resolution::Discard => "Discarded the incoming application",
+                };
+                self.action_tx.send(Action::Notify(message.into()))?;
+                self.request(DbRequest::Conflicts)?;
+            }
+            DbResponse::CategorySuggestions(suggestions) => self
+                .action_tx
+                .send(Action::CategorySuggestions(suggestions))?,
+            DbResponse::Recategorized(category, count) => {
+                self.action_tx.send(Action::Notify(format!(
+                    "Moved {count} application(s) to {category}"
+                )))?;
+                self.request(DbRequest::CategorySuggestions)?;
+            }
+            DbResponse::StatusSet(status, count) => self.action_tx.send(Action::Notify(
+                format!("Moved {count} application(s) to {status}"),
+            ))?,
+            DbResponse::Failed(message) => self.action_tx.send(Action::Error(message))?,
+            // only requested by the components that show them
+            DbResponse::JobPage(..)
+            | DbResponse::StatusTrails(_)
+            | DbResponse::Timeline(..)
+            | DbResponse::Calendar(..)
+            | DbResponse::Backlinks(_)
+            | DbResponse::ActivityLog(..)
+            | DbResponse::FollowUps(_)
+            | DbResponse::Stale(_)
+            | DbResponse::BoardPostings(_)
+            | DbResponse::PrioritySignals(_)
+            | DbResponse::Stats(_)
+            | DbResponse::ProfileComparison(_)
+            | DbResponse::Draft(..)
+            | DbResponse::DraftSaved(_)
+            | DbResponse::DraftDiscarded(_)
+            | DbResponse::Invalidated => {}
+        }
         Ok(())
     }
 
@@ -136,32 +611,199 @@ impl App {
         let Some(event) = tui.next_event().await else {
             return Ok(());
         };
+        if self.replay.is_some() {
+            return self.handle_replay_event(event);
+        }
+        if let Some(recorder) = &mut self.recorder
+            && Entry::is_input(&event)
+        {
+            recorder.record(&Entry::Event(event.clone()))?;
+        }
+        self.handle_event(event)
+    }
+
+    /// While a recording plays, the terminal's own input only controls playback; the
+    /// recording's input is fed in a step per tick instead.
+    fn handle_replay_event(&mut self, event: Event) -> Result<()> {
+        let Some(replay) = &mut self.replay else {
+            return Ok(());
+        };
+        match event {
+            Event::Key(key) => match key.code {
+                KeyCode::Char(' ') => {
+                    replay.paused = !replay.paused;
+                    let state = if replay.paused { "paused" } else { "resumed" };
+                    let message = format!("Replay {state} at {}", replay.progress());
+                    self.action_tx.send(Action::Notify(message))?;
+                }
+                KeyCode::Char('n') => replay.step(),
+                KeyCode::Char('q') | KeyCode::Esc => self.action_tx.send(Action::Quit)?,
+                _ => {}
+            },
+            Event::Tick => {
+                let entry = replay.next();
+                let finished = replay.is_finished();
+                self.handle_event(Event::Tick)?;
+                match entry {
+                    Some(Entry::Event(event)) => self.handle_event(event)?,
+                    Some(Entry::Action(action)) => self.action_tx.send(*action)?,
+                    None => {}
+                }
+                if finished {
+                    self.replay = None;
+                    self.action_tx
+                        .send(Action::Notify("Replay finished".into()))?;
+                }
+            }
+            Event::Mouse(_) | Event::Paste(_) => {}
+            event => self.handle_event(event)?,
+        }
+        Ok(())
+    }
+
+    fn handle_event(&mut self, event: Event) -> Result<()> {
         let action_tx = self.action_tx.clone();
+        if !matches!(event, Event::Tick | Event::Render) {
+            self.dirty = true;
+        }
+        if matches!(event, Event::Key(_) | Event::Mouse(_) | Event::Paste(_)) {
+            self.last_input = Instant::now();
+            if let Some(perf) = &mut self.perf {
+                perf.input_received();
+            }
+        }
+        let mut count = None;
         match event {
             Event::Quit => action_tx.send(Action::Quit)?,
             Event::Tick => action_tx.send(Action::Tick)?,
             Event::Render => action_tx.send(Action::Render)?,
             Event::Resize(x, y) => action_tx.send(Action::Resize(x, y))?,
-            Event::Key(key) => self.handle_key_event(key)?,
+            Event::Key(key) if self.count_digit(key) || self.move_focus(key) => return Ok(()),
+            Event::Key(key) => {
+                count = self.count.take();
+                self.handle_key_event(key, count)?;
+            }
+            // closes the popup as Esc would, rather than clicking through to it
+            Event::Mouse(mouse) if self.clicked_outside_popup(mouse) => {
+                return self.handle_event(Event::Key(KeyEvent::from(KeyCode::Esc)));
+            }
 
             _ => {}
         }
-        for component in self.current_mode_components.iter() {
-            let component = self.components.get_mut(*component).unwrap();
-            if let Some(action) = component.handle_events(Some(event.clone()))? {
-                action_tx.send(action)?;
+        // keys go to the focused pane alone, when there's more than one
+        let panes = FocusManager::panes(&self.components, &self.current_mode_components);
+        let focused = self.focus.focused(self.mode, &panes);
+        for idx in self
+            .current_mode_components
+            .iter()
+            .chain(self.global_components.iter())
+        {
+            if matches!(event, Event::Key(_))
+                && focused.is_some_and(|focused| focused != *idx)
+                && !self.global_components.contains(idx)
+            {
+                continue;
+            }
+            let Some(component) = self.components.get_mut(*idx) else {
+                continue;
+            };
+            // keys components handle themselves repeat where they say a count applies
+            let times = match (&event, count) {
+                (Event::Key(key), Some(count)) if component.repeats(*key) => count,
+                _ => 1,
+            };
+            for _ in 0..times {
+                match component.handle_events(Some(event.clone())) {
+                    Ok(Some(action)) => action_tx.send(action)?,
+                    Ok(None) => {}
+                    Err(err) => action_tx.send(component_error(component.as_ref(), err))?,
+                }
             }
         }
         Ok(())
     }
 
-    fn handle_key_event(&mut self, key: KeyEvent) -> Result<()> {
-        let action_tx = self.action_tx.clone();
+    fn clicked_outside_popup(&self, mouse: MouseEvent) -> bool {
+        let position = Position::new(mouse.column, mouse.row);
+        matches!(self.mode, Mode::Popup(_))
+            && mouse.kind == MouseEventKind::Down(MouseButton::Left)
+            && self
+                .current_mode_components
+                .iter()
+                .filter_map(|idx| self.popup_areas.get(idx))
+                .any(|area| !area.contains(position))
+    }
+
+    /// Tab and Shift+Tab move focus between the panes on screen, and Esc takes it back to the
+    /// main one. Returns whether the key was taken.
+    fn move_focus(&mut self, key: KeyEvent) -> bool {
+        let panes = FocusManager::panes(&self.components, &self.current_mode_components);
+        if self.locked_from.is_some() || panes.is_empty() {
+            return false;
+        }
+        match key.code {
+            KeyCode::Tab if key.modifiers.is_empty() => self.focus.cycle(self.mode, &panes, true),
+            KeyCode::BackTab => self.focus.cycle(self.mode, &panes, false),
+            KeyCode::Esc if self.focus.aside(self.mode, &panes) => {
+                self.focus.focused.insert(self.mode, panes[0]);
+            }
+            _ => return false,
+        }
+        true
+    }
+
+    /// Tell the current mode's panes which of them has focus, if any.
+    fn show_focus(&mut self) {
+        let panes = FocusManager::panes(&self.components, &self.current_mode_components);
+        let focused = self.focus.focused(self.mode, &panes);
+        for idx in &self.current_mode_components {
+            self.components[*idx].set_focus(focused == Some(*idx));
+        }
+    }
+
+    /// Take an unbound digit as part of a count for the next key, in modes without text to
+    /// type. Returns whether the key was taken.
+    fn count_digit(&mut self, key: KeyEvent) -> bool {
+        let KeyCode::Char(digit) = key.code else {
+            return false;
+        };
+        let Some(digit) = digit.to_digit(10) else {
+            return false;
+        };
+        if self.locked_from.is_some()
+            || !self.mode.takes_counts()
+            || !key.modifiers.is_empty()
+            || self.config.keybindings.action(self.mode, &[key]).is_some()
+        {
+            return false;
+        }
+        self.count = Some(
+            self.count
+                .unwrap_or(0)
+                .saturating_mul(10)
+                .saturating_add(digit as usize)
+                .min(MAX_COUNT),
+        );
+        true
+    }
+
+    fn handle_key_event(&mut self, key: KeyEvent, count: Option<usize>) -> Result<()> {
+        // only the PIN prompt sees keys while locked, not even global bindings
+        if self.locked_from.is_some() {
+            return Ok(());
+        }
+        // a pane beside the main one takes keys itself, bar the bindings every mode shares
+        let panes = FocusManager::panes(&self.components, &self.current_mode_components);
+        let mode = if self.focus.aside(self.mode, &panes) {
+            Mode::Global
+        } else {
+            self.mode
+        };
         let keymap = &self.config.keybindings;
-        match keymap.get(&vec![key]) {
+        match keymap.action(mode, &[key]) {
             Some(action) => {
                 info!("Got action: {action:?}");
-                action_tx.send(action.clone())?;
+                self.send_counted(action.clone(), count)?;
             }
             _ => {
                 // If the key was not handled as a single key action,
@@ -169,93 +811,685 @@ impl App {
                 self.last_tick_key_events.push(key);
 
                 // Check for multi-key combinations
-                if let Some(action) = keymap.get(&self.last_tick_key_events) {
+                if let Some(action) = keymap.action(mode, &self.last_tick_key_events) {
                     info!("Got action: {action:?}");
-                    action_tx.send(action.clone())?;
+                    self.send_counted(action.clone(), count)?;
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Send a bound action, with the count typed before its key: moves repeat, and a count
+    /// before changing status picks the status by number.
+    fn send_counted(&self, action: Action, count: Option<usize>) -> Result<()> {
+        match (action, count) {
+            (Action::ChangeStatus, Some(index)) => {
+                self.action_tx.send(Action::QuickStatus(index))?
+            }
+            (action, Some(times)) if action.repeats() => {
+                for _ in 0..times {
+                    self.action_tx.send(action.clone())?;
                 }
             }
+            (action, _) => self.action_tx.send(action)?,
         }
         Ok(())
     }
 
     fn handle_actions(&mut self, tui: &mut Tui) -> Result<()> {
+        if let Some(perf) = &mut self.perf {
+            perf.actions_queued(self.action_rx.len());
+        }
         while let Ok(action) = self.action_rx.try_recv() {
             if action != Action::Tick && action != Action::Render {
                 debug!("{action:?}");
+                self.dirty = true;
             }
             match action {
                 Action::Tick => {
                     self.last_tick_key_events.drain(..);
+                    if self
+                        .last_reminder_check
+                        .is_none_or(|checked| checked.elapsed() >= REMINDER_CHECK_INTERVAL)
+                    {
+                        self.last_reminder_check = Some(Instant::now());
+                        self.request(DbRequest::FireReminders)?;
+                    }
+                    if self.locked_from.is_none()
+                        && let Some(timeout) = self.config.lock.idle_timeout()
+                        && self.last_input.elapsed() >= timeout
+                    {
+                        self.action_tx.send(Action::Lock)?;
+                    }
                 }
-                Action::Quit => self.should_quit = true,
+                Action::Quit if self.unsaved_changes() => self.action_tx.send(Action::Confirm {
+                    title: "Unsaved changes".into(),
+                    message: "Quit without saving your changes?".into(),
+                    on_yes: Box::new(Action::QuitWithoutSaving),
+                })?,
+                Action::Quit | Action::QuitWithoutSaving => self.should_quit = true,
                 Action::Suspend => self.should_suspend = true,
                 Action::Resume => self.should_suspend = false,
-                Action::ClearScreen => tui.terminal.clear()?,
+                Action::TogglePerfOverlay => {
+                    self.perf = match self.perf {
+                        Some(_) => None,
+                        None => Some(Perf::default()),
+                    };
+                }
+                Action::ClearScreen => {
+                    tui.terminal.clear()?;
+                    #[cfg(feature = "logos")]
+                    self.show_logos()?;
+                }
                 Action::Resize(w, h) => self.handle_resize(tui, w, h)?,
-                Action::Render => self.render(tui)?,
-                Action::DispatchJobSearch => self.handle_job_search()?,
-                Action::ChangeMode(new_mode) => {
-                    self.last_mode = self.mode;
-                    self.mode = new_mode;
-                    self.current_mode_components.clear();
-                    for (idx, component) in self.components.iter().enumerate() {
-                        if component.mode() == self.mode {
-                            self.current_mode_components.push(idx);
+                #[cfg(feature = "logos")]
+                Action::FetchLogo(ref host) => {
+                    let data_dir = self.config.config.data_dir.clone();
+                    let host = host.clone();
+                    tokio::task::spawn_blocking(move || {
+                        if let Err(err) = crate::logo::fetch(&data_dir, &host) {
+                            debug!("No logo for {host}: {err}");
                         }
+                    });
+                }
+                Action::CheckPostings(ref postings) => {
+                    let postings = postings.clone();
+                    let tx = self.action_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let closed = postings
+                            .iter()
+                            .filter(|(_, board)| board.is_closed() == Some(true))
+                            .map(|(id, _)| *id)
+                            .collect();
+                        let _ = tx.send(Action::PostingsClosed(closed));
+                    });
+                }
+                #[cfg(feature = "logos")]
+                Action::PlaceLogos(ref logos) => {
+                    self.logos = logos.clone();
+                    self.show_logos()?;
+                }
+                Action::Render => {
+                    // every component is asked, so none is left holding a change
+                    let changed = self
+                        .components
+                        .iter_mut()
+                        .map(|component| component.take_redraw())
+                        .fold(false, |any, changed| any | changed);
+                    if self.dirty || changed {
+                        self.render(tui)?;
+                    }
+                }
+                Action::DbRequest(req_id, ref request) => {
+                    self.db_tx.send((req_id, request.clone()))?
+                }
+                Action::DbResponse(req_id, ref response)
+                    if self.pending_requests.remove(&req_id) =>
+                {
+                    self.handle_db_response(response.clone())?
+                }
+                Action::ChangeMode(new_mode) => self.change_mode(new_mode)?,
+                Action::TitleContext(mode, ref context) => {
+                    match context {
+                        Some(context) => self.title_contexts.insert(mode, context.clone()),
+                        None => self.title_contexts.remove(&mode),
+                    };
+                    self.update_title()?;
+                }
+                // shown over everything until dismissed, so a failure can't go unnoticed
+                Action::Error(_) => self.change_mode(Mode::Popup("error"))?,
+                Action::ExitPopup => {
+                    let mut popups = self.popups.clone();
+                    popups.pop();
+                    self.set_layers(self.base_mode, popups)?;
+                }
+                // views ask for their data again once this has gone ahead of them
+                Action::Refresh => {
+                    self.request(DbRequest::Invalidate)?;
+                    self.action_tx.send(Action::Notify("Refreshing…".into()))?;
+                }
+                Action::Lock if self.config.lock.pin().is_none() => self.action_tx.send(
+                    Action::Error("Set a PIN under \"lock\" in the config to lock".into()),
+                )?,
+                Action::Lock if self.locked_from.is_none() => {
+                    self.locked_from = Some((self.base_mode, self.popups.clone()));
+                    self.change_mode(Mode::Popup("lock"))?;
+                }
+                Action::Unlock => {
+                    if let Some((base, popups)) = self.locked_from.take() {
+                        self.set_layers(base, popups)?;
+                    }
+                }
+                Action::ConfirmDestructive(kind, ref on_confirm) => {
+                    match self.config.confirmation_level(kind) {
+                        ConfirmationLevel::None => self.action_tx.send(*on_confirm.clone())?,
+                        level => {
+                            self.action_tx
+                                .send(Action::ChangeMode(Mode::Popup("confirm_popup")))?;
+                            self.action_tx.send(Action::ShowConfirmation(
+                                level,
+                                "Confirm".into(),
+                                kind.question().into(),
+                                on_confirm.clone(),
+                            ))?;
+                        }
+                    }
+                }
+                Action::Confirm {
+                    ref title,
+                    ref message,
+                    ref on_yes,
+                } => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("confirm_popup")))?;
+                    self.action_tx.send(Action::ShowConfirmation(
+                        ConfirmationLevel::Confirm,
+                        title.clone(),
+                        message.clone(),
+                        on_yes.clone(),
+                    ))?;
+                }
+                Action::Prompt {
+                    ref title,
+                    ref placeholder,
+                    ref on_submit,
+                } => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("input_prompt")))?;
+                    self.action_tx.send(Action::ShowPrompt(
+                        title.clone(),
+                        placeholder.clone(),
+                        on_submit.clone(),
+                    ))?;
+                }
+                Action::SaveJob(ref job) if job.id == 0 => {
+                    self.request(DbRequest::SimilarApplications(job.clone()))?
+                }
+                Action::SaveDuplicate(ref job) => self.save_new(job.clone())?,
+                Action::ResumeInterrupted => {
+                    if let Some(request) = self.interrupted.take() {
+                        self.request(request)?;
+                    }
+                }
+                Action::DiscardInterrupted => {
+                    self.interrupted = None;
+                    if let Some(journal) = &self.journal {
+                        journal.finish()?;
+                    }
+                }
+                Action::SaveJob(ref job) | Action::SaveJobUnchecked(ref job) => {
+                    self.request(DbRequest::SaveJob(job.clone()))?
+                }
+                Action::SaveStatusChange(ref job, ref note) => {
+                    self.request(DbRequest::SaveStatusChange(job.clone(), note.clone()))?
+                }
+                Action::DeleteJob(id) => self.request(DbRequest::DeleteJob(id))?,
+                Action::Help => {
+                    let mode = self.mode;
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("help_popup")))?;
+                    self.action_tx.send(Action::ShowHelp(mode))?;
+                }
+                Action::NewJob => {
+                    self.action_tx.send(Action::ChangeMode(Mode::EditJob))?;
+                    self.action_tx
+                        .send(Action::PopulateEditJobForm(JobApplication::default()))?;
+                }
+                Action::QuickAdd => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("quick_add")))?;
+                    self.action_tx.send(Action::ShowQuickAdd)?;
+                }
+                Action::OpenJobDetail(ref job) => {
+                    self.request(DbRequest::StatusHistory(job.clone()))?
+                }
+                Action::OpenJob(ref key) => self.request(DbRequest::JobWithHistory(key.clone()))?,
+                Action::OpenInterviewForm(job_id) => self.request(DbRequest::Interviews(job_id))?,
+                Action::SaveInterview(ref interview) => {
+                    self.request(DbRequest::SaveInterview(interview.clone()))?
+                }
+                Action::ShowReminders => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("reminders")))?;
+                    self.request(DbRequest::Reminders)?;
+                }
+                Action::OpenReminderForm(job_id) => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("reminder_form")))?;
+                    self.action_tx.send(Action::PopulateReminderForm(Reminder {
+                        job_id,
+                        ..Reminder::default()
+                    }))?;
+                }
+                Action::SaveReminder(ref reminder) => {
+                    self.request(DbRequest::SaveReminder(reminder.clone()))?
+                }
+                Action::DeleteReminder(id) => self.request(DbRequest::DeleteReminder(id))?,
+                Action::ShowQuestions => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("questions")))?;
+                    self.request(DbRequest::Questions)?;
+                }
+                Action::OpenQuestionForm(ref question, ref company) => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("question_form")))?;
+                    self.action_tx.send(Action::PopulateQuestionForm(
+                        question.clone(),
+                        company.clone(),
+                    ))?;
+                }
+                Action::SaveQuestion(ref question) => {
+                    self.request(DbRequest::SaveQuestion(question.clone()))?
+                }
+                Action::DeleteQuestion(id) => self.request(DbRequest::DeleteQuestion(id))?,
+                Action::ExportReminders => {
+                    let path = self.config.config.data_dir.join("reminders.ics");
+                    self.request(DbRequest::ExportReminders(path))?;
+                }
+                Action::ExportCalendar => {
+                    let path = self.config.config.data_dir.join("calendar.ics");
+                    self.request(DbRequest::ExportCalendar(path, self.config.calendar))?;
+                }
+                // not over another popup, which closing the palette would leave stranded
+                Action::OpenPalette if !matches!(self.mode, Mode::Popup(_)) => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("palette")))?;
+                    self.request(DbRequest::AllJobs)?;
+                }
+                Action::ShowTags => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("tag_browser")))?;
+                    self.request(DbRequest::Tags)?;
+                }
+                Action::RenameTag(ref tag, ref to) => {
+                    self.request(DbRequest::RenameTag(tag.clone(), to.clone()))?
+                }
+                Action::DeleteTag(ref tag) => self.request(DbRequest::DeleteTag(tag.clone()))?,
+                Action::ShowConflicts => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("conflicts")))?;
+                    self.request(DbRequest::Conflicts)?;
+                }
+                Action::ResolveConflict(id, ref resolution) => {
+                    self.request(DbRequest::ResolveConflict(id, resolution.clone()))?
+                }
+                Action::SetFollowUp(id, days) => self.request(DbRequest::SetFollowUp(id, days))?,
+                Action::Search => self
+                    .action_tx
+                    .send(Action::ChangeMode(Mode::Popup("search")))?,
+                Action::ShowCategorySuggestions => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("recategorize")))?;
+                    self.request(DbRequest::CategorySuggestions)?;
+                }
+                Action::Recategorize(ref ids, ref category) => {
+                    self.request(DbRequest::Recategorize(ids.clone(), category.clone()))?
+                }
+                Action::SetStatus(ref ids, ref status) => {
+                    self.request(DbRequest::SetStatus(ids.clone(), status.clone()))?
+                }
+                Action::OpenContactForm(ref contact) => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("contact_form")))?;
+                    self.action_tx
+                        .send(Action::PopulateContactForm(contact.clone()))?;
+                }
+                Action::SaveContact(ref contact) => {
+                    self.request(DbRequest::SaveContact(contact.clone()))?
+                }
+                Action::DeleteContact(ref contact) => {
+                    self.request(DbRequest::DeleteContact(contact.clone()))?
+                }
+                Action::TogglePrivacy => {
+                    self.config.config.privacy_mode = !self.config.config.privacy_mode;
+                    self.action_tx
+                        .send(Action::SetPrivacy(self.config.config.privacy_mode))?;
+                }
+                Action::ShowStats => {
+                    self.action_tx.send(Action::ChangeMode(Mode::Stats))?;
+                    self.action_tx.send(Action::DispatchStats)?;
+                }
+                Action::ShowTimeline => {
+                    self.action_tx.send(Action::ChangeMode(Mode::Timeline))?;
+                    self.action_tx.send(Action::DispatchTimeline)?;
+                }
+                Action::ShowCalendar => {
+                    self.action_tx.send(Action::ChangeMode(Mode::Calendar))?;
+                    self.action_tx.send(Action::DispatchCalendar)?;
+                }
+                Action::OpenExpenseForm(job_id) => {
+                    self.action_tx
+                        .send(Action::ChangeMode(Mode::Popup("expense_form")))?;
+                    self.action_tx.send(Action::PopulateExpenseForm(Expense {
+                        job_id,
+                        ..Expense::default()
+                    }))?;
+                }
+                Action::SaveExpense(ref expense) => {
+                    self.request(DbRequest::SaveExpense(expense.clone()))?
+                }
+                Action::DeleteExpense(id) => self.request(DbRequest::DeleteExpense(id))?,
+                Action::OpenExternal(ref target) => {
+                    let openers = &self.config.openers;
+                    if let Err(err) = opener::open(target, openers, self.action_tx.clone()) {
+                        self.action_tx
+                            .send(Action::Warning(format!("Failed to open {target}: {err}")))?;
                     }
                 }
-                Action::DispatchNotesPopupData(notes) => {
-                    // self.current_mode_components.clear();
-                    // self.mode = Mode::Popup;
-                    // for (idx, component) in self.components.iter_mut().enumerate() {
-                    //     if component.mode() == Mode::Popup {
-                    //         self.current_mode_components.push(idx);
-                    //     }
-                    // }
+                Action::CopyToClipboard(ref text) => match clipboard::copy(text) {
+                    Ok(()) => self
+                        .action_tx
+                        .send(Action::Notify("Copied to clipboard".into()))?,
+                    Err(err) => self
+                        .action_tx
+                        .send(Action::Warning(format!("Failed to copy: {err}")))?,
+                },
+                Action::Export => {
+                    let path = self.config.config.data_dir.join("job_applications.csv");
+                    self.action_tx.send(Action::ExportCsv(path))?;
+                }
+                Action::ImportFile(ref path) => self.request(DbRequest::Import(path.clone()))?,
+                Action::ImportUrl => self.action_tx.send(Action::Prompt {
+                    title: "Import job posting".into(),
+                    placeholder: "https://… a posting on LinkedIn, Indeed or a careers page".into(),
+                    on_submit: Box::new(Action::ImportFromUrl(String::new())),
+                })?,
+                Action::ImportFromUrl(ref url) => {
+                    self.action_tx
+                        .send(Action::Notify(format!("Fetching {url}")))?;
+                    let url = url.clone();
+                    let tx = self.action_tx.clone();
+                    tokio::task::spawn_blocking(move || {
+                        let job = posting::fetch(&url)
+                            .map_err(|err| err.to_string())
+                            .and_then(|page| {
+                                posting::parse(&page, &url)
+                                    .ok_or_else(|| format!("No job posting found at {url}"))
+                            });
+                        // nothing to tell if the app has quit in the meantime
+                        let actions = match job {
+                            Ok(job) => {
+                                vec![
+                                    Action::ChangeMode(Mode::EditJob),
+                                    Action::PopulateEditJobForm(job),
+                                ]
+                            }
+                            Err(message) => vec![Action::Error(message)],
+                        };
+                        for action in actions {
+                            let _ = tx.send(action);
+                        }
+                    });
+                }
+                Action::Backup => {
+                    let path = self.config.config.data_dir.join("backup.json");
+                    self.request(DbRequest::Backup(path))?
                 }
+                Action::Restore(ref path) => self.request(DbRequest::Restore(path.clone()))?,
+                Action::Digest => self.request(DbRequest::Digest(self.config.digest.format))?,
+                Action::ExportPreset(ref name) => {
+                    let path = preset::preset_path(&self.config, name);
+                    self.action_tx.send(
+                        match Preset::from_config(name, &self.config).save(&path) {
+                            Ok(()) => Action::Notify(format!("Preset saved to {}", path.display())),
+                            Err(err) => Action::Error(format!(
+                                "Failed to export preset to {}: {err}",
+                                path.display()
+                            )),
+                        },
+                    )?;
+                }
+                Action::ImportPreset(ref path) => match Preset::load(path) {
+                    Ok(preset) => {
+                        self.action_tx
+                            .send(Action::ChangeMode(Mode::Popup("preset_preview")))?;
+                        self.action_tx.send(Action::PresetPreview(
+                            path.clone(),
+                            preset.diff(&self.config),
+                        ))?;
+                    }
+                    Err(err) => self.action_tx.send(Action::Error(format!(
+                        "Failed to load preset {}: {err}",
+                        path.display()
+                    )))?,
+                },
+                Action::ApplyPreset(ref path) => {
+                    let result = match self.apply_preset(path) {
+                        Ok(()) => Action::Notify(format!("Applied preset {}", path.display())),
+                        Err(err) => Action::Error(format!(
+                            "Failed to apply preset {}: {err}",
+                            path.display()
+                        )),
+                    };
+                    self.action_tx.send(result)?;
+                }
+                Action::ExportCsv(ref path) => self.request(DbRequest::ExportCsv(path.clone()))?,
                 _ => {}
             }
-            for c in self.current_mode_components.iter_mut() {
-                let component = self.components.get_mut(*c).unwrap();
-                if let Some(action) = component.update(action.clone())? {
-                    self.action_tx.send(action)?
+            for c in self.action_targets(&action) {
+                let Some(component) = self.components.get_mut(c) else {
+                    continue;
                 };
+                match component.update(action.clone()) {
+                    Ok(Some(action)) => self.action_tx.send(action)?,
+                    Ok(None) => {}
+                    Err(err) => self
+                        .action_tx
+                        .send(component_error(component.as_ref(), err))?,
+                }
+            }
+        }
+        Ok(())
+    }
+
+    // Notifications go to every subscribed component so views outside the current mode stay
+    // up to date; everything else only reaches the current mode's components.
+    fn action_targets(&self, action: &Action) -> Vec<usize> {
+        match Subscription::of(action) {
+            Some(subscription) => self
+                .components
+                .iter()
+                .enumerate()
+                .filter(|(_, component)| component.subscriptions().contains(&subscription))
+                .map(|(idx, _)| idx)
+                .collect(),
+            None => self
+                .current_mode_components
+                .iter()
+                .chain(self.global_components.iter())
+                .copied()
+                .collect(),
+        }
+    }
+
+    fn init_components(&mut self, mode: Mode) -> Result<()> {
+        for (idx, component) in self.components.iter_mut().enumerate() {
+            if component.mode() == mode && !self.initialized[idx] {
+                component.init(self.size)?;
+                self.initialized[idx] = true;
+            }
+        }
+        Ok(())
+    }
+
+    /// Whether anything shown, under the popups too, has changes quitting would lose.
+    fn unsaved_changes(&self) -> bool {
+        let layers = std::iter::once(self.base_mode)
+            .chain(self.popups.iter().copied())
+            .collect::<Vec<_>>();
+        self.components
+            .iter()
+            .any(|component| layers.contains(&component.mode()) && component.unsaved_changes())
+    }
+
+    /// Open a popup over what's on screen, or switch to another view, closing any popups. A
+    /// popup that's already open comes back to the top, closing those opened over it.
+    fn change_mode(&mut self, new_mode: Mode) -> Result<()> {
+        let mut popups = self.popups.clone();
+        let base = match new_mode {
+            Mode::Popup(_) => {
+                match popups.iter().position(|popup| *popup == new_mode) {
+                    Some(at) => popups.truncate(at + 1),
+                    None => popups.push(new_mode),
+                }
+                self.base_mode
+            }
+            _ => {
+                popups.clear();
+                new_mode
             }
+        };
+        self.set_layers(base, popups)
+    }
+
+    /// Show `base` with `popups` over it, handing input to the topmost.
+    fn set_layers(&mut self, base: Mode, popups: Vec<Mode>) -> Result<()> {
+        let new_mode = popups.last().copied().unwrap_or(base);
+        self.base_mode = base;
+        self.popups = popups;
+        if new_mode == self.mode {
+            return Ok(());
+        }
+        self.init_components(new_mode)?;
+        self.mode = new_mode;
+        self.action_tx.send(Action::ModeChanged(new_mode))?;
+        self.current_mode_components.clear();
+        for (idx, component) in self.components.iter().enumerate() {
+            if component.mode() == self.mode {
+                self.current_mode_components.push(idx);
+            }
+        }
+        #[cfg(feature = "logos")]
+        self.show_logos()?;
+        self.update_title()
+    }
+
+    /// Logos sit on top of the text, so they are only shown while the job list is.
+    #[cfg(feature = "logos")]
+    fn show_logos(&self) -> Result<()> {
+        if !crate::logo::supported() {
+            return Ok(());
+        }
+        if self.mode == Mode::Home {
+            crate::logo::place(&mut stdout(), &self.logos)
+        } else {
+            crate::logo::clear(&mut stdout())
+        }
+    }
+
+    /// Name the terminal after what is on screen; popups keep the title of the view behind.
+    fn update_title(&mut self) -> Result<()> {
+        let title = match self.title_contexts.get(&self.base_mode) {
+            Some(context) => format!("job-tracker — {context}"),
+            None => "job-tracker".to_string(),
+        };
+        if title != self.title {
+            execute!(stdout(), SetTitle(&title))?;
+            self.title = title;
         }
         Ok(())
     }
 
     fn handle_resize(&mut self, tui: &mut Tui, w: u16, h: u16) -> Result<()> {
+        self.size = Size::new(w, h);
         tui.resize(Rect::new(0, 0, w, h))?;
         self.render(tui)?;
         Ok(())
     }
 
     fn render(&mut self, tui: &mut Tui) -> Result<()> {
+        self.dirty = false;
+        self.show_focus();
+        let started = Instant::now();
+        if let Some(perf) = &mut self.perf {
+            perf.frame_started();
+        }
+        self.popup_areas.clear();
         tui.draw(|frame| {
-            if let Mode::Popup(component_id) = self.mode {
-                let id = self.current_mode_components[0];
-
-                let component = self.components.get_mut(id).unwrap();
-
-                frame.buffer_mut().merge(&self.last_buffer);
-                if let Err(err) = component.draw(frame, frame.area()) {
-                    let _ = self
-                        .action_tx
-                        .send(Action::Error(format!("Failed to draw: {:?}", err)));
-                }
-            } else {
-                for c in self.current_mode_components.iter_mut() {
-                    let component = self.components.get_mut(*c).unwrap();
-                    if let Err(err) = component.draw(frame, frame.area()) {
-                        let _ = self
-                            .action_tx
-                            .send(Action::Error(format!("Failed to draw: {:?}", err)));
+            // the view stays live under the popups, each drawn over the one opened before it
+            for mode in std::iter::once(self.base_mode).chain(self.popups.iter().copied()) {
+                for (idx, component) in self.components.iter_mut().enumerate() {
+                    if component.mode() == mode
+                        && let Some(area) = draw_component(
+                            component.as_mut(),
+                            frame,
+                            &self.action_tx,
+                            &mut self.perf,
+                        )
+                    {
+                        self.popup_areas.insert(idx, area);
                     }
                 }
-                self.last_buffer = frame.buffer_mut().clone();
+            }
+            for c in self.global_components.iter() {
+                if let Some(component) = self.components.get_mut(*c)
+                    && let Some(area) =
+                        draw_component(component.as_mut(), frame, &self.action_tx, &mut self.perf)
+                {
+                    self.popup_areas.insert(*c, area);
+                }
+            }
+            if let Some(perf) = &self.perf {
+                perf.draw(frame, frame.area(), &self.config.theme);
             }
         })?;
+        if let Some(perf) = &mut self.perf {
+            perf.frame_drawn(started);
+        }
         Ok(())
     }
 }
+
+/// The error popup's message for a failure a component returned, so one component going
+/// wrong is shown rather than closing the app.
+fn component_error(component: &dyn Component, err: color_eyre::Report) -> Action {
+    Action::Error(format!("{}: {err}", component.id()))
+}
+
+/// Draw one component, reporting a failure rather than stopping, and time it for the
+/// performance overlay when that's shown. Returns where it drew a popup, if it did.
+fn draw_component(
+    component: &mut dyn Component,
+    frame: &mut Frame,
+    action_tx: &mpsc::UnboundedSender<Action>,
+    perf: &mut Option<Perf>,
+) -> Option<Rect> {
+    let started = Instant::now();
+    if let Err(err) = component.draw(frame, frame.area()) {
+        let _ = action_tx.send(Action::Error(format!("Failed to draw: {:?}", err)));
+    }
+    if let Some(perf) = perf {
+        perf.component_drawn(component.id(), started.elapsed());
+    }
+    util::take_drawn_popup()
+}
+
+/// Raise a desktop notification through the platform's notifier. Not supported on Windows,
+/// where reminders only show in the status bar.
+pub fn notify_desktop(title: &str, body: &str) -> Result<()> {
+    let mut command = if cfg!(target_os = "windows") {
+        return Ok(());
+    } else if cfg!(target_os = "macos") {
+        let mut command = std::process::Command::new("osascript");
+        command.arg("-e").arg(format!(
+            "display notification {body:?} with title {:?}",
+            format!("Reminder: {title}")
+        ));
+        command
+    } else {
+        let mut command = std::process::Command::new("notify-send");
+        command.args([
+            "--app-name=job-tracker",
+            &format!("Reminder: {title}"),
+            body,
+        ]);
+        command
+    };
+    command
+        .stdin(std::process::Stdio::null())
+        .stdout(std::process::Stdio::null())
+        .stderr(std::process::Stdio::null())
+        .spawn()?;
+    Ok(())
+}
@@ -0,0 +1,117 @@
+use std::cmp::Reverse;
+
+use serde::{Deserialize, Serialize};
+
+use crate::database::schema::{JobApplication, PositionCategory};
+
+/// A batch of applications whose titles suggest the same change of category.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Suggestion {
+    pub from: PositionCategory,
+    pub to: PositionCategory,
+    /// Title words that led to the suggestion, e.g. "qa".
+    pub keywords: Vec<String>,
+    pub jobs: Vec<JobApplication>,
+}
+
+// checked in order, so titles like "QA Engineer" land on the more specific category
+const RULES: [(PositionCategory, &[&str]); 6] = [
+    (
+        PositionCategory::DataScience,
+        &[
+            "data scientist",
+            "data science",
+            "machine learning",
+            "ml",
+            "ai",
+        ],
+    ),
+    (
+        PositionCategory::Analyst,
+        &["analyst", "qa", "quality assurance", "tester", "test"],
+    ),
+    (
+        PositionCategory::Design,
+        &["designer", "design", "ux", "product design"],
+    ),
+    (
+        PositionCategory::Support,
+        &[
+            "support",
+            "helpdesk",
+            "help desk",
+            "service desk",
+            "technician",
+        ],
+    ),
+    (
+        PositionCategory::Development,
+        &[
+            "developer",
+            "programmer",
+            "frontend",
+            "front end",
+            "backend",
+            "back end",
+            "full stack",
+        ],
+    ),
+    (
+        PositionCategory::Engineering,
+        &["engineer", "engineering", "devops", "sre"],
+    ),
+];
+
+/// The category a position title points to and the keyword that matched, if any. Keywords
+/// match whole words, so "ai" does not match "maintenance".
+pub fn category_for(position: &str) -> Option<(PositionCategory, &'static str)> {
+    let words = position
+        .to_lowercase()
+        .split(|c: char| !c.is_alphanumeric())
+        .filter(|word| !word.is_empty())
+        .collect::<Vec<_>>()
+        .join(" ");
+    let padded = format!(" {words} ");
+    RULES.iter().find_map(|(category, keywords)| {
+        keywords
+            .iter()
+            .find(|keyword| padded.contains(&format!(" {keyword} ")))
+            .map(|keyword| (category.clone(), *keyword))
+    })
+}
+
+/// Applications whose title points to another category than the one they are filed under,
+/// grouped by the change so each group can be accepted at once.
+pub fn suggestions(jobs: &[JobApplication]) -> Vec<Suggestion> {
+    let mut groups: Vec<Suggestion> = Vec::new();
+    for job in jobs {
+        let Some((to, keyword)) = category_for(&job.position) else {
+            continue;
+        };
+        if to == job.position_category {
+            continue;
+        }
+        let group = match groups
+            .iter_mut()
+            .position(|group| group.from == job.position_category && group.to == to)
+        {
+            Some(idx) => &mut groups[idx],
+            None => {
+                groups.push(Suggestion {
+                    from: job.position_category.clone(),
+                    to,
+                    keywords: Vec::new(),
+                    jobs: Vec::new(),
+                });
+                groups.last_mut().unwrap()
+            }
+        };
+        if !group.keywords.iter().any(|k| k == keyword) {
+            group.keywords.push(keyword.to_string());
+        }
+        group.jobs.push(job.clone());
+    }
+    // biggest batches first
+    groups.sort_by_key(|group| Reverse(group.jobs.len()));
+    groups
+}
@@ -1,15 +1,150 @@
-use clap::Parser;
+use std::path::PathBuf;
+
+use clap::{Parser, Subcommand, ValueEnum};
 
 #[derive(Parser, Debug)]
 #[command(author, version = version(), about)]
 pub struct Cli {
-    /// Tick rate, i.e. number of ticks per second
-    #[arg(short, long, value_name = "FLOAT", default_value_t = 4.0)]
-    pub tick_rate: f64,
+    #[command(subcommand)]
+    pub command: Option<Command>,
+
+    /// Tick rate, i.e. number of ticks per second. Overrides `tick_rate` in the config
+    #[arg(short, long, value_name = "FLOAT")]
+    pub tick_rate: Option<f64>,
+
+    /// Frame rate, i.e. number of frames per second. Overrides `frame_rate` in the config
+    #[arg(short, long, value_name = "FLOAT")]
+    pub frame_rate: Option<f64>,
+
+    /// Import applications from a CSV or JSON export on startup, skipping duplicates
+    #[arg(short, long, value_name = "FILE")]
+    pub import: Option<PathBuf>,
+
+    /// Restore applications, history, interviews, reminders and expenses from a JSON backup
+    /// on startup. Applications already stored are kept, with differing copies sent to the
+    /// conflicts inbox
+    #[arg(long, value_name = "FILE")]
+    pub restore: Option<PathBuf>,
+
+    /// Compose the weekly digest on startup and deliver it as configured
+    #[arg(long)]
+    pub digest: bool,
+
+    /// Save the current keybindings and theme as a named preset in the config directory
+    #[arg(long, value_name = "NAME")]
+    pub export_preset: Option<String>,
+
+    /// Preview the changes a preset file would make and apply it on confirmation
+    #[arg(long, value_name = "FILE")]
+    pub import_preset: Option<PathBuf>,
+
+    /// Record the session's input to a file, to reproduce a bug with `--replay`
+    #[arg(long, value_name = "FILE")]
+    pub record: Option<PathBuf>,
+
+    /// Play back a recorded session against an empty in-memory database. Space pauses, `n`
+    /// steps while paused and `q` quits
+    #[arg(long, value_name = "FILE")]
+    pub replay: Option<PathBuf>,
+
+    /// Try the app out on made-up applications in a throwaway in-memory database
+    #[cfg(feature = "fixtures")]
+    #[arg(long, conflicts_with = "replay")]
+    pub demo: bool,
+
+    /// Start in the detail view of the application with this UUID or id
+    #[arg(long, value_name = "ID")]
+    pub open: Option<String>,
+
+    /// Start in the given view rather than the job list
+    #[arg(long, value_enum, value_name = "VIEW")]
+    pub view: Option<StartView>,
+}
+
+/// Views that can be opened straight from the command line.
+#[derive(ValueEnum, Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StartView {
+    List,
+    Stats,
+    Reminders,
+    Conflicts,
+}
+
+#[derive(Subcommand, Debug)]
+pub enum Command {
+    /// Show a read-only summary that refreshes itself, for a second monitor or tmux pane
+    Dashboard {
+        /// Seconds between refreshes from the database
+        #[arg(short, long, value_name = "SECONDS", default_value_t = 30)]
+        refresh: u64,
+    },
+    /// Run the scheduled maintenance without the interface, e.g. hourly from crontab: back up,
+    /// fire due reminders, check for stale applications and send the weekly digest when it's
+    /// due. What was done is appended to cron.log in the data directory; only failures are
+    /// printed
+    Cron,
+    /// Write upcoming interviews and application deadlines as iCalendar, to import into
+    /// Google Calendar or Outlook, with alerts as set under "calendar" in the config
+    Calendar {
+        /// File to write; printed to stdout without one
+        #[arg(short, long, value_name = "FILE")]
+        output: Option<PathBuf>,
+    },
+    /// Merge in applications from another database file, e.g. the one on another machine.
+    /// Copies are matched by UUID and the more recently updated one wins; ones that can't be
+    /// told apart go to the conflicts inbox. The other file is left as it is
+    Sync {
+        /// The other database
+        #[arg(value_name = "FILE")]
+        path: PathBuf,
+    },
+    /// Log an application from one line, e.g. "Backend Engineer @ Acme, remote, London,
+    /// https://acme.com/jobs/42". Parts are told apart by their shape; any not recognised as
+    /// the link, location type or "position @ company" become the location. The application
+    /// is dated today and its id is printed
+    Add {
+        #[arg(value_name = "TEXT")]
+        text: String,
+    },
+    /// List the statuses applications can be in, or add, recolour and remove them
+    Statuses {
+        #[command(subcommand)]
+        command: Option<StatusCommand>,
+    },
+    /// Write a new database of made-up applications, the same every time for a seed and day,
+    /// for scripts, benchmarks and tests to run against
+    #[cfg(feature = "fixtures")]
+    Fixtures {
+        /// Where to create the database; an existing file is left alone
+        path: PathBuf,
+        /// How many applications to generate
+        #[arg(short, long, default_value_t = 50)]
+        count: usize,
+        #[arg(short, long, default_value_t = 1)]
+        seed: u64,
+        /// The day taken as today, as `YYYY-MM-DD`, so the dates come out the same on any day.
+        /// Defaults to the real today
+        #[arg(long, value_name = "DATE")]
+        today: Option<String>,
+    },
+}
 
-    /// Frame rate, i.e. number of frames per second
-    #[arg(short, long, value_name = "FLOAT", default_value_t = 60.0)]
-    pub frame_rate: f64,
+#[derive(Subcommand, Debug)]
+pub enum StatusCommand {
+    /// List every status with its colour
+    List,
+    /// Add a status, or change the colour of an existing one
+    Set {
+        name: String,
+        /// A colour name such as `light_blue`, a hex code or a 256-colour index
+        #[arg(short, long)]
+        color: String,
+        /// Applications in this status are finished with, like Rejected or Accepted
+        #[arg(long)]
+        closed: bool,
+    },
+    /// Remove a status you added. Applications in it keep it
+    Remove { name: String },
 }
 
 const VERSION_MESSAGE: &str = concat!(
@@ -0,0 +1,61 @@
+//! Copying text to the clipboard: through the platform's clipboard tool when there is one,
+//! or else by asking the terminal to do it with an OSC 52 sequence, which also works over SSH
+//! on terminals that allow it.
+
+use std::{
+    io::{Write, stdout},
+    process::{Command, Stdio},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use color_eyre::Result;
+
+// tried in order, the first to succeed wins
+fn tools() -> Vec<(&'static str, &'static [&'static str])> {
+    if cfg!(target_os = "windows") {
+        vec![("clip", &[])]
+    } else if cfg!(target_os = "macos") {
+        vec![("pbcopy", &[])]
+    } else {
+        let mut tools: Vec<(&str, &[&str])> = Vec::new();
+        if std::env::var_os("WAYLAND_DISPLAY").is_some() {
+            tools.push(("wl-copy", &[]));
+        }
+        if std::env::var_os("DISPLAY").is_some() {
+            tools.push(("xclip", &["-selection", "clipboard"]));
+            tools.push(("xsel", &["--clipboard", "--input"]));
+        }
+        tools
+    }
+}
+
+fn copy_with(program: &str, args: &[&str], text: &str) -> bool {
+    let Ok(mut child) = Command::new(program)
+        .args(args)
+        .stdin(Stdio::piped())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()
+    else {
+        return false;
+    };
+    let written = child
+        .stdin
+        .take()
+        .is_some_and(|mut stdin| stdin.write_all(text.as_bytes()).is_ok());
+    child.wait().is_ok_and(|status| status.success()) && written
+}
+
+/// Put `text` on the clipboard.
+pub fn copy(text: &str) -> Result<()> {
+    if tools()
+        .into_iter()
+        .any(|(program, args)| copy_with(program, args, text))
+    {
+        return Ok(());
+    }
+    let mut out = stdout();
+    write!(out, "\x1b]52;c;{}\x07", STANDARD.encode(text))?;
+    out.flush()?;
+    Ok(())
+}
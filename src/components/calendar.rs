@@ -0,0 +1,375 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{self, List, ListItem, ListState, Paragraph},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::component::Component,
+    config::Config,
+    database::{
+        schema::{Interview, JobApplication},
+        worker::{DbRequest, DbResponse, RequestId},
+    },
+    dates,
+    privacy::Privacy,
+};
+
+const MONTHS: [&str; 12] = [
+    "January",
+    "February",
+    "March",
+    "April",
+    "May",
+    "June",
+    "July",
+    "August",
+    "September",
+    "October",
+    "November",
+    "December",
+];
+const WEEKDAYS: [&str; 7] = ["Mon", "Tue", "Wed", "Thu", "Fri", "Sat", "Sun"];
+// rows kept under the grid for the selected day's entries
+const DAY_LIST_HEIGHT: u16 = 6;
+
+// 0 for Monday; 1970-01-01 was a Thursday
+fn weekday(day: i64) -> i64 {
+    (day + 3).rem_euclid(7)
+}
+
+fn year_month(day: i64) -> (i64, i64) {
+    let date = dates::date_of(day);
+    (
+        date[..4].parse().unwrap_or(1970),
+        date[5..7].parse().unwrap_or(1),
+    )
+}
+
+fn first_of(year: i64, month: i64) -> i64 {
+    let (year, month) = (
+        year + (month - 1).div_euclid(12),
+        (month - 1).rem_euclid(12) + 1,
+    );
+    dates::day_of(&format!("{year:04}-{month:02}-01")).unwrap_or(0)
+}
+
+// the same day of the month `months` away, or that month's last day when it's shorter
+fn add_months(day: i64, months: i64) -> i64 {
+    let (year, month) = year_month(day);
+    let offset = day - first_of(year, month);
+    let first = first_of(year, month + months);
+    let length = first_of(year, month + months + 1) - first;
+    first + offset.min(length - 1)
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Kind {
+    Deadline,
+    Interview,
+}
+
+/// Something happening on a day: an application closing, or an interview for one.
+struct Entry {
+    day: i64,
+    kind: Kind,
+    // `HH:MM` for interviews
+    time: String,
+    job: JobApplication,
+}
+
+/// A month at a time, marking the days applications close and interviews happen.
+pub struct CalendarView {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    entries: Vec<Entry>,
+    selected_day: i64,
+    // which of the selected day's entries Enter opens
+    selected_entry: ListState,
+    privacy: Privacy,
+    pending: Option<RequestId>,
+    // whether the calendar on its way was asked for by a refresh, to say once it's in
+    refreshing: bool,
+}
+impl CalendarView {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            entries: Vec::new(),
+            selected_day: dates::current_day(),
+            selected_entry: ListState::default(),
+            privacy: Privacy::default(),
+            pending: None,
+            refreshing: false,
+        }
+    }
+
+    fn on(&self, day: i64) -> impl Iterator<Item = &Entry> {
+        self.entries.iter().filter(move |entry| entry.day == day)
+    }
+
+    fn select_day(&mut self, day: i64) {
+        self.selected_day = day;
+        let any = self.on(day).next().is_some();
+        self.selected_entry.select(any.then_some(0));
+    }
+
+    fn company(&self, job: &JobApplication) -> String {
+        self.privacy
+            .text(&job.company_name, &format!("Company #{}", job.id))
+    }
+
+    fn style(&self, kind: Kind) -> Style {
+        match kind {
+            Kind::Deadline => Style::default().fg(self.config.theme.warning),
+            Kind::Interview => Style::default().fg(self.config.theme.focus),
+        }
+    }
+
+    // as short as fits in a day's cell
+    fn summary(&self, entry: &Entry) -> String {
+        match entry.kind {
+            Kind::Deadline => format!("⚑ {}", self.company(&entry.job)),
+            Kind::Interview => format!("{} {}", entry.time, self.company(&entry.job)),
+        }
+    }
+
+    fn cell(&self, day: i64, month: i64, area: Rect, frame: &mut ratatui::Frame) {
+        let theme = &self.config.theme;
+        let mut number = Style::default();
+        if year_month(day).1 != month {
+            number = number.fg(theme.muted);
+        }
+        if day == dates::current_day() {
+            number = number.add_modifier(Modifier::BOLD | Modifier::UNDERLINED);
+        }
+        let mut lines = vec![Line::styled(dates::date_of(day)[8..].to_string(), number)];
+        let entries = self.on(day).collect::<Vec<_>>();
+        let room = (area.height as usize).saturating_sub(1);
+        for (index, entry) in entries.iter().enumerate() {
+            if index + 1 == room && entries.len() > room {
+                lines.push(Line::styled(
+                    format!("+{} more", entries.len() - index),
+                    Style::default().fg(theme.muted),
+                ));
+                break;
+            }
+            lines.push(Line::styled(self.summary(entry), self.style(entry.kind)));
+        }
+        let mut paragraph = Paragraph::new(lines);
+        if day == self.selected_day {
+            paragraph = paragraph.style(Style::default().bg(theme.selection_bg));
+        }
+        frame.render_widget(paragraph, area.inner(Margin::new(1, 0)));
+    }
+
+    fn request(&mut self) -> Option<Action> {
+        let req_id = RequestId::next();
+        self.pending = Some(req_id);
+        Some(Action::DbRequest(req_id, DbRequest::Calendar))
+    }
+}
+
+impl Component for CalendarView {
+    fn mode(&self) -> Mode {
+        Mode::Calendar
+    }
+    fn id(&self) -> String {
+        "Calendar".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy, Subscription::Db]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::DispatchCalendar | Action::Refresh => {
+                self.refreshing = action == Action::Refresh;
+                if !self.refreshing {
+                    self.selected_day = dates::current_day();
+                }
+                return Ok(self.request());
+            }
+            Action::DbResponse(req_id, DbResponse::Calendar(jobs, interviews))
+                if self.pending == Some(req_id) =>
+            {
+                self.pending = None;
+                let find = |id: i32| jobs.iter().find(|job| job.id == id);
+                // closed applications' deadlines no longer matter
+                let deadlines = jobs.iter().filter(|job| job.is_active).filter_map(|job| {
+                    Some(Entry {
+                        day: dates::day_of(job.deadline.as_deref()?)?,
+                        kind: Kind::Deadline,
+                        time: String::new(),
+                        job: job.clone(),
+                    })
+                });
+                let interviews = interviews.iter().filter_map(|interview: &Interview| {
+                    Some(Entry {
+                        day: dates::day_of(&interview.starts_at)?,
+                        kind: Kind::Interview,
+                        time: interview.starts_at.get(11..16)?.to_string(),
+                        job: find(interview.job_id)?.clone(),
+                    })
+                });
+                self.entries = deadlines.chain(interviews).collect();
+                // deadlines first, as they last the whole day
+                self.entries.sort_by(|a, b| {
+                    (a.day, a.kind == Kind::Interview, &a.time).cmp(&(
+                        b.day,
+                        b.kind == Kind::Interview,
+                        &b.time,
+                    ))
+                });
+                self.select_day(self.selected_day);
+                if std::mem::take(&mut self.refreshing) {
+                    return Ok(Some(Action::Notify("Refreshed".into())));
+                }
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn repeats(&self, key: crossterm::event::KeyEvent) -> bool {
+        matches!(
+            key.code,
+            KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::PageUp
+                | KeyCode::PageDown
+                | KeyCode::Char('h' | 'l' | 'j' | 'k' | '[' | ']')
+        )
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let day = self.selected_day;
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ChangeMode(Mode::Home))),
+            KeyCode::Left | KeyCode::Char('h') => self.select_day(day - 1),
+            KeyCode::Right | KeyCode::Char('l') => self.select_day(day + 1),
+            KeyCode::Up | KeyCode::Char('k') => self.select_day(day - 7),
+            KeyCode::Down | KeyCode::Char('j') => self.select_day(day + 7),
+            KeyCode::Char('[') | KeyCode::PageUp => self.select_day(add_months(day, -1)),
+            KeyCode::Char(']') | KeyCode::PageDown => self.select_day(add_months(day, 1)),
+            KeyCode::Char('t') => self.select_day(dates::current_day()),
+            KeyCode::Tab => self.selected_entry.select_next(),
+            KeyCode::BackTab => self.selected_entry.select_previous(),
+            KeyCode::Enter => {
+                let entries = self.on(day).collect::<Vec<_>>();
+                if let Some(entry) = self.selected_entry.selected().and_then(|i| entries.get(i)) {
+                    return Ok(Some(Action::OpenJobDetail(entry.job.clone())));
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let theme = &self.config.theme;
+        let (year, month) = year_month(self.selected_day);
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Thick)
+            .borders(widgets::Borders::TOP)
+            .title_top(Line::from(format!("Calendar · {} {year}", MONTHS[month as usize - 1])).centered())
+            .title_bottom(
+                Line::from(
+                    "[h/l] day   [j/k] week   [[/]] month   [t] today   [tab] next item   [enter] open   [e] export .ics   [esc] back",
+                )
+                .centered(),
+            );
+        let region = area.inner(Margin::new(2, 2));
+        let inner = block.inner(region);
+        frame.render_widget(block, region);
+
+        let [header, grid, day_list] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(DAY_LIST_HEIGHT),
+        ])
+        .areas(inner);
+        let columns = Layout::horizontal([Constraint::Fill(1); 7]);
+        for (name, cell) in WEEKDAYS.iter().zip(columns.split(header).iter()) {
+            frame.render_widget(
+                Line::styled(format!(" {name}"), Style::default().fg(theme.muted)),
+                *cell,
+            );
+        }
+
+        // whole weeks, Monday to Sunday, covering the month
+        let first = first_of(year, month);
+        let start = first - weekday(first);
+        let end = first_of(year, month + 1);
+        let weeks = (end - start + 6) / 7;
+        let rows = Layout::vertical(vec![Constraint::Fill(1); weeks as usize]).split(grid);
+        for (week, row) in rows.iter().enumerate() {
+            for (column, cell) in columns.split(*row).iter().enumerate() {
+                let day = start + week as i64 * 7 + column as i64;
+                self.cell(day, month, *cell, frame);
+            }
+        }
+
+        let items = self
+            .on(self.selected_day)
+            .map(|entry| {
+                let what = match entry.kind {
+                    Kind::Deadline => "Deadline".to_string(),
+                    Kind::Interview => format!("Interview at {}", entry.time),
+                };
+                ListItem::new(Line::from(vec![
+                    Span::styled(format!("{what:<20}"), self.style(entry.kind)),
+                    Span::raw(format!(
+                        "{} · {}",
+                        self.company(&entry.job),
+                        entry.job.position
+                    )),
+                ]))
+            })
+            .collect::<Vec<_>>();
+        let day_block = widgets::Block::bordered()
+            .borders(widgets::Borders::TOP)
+            .border_style(Style::default().fg(theme.border))
+            .title(dates::date_of(self.selected_day));
+        if items.is_empty() {
+            frame.render_widget(
+                Paragraph::new(Line::styled(
+                    "No deadlines or interviews.",
+                    Style::default().fg(theme.muted),
+                ))
+                .block(day_block),
+                day_list,
+            );
+            return Ok(());
+        }
+        let list = List::new(items)
+            .block(day_block)
+            .highlight_style(Style::default().bg(theme.selection_bg));
+        frame.render_stateful_widget(list, day_list, &mut self.selected_entry);
+        Ok(())
+    }
+}
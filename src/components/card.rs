@@ -0,0 +1,392 @@
+use ratatui::{
+    buffer::Buffer,
+    layout::{Constraint, Position, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Cell, Paragraph, Row, StatefulWidget, Table, Widget},
+};
+
+use crate::{
+    components::job_item::{JobItem, JobListingState},
+    config::{CardStyle, ListConfig},
+    database::schema::{ApplicationStatus, JobApplication},
+    theme::Theme,
+    views::Field,
+};
+
+/// Draws one application in the job list. Implement this to add a new card style; the
+/// list only needs to know how tall each card is.
+pub trait CardRenderer {
+    /// Rows taken by each card.
+    fn height(&self) -> u16;
+
+    /// `trail` is the statuses the application has been through, oldest first.
+    #[allow(clippy::too_many_arguments)]
+    fn render(
+        &self,
+        job: &JobApplication,
+        trail: &[ApplicationStatus],
+        theme: &Theme,
+        list: &ListConfig,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut JobListingState,
+    );
+
+    /// Column headings drawn in a row above the list, for cards laid out as table rows.
+    fn header(&self, _theme: &Theme) -> Option<Table<'static>> {
+        None
+    }
+
+    /// Cells a company logo can be drawn over, for cards with room for one.
+    #[cfg_attr(not(feature = "logos"), allow(dead_code))]
+    fn logo_area(&self, _area: Rect) -> Option<Rect> {
+        None
+    }
+
+    /// Update which part of the card is focused as the mouse moves over it. Cards without
+    /// focusable parts can ignore this.
+    fn handle_mouse_moved(
+        &self,
+        _job: &JobApplication,
+        _area: Rect,
+        _pos: Position,
+        _state: &mut JobListingState,
+    ) {
+    }
+}
+
+// statuses shown before the trail is cut short
+const TRAIL_LENGTH: usize = 5;
+
+/// A spark-line of the statuses an application has been through, e.g. `● → ● → ○`, each dot
+/// in its status colour. Applications still in progress end on a hollow dot for the step to
+/// come.
+pub fn status_trail(
+    job: &JobApplication,
+    trail: &[ApplicationStatus],
+    theme: &Theme,
+    list: &ListConfig,
+) -> Line<'static> {
+    let current = [job.status.clone()];
+    let trail = if trail.is_empty() {
+        &current[..]
+    } else {
+        trail
+    };
+    let arrow = Span::styled(" → ", Style::default().fg(theme.muted));
+    let mut spans = Vec::new();
+    if trail.len() > TRAIL_LENGTH {
+        spans.push(Span::styled("…", Style::default().fg(theme.muted)));
+        spans.push(arrow.clone());
+    }
+    let shown = &trail[trail.len().saturating_sub(TRAIL_LENGTH)..];
+    for (idx, status) in shown.iter().enumerate() {
+        if idx > 0 {
+            spans.push(arrow.clone());
+        }
+        spans.push(Span::styled("●", list.status_style(status)));
+    }
+    if job.is_active {
+        spans.push(arrow);
+        spans.push(Span::styled("○", Style::default().fg(theme.muted)));
+    }
+    Line::from(spans)
+}
+
+/// `columns` are those of the table view, the usual ones when empty.
+pub fn renderer(style: CardStyle, columns: &[Field]) -> Box<dyn CardRenderer> {
+    match style {
+        CardStyle::Detailed => Box::new(DetailedCard),
+        CardStyle::Compact => Box::new(CompactCard),
+        CardStyle::Status => Box::new(StatusCard),
+        CardStyle::Table if columns.is_empty() => Box::new(TableRow::new(&TableRow::COLUMNS)),
+        CardStyle::Table => Box::new(TableRow::new(columns)),
+    }
+}
+
+/// The original card, with notes and links beside the job info.
+pub struct DetailedCard;
+
+impl CardRenderer for DetailedCard {
+    fn height(&self) -> u16 {
+        8
+    }
+
+    fn render(
+        &self,
+        job: &JobApplication,
+        trail: &[ApplicationStatus],
+        theme: &Theme,
+        list: &ListConfig,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut JobListingState,
+    ) {
+        JobItem::new(job.clone())
+            .theme(theme.clone())
+            .status_style(list.status_style(&job.status))
+            .trail(status_trail(job, trail, theme, list))
+            .badges(state.badges.clone())
+            .hyperlinks(list.hyperlinks)
+            .render(area, buf, state);
+    }
+
+    // top left of the info block, inside its border and padding
+    fn logo_area(&self, area: Rect) -> Option<Rect> {
+        (area.width >= 40 && area.height >= 6).then(|| Rect::new(area.x + 2, area.y + 2, 4, 2))
+    }
+
+    fn handle_mouse_moved(
+        &self,
+        job: &JobApplication,
+        area: Rect,
+        pos: Position,
+        state: &mut JobListingState,
+    ) {
+        JobItem::new(job.clone()).handle_mouse_moved_in_region(area, pos, state);
+    }
+}
+
+/// A single line per application, to fit as many as possible on screen.
+pub struct CompactCard;
+
+impl CardRenderer for CompactCard {
+    fn height(&self) -> u16 {
+        1
+    }
+
+    fn render(
+        &self,
+        job: &JobApplication,
+        trail: &[ApplicationStatus],
+        theme: &Theme,
+        list: &ListConfig,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut JobListingState,
+    ) {
+        let base = if state.focused {
+            Style::default().fg(theme.text).bg(theme.selection_bg)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let mut spans = vec![Span::styled(
+            format!(" {:<13}", job.status.to_string()),
+            list.status_style(&job.status),
+        )];
+        spans.extend(status_trail(job, trail, theme, list).spans);
+        spans.extend([
+            Span::raw(format!("  {} @ {}", job.position, job.company_name)),
+            Span::styled(
+                job.stars()
+                    .map(|stars| format!("  {stars}"))
+                    .unwrap_or_default(),
+                Style::default().fg(theme.warning),
+            ),
+            Span::styled(
+                format!("  {}  {}", job.location, job.application_date),
+                Style::default().fg(theme.muted),
+            ),
+        ]);
+        if state.stale {
+            spans.push(Span::styled("  stale", Style::default().fg(theme.muted)));
+        }
+        if state.closed {
+            spans.push(Span::styled(
+                "  posting closed",
+                Style::default().fg(theme.warning),
+            ));
+        }
+        if !state.badges.is_empty() {
+            spans.push(Span::raw("  "));
+            spans.extend(state.badges.clone());
+        }
+        let line = Line::from(spans);
+        Paragraph::new(line).style(base).render(area, buf);
+    }
+}
+
+/// A short card framed in the status colour, so the pipeline reads at a glance.
+pub struct StatusCard;
+
+impl CardRenderer for StatusCard {
+    fn height(&self) -> u16 {
+        5
+    }
+
+    fn render(
+        &self,
+        job: &JobApplication,
+        trail: &[ApplicationStatus],
+        theme: &Theme,
+        list: &ListConfig,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut JobListingState,
+    ) {
+        let status_style = list.status_style(&job.status);
+        let block = Block::bordered()
+            .border_type(if state.focused {
+                BorderType::Thick
+            } else {
+                BorderType::Rounded
+            })
+            .border_style(status_style)
+            .title_top(
+                Line::from(job.status.to_string())
+                    .style(status_style.add_modifier(Modifier::BOLD))
+                    .left_aligned(),
+            )
+            .title_top(Line::from(state.badges.clone()).right_aligned())
+            .title_top(Line::from(job.application_date.clone()).right_aligned())
+            .title_bottom(status_trail(job, trail, theme, list).right_aligned())
+            .title_bottom(
+                Line::from(vec![
+                    Span::styled(
+                        job.stars()
+                            .map(|stars| format!("{stars} "))
+                            .unwrap_or_default(),
+                        Style::default().fg(theme.warning),
+                    ),
+                    Span::styled(
+                        if state.stale { "stale " } else { "" },
+                        Style::default().fg(theme.muted),
+                    ),
+                    Span::styled(
+                        if state.closed { "posting closed" } else { "" },
+                        Style::default().fg(theme.warning),
+                    ),
+                ])
+                .left_aligned(),
+            );
+        let text_style = Style::default().fg(if state.focused {
+            theme.text
+        } else {
+            theme.muted
+        });
+        let lines = Text::from(vec![
+            Line::from(Span::styled(
+                job.position.clone(),
+                text_style.add_modifier(Modifier::BOLD),
+            )),
+            Line::from(Span::styled(job.company_name.clone(), text_style)),
+            Line::from(Span::styled(job.location.clone(), text_style)),
+        ]);
+        Paragraph::new(lines).block(block).render(area, buf);
+    }
+
+    // right of the text, inside the border
+    fn logo_area(&self, area: Rect) -> Option<Rect> {
+        (area.width >= 20 && area.height >= 4)
+            .then(|| Rect::new(area.right().saturating_sub(6), area.y + 1, 4, 2))
+    }
+}
+
+/// One row of a table, with the columns lined up under the list's headings.
+pub struct TableRow {
+    columns: Vec<Field>,
+}
+
+impl TableRow {
+    const COLUMNS: [Field; 5] = [
+        Field::Date,
+        Field::Company,
+        Field::Position,
+        Field::Status,
+        Field::Location,
+    ];
+
+    pub fn new(columns: &[Field]) -> Self {
+        Self {
+            columns: columns.to_vec(),
+        }
+    }
+
+    fn widths(&self) -> Vec<Constraint> {
+        self.columns
+            .iter()
+            .map(|field| match field {
+                Field::Date
+                | Field::FollowUp
+                | Field::Deadline
+                | Field::SalaryMin
+                | Field::SalaryMax => Constraint::Length(10),
+                Field::Currency => Constraint::Length(8),
+                Field::Rating => Constraint::Length(6),
+                // fits "Technical Interview", and a date and time
+                Field::Status | Field::Created | Field::Updated => Constraint::Length(19),
+                Field::Position | Field::Notes | Field::Url => Constraint::Fill(3),
+                _ => Constraint::Fill(2),
+            })
+            .collect()
+    }
+}
+
+impl CardRenderer for TableRow {
+    fn height(&self) -> u16 {
+        1
+    }
+
+    fn header(&self, theme: &Theme) -> Option<Table<'static>> {
+        let header = Row::new(self.columns.iter().map(|field| field.heading())).style(
+            Style::default()
+                .fg(theme.muted)
+                .add_modifier(Modifier::BOLD),
+        );
+        Some(
+            Table::new(Vec::<Row>::new(), self.widths())
+                .header(header)
+                .column_spacing(2),
+        )
+    }
+
+    fn render(
+        &self,
+        job: &JobApplication,
+        _trail: &[ApplicationStatus],
+        theme: &Theme,
+        list: &ListConfig,
+        area: Rect,
+        buf: &mut Buffer,
+        state: &mut JobListingState,
+    ) {
+        let style = if state.focused {
+            Style::default().fg(theme.text).bg(theme.selection_bg)
+        } else if state.stale {
+            Style::default().fg(theme.muted)
+        } else {
+            Style::default().fg(theme.text)
+        };
+        let row = Row::new(self.columns.iter().map(|field| {
+            match field {
+                Field::Status if state.closed => Cell::from(Line::from(vec![
+                    Span::styled(job.status.to_string(), list.status_style(&job.status)),
+                    Span::styled(" (posting closed)", Style::default().fg(theme.warning)),
+                ])),
+                Field::Status => {
+                    Cell::from(job.status.to_string()).style(list.status_style(&job.status))
+                }
+                // notes run over several lines, which a row hasn't room for
+                Field::Notes => Cell::from(
+                    field
+                        .value(job)
+                        .lines()
+                        .next()
+                        .unwrap_or_default()
+                        .to_string(),
+                ),
+                Field::Rating => Cell::from(job.stars().unwrap_or_default())
+                    .style(Style::default().fg(theme.warning)),
+                _ => Cell::from(field.value(job)),
+            }
+        }));
+        Widget::render(
+            Table::new([row], self.widths())
+                .column_spacing(2)
+                .style(style),
+            area,
+            buf,
+        );
+    }
+}
@@ -6,7 +6,12 @@ use ratatui::{
 };
 use tokio::sync::mpsc::UnboundedSender;
 
-use crate::{action::Action, app::Mode, config::Config, tui::Event};
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    config::Config,
+    tui::Event,
+};
 
 /// `Component` is a trait that represents a visual and interactive element of the user interface.
 ///
@@ -19,6 +24,10 @@ pub trait Component {
     fn mode(&self) -> Mode {
         Mode::Home
     }
+    /// Notification topics this component wants to receive regardless of the current mode.
+    fn subscriptions(&self) -> Vec<Subscription> {
+        Vec::new()
+    }
 
     /// Register an action handler that can send actions for processing if necessary.
     ///
@@ -104,6 +113,37 @@ pub trait Component {
         let _ = mouse; // to appease clippy
         Ok(None)
     }
+    /// Whether a count typed before `key`, e.g. `5j`, repeats it, for keys the component
+    /// handles itself rather than through the keymap. Only moves should.
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The key the count was typed before.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether to handle the key once per count.
+    fn repeats(&self, key: KeyEvent) -> bool {
+        let _ = key; // to appease clippy
+        false
+    }
+    /// Whether Tab can move focus to the component from others in its mode. While more than
+    /// one can, only the focused one is handed keys.
+    fn focusable(&self) -> bool {
+        false
+    }
+    /// Told when the component gains or loses focus, to show which pane keys go to.
+    ///
+    /// # Arguments
+    ///
+    /// * `focused` - Whether the component now has focus.
+    fn set_focus(&mut self, focused: bool) {
+        let _ = focused; // to appease clippy
+    }
+    /// Whether the component holds edits that quitting now would lose, so quitting asks first.
+    fn unsaved_changes(&self) -> bool {
+        false
+    }
     /// Update the state of the component based on a received action. (REQUIRED)
     ///
     /// # Arguments
@@ -117,6 +157,16 @@ pub trait Component {
         let _ = action; // to appease clippy
         Ok(None)
     }
+    /// Whether the component changed on its own since the last frame, e.g. a message timing
+    /// out on a tick. Frames are only drawn after input, an action other than a tick, or a
+    /// component reporting a change here; each change should be reported once.
+    ///
+    /// # Returns
+    ///
+    /// * `bool` - Whether the next frame needs drawing.
+    fn take_redraw(&mut self) -> bool {
+        false
+    }
     /// Render the component on the screen. (REQUIRED)
     ///
     /// # Arguments
@@ -0,0 +1,157 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::Style,
+    text::{Line, Span, Text},
+    widgets,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::{Config, ConfirmationLevel},
+};
+
+const CONFIRM_WORD: &str = "yes";
+
+/// Asks before running an action, at a level from a plain y/n to typing the PIN.
+pub struct ConfirmPopup {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    level: ConfirmationLevel,
+    title: String,
+    message: String,
+    on_confirm: Option<Action>,
+    input: String,
+}
+impl ConfirmPopup {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            level: ConfirmationLevel::Confirm,
+            title: String::new(),
+            message: String::new(),
+            on_confirm: None,
+            input: String::new(),
+        }
+    }
+
+    fn close(&mut self, confirmed: bool) -> color_eyre::eyre::Result<()> {
+        let action = self.on_confirm.take();
+        self.input.clear();
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::ExitPopup)?;
+            if let (true, Some(action)) = (confirmed, action) {
+                tx.send(action)?;
+            }
+        }
+        Ok(())
+    }
+}
+
+impl Component for ConfirmPopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("confirm_popup")
+    }
+    fn id(&self) -> String {
+        "Confirm Popup".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::ShowConfirmation(level, title, message, on_confirm) = action {
+            self.level = level;
+            self.title = title;
+            self.message = message;
+            self.on_confirm = Some(*on_confirm);
+            self.input.clear();
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match (self.level, key.code) {
+            (_, KeyCode::Esc) => self.close(false)?,
+            (ConfirmationLevel::TypeToConfirm, KeyCode::Enter) => {
+                let confirmed = self.input.trim().eq_ignore_ascii_case(CONFIRM_WORD);
+                self.close(confirmed)?;
+            }
+            (ConfirmationLevel::Pin, KeyCode::Enter) => {
+                if self.config.lock.pin() == Some(self.input.as_str()) {
+                    self.close(true)?;
+                } else {
+                    self.input.clear();
+                    return Ok(Some(Action::Error("Wrong PIN".into())));
+                }
+            }
+            (ConfirmationLevel::TypeToConfirm | ConfirmationLevel::Pin, KeyCode::Backspace) => {
+                self.input.pop();
+            }
+            (ConfirmationLevel::TypeToConfirm | ConfirmationLevel::Pin, KeyCode::Char(c)) => {
+                self.input.push(c)
+            }
+            (_, KeyCode::Char('y')) | (_, KeyCode::Enter) => self.close(true)?,
+            (_, KeyCode::Char('n')) => self.close(false)?,
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(60),
+            Constraint::Length(7),
+            &self.config.theme,
+        );
+
+        let hint = match self.level {
+            ConfirmationLevel::TypeToConfirm => Line::from(vec![
+                Span::raw(format!("Type \"{CONFIRM_WORD}\" to confirm: ")),
+                Span::styled(
+                    self.input.clone(),
+                    Style::default().fg(self.config.theme.warning),
+                ),
+            ]),
+            ConfirmationLevel::Pin => Line::from(vec![
+                Span::raw("Enter the PIN to confirm: "),
+                Span::styled(
+                    "•".repeat(self.input.chars().count()),
+                    Style::default().fg(self.config.theme.warning),
+                ),
+            ]),
+            _ => Line::from("[y] confirm   [n] cancel"),
+        };
+        let text = Text::from(vec![Line::from(self.message.as_str()), Line::raw(""), hint]);
+
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.error))
+            .padding(widgets::Padding::horizontal(1))
+            .title(self.title.as_str());
+        let paragraph = widgets::Paragraph::new(text)
+            .centered()
+            .wrap(widgets::Wrap { trim: true })
+            .block(block);
+        frame.render_widget(paragraph, area);
+        Ok(())
+    }
+}
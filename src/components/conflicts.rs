@@ -0,0 +1,240 @@
+use std::collections::HashSet;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{self, Cell, Row, Table, TableState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::{Config, DestructiveAction},
+    database::schema::{Conflict, Resolution},
+    import::conflict::{self, FIELDS},
+    privacy::Privacy,
+};
+
+/// Incoming applications that clash with stored ones, compared field by field so each can
+/// be kept as a new application, merged into the stored one or discarded.
+pub struct ConflictInbox {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    conflicts: Vec<Conflict>,
+    current: usize,
+    // fields of the current conflict to take from the incoming copy when merging
+    take_incoming: HashSet<usize>,
+    selected: TableState,
+    privacy: Privacy,
+}
+impl ConflictInbox {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            conflicts: Vec::new(),
+            current: 0,
+            take_incoming: HashSet::new(),
+            selected: TableState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    /// Start a conflict off taking only what the stored application is missing.
+    fn show(&mut self, index: usize) {
+        self.current = index;
+        self.take_incoming.clear();
+        if let Some(conflict) = self.conflicts.get(index) {
+            self.take_incoming = conflict::differing_fields(&conflict.local, &conflict.incoming)
+                .into_iter()
+                .filter(|idx| (FIELDS[*idx].text)(&conflict.local).is_empty())
+                .collect();
+        }
+        self.selected.select(Some(0));
+    }
+
+    fn resolve(&self, resolution: impl FnOnce(&Conflict) -> Resolution) -> Option<Action> {
+        let conflict = self.conflicts.get(self.current)?;
+        Some(Action::ResolveConflict(conflict.id, resolution(conflict)))
+    }
+
+    fn table(&self, conflict: &Conflict) -> Table<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        let chosen = Style::default().fg(self.config.theme.focus);
+        let local = self.privacy.job(&conflict.local);
+        let incoming = self.privacy.job(&conflict.incoming);
+        let differing = conflict::differing_fields(&conflict.local, &conflict.incoming);
+        let rows = FIELDS.iter().enumerate().map(|(idx, field)| {
+            let (local_text, incoming_text) = ((field.text)(&local), (field.text)(&incoming));
+            if !differing.contains(&idx) {
+                return Row::new([
+                    Cell::new(field.name).style(muted),
+                    Cell::new(local_text).style(muted),
+                    Cell::new(incoming_text).style(muted),
+                ]);
+            }
+            let (local_style, incoming_style) = if self.take_incoming.contains(&idx) {
+                (muted, chosen)
+            } else {
+                (chosen, muted)
+            };
+            Row::new([
+                Cell::new(field.name).style(Style::default().add_modifier(Modifier::BOLD)),
+                Cell::new(local_text).style(local_style),
+                Cell::new(incoming_text).style(incoming_style),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Length(14),
+                Constraint::Fill(1),
+                Constraint::Fill(1),
+            ],
+        )
+        .header(
+            Row::new(["Field", "Stored", "Incoming"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().bg(self.config.theme.selection_bg))
+    }
+}
+
+impl Component for ConflictInbox {
+    fn mode(&self) -> Mode {
+        Mode::Popup("conflicts")
+    }
+    fn id(&self) -> String {
+        "Conflicts".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::ConflictResults(conflicts) => {
+                self.conflicts = conflicts;
+                self.show(self.current.min(self.conflicts.len().saturating_sub(1)));
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Right | KeyCode::Char('l') if self.current + 1 < self.conflicts.len() => {
+                self.show(self.current + 1)
+            }
+            KeyCode::Left | KeyCode::Char('h') if self.current > 0 => self.show(self.current - 1),
+            // pick which side of the selected field the merge keeps
+            KeyCode::Char(' ') => {
+                if let (Some(conflict), Some(idx)) =
+                    (self.conflicts.get(self.current), self.selected.selected())
+                    && conflict::differing_fields(&conflict.local, &conflict.incoming)
+                        .contains(&idx)
+                    && !self.take_incoming.remove(&idx)
+                {
+                    self.take_incoming.insert(idx);
+                }
+            }
+            // taking any incoming field overwrites the stored one, so that's confirmed first
+            KeyCode::Char('m') => {
+                let take = self.take_incoming.iter().copied().collect::<Vec<_>>();
+                let resolve = self.resolve(|conflict| {
+                    Resolution::Merge(Box::new(conflict::merge(
+                        &conflict.local,
+                        &conflict.incoming,
+                        &take,
+                    )))
+                });
+                if take.is_empty() {
+                    return Ok(resolve);
+                }
+                return Ok(resolve.map(|resolve| {
+                    Action::ConfirmDestructive(
+                        DestructiveAction::OverwriteImport,
+                        Box::new(resolve),
+                    )
+                }));
+            }
+            KeyCode::Char('b') => return Ok(self.resolve(|_| Resolution::KeepBoth)),
+            KeyCode::Char('d') | KeyCode::Delete => {
+                return Ok(self.resolve(|_| Resolution::Discard));
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(80),
+            Constraint::Percentage(80),
+            &self.config.theme,
+        );
+        let mut block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title("Conflicts")
+            .title_bottom(
+                Line::from(
+                    "[space] pick side   [m] merge   [b] keep both   [d] discard   [←/→] next   [esc] close",
+                )
+                .centered(),
+            );
+        let Some(conflict) = self.conflicts.get(self.current).cloned() else {
+            let inner = block.inner(area);
+            frame.render_widget(block, area);
+            frame.render_widget(
+                Line::styled(
+                    "No conflicts. Imported applications that clash with stored ones wait here.",
+                    Style::default().fg(self.config.theme.muted),
+                ),
+                inner,
+            );
+            return Ok(());
+        };
+        block = block.title(
+            Line::from(format!(
+                "{}/{} from {}",
+                self.current + 1,
+                self.conflicts.len(),
+                conflict.source
+            ))
+            .right_aligned(),
+        );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+        let table = self.table(&conflict);
+        frame.render_stateful_widget(table, inner, &mut self.selected);
+        Ok(())
+    }
+}
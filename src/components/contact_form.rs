@@ -0,0 +1,174 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::Line,
+    widgets::{self, Block, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::Contact,
+};
+
+const FIELDS: [&str; 5] = ["Name", "Role", "Email", "Phone", "LinkedIn"];
+
+pub struct ContactForm<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    contact: Contact,
+    fields: Vec<TextArea<'a>>,
+    focused: usize,
+}
+impl ContactForm<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            contact: Contact::default(),
+            fields: Self::create_fields(&Contact::default()),
+            focused: 0,
+        }
+    }
+
+    fn create_fields<'a>(contact: &Contact) -> Vec<TextArea<'a>> {
+        let values = [
+            contact.name.clone(),
+            contact.role.clone(),
+            contact.email.clone(),
+            contact.phone.clone(),
+            contact.linkedin.clone(),
+        ];
+        FIELDS
+            .iter()
+            .zip(values)
+            .map(|(title, value)| {
+                let mut field = TextArea::from([value]);
+                field.set_block(
+                    Block::bordered()
+                        .padding(Padding::horizontal(1))
+                        .title(*title),
+                );
+                field
+            })
+            .collect()
+    }
+
+    fn field_value(&self, index: usize) -> String {
+        self.fields[index].lines().join(" ").trim().to_string()
+    }
+
+    fn contact_from_fields(&self) -> Result<Contact, String> {
+        let name = self.field_value(0);
+        if name.is_empty() {
+            return Err("A contact needs a name".to_string());
+        }
+        Ok(Contact {
+            name,
+            role: self.field_value(1),
+            email: self.field_value(2),
+            phone: self.field_value(3),
+            linkedin: self.field_value(4),
+            ..self.contact.clone()
+        })
+    }
+}
+
+impl Component for ContactForm<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("contact_form")
+    }
+    fn id(&self) -> String {
+        "Contact Form".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::PopulateContactForm(contact) = action {
+            self.fields = Self::create_fields(&contact);
+            self.contact = contact;
+            self.focused = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Tab | KeyCode::Down => self.focused = (self.focused + 1) % FIELDS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + FIELDS.len() - 1) % FIELDS.len();
+            }
+            KeyCode::Enter => match self.contact_from_fields() {
+                Ok(contact) => {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::SaveContact(contact))?;
+                }
+                Err(message) => return Ok(Some(Action::Error(message))),
+            },
+            _ => {
+                self.fields[self.focused].input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(50),
+            Constraint::Length(17),
+            &self.config.theme,
+        );
+        let title = if self.contact.id == 0 {
+            format!("New contact for application #{}", self.contact.job_id)
+        } else {
+            format!("Edit {}", self.contact.name)
+        };
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title(title)
+            .title_bottom(Line::from("[tab] next field   [enter] save   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical([Constraint::Length(3); 5]).split(inner);
+        let theme = &self.config.theme;
+        for (i, (field, row)) in self.fields.iter_mut().zip(rows.iter()).enumerate() {
+            let color = if i == self.focused {
+                theme.focus
+            } else {
+                theme.border
+            };
+            if let Some(block) = field.block().cloned() {
+                field.set_block(block.border_style(Style::default().fg(color)));
+            }
+            frame.render_widget(&*field, *row);
+        }
+        Ok(())
+    }
+}
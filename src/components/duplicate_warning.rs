@@ -0,0 +1,185 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Text},
+    widgets::{self, Cell, Row, Table, TableState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::JobApplication,
+    privacy::Privacy,
+};
+
+/// Shown when a new application looks like one already stored, to open the existing one
+/// instead of adding it twice. It has no popup area, so a stray click can't throw away the
+/// new application.
+pub struct DuplicateWarning {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: JobApplication,
+    similar: Vec<JobApplication>,
+    selected: TableState,
+    privacy: Privacy,
+}
+impl DuplicateWarning {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: JobApplication::default(),
+            similar: Vec::new(),
+            selected: TableState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn close(&mut self, then: Vec<Action>) -> color_eyre::eyre::Result<()> {
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::ExitPopup)?;
+            for action in then {
+                tx.send(action)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn table(&self) -> Table<'static> {
+        let rows = self.similar.iter().map(|job| {
+            Row::new([
+                Cell::new(job.application_date.clone()),
+                Cell::new(job.position.clone()),
+                Cell::new(job.status.to_string()).style(self.config.list.status_style(&job.status)),
+                Cell::new(job.location.clone()),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Fill(2),
+                Constraint::Length(19),
+                Constraint::Fill(1),
+            ],
+        )
+        .column_spacing(2)
+        .header(
+            Row::new(["Applied", "Position", "Status", "Location"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().bg(self.config.theme.selection_bg))
+    }
+}
+
+impl Component for DuplicateWarning {
+    fn mode(&self) -> Mode {
+        Mode::Popup("duplicate_warning")
+    }
+    fn id(&self) -> String {
+        "Duplicate Warning".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::ShowDuplicates(job, similar) => {
+                self.job = job;
+                self.similar = similar;
+                self.selected
+                    .select((!self.similar.is_empty()).then_some(0));
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Enter | KeyCode::Char('o') => {
+                let selected = self.selected.selected();
+                if let Some(existing) = selected.and_then(|i| self.similar.get(i)).cloned() {
+                    self.close(vec![Action::OpenJobDetail(existing)])?;
+                }
+            }
+            KeyCode::Char('s') => {
+                let job = std::mem::take(&mut self.job);
+                self.close(vec![Action::SaveDuplicate(job)])?;
+            }
+            // back to the form, with what was entered
+            KeyCode::Esc | KeyCode::Char('e') => {
+                let job = std::mem::take(&mut self.job);
+                self.close(vec![
+                    Action::ChangeMode(Mode::EditJob),
+                    Action::PopulateEditJobForm(job),
+                ])?;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let height = self.similar.len().min(8) as u16 + 5;
+        let [area] = Layout::vertical([Constraint::Length(height)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Percentage(70)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        util::clear_popup(frame, area, &self.config.theme);
+        let theme = &self.config.theme;
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.warning))
+            .padding(widgets::Padding::horizontal(1))
+            .title("Possible duplicate")
+            .title_bottom(
+                Line::from("[o] open existing   [s] save anyway   [esc] keep editing").centered(),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [message, rows] =
+            Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(inner);
+        let company = self.privacy.text(&self.job.company_name, "this company");
+        frame.render_widget(
+            Text::from(vec![
+                Line::from(format!(
+                    "Already applied to {company} for something like \"{}\":",
+                    self.job.position
+                )),
+                Line::raw(""),
+            ]),
+            message,
+        );
+        frame.render_stateful_widget(self.table(), rows, &mut self.selected);
+        Ok(())
+    }
+}
@@ -1,17 +1,27 @@
 use std::{
     collections::HashMap,
     ops::{Add, AddAssign, SubAssign},
+    str::FromStr,
+    time::{Duration, Instant},
 };
 
 use crate::{
-    action::Action, app::Mode, components::component::Component, database::schema::JobApplication,
+    action::{Action, Subscription},
+    app::Mode,
+    components::component::Component,
+    database::{
+        builder::JobApplicationBuilder,
+        schema::{ApplicationStatus, Files, JobApplication},
+        worker::{DbRequest, DbResponse, RequestId},
+    },
+    dates::{add_days, local_time},
+    spelling::{self, Speller},
 };
 use color_eyre::Result;
-use crossterm::event::{KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
 use ratatui::{
     Frame,
-    layout::{Constraint, Layout, Margin},
-    style::{Color, Modifier, Style},
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
     widgets::{Block, Padding, Paragraph},
 };
 use tokio::sync::mpsc::UnboundedSender;
@@ -28,15 +38,22 @@ pub enum Field {
     Location = 4,
     LocationType = 5,
     ApplicationDate = 6,
-    Status = 7,
-    ContactInfo = 8,
-    Url = 9,
-    Files = 10,
-    Notes = 11,
+    Deadline = 7,
+    Status = 8,
+    Rating = 9,
+    SalaryMin = 10,
+    SalaryMax = 11,
+    Currency = 12,
+    Url = 13,
+    Files = 14,
+    Notes = 15,
+    CompensationNotes = 16,
+    Description = 17,
+    Tags = 18,
 }
 impl Field {
     pub fn len() -> i8 {
-        12
+        19
     }
 }
 impl Into<i8> for Field {
@@ -55,11 +72,18 @@ impl From<i8> for Field {
             4 => Field::Location,
             5 => Field::LocationType,
             6 => Field::ApplicationDate,
-            7 => Field::Status,
-            8 => Field::ContactInfo,
-            9 => Field::Url,
-            10 => Field::Files,
-            11 => Field::Notes,
+            7 => Field::Deadline,
+            8 => Field::Status,
+            9 => Field::Rating,
+            10 => Field::SalaryMin,
+            11 => Field::SalaryMax,
+            12 => Field::Currency,
+            13 => Field::Url,
+            14 => Field::Files,
+            15 => Field::Notes,
+            16 => Field::CompensationNotes,
+            17 => Field::Description,
+            18 => Field::Tags,
             _ => Field::None,
         }
     }
@@ -77,6 +101,9 @@ impl AddAssign<i8> for Field {
     }
 }
 
+/// How often the form is kept as a draft while it's edited.
+const DRAFT_INTERVAL: Duration = Duration::from_secs(5);
+
 pub struct EditJob<'a> {
     command_tx: Option<UnboundedSender<crate::action::Action>>,
     config: crate::config::Config,
@@ -84,19 +111,28 @@ pub struct EditJob<'a> {
     text_fields: HashMap<Field, TextArea<'a>>,
     focused_field: Field,
     focused_updated: bool,
+    // the draft looked up when the form was opened, which holds off saving one until it's
+    // answered, and the fields last kept as a draft, to skip saving them again unchanged
+    pending_draft: Option<RequestId>,
+    drafted: Option<Vec<String>>,
+    last_drafted: Instant,
+    speller: Speller,
 }
 
 impl<'a> EditJob<'a> {
     pub fn new() -> Self {
-        let mut text_fields: HashMap<Field, TextArea<'a>> =
-            Self::create_fields().unwrap_or_default();
         Self {
             command_tx: None,
-            config: crate::config::Config::new().unwrap_or_default(),
+            config: crate::config::Config::default(),
             job: JobApplication::default(),
-            text_fields,
+            // built the first time the form is opened
+            text_fields: HashMap::new(),
             focused_field: Field::Position,
             focused_updated: false,
+            pending_draft: None,
+            drafted: None,
+            last_drafted: Instant::now(),
+            speller: Speller::default(),
         }
     }
 
@@ -104,14 +140,15 @@ impl<'a> EditJob<'a> {
         for (field, text_area) in self.text_fields.iter_mut() {
             let block = text_area.block().cloned().unwrap_or_default();
             let is_focused = *field == self.focused_field;
-            let mut style = Style::default().fg(Color::White);
+            let theme = &self.config.theme;
+            let mut style = Style::default().fg(theme.text);
             if is_focused {
-                style = Style::default().fg(ratatui::style::Color::Blue);
+                style = Style::default().fg(theme.focus);
                 text_area.cancel_selection();
 
                 text_area.set_cursor_style(
                     Style::default()
-                        .fg(Color::White)
+                        .fg(theme.text)
                         .add_modifier(Modifier::UNDERLINED),
                 );
                 text_area.set_cursor_line_style(Style::default());
@@ -122,6 +159,154 @@ impl<'a> EditJob<'a> {
         }
     }
 
+    fn focus_next(&mut self) {
+        self.focused_field = Field::from((self.focused_field as i8 + 1).rem_euclid(Field::len()));
+        self.focused_updated = false;
+    }
+
+    fn focus_previous(&mut self) {
+        self.focused_field = Field::from((self.focused_field as i8 - 1).rem_euclid(Field::len()));
+        self.focused_updated = false;
+    }
+
+    fn field_text(job: &JobApplication, field: Field) -> String {
+        match field {
+            Field::None => String::new(),
+            Field::Position => job.position.clone(),
+            Field::PositionCategory => job.position_category.to_string(),
+            Field::CompanyName => job.company_name.clone(),
+            Field::WorkType => job.work_type.to_string(),
+            Field::Location => job.location.clone(),
+            Field::LocationType => job.location_type.to_string(),
+            Field::ApplicationDate => job.application_date.clone(),
+            Field::Deadline => job.deadline.clone().unwrap_or_default(),
+            Field::Status => job.status.to_string(),
+            Field::Rating => job.rating.map(|v| v.to_string()).unwrap_or_default(),
+            Field::Url => job.url.clone().unwrap_or_default(),
+            Field::Files => job.files.to_string(),
+            Field::Notes => job.notes.clone().unwrap_or_default(),
+            Field::Description => job.description.clone().unwrap_or_default(),
+            Field::SalaryMin => job.salary_min.map(|v| v.to_string()).unwrap_or_default(),
+            Field::SalaryMax => job.salary_max.map(|v| v.to_string()).unwrap_or_default(),
+            Field::Currency => job.currency.clone().unwrap_or_default(),
+            Field::CompensationNotes => job.compensation_notes.clone().unwrap_or_default(),
+            Field::Tags => job.tags.join(", "),
+        }
+    }
+
+    fn ensure_fields(&mut self) {
+        if self.text_fields.is_empty() {
+            self.text_fields = Self::create_fields().unwrap_or_default();
+        }
+    }
+
+    fn populate_fields(&mut self) {
+        let job = self.job.clone();
+        self.fill_fields(|field| Self::field_text(&job, field));
+    }
+
+    fn fill_fields(&mut self, text: impl Fn(Field) -> String) {
+        self.ensure_fields();
+        for (field, text_area) in self.text_fields.iter_mut() {
+            let text = text(*field);
+            let mut populated = TextArea::from(text.lines());
+            if let Some(block) = text_area.block() {
+                populated.set_block(block.clone());
+            }
+            *text_area = populated;
+        }
+        self.focused_field = Field::Position;
+        self.focused_updated = false;
+    }
+
+    /// Every field's text, in the form's order, to keep as a draft.
+    fn draft_fields(&self) -> Vec<String> {
+        (0..Field::len())
+            .map(|idx| self.field_value(Field::from(idx)))
+            .collect()
+    }
+
+    /// Keep the form as a draft when it's changed since last kept, or discard the draft once
+    /// the form is back as it was opened.
+    fn save_draft(&mut self) -> Result<()> {
+        self.last_drafted = Instant::now();
+        let fields = self.draft_fields();
+        if self.pending_draft.is_some() || self.drafted.as_ref() == Some(&fields) {
+            return Ok(());
+        }
+        let request = if self.edited() {
+            DbRequest::SaveDraft(self.job.id, fields.clone())
+        } else {
+            DbRequest::DeleteDraft(self.job.id)
+        };
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::DbRequest(RequestId::next(), request))?;
+        }
+        self.drafted = Some(fields);
+        Ok(())
+    }
+
+    /// Whether any field differs from the application the form was filled in from.
+    fn edited(&self) -> bool {
+        self.text_fields
+            .keys()
+            .any(|field| self.field_value(*field) != Self::field_text(&self.job, *field).trim())
+    }
+
+    // every field is created together, but one gone missing is left blank rather than panicking
+    fn render_field(&self, frame: &mut Frame, field: Field, area: Rect) {
+        if let Some(text_area) = self.text_fields.get(&field) {
+            frame.render_widget(text_area, area);
+        }
+    }
+
+    fn field_value(&self, field: Field) -> String {
+        self.text_fields
+            .get(&field)
+            .map(|text_area| text_area.lines().join("\n").trim().to_string())
+            .unwrap_or_default()
+    }
+
+    /// Build the edited application from the form, failing with a message naming the first
+    /// field that doesn't parse.
+    fn job_from_fields(&self) -> std::result::Result<JobApplication, String> {
+        let value = |field: Field| self.field_value(field);
+        let mut job = JobApplicationBuilder::from(self.job.clone())
+            .company_name(&value(Field::CompanyName))
+            .position(&value(Field::Position))
+            .position_category(&value(Field::PositionCategory))
+            .work_type(&value(Field::WorkType))
+            .location(&value(Field::Location))
+            .location_type(&value(Field::LocationType))
+            .application_date(&value(Field::ApplicationDate))
+            .deadline(&value(Field::Deadline))
+            .status(&value(Field::Status))
+            .rating(&value(Field::Rating))
+            .notes(&value(Field::Notes))
+            .url(&value(Field::Url))
+            .files(Files::from_str(&value(Field::Files)).unwrap_or_default())
+            .description(&value(Field::Description))
+            .salary_min(&value(Field::SalaryMin))
+            .salary_max(&value(Field::SalaryMax))
+            .currency(&value(Field::Currency))
+            .compensation_notes(&value(Field::CompensationNotes))
+            .tags(&value(Field::Tags))
+            .build()?;
+        // a new entry with neither a status nor a date applied is a posting saved for later
+        if job.id == 0
+            && value(Field::Status).is_empty()
+            && value(Field::ApplicationDate).is_empty()
+        {
+            job.status = ApplicationStatus::Saved;
+            job.is_active = true;
+        }
+        // new applications are due a follow-up once the usual wait for a reply is over
+        if job.id == 0 && job.status == ApplicationStatus::Applied {
+            job.follow_up_date = add_days(&job.application_date, self.config.config.follow_up_days);
+        }
+        Ok(job)
+    }
+
     fn create_fields() -> Result<HashMap<Field, TextArea<'a>>> {
         let mut default_block = Block::bordered().padding(Padding::horizontal(1));
 
@@ -137,11 +322,18 @@ impl<'a> EditJob<'a> {
         fields.insert(Field::Location, TextArea::default());
         fields.insert(Field::LocationType, TextArea::default());
         fields.insert(Field::ApplicationDate, TextArea::default());
+        fields.insert(Field::Deadline, TextArea::default());
         fields.insert(Field::Status, TextArea::default());
+        fields.insert(Field::Rating, TextArea::default());
         fields.insert(Field::Notes, TextArea::default());
-        fields.insert(Field::ContactInfo, TextArea::default());
         fields.insert(Field::Url, TextArea::default());
         fields.insert(Field::Files, TextArea::default());
+        fields.insert(Field::Description, TextArea::default());
+        fields.insert(Field::SalaryMin, TextArea::default());
+        fields.insert(Field::SalaryMax, TextArea::default());
+        fields.insert(Field::Currency, TextArea::default());
+        fields.insert(Field::CompensationNotes, TextArea::default());
+        fields.insert(Field::Tags, TextArea::default());
 
         let fields = fields
             .iter()
@@ -162,11 +354,17 @@ impl Component for EditJob<'_> {
     fn mode(&self) -> Mode {
         Mode::EditJob
     }
+    fn unsaved_changes(&self) -> bool {
+        self.edited()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Db]
+    }
     fn register_action_handler(
         &mut self,
         tx: tokio::sync::mpsc::UnboundedSender<crate::action::Action>,
     ) -> color_eyre::eyre::Result<()> {
-        let _ = tx; // to appease clippy
+        self.command_tx = Some(tx);
         Ok(())
     }
 
@@ -174,7 +372,7 @@ impl Component for EditJob<'_> {
         &mut self,
         config: crate::config::Config,
     ) -> color_eyre::eyre::Result<()> {
-        let _ = config; // to appease clippy
+        self.config = config;
         Ok(())
     }
 
@@ -182,47 +380,12 @@ impl Component for EditJob<'_> {
         &mut self,
         key: crossterm::event::KeyEvent,
     ) -> color_eyre::eyre::Result<Option<crate::action::Action>> {
-        match key {
-            KeyEvent {
-                code: KeyCode::Tab,
-                modifiers: KeyModifiers::NONE,
-                kind: KeyEventKind::Press,
-                state,
-            } => {
-                self.focused_field += 1;
-                if self.focused_field as i8 > Field::len() {
-                    self.focused_field = Field::Position;
-                }
-                self.focused_updated = false;
-            }
-            KeyEvent {
-                code: KeyCode::BackTab,
-                modifiers: KeyModifiers::SHIFT,
-                kind: KeyEventKind::Press,
-                state: _,
-            } => {
-                self.focused_field -= 1;
-                if (self.focused_field as i8) < 0_i8 {
-                    self.focused_field = Field::from(Field::len() - 1);
-                }
-                self.focused_updated = false;
-            }
-            KeyEvent {
-                code: KeyCode::Enter,
-                modifiers: _,
-                kind: _,
-                state: _,
-            } => {
-                self.focused_field += 1;
-                if self.focused_field as i8 > Field::len() {
-                    self.focused_field = Field::Position;
-                }
-                self.focused_updated = false;
-            }
-            _ => {
-                let field = self.text_fields.get_mut(&self.focused_field).unwrap();
-                field.input(key);
-            }
+        // keys bound in the keymap arrive as actions instead of text input
+        if self.config.keybindings.is_bound(self.mode(), key) {
+            return Ok(None);
+        }
+        if let Some(field) = self.text_fields.get_mut(&self.focused_field) {
+            field.input(key);
         }
         Ok(None)
     }
@@ -232,6 +395,7 @@ impl Component for EditJob<'_> {
         action: crate::action::Action,
     ) -> color_eyre::eyre::Result<Option<crate::action::Action>> {
         match action {
+            Action::Tick if self.last_drafted.elapsed() >= DRAFT_INTERVAL => self.save_draft()?,
             Action::Render => {
                 let focus_updated = &self.focused_updated;
                 if !focus_updated {
@@ -240,8 +404,102 @@ impl Component for EditJob<'_> {
                 }
                 // add any logic here that should run on every tick
             }
+            Action::SuggestSpelling => {
+                if let (Some(field), Some(tx)) =
+                    (self.text_fields.get(&self.focused_field), &self.command_tx)
+                {
+                    self.speller.suggest(field, &self.config, tx)?;
+                }
+            }
+            Action::ReplaceWord(word, replacement) => {
+                if let Some(field) = self.text_fields.get_mut(&self.focused_field) {
+                    spelling::replace_at_cursor(field, &word, &replacement);
+                }
+            }
+            Action::WordAdded(word) => self.speller.add(&word),
+            Action::FocusNext => self.focus_next(),
+            Action::FocusPrevious => self.focus_previous(),
             Action::PopulateEditJobForm(job) => {
+                if let Some(tx) = &self.command_tx {
+                    let context = match job.id {
+                        0 => "New application".to_string(),
+                        id => format!("Editing application #{id}"),
+                    };
+                    tx.send(Action::TitleContext(Mode::EditJob, Some(context)))?;
+                }
                 self.job = job;
+                self.populate_fields();
+                self.drafted = None;
+                let req_id = RequestId::next();
+                self.pending_draft = Some(req_id);
+                return Ok(Some(Action::DbRequest(
+                    req_id,
+                    DbRequest::Draft(self.job.id),
+                )));
+            }
+            Action::DbResponse(req_id, DbResponse::Draft(_, draft))
+                if self.pending_draft == Some(req_id) =>
+            {
+                self.pending_draft = None;
+                if let Some(draft) = draft {
+                    return Ok(Some(Action::Confirm {
+                        title: "Restore draft".into(),
+                        message: format!(
+                            "Restore the changes left unsaved at {}? They're discarded otherwise.",
+                            local_time(&draft.saved_at)
+                        ),
+                        on_yes: Box::new(Action::RestoreDraft(draft)),
+                    }));
+                }
+            }
+            Action::RestoreDraft(draft) if draft.job_id == self.job.id => {
+                self.fill_fields(|field| {
+                    draft
+                        .fields
+                        .get(field as usize)
+                        .cloned()
+                        .unwrap_or_default()
+                });
+                self.drafted = Some(self.draft_fields());
+            }
+            Action::Save => match self.job_from_fields() {
+                Ok(job) => {
+                    if let Some(tx) = &self.command_tx {
+                        // kept again on the next tick if the form is come back to unsaved
+                        tx.send(Action::DbRequest(
+                            RequestId::next(),
+                            DbRequest::DeleteDraft(self.job.id),
+                        ))?;
+                        self.drafted = None;
+                        if job.id != 0 && job.status != self.job.status {
+                            tx.send(Action::ChangeMode(Mode::Popup("status_note")))?;
+                            tx.send(Action::PromptStatusNote(job))?;
+                        } else {
+                            tx.send(Action::SaveJob(job))?;
+                            tx.send(Action::ChangeMode(Mode::Home))?;
+                        }
+                    }
+                }
+                Err(message) => return Ok(Some(Action::Error(message))),
+            },
+            Action::PickFiles => {
+                if let Some(tx) = &self.command_tx {
+                    let files =
+                        Files::from_str(&self.field_value(Field::Files)).unwrap_or_default();
+                    tx.send(Action::ChangeMode(Mode::Popup("file_picker")))?;
+                    tx.send(Action::PromptFilePicker(files))?;
+                }
+            }
+            Action::FilesPicked(files) => {
+                if let Some(text_area) = self.text_fields.get_mut(&Field::Files) {
+                    let mut picked = TextArea::from([files.to_string()]);
+                    if let Some(block) = text_area.block() {
+                        picked.set_block(block.clone());
+                    }
+                    *text_area = picked;
+                    self.focused_field = Field::Files;
+                    self.focused_updated = false;
+                }
             }
             _ => {}
         }
@@ -253,6 +511,7 @@ impl Component for EditJob<'_> {
         frame: &mut Frame,
         area: ratatui::prelude::Rect,
     ) -> color_eyre::eyre::Result<()> {
+        self.ensure_fields();
         let root = Block::bordered()
             .padding(Padding::uniform(1))
             .title("Edit Job");
@@ -276,9 +535,10 @@ impl Component for EditJob<'_> {
             Constraint::Length(3),
             Constraint::Length(3),
             Constraint::Length(3),
+            Constraint::Length(3),
         ]);
 
-        // Order chunks: c1 r1, c1 r2, ..., c1 r6, c2 r1, c2 r2, ..., c2 r6
+        // Order chunks: c1 r1, c1 r2, ..., c1 r7, c2 r1, c2 r2, ..., c2 r7
         let col1_chunks = layout_vertical.split(layout_horizontal[0]);
         let col2_chunks = layout_vertical.split(layout_horizontal[1]);
         let layout = col1_chunks
@@ -292,73 +552,81 @@ impl Component for EditJob<'_> {
         let position_chunk_split =
             Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
                 .split(position_chunk);
-        frame.render_widget(
-            self.text_fields.get(&Field::Position).unwrap(),
-            position_chunk_split[0],
-        );
-        frame.render_widget(
-            self.text_fields.get(&Field::PositionCategory).unwrap(),
-            position_chunk_split[1],
-        );
+        self.render_field(frame, Field::Position, position_chunk_split[0]);
+        self.render_field(frame, Field::PositionCategory, position_chunk_split[1]);
 
         // Company
         let company_chunk = layout[1];
-        frame.render_widget(
-            self.text_fields.get(&Field::CompanyName).unwrap(),
-            company_chunk,
-        );
+        self.render_field(frame, Field::CompanyName, company_chunk);
 
         // Work Type
         let work_type_chunk = layout[2];
-        frame.render_widget(
-            self.text_fields.get(&Field::WorkType).unwrap(),
-            work_type_chunk,
-        );
+        self.render_field(frame, Field::WorkType, work_type_chunk);
 
         // Location + Location Type
         let location_chunk = layout[3];
         let location_chunk_split =
             Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
                 .split(location_chunk);
-        frame.render_widget(
-            self.text_fields.get(&Field::Location).unwrap(),
-            location_chunk_split[0],
-        );
-        frame.render_widget(
-            self.text_fields.get(&Field::LocationType).unwrap(),
-            location_chunk_split[1],
-        );
-
-        // Date
+        self.render_field(frame, Field::Location, location_chunk_split[0]);
+        self.render_field(frame, Field::LocationType, location_chunk_split[1]);
+
+        // Date + Deadline
         let date_chunk = layout[4];
-        frame.render_widget(
-            self.text_fields.get(&Field::ApplicationDate).unwrap(),
-            date_chunk,
-        );
+        let date_chunk_split =
+            Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+                .split(date_chunk);
+        self.render_field(frame, Field::ApplicationDate, date_chunk_split[0]);
+        self.render_field(frame, Field::Deadline, date_chunk_split[1]);
 
-        // Status
+        // Status + Rating
         let status_chunk = layout[5];
-        frame.render_widget(self.text_fields.get(&Field::Status).unwrap(), status_chunk);
-
-        // Contact Info
-        let contact_info_chunk = layout[6];
-        frame.render_widget(
-            self.text_fields.get(&Field::ContactInfo).unwrap(),
-            contact_info_chunk,
-        );
+        let status_chunk_split =
+            Layout::horizontal([Constraint::Percentage(70), Constraint::Percentage(30)])
+                .split(status_chunk);
+        self.render_field(frame, Field::Status, status_chunk_split[0]);
+        self.render_field(frame, Field::Rating, status_chunk_split[1]);
+
+        // Salary range + Currency
+        let salary_chunk = layout[6];
+        let salary_chunk_split = Layout::horizontal([
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+            Constraint::Percentage(30),
+        ])
+        .split(salary_chunk);
+        self.render_field(frame, Field::SalaryMin, salary_chunk_split[0]);
+        self.render_field(frame, Field::SalaryMax, salary_chunk_split[1]);
+        self.render_field(frame, Field::Currency, salary_chunk_split[2]);
 
         // Url
         let url_chunk = layout[7];
-        frame.render_widget(self.text_fields.get(&Field::Url).unwrap(), url_chunk);
+        self.render_field(frame, Field::Url, url_chunk);
 
         // Files
         // TODO: needs custom rendering for options
         let files_chunk = layout[8];
-        frame.render_widget(self.text_fields.get(&Field::Files).unwrap(), files_chunk);
+        self.render_field(frame, Field::Files, files_chunk);
 
-        // Notes
+        // Notes, with misspellings underlined
         let notes_chunk = layout[9];
-        frame.render_widget(self.text_fields.get(&Field::Notes).unwrap(), notes_chunk);
+        if let Some(notes) = self.text_fields.get_mut(&Field::Notes) {
+            self.speller
+                .highlight(notes, &self.config, self.command_tx.as_ref());
+        }
+        self.render_field(frame, Field::Notes, notes_chunk);
+
+        // Compensation notes
+        let compensation_notes_chunk = layout[10];
+        self.render_field(frame, Field::CompensationNotes, compensation_notes_chunk);
+
+        // Tags
+        let tags_chunk = layout[11];
+        self.render_field(frame, Field::Tags, tags_chunk);
+
+        // Description, taking up the rest of the second column
+        let description_chunk = layout[12].union(layout[13]);
+        self.render_field(frame, Field::Description, description_chunk);
 
         // Confirm area
         let confirm_button = Paragraph::new(" Save Changes ")
@@ -366,7 +634,7 @@ impl Component for EditJob<'_> {
                 Block::bordered()
                     .padding(Padding::horizontal(2))
                     .title("Confirm")
-                    .border_style(Style::default().fg(Color::Green)),
+                    .border_style(Style::default().fg(self.config.theme.success)),
             )
             .centered();
         frame.render_widget(confirm_button, confirm_area);
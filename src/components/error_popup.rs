@@ -0,0 +1,125 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::Style,
+    text::{Line, Text},
+    widgets::{self, Paragraph, Wrap},
+};
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+};
+
+// widest the popup gets, borders and padding included
+const WIDTH: u16 = 70;
+
+/// Every `Action::Error` ends up here: what failed, over whatever was on screen, until it's
+/// dismissed. Errors arriving while one is shown queue up behind it, without repeats.
+pub struct ErrorPopup {
+    config: Config,
+    messages: Vec<String>,
+}
+impl ErrorPopup {
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            messages: Vec::new(),
+        }
+    }
+
+    fn dismiss(&mut self) -> Option<Action> {
+        if !self.messages.is_empty() {
+            self.messages.remove(0);
+        }
+        self.messages.is_empty().then_some(Action::ExitPopup)
+    }
+}
+
+impl Component for ErrorPopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("error")
+    }
+    fn id(&self) -> String {
+        "Error Popup".into()
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::Error(message) = action
+            && !self.messages.contains(&message)
+        {
+            self.messages.push(message);
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Enter | KeyCode::Char(' ') => return Ok(self.dismiss()),
+            KeyCode::Esc => {
+                self.messages.clear();
+                return Ok(Some(Action::ExitPopup));
+            }
+            KeyCode::Char('c') => {
+                if let Some(message) = self.messages.first() {
+                    return Ok(Some(Action::CopyToClipboard(message.clone())));
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let Some(message) = self.messages.first() else {
+            return Ok(());
+        };
+        let width = WIDTH.min(area.width);
+        // borders and padding take four columns; borders, the gap and the hint four rows
+        let text_width = usize::from(width.saturating_sub(4)).max(1);
+        let lines = message
+            .lines()
+            .map(|line| line.chars().count().div_ceil(text_width).max(1))
+            .sum::<usize>();
+        let height = u16::try_from(lines + 4).unwrap_or(u16::MAX);
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(width),
+            Constraint::Length(height),
+            &self.config.theme,
+        );
+        let title = match self.messages.len() {
+            1 => "Error".to_string(),
+            count => format!("Error (1 of {count})"),
+        };
+        let hint = if self.messages.len() > 1 {
+            "[enter] next   [c] copy   [esc] dismiss all"
+        } else {
+            "[enter] dismiss   [c] copy"
+        };
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.error))
+            .padding(widgets::Padding::horizontal(1))
+            .title(title);
+        let mut text = Text::raw(message.clone());
+        text.push_line(Line::raw(""));
+        text.push_line(Line::styled(hint, Style::default().fg(self.config.theme.muted)).centered());
+        frame.render_widget(
+            Paragraph::new(text).wrap(Wrap { trim: false }).block(block),
+            area,
+        );
+        Ok(())
+    }
+}
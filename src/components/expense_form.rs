@@ -0,0 +1,194 @@
+use std::str::FromStr;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::Line,
+    widgets::{self, Block, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::{Expense, ExpenseCategory},
+    stats::parse_amount,
+};
+
+const FIELDS: [&str; 4] = ["Date", "Amount", "Category", "Description"];
+
+pub struct ExpenseForm<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    expense: Expense,
+    fields: Vec<TextArea<'a>>,
+    focused: usize,
+}
+impl ExpenseForm<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            expense: Expense::default(),
+            fields: Self::create_fields(&Expense::default()),
+            focused: 0,
+        }
+    }
+
+    fn create_fields<'a>(expense: &Expense) -> Vec<TextArea<'a>> {
+        let values = [
+            expense.date.clone(),
+            String::new(),
+            expense.category.to_string(),
+            expense.description.clone(),
+        ];
+        FIELDS
+            .iter()
+            .zip(values)
+            .map(|(title, value)| {
+                let mut field = TextArea::from([value]);
+                field.set_block(
+                    Block::bordered()
+                        .padding(Padding::horizontal(1))
+                        .title(*title),
+                );
+                field
+            })
+            .collect()
+    }
+
+    fn field_value(&self, index: usize) -> String {
+        self.fields[index].lines().join(" ").trim().to_string()
+    }
+
+    fn expense_from_fields(&self) -> Result<Expense, String> {
+        let date = self.field_value(0);
+        let valid_date = date.is_empty()
+            || (date.len() == 10
+                && date.chars().enumerate().all(|(i, c)| {
+                    if i == 4 || i == 7 {
+                        c == '-'
+                    } else {
+                        c.is_ascii_digit()
+                    }
+                }));
+        if !valid_date {
+            return Err(format!("Invalid Date: \"{date}\" (expected YYYY-MM-DD)"));
+        }
+        let amount = self.field_value(1);
+        let amount_cents =
+            parse_amount(&amount).ok_or_else(|| format!("Invalid Amount: \"{amount}\""))?;
+        let category = self.field_value(2);
+        let category = ExpenseCategory::from_str(&category)
+            .map_err(|_| format!("Invalid Category: \"{category}\""))?;
+        Ok(Expense {
+            date,
+            amount_cents,
+            category,
+            description: self.field_value(3),
+            ..self.expense.clone()
+        })
+    }
+}
+
+impl Component for ExpenseForm<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("expense_form")
+    }
+    fn id(&self) -> String {
+        "Expense Form".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::PopulateExpenseForm(expense) = action {
+            self.fields = Self::create_fields(&expense);
+            self.expense = expense;
+            self.focused = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Tab | KeyCode::Down => self.focused = (self.focused + 1) % FIELDS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + FIELDS.len() - 1) % FIELDS.len();
+            }
+            KeyCode::Enter => match self.expense_from_fields() {
+                Ok(expense) => {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::SaveExpense(expense))?;
+                }
+                Err(message) => return Ok(Some(Action::Error(message))),
+            },
+            _ => {
+                self.fields[self.focused].input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(50),
+            Constraint::Length(16),
+            &self.config.theme,
+        );
+        let title = match self.expense.job_id {
+            Some(id) => format!("New expense for application #{id}"),
+            None => "New expense".to_string(),
+        };
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title(title)
+            .title_bottom(Line::from("[tab] next field   [enter] save   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical([Constraint::Length(3); 4]).split(inner);
+        let theme = &self.config.theme;
+        for (i, (field, row)) in self.fields.iter_mut().zip(rows.iter()).enumerate() {
+            let color = if i == self.focused {
+                theme.focus
+            } else {
+                theme.border
+            };
+            if let Some(block) = field.block().cloned() {
+                field.set_block(block.border_style(Style::default().fg(color)));
+            }
+            field.set_cursor_style(if i == self.focused {
+                Style::default().add_modifier(ratatui::style::Modifier::REVERSED)
+            } else {
+                Style::default()
+            });
+            frame.render_widget(&*field, *row);
+        }
+        Ok(())
+    }
+}
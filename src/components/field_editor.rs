@@ -0,0 +1,187 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Padding},
+};
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::{builder::JobApplicationBuilder, schema::JobApplication},
+    privacy::Privacy,
+};
+
+/// A field simple enough to change on its own from the detail view.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum InlineField {
+    FollowUp,
+    Deadline,
+    Rating,
+    Tags,
+}
+impl InlineField {
+    pub fn label(self) -> &'static str {
+        match self {
+            InlineField::FollowUp => "Follow up",
+            InlineField::Deadline => "Deadline",
+            InlineField::Rating => "Rating",
+            InlineField::Tags => "Tags",
+        }
+    }
+
+    fn hint(self) -> &'static str {
+        match self {
+            InlineField::FollowUp | InlineField::Deadline => "YYYY-MM-DD, empty to clear",
+            InlineField::Rating => "1 to 5, empty to clear",
+            InlineField::Tags => "Comma separated",
+        }
+    }
+
+    pub fn value(self, job: &JobApplication) -> String {
+        match self {
+            InlineField::FollowUp => job.follow_up_date.clone().unwrap_or_default(),
+            InlineField::Deadline => job.deadline.clone().unwrap_or_default(),
+            InlineField::Rating => job.stars().unwrap_or_default(),
+            InlineField::Tags => job.tags.join(", "),
+        }
+    }
+
+    /// `job` with the field set to `value`, checked as the edit form would.
+    fn apply(self, job: &JobApplication, value: &str) -> Result<JobApplication, String> {
+        let builder = JobApplicationBuilder::from(job.clone());
+        match self {
+            InlineField::FollowUp => builder.follow_up_date(value),
+            InlineField::Deadline => builder.deadline(value),
+            InlineField::Rating => builder.rating(value),
+            InlineField::Tags => builder.tags(value),
+        }
+        .build()
+    }
+}
+
+/// A one-line popup changing a single field of an application, saved as soon as it's
+/// confirmed.
+pub struct FieldEditor<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: JobApplication,
+    field: InlineField,
+    input: TextArea<'a>,
+    error: Option<String>,
+    privacy: Privacy,
+}
+impl FieldEditor<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: JobApplication::default(),
+            field: InlineField::FollowUp,
+            input: TextArea::default(),
+            error: None,
+            privacy: Privacy::default(),
+        }
+    }
+}
+
+impl Component for FieldEditor<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("field_editor")
+    }
+    fn id(&self) -> String {
+        "Field Editor".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::EditField(job, field) => {
+                self.input = TextArea::from([field.value(&job)]);
+                self.input.set_cursor_line_style(Style::default());
+                self.input.move_cursor(tui_textarea::CursorMove::End);
+                self.job = job;
+                self.field = field;
+                self.error = None;
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Enter => {
+                let value = self.input.lines().join(" ").trim().to_string();
+                if value == self.field.value(&self.job) {
+                    tx.send(Action::ExitPopup)?;
+                    return Ok(None);
+                }
+                match self.field.apply(&self.job, &value) {
+                    Ok(job) => {
+                        tx.send(Action::ExitPopup)?;
+                        tx.send(Action::SaveJob(job))?;
+                    }
+                    Err(error) => self.error = Some(error),
+                }
+            }
+            _ => {
+                self.error = None;
+                self.input.input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(50),
+            Constraint::Length(3),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let company = self.privacy.text(&self.job.company_name, "application");
+        let bottom = match &self.error {
+            Some(error) => Line::styled(error.clone(), Style::default().fg(theme.error)),
+            None => Line::from("[enter] save   [esc] cancel"),
+        };
+        self.input.set_block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.focus))
+                .padding(Padding::horizontal(1))
+                .title(format!("{} for {company}", self.field.label()))
+                .title_bottom(bottom.centered()),
+        );
+        self.input.set_placeholder_text(self.field.hint());
+        frame.render_widget(&self.input, area);
+        Ok(())
+    }
+}
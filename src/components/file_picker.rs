@@ -0,0 +1,300 @@
+use std::path::{Path, PathBuf};
+
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, List, ListItem, ListState, Padding, Paragraph},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::{FileKind, Files},
+};
+
+/// Browses the filesystem to attach a CV, cover letter and other documents to the
+/// application being edited, instead of typing their paths.
+pub struct FilePicker {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    dir: PathBuf,
+    // `(name, is_dir)`, directories first
+    entries: Vec<(String, bool)>,
+    selected: ListState,
+    show_hidden: bool,
+    files: Files,
+    // why the directory couldn't be listed, if it couldn't
+    error: Option<String>,
+}
+impl FilePicker {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            dir: PathBuf::new(),
+            entries: Vec::new(),
+            selected: ListState::default(),
+            show_hidden: false,
+            files: Files::default(),
+            error: None,
+        }
+    }
+
+    /// Start beside the first document already attached, or in the home directory.
+    fn start_dir(files: &Files) -> PathBuf {
+        files
+            .entries()
+            .first()
+            .and_then(|(_, path)| Path::new(path).parent().map(Path::to_path_buf))
+            .filter(|dir| dir.is_dir())
+            .or_else(|| directories::UserDirs::new().map(|dirs| dirs.home_dir().to_path_buf()))
+            .or_else(|| std::env::current_dir().ok())
+            .unwrap_or_default()
+    }
+
+    fn open(&mut self, dir: PathBuf) {
+        let listing = std::fs::read_dir(&dir).map(|entries| {
+            let mut entries = entries
+                .filter_map(Result::ok)
+                .map(|entry| {
+                    let name = entry.file_name().to_string_lossy().to_string();
+                    (name, entry.path().is_dir())
+                })
+                .filter(|(name, _)| self.show_hidden || !name.starts_with('.'))
+                .collect::<Vec<_>>();
+            entries.sort_by_key(|(name, is_dir)| (!is_dir, name.to_lowercase()));
+            entries
+        });
+        match listing {
+            Ok(entries) => {
+                self.entries = entries;
+                self.error = None;
+            }
+            Err(err) => {
+                self.entries.clear();
+                self.error = Some(format!("Can't list {}: {err}", dir.display()));
+            }
+        }
+        if dir.parent().is_some() {
+            self.entries.insert(0, ("..".to_string(), true));
+        }
+        self.dir = dir;
+        self.selected
+            .select((!self.entries.is_empty()).then_some(0));
+    }
+
+    fn selected_entry(&self) -> Option<&(String, bool)> {
+        self.selected
+            .selected()
+            .and_then(|idx| self.entries.get(idx))
+    }
+
+    fn parent(&mut self) {
+        let Some(parent) = self.dir.parent().map(Path::to_path_buf) else {
+            return;
+        };
+        // keep the directory just left selected
+        let left = self
+            .dir
+            .file_name()
+            .map(|name| name.to_string_lossy().to_string());
+        self.open(parent);
+        if let Some(idx) =
+            left.and_then(|left| self.entries.iter().position(|(name, _)| *name == left))
+        {
+            self.selected.select(Some(idx));
+        }
+    }
+
+    /// Attach the selected file as `kind`, or as the first of CV, cover letter and additional
+    /// document not yet attached when `None`.
+    fn pick(&mut self, kind: Option<FileKind>) {
+        let Some((name, false)) = self.selected_entry() else {
+            return;
+        };
+        let path = self.dir.join(name).to_string_lossy().to_string();
+        let kind = kind.unwrap_or(if self.files.cv.is_empty() {
+            FileKind::Cv
+        } else if self.files.cover_letter.is_empty() {
+            FileKind::CoverLetter
+        } else {
+            FileKind::Additional
+        });
+        if kind == FileKind::Additional && self.files.additional_documents.contains(&path) {
+            return;
+        }
+        self.files.push(kind, path);
+    }
+
+    fn picks(&self) -> Paragraph<'static> {
+        let theme = &self.config.theme;
+        let label = Style::default().fg(theme.muted);
+        let missing = Style::default().fg(theme.warning);
+        let row = |name: &'static str, path: &str| {
+            let mut spans = vec![Span::styled(format!("{name:<14}"), label)];
+            if path.is_empty() {
+                spans.push(Span::styled("–", label));
+            } else {
+                spans.push(Span::raw(path.to_string()));
+                if !Path::new(path).exists() {
+                    spans.push(Span::styled("  missing", missing));
+                }
+            }
+            Line::from(spans)
+        };
+        let mut lines = vec![
+            row("CV", &self.files.cv),
+            row("Cover letter", &self.files.cover_letter),
+        ];
+        if self.files.additional_documents.is_empty() {
+            lines.push(row("Additional", ""));
+        }
+        for path in &self.files.additional_documents {
+            lines.push(row("Additional", path));
+        }
+        Paragraph::new(lines).block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+                .title("Attached"),
+        )
+    }
+}
+
+impl Component for FilePicker {
+    fn mode(&self) -> Mode {
+        Mode::Popup("file_picker")
+    }
+    fn id(&self) -> String {
+        "File Picker".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::PromptFilePicker(files) = action {
+            let dir = Self::start_dir(&files);
+            self.files = files;
+            self.open(dir);
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Char('s') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                // files can go between being picked and being saved
+                if let Some((_, path)) = self
+                    .files
+                    .entries()
+                    .into_iter()
+                    .find(|(_, path)| !Path::new(path).exists())
+                {
+                    return Ok(Some(Action::Error(format!("File not found: {path}"))));
+                }
+                tx.send(Action::ExitPopup)?;
+                tx.send(Action::FilesPicked(self.files.clone()))?;
+            }
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Backspace | KeyCode::Left | KeyCode::Char('h') => self.parent(),
+            KeyCode::Enter | KeyCode::Right | KeyCode::Char('l') => match self.selected_entry() {
+                Some((name, true)) if name == ".." => self.parent(),
+                Some((name, true)) => self.open(self.dir.join(name)),
+                Some((_, false)) if key.code == KeyCode::Enter => self.pick(None),
+                _ => {}
+            },
+            KeyCode::Char('c') => self.pick(Some(FileKind::Cv)),
+            KeyCode::Char('v') => self.pick(Some(FileKind::CoverLetter)),
+            KeyCode::Char('a') => self.pick(Some(FileKind::Additional)),
+            KeyCode::Char('x') => self.files = Files::default(),
+            KeyCode::Char('.') => {
+                self.show_hidden = !self.show_hidden;
+                self.open(self.dir.clone());
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(70),
+            Constraint::Percentage(80),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title("Attach documents")
+            .title_bottom(
+                Line::from(
+                    "[enter] open / attach   [c] CV   [v] cover letter   [a] additional   [x] clear   [.] hidden   [ctrl-s] done   [esc] cancel",
+                )
+                .centered(),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let picks = 3 + self.files.additional_documents.len().max(1) as u16;
+        let [dir, listing, attached] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Min(3),
+            Constraint::Length(picks),
+        ])
+        .areas(inner);
+        frame.render_widget(
+            Line::styled(
+                self.dir.display().to_string(),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            dir,
+        );
+        if let Some(error) = &self.error {
+            frame.render_widget(
+                Line::styled(error.clone(), Style::default().fg(theme.error)),
+                listing,
+            );
+        }
+        let items = self
+            .entries
+            .iter()
+            .filter(|_| self.error.is_none())
+            .map(|(name, is_dir)| {
+                if *is_dir {
+                    ListItem::new(format!("{name}/")).style(Style::default().fg(theme.link))
+                } else {
+                    ListItem::new(name.clone())
+                }
+            });
+        frame.render_stateful_widget(
+            List::new(items).highlight_style(Style::default().bg(theme.selection_bg)),
+            listing,
+            &mut self.selected,
+        );
+        frame.render_widget(self.picks(), attached);
+        Ok(())
+    }
+}
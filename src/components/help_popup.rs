@@ -0,0 +1,126 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets,
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::{Config, key_sequence_to_string},
+};
+
+pub struct HelpPopup {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    mode: Mode,
+}
+impl HelpPopup {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            mode: Mode::Home,
+        }
+    }
+
+    fn binding_lines(&self, mode: Mode) -> Vec<Line<'static>> {
+        let Some(bindings) = self.config.keybindings.get(&mode) else {
+            return Vec::new();
+        };
+        let mut bindings = bindings
+            .iter()
+            .map(|(keys, action)| (action.to_string(), key_sequence_to_string(keys)))
+            .collect::<Vec<_>>();
+        bindings.sort();
+        bindings
+            .into_iter()
+            .map(|(action, keys)| {
+                Line::from(vec![
+                    Span::styled(
+                        format!("{keys:>14}  "),
+                        Style::default().fg(self.config.theme.focus),
+                    ),
+                    Span::raw(action),
+                ])
+            })
+            .collect()
+    }
+}
+
+impl Component for HelpPopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("help_popup")
+    }
+    fn id(&self) -> String {
+        "Help Popup".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::ShowHelp(mode) = action {
+            self.mode = mode;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Some(tx) = &self.command_tx {
+            match key.code {
+                KeyCode::Esc | KeyCode::Char('q') => tx.send(Action::ExitPopup)?,
+                KeyCode::Char('t') => {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::ToggleTutorial)?;
+                }
+                _ => {}
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let heading = Style::default().add_modifier(Modifier::BOLD);
+        let mut lines = vec![Line::styled(format!("{:?}", self.mode), heading)];
+        lines.extend(self.binding_lines(self.mode));
+        lines.push(Line::raw(""));
+        lines.push(Line::styled("Everywhere", heading));
+        lines.extend(self.binding_lines(Mode::Global));
+        lines.push(Line::raw(""));
+        lines.push(Line::from("[t] start the tutorial   [esc] close").centered());
+
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(50),
+            Constraint::Length(lines.len() as u16 + 2),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title("Help");
+        frame.render_widget(
+            widgets::Paragraph::new(Text::from(lines)).block(block),
+            area,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    text::{Line, Text},
+    widgets::{self, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    import::ImportSummary,
+};
+
+pub struct ImportSummaryPopup {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    summary: ImportSummary,
+    scroll: u16,
+}
+impl ImportSummaryPopup {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            summary: ImportSummary::default(),
+            scroll: 0,
+        }
+    }
+}
+
+impl Component for ImportSummaryPopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("import_summary")
+    }
+    fn id(&self) -> String {
+        "Import Summary".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::ImportSummary(summary) = action {
+            self.summary = summary;
+            self.scroll = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Enter => {
+                if let Some(tx) = &self.command_tx {
+                    tx.send(Action::ExitPopup)?;
+                }
+            }
+            // imports often file everything under one category, so offer to tidy up
+            KeyCode::Char('g') => {
+                if let Some(tx) = &self.command_tx {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::ShowCategorySuggestions)?;
+                }
+            }
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let heading = Style::default().add_modifier(Modifier::BOLD);
+        let muted = Style::default().fg(self.config.theme.muted);
+        let mut lines = vec![
+            Line::from(format!(
+                "Imported {} application(s) from {}",
+                self.summary.imported, self.summary.source
+            )),
+            Line::raw(""),
+        ];
+        if !self.summary.duplicates.is_empty() {
+            lines.push(Line::styled(
+                format!("Skipped {} duplicate(s)", self.summary.duplicates.len()),
+                heading,
+            ));
+            lines.extend(
+                self.summary
+                    .duplicates
+                    .iter()
+                    .map(|d| Line::styled(format!("  {d}"), muted)),
+            );
+            lines.push(Line::raw(""));
+        }
+        if !self.summary.conflicts.is_empty() {
+            lines.push(Line::styled(
+                format!(
+                    "Sent {} changed application(s) to the conflicts inbox",
+                    self.summary.conflicts.len()
+                ),
+                heading,
+            ));
+            lines.extend(
+                self.summary
+                    .conflicts
+                    .iter()
+                    .map(|c| Line::styled(format!("  {c}"), muted)),
+            );
+            lines.push(Line::raw(""));
+        }
+        if !self.summary.invalid.is_empty() {
+            lines.push(Line::styled(
+                format!("Rejected {} invalid row(s)", self.summary.invalid.len()),
+                heading,
+            ));
+            lines.extend(
+                self.summary
+                    .invalid
+                    .iter()
+                    .map(|e| Line::styled(format!("  {e}"), muted)),
+            );
+        }
+
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(60),
+            Constraint::Percentage(60),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title("Import")
+            .title_bottom(
+                Line::from("[g] review categories   [↑/↓] scroll   [esc] close").centered(),
+            );
+        frame.render_widget(
+            widgets::Paragraph::new(Text::from(lines))
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0))
+                .block(block),
+            area,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,127 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    tui::Event,
+};
+
+/// Asks for a line of text, e.g. a job posting's link, and runs the action it was opened for
+/// with it.
+pub struct InputPrompt<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    title: String,
+    input: TextArea<'a>,
+    on_submit: Option<Action>,
+}
+impl InputPrompt<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            title: String::new(),
+            input: TextArea::default(),
+            on_submit: None,
+        }
+    }
+}
+
+impl Component for InputPrompt<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("input_prompt")
+    }
+    fn id(&self) -> String {
+        "Input Prompt".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::ShowPrompt(title, placeholder, on_submit) = action {
+            self.title = title;
+            self.input = TextArea::from([on_submit.input().unwrap_or_default()]);
+            self.input.move_cursor(tui_textarea::CursorMove::End);
+            self.input.set_cursor_line_style(Style::default());
+            self.input.set_placeholder_text(placeholder);
+            self.on_submit = Some(*on_submit);
+        }
+        Ok(None)
+    }
+    fn handle_events(&mut self, event: Option<Event>) -> color_eyre::eyre::Result<Option<Action>> {
+        match event {
+            // a link copied with a trailing newline would otherwise be cut at it
+            Some(Event::Paste(text)) => {
+                self.input.insert_str(text.trim());
+                Ok(None)
+            }
+            Some(Event::Key(key)) => self.handle_key_event(key),
+            _ => Ok(None),
+        }
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => {
+                self.on_submit = None;
+                tx.send(Action::ExitPopup)?;
+            }
+            KeyCode::Enter => {
+                let input = self.input.lines().join("").trim().to_string();
+                if !input.is_empty() {
+                    tx.send(Action::ExitPopup)?;
+                    if let Some(action) = self.on_submit.take().and_then(|on| on.with_input(input))
+                    {
+                        tx.send(action)?;
+                    }
+                }
+            }
+            _ => {
+                self.input.input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(60),
+            Constraint::Length(3),
+            &self.config.theme,
+        );
+        self.input.set_block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(self.config.theme.focus))
+                .padding(Padding::horizontal(1))
+                .title(self.title.clone())
+                .title_bottom(Line::from("[enter] ok   [esc] cancel").centered()),
+        );
+        frame.render_widget(&self.input, area);
+        Ok(())
+    }
+}
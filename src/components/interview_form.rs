@@ -0,0 +1,239 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::{Line, Text},
+    widgets::{self, Block, Padding, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::Interview,
+    dates::parse_datetime,
+    interviews::{Conflict, find_conflicts},
+    privacy::Privacy,
+};
+
+const FIELDS: [&str; 3] = [
+    "Starts at (YYYY-MM-DD HH:MM)",
+    "Duration (minutes)",
+    "Notes",
+];
+
+pub struct InterviewForm<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    interview: Interview,
+    existing: Vec<(Interview, String)>,
+    conflicts: Vec<Conflict>,
+    fields: Vec<TextArea<'a>>,
+    focused: usize,
+    privacy: Privacy,
+}
+impl InterviewForm<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            interview: Interview::default(),
+            existing: Vec::new(),
+            conflicts: Vec::new(),
+            fields: Self::create_fields(&Interview::default()),
+            focused: 0,
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn create_fields<'a>(interview: &Interview) -> Vec<TextArea<'a>> {
+        let values = [
+            interview.starts_at.clone(),
+            interview.duration_minutes.to_string(),
+            interview.notes.clone(),
+        ];
+        FIELDS
+            .iter()
+            .zip(values)
+            .map(|(title, value)| {
+                let mut field = TextArea::from([value]);
+                field.set_block(
+                    Block::bordered()
+                        .padding(Padding::horizontal(1))
+                        .title(*title),
+                );
+                field
+            })
+            .collect()
+    }
+
+    fn field_value(&self, index: usize) -> String {
+        self.fields[index].lines().join(" ").trim().to_string()
+    }
+
+    fn interview_from_fields(&self) -> Result<Interview, String> {
+        let starts_at = self.field_value(0);
+        if parse_datetime(&starts_at).is_none() {
+            return Err(format!(
+                "Invalid start: \"{starts_at}\" (expected YYYY-MM-DD HH:MM)"
+            ));
+        }
+        let duration = self.field_value(1);
+        let duration_minutes = duration
+            .parse::<u32>()
+            .ok()
+            .filter(|minutes| *minutes > 0)
+            .ok_or_else(|| format!("Invalid duration: \"{duration}\""))?;
+        Ok(Interview {
+            starts_at,
+            duration_minutes,
+            notes: self.field_value(2),
+            ..self.interview.clone()
+        })
+    }
+
+    // re-checked on every edit so the warning follows what is typed
+    fn update_conflicts(&mut self) {
+        self.conflicts = match self.interview_from_fields() {
+            Ok(interview) => find_conflicts(
+                &interview,
+                &self.existing,
+                self.config.interviews.buffer_minutes,
+            )
+            .into_iter()
+            .map(|conflict| Conflict {
+                company: self.privacy.text(
+                    &conflict.company,
+                    &format!("Company #{}", conflict.interview.job_id),
+                ),
+                ..conflict
+            })
+            .collect(),
+            Err(_) => Vec::new(),
+        };
+    }
+}
+
+impl Component for InterviewForm<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("interview_form")
+    }
+    fn id(&self) -> String {
+        "Interview Form".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::PopulateInterviewForm(interview, existing) => {
+                self.fields = Self::create_fields(&interview);
+                self.interview = interview;
+                self.existing = existing;
+                self.focused = 0;
+                self.update_conflicts();
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Tab | KeyCode::Down => self.focused = (self.focused + 1) % FIELDS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + FIELDS.len() - 1) % FIELDS.len();
+            }
+            KeyCode::Enter => match self.interview_from_fields() {
+                Ok(interview) => {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::SaveInterview(interview))?;
+                }
+                Err(message) => return Ok(Some(Action::Error(message))),
+            },
+            _ => {
+                self.fields[self.focused].input(key);
+                self.update_conflicts();
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let warning_height = if self.conflicts.is_empty() {
+            0
+        } else {
+            self.conflicts.len() as u16 + 2
+        };
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(50),
+            Constraint::Length(11 + warning_height),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title("Schedule interview")
+            .title_bottom(Line::from("[tab] next field   [enter] save   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [fields, warnings] =
+            Layout::vertical([Constraint::Length(9), Constraint::Min(0)]).areas(inner);
+        let rows = Layout::vertical([Constraint::Length(3); 3]).split(fields);
+        let theme = &self.config.theme;
+        for (i, (field, row)) in self.fields.iter_mut().zip(rows.iter()).enumerate() {
+            let color = if i == self.focused {
+                theme.focus
+            } else {
+                theme.border
+            };
+            if let Some(block) = field.block().cloned() {
+                field.set_block(block.border_style(Style::default().fg(color)));
+            }
+            frame.render_widget(&*field, *row);
+        }
+
+        if !self.conflicts.is_empty() {
+            let lines = self
+                .conflicts
+                .iter()
+                .map(|conflict| Line::raw(format!("! {}", conflict.message())));
+            frame.render_widget(
+                widgets::Paragraph::new(Text::from(lines.collect::<Vec<_>>()))
+                    .style(Style::default().fg(self.config.theme.warning))
+                    .wrap(Wrap { trim: false })
+                    .block(Block::bordered().title("Conflicts")),
+                warnings,
+            );
+        }
+        Ok(())
+    }
+}
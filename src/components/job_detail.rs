@@ -0,0 +1,640 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{self, Cell, Row, Table, TableState, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, JobChange, Subscription},
+    analysis,
+    app::Mode,
+    components::{component::Component, field_editor::InlineField},
+    config::{Config, DestructiveAction},
+    database::schema::{Contact, FieldChange, FileKind, JobApplication, StatusChange},
+    database::worker::{DbRequest, DbResponse, RequestId},
+    dates::local_time,
+    links::{self, NoteLink},
+    privacy::Privacy,
+};
+
+/// The fields that can be changed from here without the edit form, in the order they're shown.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Editable {
+    Status,
+    Inline(InlineField),
+}
+const EDITABLE: [Editable; 5] = [
+    Editable::Status,
+    Editable::Inline(InlineField::FollowUp),
+    Editable::Inline(InlineField::Deadline),
+    Editable::Inline(InlineField::Rating),
+    Editable::Inline(InlineField::Tags),
+];
+
+/// Read-only view of a single application along with its status timeline.
+pub struct JobDetail {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: JobApplication,
+    history: Vec<StatusChange>,
+    // the application's activity log, latest first
+    activity: Vec<FieldChange>,
+    contact: TableState,
+    // lines scrolled past in the details pane, for long notes and descriptions
+    scroll: u16,
+    // applications whose notes link to this one
+    backlinks: Vec<JobApplication>,
+    pending_backlinks: Option<RequestId>,
+    // the application and its history asked for again by a refresh
+    pending_refresh: Option<RequestId>,
+    // the link selected to follow, counting those in the notes and then the backlinks
+    link: Option<usize>,
+    // index into `EDITABLE` of the field e changes, or none to open the whole form
+    field: Option<usize>,
+    privacy: Privacy,
+}
+impl JobDetail {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: JobApplication::default(),
+            history: Vec::new(),
+            activity: Vec::new(),
+            contact: TableState::default(),
+            scroll: 0,
+            backlinks: Vec::new(),
+            pending_backlinks: None,
+            pending_refresh: None,
+            link: None,
+            field: None,
+            privacy: Privacy::default(),
+        }
+    }
+
+    /// Links that can be followed from here: those in the notes, then the backlinks.
+    fn links(&self) -> Vec<NoteLink> {
+        let notes = links::find(self.job.notes.as_deref().unwrap_or_default());
+        notes
+            .into_iter()
+            .map(|(_, link)| link)
+            .chain(
+                self.backlinks
+                    .iter()
+                    .map(|job| NoteLink::Application(job.id)),
+            )
+            .collect()
+    }
+
+    fn select_link(&mut self, forward: bool) {
+        let count = self.links().len();
+        if count == 0 {
+            return;
+        }
+        self.link = Some(match (self.link, forward) {
+            (None, true) => 0,
+            (None, false) => count - 1,
+            (Some(idx), true) => (idx + 1) % count,
+            (Some(idx), false) => (idx + count - 1) % count,
+        });
+    }
+
+    fn details(&self) -> Text<'_> {
+        let label = Style::default().fg(self.config.theme.muted);
+        let row = |name: &'static str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{name:<12}"), label),
+                Span::raw(value),
+            ])
+        };
+        let link_style = Style::default()
+            .fg(self.config.theme.focus)
+            .add_modifier(Modifier::UNDERLINED);
+        let focused = self.field.map(|idx| EDITABLE[idx]);
+        let editable = |field: Editable, name: &'static str, value: String| {
+            let line = row(name, value);
+            if focused == Some(field) {
+                line.patch_style(Style::default().bg(self.config.theme.selection_bg))
+            } else {
+                line
+            }
+        };
+        let job = self.privacy.job(&self.job);
+        let inline = |field: InlineField| {
+            editable(Editable::Inline(field), field.label(), field.value(&job))
+        };
+        let mut text = Text::from(vec![
+            Line::styled(
+                format!("{} — {}", job.company_name, job.position),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Line::raw(""),
+            editable(Editable::Status, "Status", job.status.to_string()),
+            row("Applied", job.application_date.clone()),
+            inline(InlineField::FollowUp),
+            inline(InlineField::Deadline),
+            inline(InlineField::Rating),
+            row(
+                "Location",
+                format!("{} ({})", job.location, job.location_type),
+            ),
+            row("Work type", job.work_type.to_string()),
+            row("Category", job.position_category.to_string()),
+            row("Salary", job.salary_range().unwrap_or_default()),
+            row(
+                "Compensation",
+                job.compensation_notes.clone().unwrap_or_default(),
+            ),
+            row("URL", job.url.clone().unwrap_or_default()),
+            inline(InlineField::Tags),
+            row("ID", format!("#{}  {}", job.id, job.uuid)),
+            row(
+                "Created",
+                job.created_at
+                    .as_deref()
+                    .map(local_time)
+                    .unwrap_or_default(),
+            ),
+            row(
+                "Updated",
+                job.last_updated
+                    .as_deref()
+                    .map(local_time)
+                    .unwrap_or_default(),
+            ),
+        ]);
+        let heading =
+            |name: &'static str| Line::styled(name, Style::default().add_modifier(Modifier::BOLD));
+        text.push_line(Line::raw(""));
+        text.push_line(heading("Documents"));
+        let files = job.files.entries();
+        if files.is_empty() {
+            text.push_line(Line::styled("None attached", label));
+        }
+        let missing = Style::default().fg(self.config.theme.warning);
+        for (kind, path) in files {
+            let mut line = row(
+                match kind {
+                    FileKind::Cv => "CV",
+                    FileKind::CoverLetter => "Cover letter",
+                    FileKind::Additional => "Additional",
+                },
+                path.to_string(),
+            );
+            if !std::path::Path::new(path).exists() {
+                line.push_span(Span::styled("  missing", missing));
+            }
+            text.push_line(line);
+        }
+        if let Some(notes) = job.notes.as_deref().filter(|n| !n.trim().is_empty()) {
+            text.push_line(Line::raw(""));
+            text.push_line(heading("Notes"));
+            text.extend(links::highlight(notes, link_style, self.link));
+        }
+        if !self.backlinks.is_empty() {
+            let first = links::find(job.notes.as_deref().unwrap_or_default()).len();
+            text.push_line(Line::raw(""));
+            text.push_line(heading("Linked from"));
+            for (idx, other) in self.backlinks.iter().enumerate() {
+                let other = self.privacy.job(other);
+                let style = if self.link == Some(first + idx) {
+                    link_style.add_modifier(Modifier::REVERSED)
+                } else {
+                    link_style
+                };
+                text.push_line(Line::styled(
+                    format!(
+                        "← {} — {} (#{})",
+                        other.company_name, other.position, other.id
+                    ),
+                    style,
+                ));
+            }
+        }
+        if let Some(description) = job.description.as_deref().filter(|d| !d.trim().is_empty()) {
+            text.push_line(Line::raw(""));
+            text.push_line(heading("Description"));
+            text.push_line(row("Analysis", analysis::analyze(description).to_string()));
+            text.push_line(Line::raw(""));
+            text.extend(Text::raw(description.to_string()));
+        }
+        text
+    }
+
+    fn contacts(&self) -> Table<'static> {
+        let job = self.privacy.job(&self.job);
+        let rows = job.contacts.into_iter().map(|contact| {
+            Row::new([
+                Cell::new(contact.name),
+                Cell::new(contact.role),
+                Cell::new(contact.email),
+                Cell::new(contact.phone),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+                Constraint::Fill(2),
+                Constraint::Fill(1),
+            ],
+        )
+        .header(
+            Row::new(["Name", "Role", "Email", "Phone"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().bg(self.config.theme.selection_bg))
+    }
+
+    fn selected_contact(&self) -> Option<&Contact> {
+        self.job.contacts.get(self.contact.selected()?)
+    }
+
+    fn request_activity(&self, job_id: i32) -> color_eyre::eyre::Result<()> {
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::DbRequest(
+                RequestId::next(),
+                DbRequest::ActivityLog(job_id),
+            ))?;
+        }
+        Ok(())
+    }
+
+    fn activity(&self) -> Text<'_> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        if self.activity.is_empty() {
+            return Text::styled("No changes recorded.", muted);
+        }
+        let value = |field: &str, value: &Option<String>| {
+            let value = value.as_deref().unwrap_or("–").replace('\n', " ");
+            let value = match field {
+                "company" => self.privacy.text(&value, "Hidden company"),
+                "contacts" => self.privacy.text(&value, "Hidden contacts"),
+                "url" => self.privacy.text(&value, "Hidden link"),
+                _ => value,
+            };
+            match value.char_indices().nth(30) {
+                Some((end, _)) => format!("{}…", &value[..end]),
+                None => value,
+            }
+        };
+        let lines = self.activity.iter().map(|change| {
+            let mut spans = vec![
+                Span::styled(format!("{:<17}", local_time(&change.changed_at)), muted),
+                Span::styled(
+                    format!("{} ", change.field),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ];
+            if change.old_value.is_some() {
+                spans.push(Span::raw(value(&change.field, &change.old_value)));
+                spans.push(Span::styled(" → ", muted));
+            }
+            spans.push(Span::raw(value(&change.field, &change.new_value)));
+            Line::from(spans)
+        });
+        Text::from(lines.collect::<Vec<_>>())
+    }
+
+    fn timeline(&self) -> Text<'_> {
+        if self.history.is_empty() {
+            return Text::styled(
+                "No status changes recorded.",
+                Style::default().fg(self.config.theme.muted),
+            );
+        }
+        let last = self.history.len() - 1;
+        let lines = self.history.iter().enumerate().flat_map(|(i, change)| {
+            let marker = if i == last { "●" } else { "○" };
+            let mut lines = vec![Line::from(vec![
+                Span::styled(
+                    format!("{marker} "),
+                    Style::default().fg(self.config.theme.focus),
+                ),
+                Span::raw(format!("{:<14}", change.status.to_string())),
+                Span::styled(
+                    local_time(&change.changed_at),
+                    Style::default().fg(self.config.theme.muted),
+                ),
+            ])];
+            if let Some(note) = &change.note {
+                let rail = if i == last { "  " } else { "│ " };
+                lines.push(Line::from(vec![
+                    Span::styled(rail, Style::default().fg(self.config.theme.focus)),
+                    Span::styled(
+                        format!("“{note}”"),
+                        Style::default().fg(self.config.theme.muted),
+                    ),
+                ]));
+            }
+            if i != last {
+                lines.push(Line::styled(
+                    "│",
+                    Style::default().fg(self.config.theme.focus),
+                ));
+            }
+            lines
+        });
+        Text::from(lines.collect::<Vec<_>>())
+    }
+}
+
+impl Component for JobDetail {
+    fn mode(&self) -> Mode {
+        Mode::ViewJob
+    }
+    fn id(&self) -> String {
+        "Job Detail".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Jobs, Subscription::Privacy, Subscription::Db]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::ShowJobDetail(job, history) => {
+                if let Some(tx) = &self.command_tx {
+                    let masked = self.privacy.job(&job);
+                    tx.send(Action::TitleContext(
+                        Mode::ViewJob,
+                        Some(format!("{}, {}", masked.company_name, masked.position)),
+                    ))?;
+                    let req_id = RequestId::next();
+                    self.pending_backlinks = Some(req_id);
+                    tx.send(Action::DbRequest(req_id, DbRequest::Backlinks(job.clone())))?;
+                }
+                self.activity.clear();
+                self.request_activity(job.id)?;
+                self.contact.select((!job.contacts.is_empty()).then_some(0));
+                self.job = job;
+                self.history = history;
+                self.scroll = 0;
+                self.backlinks.clear();
+                self.link = None;
+                self.field = None;
+            }
+            Action::DbResponse(req_id, DbResponse::Backlinks(jobs))
+                if self.pending_backlinks == Some(req_id) =>
+            {
+                self.pending_backlinks = None;
+                self.backlinks = jobs;
+            }
+            Action::Refresh => {
+                if let Some(tx) = &self.command_tx {
+                    let req_id = RequestId::next();
+                    self.pending_refresh = Some(req_id);
+                    tx.send(Action::DbRequest(
+                        req_id,
+                        DbRequest::JobWithHistory(self.job.id.to_string()),
+                    ))?;
+                    let req_id = RequestId::next();
+                    self.pending_backlinks = Some(req_id);
+                    tx.send(Action::DbRequest(
+                        req_id,
+                        DbRequest::Backlinks(self.job.clone()),
+                    ))?;
+                }
+                self.request_activity(self.job.id)?;
+            }
+            Action::DbResponse(req_id, DbResponse::StatusHistory(job, history))
+                if self.pending_refresh == Some(req_id) =>
+            {
+                self.pending_refresh = None;
+                let last = job.contacts.len().checked_sub(1);
+                self.contact
+                    .select(last.map(|last| self.contact.selected().unwrap_or(0).min(last)));
+                self.job = job;
+                self.history = history;
+                self.link = self.link.filter(|&idx| idx < self.links().len());
+                return Ok(Some(Action::Notify("Refreshed".into())));
+            }
+            Action::DbResponse(req_id, DbResponse::Failed(message))
+                if self.pending_refresh == Some(req_id) =>
+            {
+                // gone, e.g. deleted by another program
+                self.pending_refresh = None;
+                return Ok(Some(Action::Error(message)));
+            }
+            Action::DbResponse(_, DbResponse::ActivityLog(job_id, activity))
+                if job_id == self.job.id =>
+            {
+                self.activity = activity;
+            }
+            // keeps the contacts pane current as contacts are added and removed
+            Action::JobChanged(change) if change.id() == self.job.id => {
+                if let JobChange::Updated(job) = change {
+                    self.request_activity(job.id)?;
+                    let last = job.contacts.len().checked_sub(1);
+                    self.contact
+                        .select(last.map(|last| self.contact.selected().unwrap_or(0).min(last)));
+                    self.job = job;
+                    // the notes may have lost links
+                    self.link = self.link.filter(|&idx| idx < self.links().len());
+                }
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn repeats(&self, key: crossterm::event::KeyEvent) -> bool {
+        matches!(
+            key.code,
+            KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::PageUp
+                | KeyCode::PageDown
+                | KeyCode::Tab
+                | KeyCode::BackTab
+                | KeyCode::Char('j' | 'k' | 'J' | 'K')
+        )
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => Ok(Some(Action::ChangeMode(Mode::Home))),
+            KeyCode::Char('e') => {
+                if let Some(tx) = &self.command_tx {
+                    match self.field.map(|idx| EDITABLE[idx]) {
+                        Some(Editable::Status) => {
+                            tx.send(Action::ChangeMode(Mode::Popup("status_picker")))?;
+                            tx.send(Action::PickStatus(self.job.clone()))?;
+                        }
+                        Some(Editable::Inline(field)) => {
+                            tx.send(Action::ChangeMode(Mode::Popup("field_editor")))?;
+                            tx.send(Action::EditField(self.job.clone(), field))?;
+                        }
+                        None => {
+                            tx.send(Action::ChangeMode(Mode::EditJob))?;
+                            tx.send(Action::PopulateEditJobForm(self.job.clone()))?;
+                        }
+                    }
+                }
+                Ok(None)
+            }
+            KeyCode::Char('E') => {
+                if let Some(tx) = &self.command_tx {
+                    tx.send(Action::ChangeMode(Mode::EditJob))?;
+                    tx.send(Action::PopulateEditJobForm(self.job.clone()))?;
+                }
+                Ok(None)
+            }
+            // through the editable fields and back to none
+            KeyCode::Char('J') => {
+                self.field = match self.field {
+                    None => Some(0),
+                    Some(idx) => (idx + 1 < EDITABLE.len()).then_some(idx + 1),
+                };
+                Ok(None)
+            }
+            KeyCode::Char('K') => {
+                self.field = match self.field {
+                    None => Some(EDITABLE.len() - 1),
+                    Some(idx) => idx.checked_sub(1),
+                };
+                Ok(None)
+            }
+            KeyCode::Down | KeyCode::Char('j') => {
+                self.contact.select_next();
+                Ok(None)
+            }
+            KeyCode::Up | KeyCode::Char('k') => {
+                self.contact.select_previous();
+                Ok(None)
+            }
+            KeyCode::PageDown => {
+                self.scroll = self.scroll.saturating_add(5);
+                Ok(None)
+            }
+            KeyCode::PageUp => {
+                self.scroll = self.scroll.saturating_sub(5);
+                Ok(None)
+            }
+            KeyCode::Char('a') => Ok(Some(Action::OpenContactForm(Contact {
+                job_id: self.job.id,
+                ..Contact::default()
+            }))),
+            KeyCode::Tab => {
+                self.select_link(true);
+                Ok(None)
+            }
+            KeyCode::BackTab => {
+                self.select_link(false);
+                Ok(None)
+            }
+            KeyCode::Enter => {
+                // follow the selected link, otherwise edit the selected contact
+                if let Some(link) = self.link.and_then(|idx| self.links().get(idx).cloned()) {
+                    if let Some(tx) = &self.command_tx {
+                        for action in link.follow() {
+                            tx.send(action)?;
+                        }
+                    }
+                    return Ok(None);
+                }
+                Ok(self
+                    .selected_contact()
+                    .cloned()
+                    .map(Action::OpenContactForm))
+            }
+            KeyCode::Char('d') | KeyCode::Delete => {
+                Ok(self.selected_contact().cloned().map(|contact| {
+                    Action::ConfirmDestructive(
+                        DestructiveAction::Delete,
+                        Box::new(Action::DeleteContact(contact)),
+                    )
+                }))
+            }
+            _ => Ok(None),
+        }
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Thick)
+            .borders(widgets::Borders::TOP)
+            .title_top(Line::from("Application").centered())
+            .title_bottom(
+                Line::from(
+                    "[J/K] select field   [e] edit field or all   [tab] select link   [a] add contact   [enter] follow link / edit contact   [d] remove contact   [pgup/pgdn] scroll   [esc] back",
+                )
+                .centered(),
+            );
+        let region = area.inner(Margin::new(2, 2));
+        let inner = block.inner(region);
+        frame.render_widget(block, region);
+
+        let [details, side] =
+            Layout::horizontal([Constraint::Percentage(60), Constraint::Percentage(40)])
+                .spacing(2)
+                .areas(inner);
+        let [contacts, timeline, activity] = Layout::vertical([
+            Constraint::Percentage(30),
+            Constraint::Percentage(35),
+            Constraint::Percentage(35),
+        ])
+        .areas(side);
+        frame.render_widget(
+            widgets::Paragraph::new(self.details())
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0)),
+            details,
+        );
+        frame.render_widget(
+            widgets::Paragraph::new(self.timeline()).block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .padding(widgets::Padding::horizontal(1))
+                    .title("Status history"),
+            ),
+            timeline,
+        );
+        frame.render_widget(
+            widgets::Paragraph::new(self.activity()).block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .padding(widgets::Padding::horizontal(1))
+                    .title("Activity"),
+            ),
+            activity,
+        );
+        let contacts_block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .padding(widgets::Padding::horizontal(1))
+            .title("Contacts");
+        if self.job.contacts.is_empty() {
+            frame.render_widget(
+                widgets::Paragraph::new(Line::styled(
+                    "No contacts yet. Press a to add one.",
+                    Style::default().fg(self.config.theme.muted),
+                ))
+                .block(contacts_block),
+                contacts,
+            );
+        } else {
+            let table = self.contacts().block(contacts_block);
+            frame.render_stateful_widget(table, contacts, &mut self.contact);
+        }
+        Ok(())
+    }
+}
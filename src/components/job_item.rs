@@ -2,15 +2,20 @@ use std::ops::{Add, AddAssign, SubAssign};
 
 use crossterm::event::MouseEvent;
 use ratatui::{
-    layout::{Layout, Position},
-    style::{self, Style},
+    layout::{Layout, Margin, Position},
+    style::{Modifier, Style},
     text::{Line, Span, Text},
-    widgets::{Block, Padding, Paragraph, StatefulWidget, Widget},
+    widgets::{
+        Block, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState, StatefulWidget,
+        Widget, Wrap,
+    },
 };
 
 use crate::{
     components::util::{is_focused_field_to_bg_color, is_focused_field_to_fg_color},
     database::schema::JobApplication,
+    links,
+    theme::Theme,
 };
 
 #[derive(Clone, Default, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
@@ -60,19 +65,120 @@ impl AddAssign<i8> for FocusedField {
         *self = FocusedField::from(new_value);
     }
 }
+/// What the link under `field` points to, or `None` when the field isn't a link. The
+/// company website is taken to be the root of the application link.
+pub fn link_target(job: &JobApplication, field: FocusedField) -> Option<String> {
+    let url = job.url.clone().unwrap_or_default();
+    let target = match field {
+        FocusedField::ApplicationLink => url,
+        FocusedField::CompanyWebsite => match url.split_once("://") {
+            Some((scheme, rest)) => {
+                let host = rest.split(['/', '?', '#']).next().unwrap_or_default();
+                format!("{scheme}://{host}")
+            }
+            None => url.split('/').next().unwrap_or_default().to_string(),
+        },
+        FocusedField::CV => job.files.cv.clone(),
+        FocusedField::CoverLetter => job.files.cover_letter.clone(),
+        _ => return None,
+    };
+    Some(target)
+}
+
 #[derive(Clone, Default)]
 pub struct JobListingState {
     pub focused: bool,
     pub focused_field: FocusedField,
+    /// Active but left unchanged for longer than `stale_after_days`.
+    pub stale: bool,
+    /// Its Greenhouse or Lever posting has been taken down.
+    pub closed: bool,
+    /// Rows of the notes scrolled out of view above them, kept to those that can scroll.
+    pub notes_scroll: u16,
+    /// The configured badges it matches, from `ListConfig::badges`.
+    pub badges: Vec<Span<'static>>,
 }
 
 pub struct JobItem {
     job: JobApplication,
+    theme: Theme,
+    status_style: Style,
+    trail: Line<'static>,
+    badges: Vec<Span<'static>>,
+    hyperlinks: bool,
 }
 
+// the links block's lines, top to bottom
+const LINKS: [(&str, FocusedField); 4] = [
+    ("Application Link", FocusedField::ApplicationLink),
+    ("Company Website", FocusedField::CompanyWebsite),
+    ("CV", FocusedField::CV),
+    ("Cover Letter", FocusedField::CoverLetter),
+];
+
 impl JobItem {
     pub fn new(job: JobApplication) -> Self {
-        JobItem { job }
+        let status_style =
+            Style::default().fg(crate::components::util::status_colour(job.status.clone()));
+        JobItem {
+            job,
+            theme: Theme::default(),
+            status_style,
+            trail: Line::default(),
+            badges: Vec::new(),
+            hyperlinks: false,
+        }
+    }
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+    pub fn status_style(mut self, style: Style) -> Self {
+        self.status_style = style;
+        self
+    }
+    /// Progress spark-line shown along the bottom of the info block.
+    pub fn trail(mut self, trail: Line<'static>) -> Self {
+        self.trail = trail;
+        self
+    }
+    /// Symbols from the configured badges the application matches.
+    pub fn badges(mut self, badges: Vec<Span<'static>>) -> Self {
+        self.badges = badges;
+        self
+    }
+    /// Make the links clickable on terminals supporting OSC 8.
+    pub fn hyperlinks(mut self, enabled: bool) -> Self {
+        self.hyperlinks = enabled;
+        self
+    }
+
+    /// Turn each link label drawn in `area` by `links_block` into a hyperlink to its target.
+    /// Local files are linked with `file://` when their path is absolute.
+    fn link_labels(&self, area: ratatui::layout::Rect, buf: &mut ratatui::buffer::Buffer) {
+        // inside the border and a padding of 1, centred as the paragraph centres lines
+        let inner = area.inner(ratatui::layout::Margin::new(2, 2));
+        for (row, (label, field)) in LINKS.iter().enumerate() {
+            let width = label.len() as u16;
+            if row as u16 >= inner.height || width > inner.width {
+                break;
+            }
+            let Some(target) = link_target(&self.job, *field).filter(|t| !t.is_empty()) else {
+                continue;
+            };
+            let url = if target.contains("://") {
+                target
+            } else if std::path::Path::new(&target).is_absolute() {
+                format!("file://{target}")
+            } else {
+                continue;
+            };
+            let pos = Position::new(
+                inner.x + (inner.width / 2).saturating_sub(width / 2),
+                inner.y + row as u16,
+            );
+            crate::components::util::hyperlink(buf, pos, label, &url);
+        }
     }
     pub fn handle_mouse_event(mouse_event: MouseEvent, state: &mut JobListingState) {
         let pos = Position::new(mouse_event.column, mouse_event.row);
@@ -109,17 +215,38 @@ impl JobItem {
         let left_block = Block::bordered()
             .border_type(ratatui::widgets::BorderType::Rounded)
             .border_style(if state.focused {
-                ratatui::style::Style::default().fg(ratatui::style::Color::White)
+                ratatui::style::Style::default().fg(self.theme.text)
             } else {
-                ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray)
+                ratatui::style::Style::default().fg(self.theme.muted)
             })
             .padding(Padding::uniform(1))
-            .title_top(Line::from(self.job.status.to_string()).centered().style(
-                style::Style::default().fg(crate::components::util::status_colour(
-                    self.job.status.clone(),
-                )),
-            ))
-            .title_top(Line::from(self.job.application_date.clone()).left_aligned());
+            .title_top(
+                Line::from(self.job.status.to_string())
+                    .centered()
+                    .style(self.status_style),
+            )
+            .title_top(Line::from(self.job.application_date.clone()).left_aligned())
+            .title_top({
+                let mut badges = self.badges.clone();
+                if state.stale {
+                    badges.push(Span::styled("stale", Style::default().fg(self.theme.muted)));
+                }
+                if state.closed {
+                    badges.push(Span::styled(
+                        " posting closed",
+                        Style::default().fg(self.theme.warning),
+                    ));
+                }
+                Line::from(badges).right_aligned()
+            })
+            .title_bottom(self.trail.clone().centered())
+            .title_bottom(
+                Line::styled(
+                    self.job.stars().unwrap_or_default(),
+                    Style::default().fg(self.theme.warning),
+                )
+                .left_aligned(),
+            );
         // block.render(chunks[0], buf);
 
         let lines = Text::from(vec![
@@ -128,7 +255,10 @@ impl JobItem {
                 self.job.company_name.clone(),
                 Style::default(),
             )),
-            Line::from(Span::raw("")),
+            Line::from(Span::styled(
+                self.job.salary_range().unwrap_or_default(),
+                Style::default().fg(self.theme.muted),
+            )),
             Line::from(Span::styled(self.job.location.clone(), Style::default())),
         ]);
 
@@ -140,11 +270,11 @@ impl JobItem {
     pub fn links_block(&self, state: &JobListingState) -> Paragraph {
         let block = Block::bordered()
             .border_type(ratatui::widgets::BorderType::Rounded)
-            .border_style(is_focused_field_to_fg_color(state, 1, true))
+            .border_style(is_focused_field_to_fg_color(state, &self.theme, 1, true))
             .padding(Padding::uniform(1))
             .title_top(Line::from("Links").centered());
 
-        let style = Style::default().fg(ratatui::style::Color::Blue);
+        let style = Style::default().fg(self.theme.link);
         let modifier = ratatui::style::Modifier::UNDERLINED;
 
         let lines = Text::from(vec![
@@ -154,6 +284,7 @@ impl JobItem {
                     .clone()
                     .bg(is_focused_field_to_bg_color(
                         state,
+                        &self.theme,
                         FocusedField::ApplicationLink as i8,
                         false,
                     ))
@@ -165,6 +296,7 @@ impl JobItem {
                     .clone()
                     .bg(is_focused_field_to_bg_color(
                         state,
+                        &self.theme,
                         FocusedField::CompanyWebsite as i8,
                         false,
                     ))
@@ -176,6 +308,7 @@ impl JobItem {
                     .clone()
                     .bg(is_focused_field_to_bg_color(
                         state,
+                        &self.theme,
                         FocusedField::CV as i8,
                         false,
                     ))
@@ -187,6 +320,7 @@ impl JobItem {
                     .clone()
                     .bg(is_focused_field_to_bg_color(
                         state,
+                        &self.theme,
                         FocusedField::CoverLetter as i8,
                         false,
                     ))
@@ -204,25 +338,61 @@ impl JobItem {
             .border_type(ratatui::widgets::BorderType::Rounded)
             .border_style(if state.focused {
                 if state.focused_field == FocusedField::Notes {
-                    ratatui::style::Style::default().fg(ratatui::style::Color::Blue)
+                    ratatui::style::Style::default().fg(self.theme.focus)
                 } else {
-                    ratatui::style::Style::default().fg(ratatui::style::Color::White)
+                    ratatui::style::Style::default().fg(self.theme.text)
                 }
             } else {
-                ratatui::style::Style::default().fg(ratatui::style::Color::DarkGray)
+                ratatui::style::Style::default().fg(self.theme.muted)
             })
             .padding(Padding::uniform(1))
             .title_top(Line::from("Notes").centered());
 
-        let lines = Text::from(vec![Line::from(Span::raw(
-            self.job.notes.clone().unwrap_or_default(),
-        ))]);
-
-        ratatui::widgets::Paragraph::new(lines)
+        ratatui::widgets::Paragraph::new(self.notes_text())
             .centered()
+            .wrap(Wrap { trim: false })
+            .scroll((state.notes_scroll, 0))
             .block(block)
     }
 
+    fn notes_text(&self) -> Text<'static> {
+        links::highlight(
+            self.job.notes.as_deref().unwrap_or_default(),
+            Style::default()
+                .fg(self.theme.focus)
+                .add_modifier(Modifier::UNDERLINED),
+            None,
+        )
+    }
+
+    /// Draw the notes block in `area`, scrolled no further than their last line reaching its
+    /// bottom, with a scrollbar over its right border when they don't all fit.
+    fn render_notes(
+        &self,
+        area: ratatui::layout::Rect,
+        buf: &mut ratatui::buffer::Buffer,
+        state: &mut JobListingState,
+    ) {
+        // inside the border and a padding of 1, counting the rows the notes wrap onto as the
+        // paragraph lays them out, a word at a time
+        let inner = area.inner(Margin::new(2, 2));
+        let rows = Paragraph::new(self.notes_text())
+            .wrap(Wrap { trim: false })
+            .line_count(inner.width.max(1));
+        let hidden = rows.saturating_sub(usize::from(inner.height));
+        state.notes_scroll = state.notes_scroll.min(hidden as u16);
+        self.notes_block(state).render(area, buf);
+        if hidden == 0 || area.height < 3 {
+            return;
+        }
+        let mut scrollbar = ScrollbarState::new(hidden + 1).position(state.notes_scroll.into());
+        Scrollbar::new(ScrollbarOrientation::VerticalRight)
+            .begin_symbol(None)
+            .end_symbol(None)
+            .style(Style::default().fg(self.theme.muted))
+            .render(area.inner(Margin::new(0, 1)), buf, &mut scrollbar);
+    }
+
     pub fn layout(&self, area: ratatui::layout::Rect) -> Layout {
         Layout::horizontal([
             ratatui::layout::Constraint::Length(40),
@@ -245,8 +415,11 @@ impl StatefulWidget for JobItem {
 
         self.info_block(state).render(layout[0], buf);
 
-        self.notes_block(state).render(layout[1], buf);
+        self.render_notes(layout[1], buf, state);
 
         self.links_block(state).render(layout[2], buf);
+        if self.hyperlinks {
+            self.link_labels(layout[2], buf);
+        }
     }
 }
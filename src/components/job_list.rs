@@ -1,161 +1,930 @@
-use crossterm::event::{KeyCode, MouseEventKind};
+use std::{
+    collections::{HashMap, HashSet},
+    time::{Duration, Instant},
+};
+
+use crossterm::event::{KeyCode, MouseButton, MouseEventKind};
 use ratatui::{
     Frame,
-    layout::{Layout, Margin, Position, Rect},
-    widgets::Paragraph,
+    buffer::Buffer,
+    layout::{Constraint, Layout, Margin, Position, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{
+        Block, BorderType, Padding, Paragraph, Scrollbar, ScrollbarOrientation, ScrollbarState,
+        Tabs,
+    },
 };
 use tokio::sync::mpsc::UnboundedSender;
 
 use crate::{
-    action::Action,
+    action::{Action, JobChange, Subscription},
     app::Mode,
     components::{
+        card::{self, CardRenderer},
         component::Component,
-        job_item::{JobItem, JobListingState},
+        job_item::{FocusedField, JobListingState, link_target},
+        notes_preview, status_picker,
     },
-    config::Config,
-    database::schema::JobApplication,
+    config::{CardStyle, Config, DestructiveAction, SortOrder},
+    database::schema::{ApplicationStatus, JobApplication, JobQuery, Question, Stage},
+    database::worker::{DbRequest, DbResponse, RequestId},
+    dates, export, priority,
+    privacy::Privacy,
 };
 use color_eyre::Result;
 
+/// Open the link `field` is on, or the application's detail when it isn't on one.
+fn activate(job: &JobApplication, field: FocusedField) -> Action {
+    match link_target(job, field) {
+        Some(target) if target.is_empty() => {
+            Action::Error(format!("No {field:?} set for this application"))
+        }
+        Some(target) => Action::OpenExternal(target),
+        None => Action::OpenJobDetail(job.clone()),
+    }
+}
+
 #[derive(Default)]
 pub struct JobListState {
-    visible_start_index: usize,
+    // rows of the list scrolled out of view above it
+    scroll: usize,
     selected_index: usize,
     selected_job_state: JobListingState,
+    // the application whose notes were last scrolled, so others' start at the top
+    notes_scrolled: Option<i32>,
 }
 
+/// A card at least partly in view: the rows of the list it covers, and how many of its own
+/// rows are scrolled off above them.
+struct VisibleCard {
+    index: usize,
+    area: Rect,
+    clipped_top: u16,
+}
+impl VisibleCard {
+    fn is_whole(&self, height: u16) -> bool {
+        self.clipped_top == 0 && self.area.height == height
+    }
+    /// Where the whole card would be drawn, were it not cut off.
+    fn full_area(&self, height: u16) -> Option<Rect> {
+        Some(Rect::new(
+            self.area.x,
+            self.area.y.checked_sub(self.clipped_top)?,
+            self.area.width,
+            height,
+        ))
+    }
+}
+
+// follow-ups listed in the "Needs attention" section before the rest are summed up
+const FOLLOW_UPS_SHOWN: usize = 3;
+/// How often due follow-ups and stale applications are looked for, so they turn up when the
+/// day rolls over.
+const FOLLOW_UP_CHECK_INTERVAL: Duration = Duration::from_secs(60);
+// longest gap between the clicks of a double-click
+const DOUBLE_CLICK: Duration = Duration::from_millis(400);
+// applications loaded at once around those in view, so scrolling rarely waits for the next
+const PAGE_SIZE: usize = 100;
+// rows of a card's notes one PgUp or PgDn moves them, leaving one in view from before
+const NOTES_PAGE: u16 = 3;
+
 pub struct JobList {
     command_tx: Option<UnboundedSender<Action>>,
     config: Config,
+    // the loaded page of the applications listed, which starts `offset` into the `total`
+    // matching the search and view; indexes into the list count from the start of them all
     jobs: Vec<JobApplication>,
+    offset: usize,
+    total: usize,
+    pending_page: Option<RequestId>,
+    // whether the page on its way was asked for by a refresh, to say once it's in
+    refreshing: bool,
+    // application to keep selected once a reloaded page arrives, wherever it has moved to
+    reselect: Option<i32>,
+    // the view from the config being shown, or none for every application
+    view: Option<usize>,
     state: JobListState,
     area: Option<Rect>,
-    notes_popup_visible: bool,
+    privacy: Privacy,
+    // text the list is filtered by, empty for every application
+    search: String,
+    // whether saved postings are listed with the applications, apart from them or not at all
+    stage: Stage,
+    // statuses each application has been through, for the cards' progress trails
+    trails: HashMap<i32, Vec<ApplicationStatus>>,
+    pending_trails: Option<RequestId>,
+    // applications due a follow-up, shown above the list, and when they were last looked for
+    follow_ups: Vec<JobApplication>,
+    pending_follow_ups: Option<RequestId>,
+    follow_ups_checked: Option<Instant>,
+    // active applications left unchanged for longer than configured
+    stale: HashSet<i32>,
+    pending_stale: Option<RequestId>,
+    // active applications whose Greenhouse or Lever posting has been taken down
+    closed: HashSet<i32>,
+    pending_postings: Option<RequestId>,
+    postings_checked: Option<Instant>,
+    sort: SortOrder,
+    // active applications most pressing first, while sorted by priority
+    ranking: Vec<i32>,
+    pending_priorities: Option<RequestId>,
+    // card last clicked and when, to tell a double-click
+    last_click: Option<(usize, Instant)>,
+    // selected application as last sent for the terminal title
+    title_context: Option<String>,
+    // hosts whose logo has been asked for, and the logos last sent to be shown
+    #[cfg(feature = "logos")]
+    logos_requested: std::collections::HashSet<String>,
+    #[cfg(feature = "logos")]
+    placed_logos: Vec<(Rect, std::path::PathBuf)>,
+    // how cards are drawn, which the table view can stand in for
+    card_style: CardStyle,
+    renderer: Box<dyn CardRenderer>,
+    // whether the selected job's details are shown beside the list, the percentage of its
+    // width left for them, and the job last sent to be shown
+    notes_preview: bool,
+    notes_preview_width: u16,
+    previewed: Option<Option<JobApplication>>,
+    // whether the list has focus rather than the notes beside it
+    focused: bool,
 }
 
 impl JobList {
     pub fn new() -> Self {
         Self {
             command_tx: None,
-            config: Config::new().unwrap_or_default(),
+            config: Config::default(),
             jobs: Vec::new(),
+            offset: 0,
+            total: 0,
+            pending_page: None,
+            refreshing: false,
+            reselect: None,
+            view: None,
             state: JobListState::default(),
             area: None,
-            notes_popup_visible: false,
+            privacy: Privacy::default(),
+            search: String::new(),
+            stage: Stage::default(),
+            trails: HashMap::new(),
+            pending_trails: None,
+            follow_ups: Vec::new(),
+            pending_follow_ups: None,
+            follow_ups_checked: None,
+            stale: HashSet::new(),
+            pending_stale: None,
+            closed: HashSet::new(),
+            pending_postings: None,
+            postings_checked: None,
+            sort: SortOrder::default(),
+            ranking: Vec::new(),
+            pending_priorities: None,
+            last_click: None,
+            title_context: None,
+            #[cfg(feature = "logos")]
+            logos_requested: std::collections::HashSet::new(),
+            #[cfg(feature = "logos")]
+            placed_logos: Vec::new(),
+            card_style: CardStyle::default(),
+            renderer: card::renderer(Default::default(), &[]),
+            notes_preview: false,
+            notes_preview_width: 0,
+            previewed: None,
+            focused: false,
         }
     }
-    pub fn layout(&self, area: Rect) -> Layout {
-        let num_rows = area.height / 8;
-        Layout::vertical(
-            std::iter::repeat(ratatui::layout::Constraint::Length(8))
-                .take(num_rows as usize)
-                .collect::<Vec<_>>(),
-        )
-    }
-    fn set_visible_jobs(&mut self, start: usize) {
-        self.state.visible_start_index = start;
-    }
-    pub fn get_visible_jobs(&self, area: Rect) -> usize {
-        // let area = self.area.unwrap_or(Rect::default());
-        let num_visible = (area.height / 8) as usize;
-        let selected = self.state.selected_index;
-        let jobs_len = self.jobs.len();
-
-        // Center selected_index in the visible window if possible
-        let mut start = if jobs_len > num_visible {
-            if num_visible == 1 {
-                selected
-            } else if selected >= num_visible / 2 {
-                std::cmp::min(
-                    selected + 1 - num_visible / 2,
-                    jobs_len.saturating_sub(num_visible),
-                )
-            } else {
-                0
+    /// Scroll so the selected card sits in the middle of the list where there is room. The
+    /// offset is in rows rather than cards, so cards at the edges can be partly in view.
+    fn scroll_to_selected(&mut self) {
+        let Some(area) = self.area else {
+            return;
+        };
+        let height = self.renderer.height() as usize;
+        let visible = area.height as usize;
+        let centre = self.state.selected_index * height + height / 2;
+        self.state.scroll = centre
+            .saturating_sub(visible / 2)
+            .min((self.total * height).saturating_sub(visible));
+    }
+    /// The application at `index` in the whole list, if its page is loaded.
+    fn job_at(&self, index: usize) -> Option<&JobApplication> {
+        self.jobs.get(index.checked_sub(self.offset)?)
+    }
+    fn selected_job(&self) -> Option<&JobApplication> {
+        self.job_at(self.state.selected_index)
+    }
+    fn query(&self) -> JobQuery {
+        JobQuery {
+            search: self.search.clone(),
+            stage: self.stage,
+            filter: self
+                .view
+                .and_then(|idx| self.config.views.get(idx))
+                .map(|view| view.filter.clone())
+                .unwrap_or_default(),
+            sort: self.sort,
+            ranking: self.ranking.clone(),
+        }
+    }
+    /// Indexes of the applications in view, or of the selected one before the list is drawn.
+    fn in_view(&self) -> std::ops::Range<usize> {
+        let Some(area) = self.area else {
+            return self.state.selected_index..self.state.selected_index + 1;
+        };
+        let height = self.renderer.height() as usize;
+        let first = self.state.scroll / height;
+        let last = (self.state.scroll + area.height as usize).div_ceil(height);
+        first..last.min(self.total).max(first)
+    }
+    /// Ask for the page around the applications in view.
+    fn request_page(&mut self) -> Result<()> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(());
+        };
+        let in_view = self.in_view();
+        let limit = PAGE_SIZE.max(in_view.len() * 3);
+        let offset = in_view.start.saturating_sub((limit - in_view.len()) / 2);
+        let req_id = RequestId::next();
+        self.pending_page = Some(req_id);
+        tx.send(Action::DbRequest(
+            req_id,
+            DbRequest::JobPage(self.query(), offset, limit),
+        ))?;
+        Ok(())
+    }
+    /// Load the page in view when it isn't already, e.g. after scrolling past the last one.
+    fn load_in_view(&mut self) -> Result<()> {
+        let in_view = self.in_view();
+        let loaded = self.offset..self.offset + self.jobs.len();
+        let covered =
+            in_view.is_empty() || (loaded.contains(&in_view.start) && in_view.end <= loaded.end);
+        if covered || self.pending_page.is_some() {
+            return Ok(());
+        }
+        self.request_page()
+    }
+    /// Fetch the list again, as the search, view, order or applications have changed,
+    /// keeping the same application selected.
+    fn reload(&mut self) -> Result<()> {
+        self.reselect = self.selected_job().map(|job| job.id);
+        self.request_page()
+    }
+    /// Every card at least partly inside `area` at the current scroll offset, top to bottom.
+    fn visible_cards(&self, area: Rect) -> Vec<VisibleCard> {
+        let height = self.renderer.height() as usize;
+        let mut cards = Vec::new();
+        for index in self.state.scroll / height..self.total {
+            let top = index * height;
+            let row = top.saturating_sub(self.state.scroll);
+            if row >= area.height as usize {
+                break;
             }
-        } else {
-            0
+            let clipped_top = self.state.scroll.saturating_sub(top) as u16;
+            let y = area.y + row as u16;
+            cards.push(VisibleCard {
+                index,
+                area: Rect::new(
+                    area.x,
+                    y,
+                    area.width,
+                    (height as u16 - clipped_top).min(area.bottom() - y),
+                ),
+                clipped_top,
+            });
+        }
+        cards
+    }
+    /// Show the cached logo of each card drawn in `cards`, asking for any not cached yet.
+    /// Logos give the company away, so none are shown in privacy mode.
+    #[cfg(feature = "logos")]
+    fn place_logos(&mut self, cards: &[(Rect, usize)]) -> Result<()> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(());
         };
-        start
+        let mut placements = Vec::new();
+        if self.config.list.logos && !self.privacy.enabled && crate::logo::supported() {
+            for (area, idx) in cards {
+                let (Some(area), Some(host)) = (
+                    self.renderer.logo_area(*area),
+                    self.job_at(*idx)
+                        .and_then(|job| job.url.as_deref())
+                        .and_then(crate::logo::host)
+                        // owned, as the job borrowed to get it is part of self
+                        .map(str::to_string),
+                ) else {
+                    continue;
+                };
+                let path = crate::logo::cache_path(&self.config.config.data_dir, &host);
+                if path.exists() {
+                    placements.push((area, path));
+                } else if self.logos_requested.insert(host.clone()) {
+                    tx.send(Action::FetchLogo(host))?;
+                }
+            }
+        }
+        if placements != self.placed_logos {
+            tx.send(Action::PlaceLogos(placements.clone()))?;
+            self.placed_logos = placements;
+        }
+        Ok(())
     }
-}
-
-impl Component for JobList {
-    fn mode(&self) -> Mode {
-        Mode::Home
+    /// Ask to move every stale application to `status`, as one bulk status change.
+    fn close_stale(&self, status: ApplicationStatus) -> Option<Action> {
+        if self.stale.is_empty() {
+            return Some(Action::Notify("No stale applications".into()));
+        }
+        let mut ids = self.stale.iter().copied().collect::<Vec<_>>();
+        ids.sort_unstable();
+        Some(Action::ConfirmDestructive(
+            DestructiveAction::BulkStatusChange,
+            Box::new(Action::SetStatus(ids, status)),
+        ))
     }
-    fn id(&self) -> String {
-        "Job List".into()
+    fn request_priorities(&mut self) -> Result<()> {
+        if self.sort == SortOrder::Priority
+            && let Some(tx) = &self.command_tx
+        {
+            let req_id = RequestId::next();
+            self.pending_priorities = Some(req_id);
+            tx.send(Action::DbRequest(req_id, DbRequest::PrioritySignals))?;
+        }
+        Ok(())
     }
-    fn update(&mut self, action: Action) -> Result<Option<Action>> {
-        if self.jobs.len() == 0 {
-            self.command_tx
-                .clone()
-                .unwrap()
-                .send(Action::DispatchJobSearch)?;
+    /// Switch to the view at `view`, or every application for none, taking up its order and
+    /// card style.
+    fn select_view(&mut self, view: Option<usize>) -> Result<()> {
+        self.view = view;
+        let view = view.and_then(|idx| self.config.views.get(idx));
+        self.sort = view.map_or(SortOrder::default(), |view| view.sort);
+        self.card_style = self.configured_style();
+        self.set_renderer();
+        self.request_priorities()?;
+        self.reload()
+    }
+    /// The card style of the current view, or of the list when it has none.
+    fn configured_style(&self) -> CardStyle {
+        self.view
+            .and_then(|idx| self.config.views.get(idx))
+            .and_then(|view| view.style)
+            .unwrap_or(self.config.list.card_style)
+    }
+    fn set_renderer(&mut self) {
+        let columns = self
+            .view
+            .and_then(|idx| self.config.views.get(idx))
+            .map_or(&[][..], |view| view.fields.as_slice());
+        self.renderer = card::renderer(self.card_style, columns);
+        self.scroll_to_selected();
+    }
+    fn request_trails(&mut self) -> Result<()> {
+        if let Some(tx) = &self.command_tx {
+            let req_id = RequestId::next();
+            self.pending_trails = Some(req_id);
+            tx.send(Action::DbRequest(req_id, DbRequest::StatusTrails))?;
+        }
+        Ok(())
+    }
+    fn request_follow_ups(&mut self) -> Result<()> {
+        self.follow_ups_checked = Some(Instant::now());
+        if let Some(tx) = &self.command_tx {
+            let req_id = RequestId::next();
+            self.pending_follow_ups = Some(req_id);
+            tx.send(Action::DbRequest(req_id, DbRequest::FollowUps))?;
+            let days = self.config.config.stale_after_days;
+            if days > 0 {
+                let req_id = RequestId::next();
+                self.pending_stale = Some(req_id);
+                tx.send(Action::DbRequest(req_id, DbRequest::Stale(days)))?;
+            }
+        }
+        Ok(())
+    }
+    /// The follow-up the snooze and done keys act on: the selected application when it is
+    /// due one, otherwise the longest overdue.
+    fn follow_up_target(&self) -> Option<&JobApplication> {
+        let selected = self.selected_job();
+        self.follow_ups
+            .iter()
+            .find(|job| selected.is_some_and(|selected| selected.id == job.id))
+            .or(self.follow_ups.first())
+    }
+    fn draw_follow_ups(&self, frame: &mut Frame, area: Rect) {
+        let theme = &self.config.theme;
+        let muted = Style::default().fg(theme.muted);
+        let target = self.follow_up_target().map(|job| job.id);
+        let mut lines = self
+            .follow_ups
+            .iter()
+            .take(FOLLOW_UPS_SHOWN)
+            .map(|job| {
+                let marker = if Some(job.id) == target { "› " } else { "  " };
+                let job = self.privacy.job(job);
+                Line::from(vec![
+                    Span::raw(format!("{marker}{} - {}", job.company_name, job.position)),
+                    Span::styled(
+                        format!("  due {}", job.follow_up_date.unwrap_or_default()),
+                        muted,
+                    ),
+                ])
+            })
+            .collect::<Vec<_>>();
+        if self.follow_ups.len() > FOLLOW_UPS_SHOWN {
+            lines.push(Line::styled(
+                format!("  and {} more", self.follow_ups.len() - FOLLOW_UPS_SHOWN),
+                muted,
+            ));
         }
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title(format!("Needs attention ({})", self.follow_ups.len()))
+            .title_bottom(Line::from("[z] snooze   [f] done").right_aligned());
+        frame.render_widget(Paragraph::new(lines).block(block), area);
+    }
+    /// Handle an action, before the notes preview is brought up to date with the selection.
+    fn apply(&mut self, action: Action) -> Result<Option<Action>> {
         match action {
-            Action::Tick => {
-                // add any logic here that should run on every tick
+            Action::Tick
+                if self
+                    .follow_ups_checked
+                    .is_none_or(|checked| checked.elapsed() >= FOLLOW_UP_CHECK_INTERVAL) =>
+            {
+                self.request_follow_ups()?;
+                self.request_priorities()?;
+            }
+            Action::Tick
+                if self.config.postings.check_closed
+                    && self.postings_checked.is_none_or(|checked| {
+                        checked.elapsed()
+                            >= Duration::from_secs(self.config.postings.interval_hours * 3600)
+                    }) =>
+            {
+                self.postings_checked = Some(Instant::now());
+                let req_id = RequestId::next();
+                self.pending_postings = Some(req_id);
+                return Ok(Some(Action::DbRequest(req_id, DbRequest::BoardPostings)));
             }
             Action::Render => {
                 // add any logic here that should run on every render
             }
-            Action::JobResults(res) => {
-                self.jobs = res;
+            Action::DbResponse(req_id, DbResponse::BoardPostings(postings))
+                if self.pending_postings == Some(req_id) =>
+            {
+                self.pending_postings = None;
+                if !postings.is_empty() {
+                    return Ok(Some(Action::CheckPostings(postings)));
+                }
+            }
+            Action::PostingsClosed(ids) => self.closed = ids,
+            Action::DbResponse(req_id, DbResponse::JobPage(offset, total, jobs))
+                if self.pending_page == Some(req_id) =>
+            {
+                self.pending_page = None;
+                self.offset = offset;
+                self.total = total;
+                self.jobs = jobs;
+                let reselected = self
+                    .reselect
+                    .take()
+                    .and_then(|id| self.jobs.iter().position(|job| job.id == id));
+                if let Some(idx) = reselected {
+                    self.state.selected_index = offset + idx;
+                }
+                self.state.selected_index = self.state.selected_index.min(total.saturating_sub(1));
+                self.scroll_to_selected();
+                if std::mem::take(&mut self.refreshing) {
+                    return Ok(Some(Action::Notify("Refreshed".into())));
+                }
+            }
+            Action::DbResponse(req_id, DbResponse::PrioritySignals(signals))
+                if self.pending_priorities == Some(req_id) =>
+            {
+                self.pending_priorities = None;
+                self.ranking = priority::rank(
+                    &signals,
+                    &self.config.priority,
+                    self.config.config.stale_after_days,
+                );
+                self.reload()?;
+            }
+            Action::ToggleTable => {
+                self.card_style = match (self.card_style, self.configured_style()) {
+                    (CardStyle::Table, CardStyle::Table) => CardStyle::Detailed,
+                    (CardStyle::Table, configured) => configured,
+                    _ => CardStyle::Table,
+                };
+                self.set_renderer();
+            }
+            Action::ToggleNotesPreview => self.notes_preview = !self.notes_preview,
+            Action::WidenNotesPreview | Action::NarrowNotesPreview if self.notes_preview => {
+                self.notes_preview_width = notes_preview::resize(self.notes_preview_width, &action);
+            }
+            Action::ToggleSaved => {
+                self.stage = self.stage.next();
+                self.reload()?;
+            }
+            Action::CycleSort => {
+                self.sort = self.sort.next();
+                self.request_priorities()?;
+                self.reload()?;
+            }
+            Action::NextView if !self.config.views.is_empty() => {
+                let view = match self.view {
+                    None => Some(0),
+                    Some(idx) if idx + 1 < self.config.views.len() => Some(idx + 1),
+                    Some(_) => None,
+                };
+                self.select_view(view)?;
+            }
+            Action::PreviousView if !self.config.views.is_empty() => {
+                let view = match self.view {
+                    None => Some(self.config.views.len() - 1),
+                    Some(0) => None,
+                    Some(idx) => Some(idx - 1),
+                };
+                self.select_view(view)?;
+            }
+            Action::ApplySearch(text) => {
+                self.search = text;
+                self.reload()?;
+            }
+            Action::DbResponse(req_id, DbResponse::StatusTrails(trails))
+                if self.pending_trails == Some(req_id) =>
+            {
+                self.pending_trails = None;
+                self.trails = trails;
+            }
+            Action::DbResponse(req_id, DbResponse::FollowUps(jobs))
+                if self.pending_follow_ups == Some(req_id) =>
+            {
+                self.pending_follow_ups = None;
+                self.follow_ups = jobs;
+            }
+            Action::DbResponse(req_id, DbResponse::Stale(ids))
+                if self.pending_stale == Some(req_id) =>
+            {
+                self.pending_stale = None;
+                self.stale = ids;
+            }
+            Action::Refresh => {
+                self.refreshing = true;
+                self.reload()?;
+                self.request_trails()?;
+                self.request_follow_ups()?;
+                self.request_priorities()?;
+            }
+            Action::JobChanged(change) => {
+                self.apply_job_change(change)?;
+                // a change may have moved the application on to a new status or follow-up
+                self.request_trails()?;
+                self.request_follow_ups()?;
+                self.request_priorities()?;
+            }
+            Action::SnoozeFollowUp => {
+                if let Some(job) = self.follow_up_target() {
+                    let days = self.config.config.follow_up_days;
+                    return Ok(Some(Action::SetFollowUp(job.id, Some(days))));
+                }
+            }
+            Action::FollowUpDone => {
+                if let Some(job) = self.follow_up_target() {
+                    return Ok(Some(Action::SetFollowUp(job.id, None)));
+                }
+            }
+            Action::RejectStale => return Ok(self.close_stale(ApplicationStatus::Rejected)),
+            Action::GhostStale => return Ok(self.close_stale(ApplicationStatus::Ghosted)),
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            Action::IndexNext => {
+                if self.state.selected_index + 1 < self.total {
+                    self.state.selected_index += 1;
+                    self.scroll_to_selected();
+                }
+            }
+            Action::IndexPrevious => {
+                if self.state.selected_index > 0 {
+                    self.state.selected_index -= 1;
+                    self.scroll_to_selected();
+                }
+            }
+            Action::FocusNext => {
+                if self.state.selected_job_state.focused_field < FocusedField::CoverLetter {
+                    self.state.selected_job_state.focused_field += 1;
+                }
+            }
+            Action::FocusPrevious => {
+                if self.state.selected_job_state.focused_field as i8 > 0 {
+                    self.state.selected_job_state.focused_field -= 1;
+                }
+            }
+            Action::Delete => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(Action::ConfirmDestructive(
+                        DestructiveAction::Delete,
+                        Box::new(Action::DeleteJob(job.id)),
+                    )));
+                }
+            }
+            Action::ViewJob => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(Action::OpenJobDetail(job.clone())));
+                }
+            }
+            Action::ScheduleInterview => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(Action::OpenInterviewForm(job.id)));
+                }
+            }
+            Action::NewReminder => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(Action::OpenReminderForm(job.id)));
+                }
+            }
+            Action::NewQuestion => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(Action::OpenQuestionForm(
+                        Question {
+                            job_id: job.id,
+                            ..Question::default()
+                        },
+                        job.company_name.clone(),
+                    )));
+                }
+            }
+            Action::NewExpense => {
+                let job_id = self.selected_job().map(|job| job.id);
+                return Ok(Some(Action::OpenExpenseForm(job_id)));
+            }
+            Action::ChangeStatus => {
+                if let (Some(tx), Some(job)) = (&self.command_tx, self.selected_job()) {
+                    tx.send(Action::ChangeMode(Mode::Popup("status_picker")))?;
+                    tx.send(Action::PickStatus(job.clone()))?;
+                }
             }
-            Action::NotesPopupData(str) => {
-                self.notes_popup_visible = false;
-                self.jobs.get_mut(0).unwrap().notes = Some(str.into());
+            Action::QuickStatus(index) => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(match ApplicationStatus::built_in().get(index) {
+                        Some(status) => status_picker::change_status(job, status.clone()),
+                        None => Action::Notify(format!("No status {index}")),
+                    }));
+                }
+            }
+            Action::EditNotes => {
+                if let (Some(tx), Some(job)) = (&self.command_tx, self.selected_job()) {
+                    tx.send(Action::ChangeMode(Mode::Popup("notes_popup")))?;
+                    tx.send(Action::DispatchNotesPopupData(job.clone()))?;
+                }
+            }
+            Action::CopyUrl => match self.selected_job().map(|job| job.url.clone()) {
+                Some(Some(url)) if !url.is_empty() => {
+                    return Ok(Some(Action::CopyToClipboard(url)));
+                }
+                Some(_) => {
+                    return Ok(Some(Action::Error(
+                        "No URL set for this application".into(),
+                    )));
+                }
+                None => {}
+            },
+            Action::CopyJob => {
+                if let Some(job) = self.selected_job() {
+                    return Ok(Some(Action::CopyToClipboard(export::markdown::job(job))));
+                }
+            }
+            Action::ShowTemplates => {
+                if self.config.templates.is_empty() {
+                    return Ok(Some(Action::Notify(
+                        "No templates yet, add some under \"templates\" in the config".into(),
+                    )));
+                }
+                if let (Some(tx), Some(job)) = (&self.command_tx, self.selected_job()) {
+                    tx.send(Action::ChangeMode(Mode::Popup("templates")))?;
+                    tx.send(Action::PickTemplate(job.clone()))?;
+                }
+            }
+            Action::PasteUpdate => {
+                if let (Some(tx), Some(job)) = (&self.command_tx, self.selected_job()) {
+                    tx.send(Action::ChangeMode(Mode::Popup("paste_update")))?;
+                    tx.send(Action::PromptPasteUpdate(job.clone()))?;
+                }
             }
 
             _ => {}
         }
         Ok(None)
     }
+    /// Pass the selected application on to the details beside the list when it differs from
+    /// what they were last sent.
+    fn preview_notes(&mut self) -> Result<()> {
+        let job = self.selected_job();
+        if self.previewed.as_ref().map(Option::as_ref) != Some(job)
+            && let Some(tx) = &self.command_tx
+        {
+            let job = job.cloned();
+            tx.send(Action::PreviewJob(job.clone()))?;
+            self.previewed = Some(job);
+        }
+        Ok(())
+    }
+    fn apply_job_change(&mut self, change: JobChange) -> Result<()> {
+        // show an edit straight away, while the page it may have moved in is fetched
+        if let JobChange::Updated(job) = &change
+            && let Some(existing) = self.jobs.iter_mut().find(|existing| existing.id == job.id)
+        {
+            *existing = job.clone();
+        }
+        self.reload()
+    }
+}
+
+impl Component for JobList {
+    fn mode(&self) -> Mode {
+        Mode::Home
+    }
+    fn id(&self) -> String {
+        "Job List".into()
+    }
+    fn focusable(&self) -> bool {
+        true
+    }
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Jobs, Subscription::Privacy, Subscription::Db]
+    }
+    fn init(&mut self, _area: ratatui::layout::Size) -> Result<()> {
+        self.request_page()?;
+        self.request_follow_ups()?;
+        self.request_trails()
+    }
+    fn update(&mut self, action: Action) -> Result<Option<Action>> {
+        let action = self.apply(action)?;
+        self.preview_notes()?;
+        Ok(action)
+    }
 
     fn draw(&mut self, frame: &mut Frame, area: Rect) -> Result<()> {
         let block = ratatui::widgets::Block::bordered()
             .border_type(ratatui::widgets::BorderType::Thick)
             .borders(ratatui::widgets::Borders::TOP)
-            .title_top(ratatui::text::Line::from("Job Applications").centered());
+            .border_style(if self.focused {
+                Style::default().fg(self.config.theme.focus)
+            } else {
+                Style::default()
+            })
+            .title_top(
+                ratatui::text::Line::from({
+                    let mut title = match self.stage {
+                        Stage::All => "Job Applications",
+                        Stage::Applications => "Job Applications, without saved postings",
+                        Stage::Saved => "Saved Postings",
+                    }
+                    .to_string();
+                    if !self.search.is_empty() {
+                        title.push_str(&format!(" matching \"{}\"", self.search));
+                    }
+                    title.push_str(match self.sort {
+                        SortOrder::Added => "",
+                        SortOrder::Priority => " by priority",
+                        SortOrder::Date => " by date",
+                        SortOrder::Company => " by company",
+                        SortOrder::Rating => " by rating",
+                    });
+                    title
+                })
+                .centered(),
+            );
 
         let region = area.inner(Margin::new(2, 2));
         let mut area = block.inner(region);
-        self.area = Some(area);
-        // Clamp height to a multiple of 8
-        // inner.height = ((area.height / 8) * 8);
-        // block.render(area, buf);
-
         frame.render_widget(block, region);
+        if self.notes_preview {
+            // the preview draws itself beside the list
+            [area, _] = notes_preview::split(area, self.notes_preview_width);
+        }
+        if !self.config.views.is_empty() {
+            let theme = &self.config.theme;
+            let names = std::iter::once("All")
+                .chain(self.config.views.iter().map(|view| view.name.as_str()));
+            let tabs = Tabs::new(names)
+                .select(self.view.map_or(0, |idx| idx + 1))
+                .style(Style::default().fg(theme.muted))
+                .highlight_style(Style::default().fg(theme.focus));
+            let [tabs_area, rest] =
+                Layout::vertical([Constraint::Length(2), Constraint::Fill(1)]).areas(area);
+            frame.render_widget(tabs, tabs_area);
+            area = rest;
+        }
+        if !self.follow_ups.is_empty() {
+            // one line per follow-up shown, one for the rest, and the border
+            let rows = self.follow_ups.len().min(FOLLOW_UPS_SHOWN + 1) as u16 + 2;
+            let [follow_ups, rest] =
+                Layout::vertical([Constraint::Length(rows), Constraint::Fill(1)]).areas(area);
+            self.draw_follow_ups(frame, follow_ups);
+            area = rest;
+        }
+        if let Some(header) = self.renderer.header(&self.config.theme) {
+            let [header_area, rest] =
+                Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(area);
+            frame.render_widget(header, header_area);
+            area = rest;
+        }
+        if self.total * self.renderer.height() as usize > area.height as usize {
+            // where the selection is among every application listed, not just those loaded
+            let [list, scrollbar] =
+                Layout::horizontal([Constraint::Fill(1), Constraint::Length(1)]).areas(area);
+            let mut state = ScrollbarState::new(self.total).position(self.state.selected_index);
+            frame.render_stateful_widget(
+                Scrollbar::new(ScrollbarOrientation::VerticalRight)
+                    .style(Style::default().fg(self.config.theme.muted)),
+                scrollbar,
+                &mut state,
+            );
+            area = list;
+        }
+        if self.area != Some(area) {
+            // keep the selection in view when the terminal is resized
+            self.area = Some(area);
+            self.scroll_to_selected();
+        }
+        self.load_in_view()?;
 
-        // .padding(ratatui::layout::Padding::uniform(1));
-        // Split the inner area into rows of height 8
-        let layout = self.layout(area).split(area);
-
-        let num_visible = (area.height / 8) as usize;
-        let end = std::cmp::min(
-            self.state.visible_start_index + num_visible,
-            self.jobs.len(),
-        );
-        let visible_jobs = &self.jobs[self.state.visible_start_index..end];
+        let context = self.selected_job().map(|job| {
+            let job = self.privacy.job(job);
+            format!("{}, {}", job.company_name, job.position)
+        });
+        // nothing to go on while the selection's page loads
+        let loading = context.is_none() && self.total > 0;
+        if !loading
+            && context != self.title_context
+            && let Some(tx) = &self.command_tx
+        {
+            tx.send(Action::TitleContext(Mode::Home, context.clone()))?;
+            self.title_context = context;
+        }
 
-        // Implementation for rendering the job list goes here
-        for (chunk, job) in layout.iter().zip(visible_jobs.iter()) {
-            let job_listing = JobItem::new(job.clone());
+        let height = self.renderer.height();
+        let cards = self.visible_cards(area);
+        let today = dates::today();
+        for card in &cards {
+            let Some(job) = self.job_at(card.index) else {
+                continue;
+            };
+            let job = self.privacy.job(job);
             let mut job_state = self.state.selected_job_state.clone();
-
-            // Focus the first element in the visible jobs
-            job_state.focused = layout.iter().position(|c| c == chunk) == Some(0);
-            if self.state.selected_index < self.jobs.len()
-                && self.jobs[self.state.selected_index].id == job.id
-            {
-                job_state.focused = true;
+            job_state.focused = card.index == self.state.selected_index;
+            job_state.stale = self.stale.contains(&job.id);
+            job_state.closed = self.closed.contains(&job.id);
+            job_state.badges = self.config.list.badges(&job, &today);
+            if !job_state.focused || self.state.notes_scrolled != Some(job.id) {
+                job_state.notes_scroll = 0;
+            }
+            let trail = self.trails.get(&job.id).map_or(&[][..], Vec::as_slice);
+            if card.is_whole(height) {
+                self.renderer.render(
+                    &job,
+                    trail,
+                    &self.config.theme,
+                    &self.config.list,
+                    card.area,
+                    frame.buffer_mut(),
+                    &mut job_state,
+                );
             } else {
-                job_state.focused = false;
+                // draw cards cut off by the edges of the list whole, then copy the rows in view
+                let mut scratch = Buffer::empty(Rect::new(card.area.x, 0, card.area.width, height));
+                self.renderer.render(
+                    &job,
+                    trail,
+                    &self.config.theme,
+                    &self.config.list,
+                    scratch.area,
+                    &mut scratch,
+                    &mut job_state,
+                );
+                let buf = frame.buffer_mut();
+                for row in 0..card.area.height {
+                    for x in card.area.left()..card.area.right() {
+                        buf[(x, card.area.y + row)] = scratch[(x, card.clipped_top + row)].clone();
+                    }
+                }
+            }
+            // the card keeps the scroll to what its notes can scroll
+            if job_state.focused {
+                self.state.selected_job_state.notes_scroll = job_state.notes_scroll;
             }
-            frame.render_stateful_widget(job_listing, *chunk, &mut job_state);
+        }
+        #[cfg(feature = "logos")]
+        {
+            // logos are images over the terminal rather than cells, so can't be cut off
+            let cards = cards
+                .iter()
+                .filter(|card| card.is_whole(height))
+                .map(|card| (card.area, card.index))
+                .collect::<Vec<_>>();
+            self.place_logos(&cards)?;
         }
         Ok(())
     }
@@ -169,6 +938,11 @@ impl Component for JobList {
     }
 
     fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.card_style = config.list.card_style;
+        self.notes_preview = config.list.notes_preview;
+        self.notes_preview_width = notes_preview::width(&config);
+        self.renderer = card::renderer(self.card_style, &[]);
         self.config = config;
         Ok(())
     }
@@ -182,6 +956,8 @@ impl Component for JobList {
             Some(crate::tui::Event::Mouse(mouse_event)) => self.handle_mouse_event(mouse_event)?,
             _ => None,
         };
+        // a click may have selected another application
+        self.preview_notes()?;
         Ok(action)
     }
 
@@ -189,60 +965,28 @@ impl Component for JobList {
         &mut self,
         key: crossterm::event::KeyEvent,
     ) -> color_eyre::eyre::Result<Option<Action>> {
-        match key.code {
-            KeyCode::Tab => {
-                if self.state.selected_index + 1 < self.jobs.len() {
-                    self.state.selected_index += 1;
-                }
-            }
-            KeyCode::BackTab => {
-                if self.state.selected_index > 0 {
-                    self.state.selected_index -= 1;
-                }
-            }
-            KeyCode::Enter => {
-                // Send an action to edit the selected job
-                if let Some(tx) = &self.command_tx {
-                    tx.send(Action::ChangeMode(Mode::EditJob))?;
-                }
-            }
-            KeyCode::Esc => {
-                // Send an action to go back to home mode
-                if let Some(tx) = &self.command_tx {
-                    tx.send(Action::ChangeMode(Mode::Popup("notes_popup")))?;
-
-                    tx.send(Action::DispatchNotesPopupData(
-                        "This is a test note popup.".into(),
-                    ))?;
-                }
-            }
-            KeyCode::Up => {
-                if self.state.selected_index > 0 {
-                    self.state.selected_index -= 1;
-                    if self.area.is_some() {
-                        self.state.visible_start_index = self.get_visible_jobs(self.area.unwrap());
-                    }
-                }
-            }
-            KeyCode::Down => {
-                if self.state.selected_index + 1 < self.jobs.len() {
-                    self.state.selected_index += 1;
-                    if self.area.is_some() {
-                        self.state.visible_start_index = self.get_visible_jobs(self.area.unwrap());
-                    }
-                }
-            }
-            KeyCode::Right => {
-                if (self.state.selected_job_state.focused_field as i8) < 4 {
-                    self.state.selected_job_state.focused_field += 1;
-                }
-            }
-            KeyCode::Left => {
-                if self.state.selected_job_state.focused_field as i8 > 0 {
-                    self.state.selected_job_state.focused_field -= 1;
-                }
+        if key.code == KeyCode::Enter
+            && let Some(job) = self.selected_job()
+        {
+            return Ok(Some(activate(
+                job,
+                self.state.selected_job_state.focused_field,
+            )));
+        }
+        if matches!(key.code, KeyCode::PageDown | KeyCode::PageUp)
+            && self.state.selected_job_state.focused_field == FocusedField::Notes
+            && let Some(id) = self.selected_job().map(|job| job.id)
+        {
+            let state = &mut self.state;
+            if state.notes_scrolled != Some(id) {
+                state.notes_scrolled = Some(id);
+                state.selected_job_state.notes_scroll = 0;
             }
-            _ => {}
+            let scroll = &mut state.selected_job_state.notes_scroll;
+            *scroll = match key.code {
+                KeyCode::PageDown => scroll.saturating_add(NOTES_PAGE),
+                _ => scroll.saturating_sub(NOTES_PAGE),
+            };
         }
         Ok(None)
     }
@@ -258,33 +1002,77 @@ impl Component for JobList {
             modifiers,
         } = mouse;
         match kind {
+            MouseEventKind::Down(MouseButton::Left) => {
+                let Some(area) = self.area else {
+                    return Ok(None);
+                };
+                let pos = Position::new(column, row);
+                let Some(card) = self
+                    .visible_cards(area)
+                    .into_iter()
+                    .find(|card| card.area.contains(pos))
+                else {
+                    return Ok(None);
+                };
+                let double = self
+                    .last_click
+                    .is_some_and(|(index, at)| index == card.index && at.elapsed() <= DOUBLE_CLICK);
+                self.last_click = (!double).then(|| (card.index, Instant::now()));
+                self.state.selected_index = card.index;
+                // act on what was clicked, not what the keyboard last focused
+                self.state.selected_job_state.focused_field = FocusedField::None;
+                let job = card
+                    .index
+                    .checked_sub(self.offset)
+                    .and_then(|idx| self.jobs.get(idx));
+                let (Some(full_area), Some(job)) = (card.full_area(self.renderer.height()), job)
+                else {
+                    return Ok(None);
+                };
+                self.renderer.handle_mouse_moved(
+                    job,
+                    full_area,
+                    pos,
+                    &mut self.state.selected_job_state,
+                );
+                let field = self.state.selected_job_state.focused_field;
+                if double || link_target(job, field).is_some() {
+                    return Ok(Some(activate(job, field)));
+                }
+            }
             MouseEventKind::ScrollUp => {
                 if self.state.selected_index > 0 {
                     self.state.selected_index -= 1;
                 }
-                if self.area.is_some() {
-                    self.state.visible_start_index = self.get_visible_jobs(self.area.unwrap());
-                }
+                self.scroll_to_selected();
             }
             MouseEventKind::ScrollDown => {
-                if self.state.selected_index + 1 < self.jobs.len() {
+                if self.state.selected_index + 1 < self.total {
                     self.state.selected_index += 1;
                 }
-                if self.area.is_some() {
-                    self.state.visible_start_index = self.get_visible_jobs(self.area.unwrap());
-                }
+                self.scroll_to_selected();
             }
             MouseEventKind::Moved => {
-                let regions = self.layout(self.area.unwrap()).split(self.area.unwrap());
-                for (i, region) in regions.iter().enumerate() {
-                    let pos = Position::new(column, row);
-                    if region.contains(pos) {
-                        self.state.selected_index = self.state.visible_start_index + i;
-
-                        let job_listing =
-                            JobItem::new(self.jobs[self.state.selected_index].clone());
-                        job_listing.handle_mouse_moved_in_region(
-                            *region,
+                let Some(area) = self.area else {
+                    return Ok(None);
+                };
+                let pos = Position::new(column, row);
+                let height = self.renderer.height();
+                if let Some(card) = self
+                    .visible_cards(area)
+                    .into_iter()
+                    .find(|card| card.area.contains(pos))
+                {
+                    self.state.selected_index = card.index;
+                    // the fields rather than `job_at`, to borrow the card's state alongside
+                    let job = card
+                        .index
+                        .checked_sub(self.offset)
+                        .and_then(|idx| self.jobs.get(idx));
+                    if let (Some(full_area), Some(job)) = (card.full_area(height), job) {
+                        self.renderer.handle_mouse_moved(
+                            job,
+                            full_area,
                             pos,
                             &mut self.state.selected_job_state,
                         );
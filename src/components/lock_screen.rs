@@ -0,0 +1,120 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Padding, Paragraph},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+};
+
+/// Covers the whole screen until the lock PIN is entered. It has no popup area, so clicks
+/// can't close it, and the app hands it every key.
+pub struct LockScreen {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    input: String,
+    failed: bool,
+}
+impl LockScreen {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            input: String::new(),
+            failed: false,
+        }
+    }
+}
+
+impl Component for LockScreen {
+    fn mode(&self) -> Mode {
+        Mode::Popup("lock")
+    }
+    fn id(&self) -> String {
+        "Lock Screen".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                tx.send(Action::Quit)?
+            }
+            KeyCode::Enter => {
+                let unlocked = self.config.lock.pin() == Some(self.input.as_str());
+                self.failed = !unlocked;
+                self.input.clear();
+                if unlocked {
+                    tx.send(Action::Unlock)?;
+                }
+            }
+            KeyCode::Esc => self.input.clear(),
+            KeyCode::Backspace => {
+                self.input.pop();
+            }
+            KeyCode::Char(c) => self.input.push(c),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        // nothing behind the lock stays readable
+        util::clear_popup(frame, area, &self.config.theme);
+        let [area] = Layout::vertical([Constraint::Length(7)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Length(40)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        let theme = &self.config.theme;
+        let status = if self.failed {
+            Line::styled("Wrong PIN", Style::default().fg(theme.error))
+        } else {
+            Line::styled(
+                "[enter] unlock   [ctrl-c] quit",
+                Style::default().fg(theme.muted),
+            )
+        };
+        let text = Text::from(vec![
+            Line::from(vec![
+                Span::raw("PIN: "),
+                Span::styled(
+                    "•".repeat(self.input.chars().count()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::raw(""),
+            status,
+        ]);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title("Locked");
+        frame.render_widget(Paragraph::new(text).centered().block(block), area);
+        Ok(())
+    }
+}
@@ -1,8 +1,48 @@
+pub mod calendar;
+pub mod card;
 pub mod component;
+pub mod confirm_popup;
+pub mod conflicts;
+pub mod contact_form;
+pub mod duplicate_warning;
 pub mod edit_job;
+pub mod error_popup;
+pub mod expense_form;
+pub mod field_editor;
+pub mod file_picker;
+pub mod help_popup;
+pub mod import_summary;
+pub mod input_prompt;
+pub mod interview_form;
+pub mod job_detail;
 pub mod job_item;
 pub mod job_list;
+pub mod lock_screen;
 pub mod notes_popup;
+pub mod notes_preview;
+pub mod palette;
+pub mod paste_update;
+pub mod preset_preview;
+pub mod question_form;
+pub mod questions;
+pub mod quick_add;
+pub mod recategorize;
+pub mod recovery;
+pub mod reminder_form;
+pub mod reminders;
 pub mod search;
+pub mod search_bar;
 pub mod select_box;
+pub mod spelling_popup;
+pub mod stats;
+pub mod status_bar;
+pub mod status_note;
+pub mod status_picker;
+pub mod tag_browser;
+pub mod template_popup;
+pub mod timeline;
+pub mod tutorial;
 pub mod util;
+
+#[cfg(test)]
+mod render_tests;
@@ -1,32 +1,61 @@
+use crossterm::event::KeyCode;
 use ratatui::{
     layout::Margin,
-    style::{Color, Style},
-    widgets,
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Padding},
 };
 use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
 
-use crate::{action::Action, app::Mode, components::component::Component};
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::JobApplication,
+    privacy::Privacy,
+    spelling::{self, Speller},
+};
 
-pub struct NotesPopup {
+/// Edits one application's notes over the job list, saving them when closed if they changed.
+pub struct NotesPopup<'a> {
     command_tx: Option<UnboundedSender<Action>>,
-    notes: String,
+    config: Config,
+    job: Option<JobApplication>,
+    editor: TextArea<'a>,
+    privacy: Privacy,
+    speller: Speller,
 }
-impl NotesPopup {
+impl NotesPopup<'_> {
     pub fn new() -> Self {
         Self {
             command_tx: None,
-            notes: String::new(),
+            config: Config::default(),
+            job: None,
+            editor: TextArea::default(),
+            privacy: Privacy::default(),
+            speller: Speller::default(),
         }
     }
+
+    fn notes(&self) -> Option<String> {
+        let notes = self.editor.lines().join("\n");
+        let notes = notes.trim_end();
+        (!notes.is_empty()).then(|| notes.to_string())
+    }
 }
 
-impl Component for NotesPopup {
+impl Component for NotesPopup<'_> {
     fn mode(&self) -> Mode {
         Mode::Popup("notes_popup")
     }
     fn id(&self) -> String {
         "Notes Popup".into()
     }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy, Subscription::Spelling]
+    }
     fn register_action_handler(
         &mut self,
         tx: tokio::sync::mpsc::UnboundedSender<Action>,
@@ -34,14 +63,26 @@ impl Component for NotesPopup {
         self.command_tx = Some(tx);
         Ok(())
     }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
     fn update(
         &mut self,
         action: crate::action::Action,
     ) -> color_eyre::eyre::Result<Option<crate::action::Action>> {
         match action {
-            Action::DispatchNotesPopupData(notes) => {
-                self.notes = notes.into();
+            Action::DispatchNotesPopupData(job) => {
+                self.editor = TextArea::from(job.notes.clone().unwrap_or_default().lines());
+                self.editor.set_cursor_line_style(Style::default());
+                self.job = Some(job);
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            Action::ReplaceWord(word, replacement) => {
+                spelling::replace_at_cursor(&mut self.editor, &word, &replacement)
             }
+            Action::WordAdded(word) => self.speller.add(&word),
             _ => {}
         }
         Ok(None)
@@ -50,14 +91,23 @@ impl Component for NotesPopup {
         &mut self,
         key: crossterm::event::KeyEvent,
     ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
         match key.code {
-            crossterm::event::KeyCode::Esc => {
-                if let Some(tx) = &self.command_tx {
-                    tx.send(Action::ChangeMode(Mode::Home))?;
-                    tx.send(Action::NotesPopupData("Cunt".into()))?;
+            KeyCode::Esc => {
+                tx.send(Action::ExitPopup)?;
+                if let Some(job) = self.job.take() {
+                    let notes = self.notes();
+                    if notes != job.notes {
+                        tx.send(Action::SaveJob(JobApplication { notes, ..job }))?;
+                    }
                 }
             }
-            _ => {}
+            KeyCode::F(7) => self.speller.suggest(&self.editor, &self.config, tx)?,
+            _ => {
+                self.editor.input(key);
+            }
         }
         Ok(None)
     }
@@ -71,13 +121,32 @@ impl Component for NotesPopup {
             horizontal: 8,
         });
 
-        frame.render_widget(widgets::Clear, area);
+        util::popup(frame, area, &self.config.theme);
 
-        let block = ratatui::widgets::Block::bordered()
-            .style(Style::default().bg(Color::Red).fg(Color::White))
-            .title("Notes");
-        let paragraph = widgets::Paragraph::new(self.notes.clone()).block(block);
-        frame.render_widget(paragraph, area);
+        let theme = &self.config.theme;
+        let title = match &self.job {
+            Some(job) => {
+                let job = self.privacy.job(job);
+                format!("Notes - {}, {}", job.company_name, job.position)
+            }
+            None => "Notes".to_string(),
+        };
+        self.editor.set_block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.focus))
+                .padding(Padding::horizontal(1))
+                .title(title)
+                .title_bottom(
+                    Line::from(
+                        "[esc] save and close   [ctrl+u] undo   [pgup/pgdn] scroll   [f7] spelling",
+                    )
+                    .centered(),
+                ),
+        );
+        self.speller
+            .highlight(&mut self.editor, &self.config, self.command_tx.as_ref());
+        frame.render_widget(&self.editor, area);
         Ok(())
     }
 }
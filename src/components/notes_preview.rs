@@ -0,0 +1,229 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Margin, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Padding, Paragraph, Wrap},
+};
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::component::Component,
+    config::Config,
+    database::schema::{FileKind, JobApplication},
+    links,
+    state::State,
+};
+
+// percentages of the list's width the details can take, and how far one resize moves them
+const MIN_WIDTH: u16 = 20;
+const MAX_WIDTH: u16 = 70;
+const DEFAULT_WIDTH: u16 = 33;
+const RESIZE_STEP: u16 = 5;
+
+/// Percentage of the list's width the details take: as last resized, or as configured.
+pub fn width(config: &Config) -> u16 {
+    State::load(&config.config.data_dir)
+        .notes_preview_width
+        .or(config.list.notes_preview_width)
+        .unwrap_or(DEFAULT_WIDTH)
+        .clamp(MIN_WIDTH, MAX_WIDTH)
+}
+
+/// `width` after a widen or narrow action, or unchanged for any other.
+pub fn resize(width: u16, action: &Action) -> u16 {
+    match action {
+        Action::WidenNotesPreview => (width + RESIZE_STEP).min(MAX_WIDTH),
+        Action::NarrowNotesPreview => width.saturating_sub(RESIZE_STEP).max(MIN_WIDTH),
+        _ => width,
+    }
+}
+
+/// The job list's area and the details' beside it, split out of the area inside the list's
+/// frame with the details taking `width` percent of it.
+pub fn split(area: Rect, width: u16) -> [Rect; 2] {
+    Layout::horizontal([Constraint::Fill(1), Constraint::Percentage(width)])
+        .spacing(1)
+        .areas(area)
+}
+
+/// The selected application's notes, link and documents beside the job list, following the
+/// selection, so notes cut short on its card can be read in full. Tab focuses it to scroll
+/// with the arrow keys.
+pub struct NotesPreview {
+    config: Config,
+    visible: bool,
+    focused: bool,
+    width: u16,
+    job: Option<JobApplication>,
+    scroll: u16,
+}
+impl NotesPreview {
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            visible: false,
+            focused: false,
+            width: DEFAULT_WIDTH,
+            job: None,
+            scroll: 0,
+        }
+    }
+
+    fn save_width(&self) -> color_eyre::eyre::Result<()> {
+        let data_dir = &self.config.config.data_dir;
+        let mut state = State::load(data_dir);
+        state.notes_preview_width = Some(self.width);
+        state.save(data_dir)
+    }
+
+    fn text(&self) -> Text<'static> {
+        let theme = &self.config.theme;
+        let muted = Style::default().fg(theme.muted);
+        let Some(job) = &self.job else {
+            return Text::styled("Nothing selected", muted);
+        };
+        let heading =
+            |name: &'static str| Line::styled(name, Style::default().add_modifier(Modifier::BOLD));
+        let mut text = Text::default();
+        if let Some(url) = job.url.as_deref().filter(|url| !url.trim().is_empty()) {
+            text.push_line(heading("Link"));
+            text.push_line(Line::styled(
+                url.to_string(),
+                Style::default().fg(theme.focus),
+            ));
+            text.push_line(Line::raw(""));
+        }
+        let files = job.files.entries();
+        if !files.is_empty() {
+            text.push_line(heading("Documents"));
+            for (kind, path) in files {
+                let kind = match kind {
+                    FileKind::Cv => "CV ",
+                    FileKind::CoverLetter => "Cover letter ",
+                    FileKind::Additional => "",
+                };
+                text.push_line(Line::from(vec![
+                    Span::styled(kind, muted),
+                    Span::raw(path.to_string()),
+                ]));
+            }
+            text.push_line(Line::raw(""));
+        }
+        text.push_line(heading("Notes"));
+        match job
+            .notes
+            .as_deref()
+            .filter(|notes| !notes.trim().is_empty())
+        {
+            Some(notes) => text.extend(links::highlight(
+                notes,
+                Style::default()
+                    .fg(theme.focus)
+                    .add_modifier(Modifier::UNDERLINED),
+                None,
+            )),
+            None => text.push_line(Line::styled("No notes", muted)),
+        }
+        text
+    }
+}
+
+impl Component for NotesPreview {
+    fn mode(&self) -> Mode {
+        Mode::Home
+    }
+    fn id(&self) -> String {
+        "Notes Preview".into()
+    }
+    fn focusable(&self) -> bool {
+        self.visible
+    }
+    fn set_focus(&mut self, focused: bool) {
+        self.focused = focused;
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.visible = config.list.notes_preview;
+        self.width = width(&config);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::ToggleNotesPreview => self.visible = !self.visible,
+            Action::WidenNotesPreview | Action::NarrowNotesPreview if self.visible => {
+                let width = resize(self.width, &action);
+                if width != self.width {
+                    self.width = width;
+                    self.save_width()?;
+                }
+            }
+            Action::PreviewJob(job) => {
+                if self.job.as_ref().map(|job| job.id) != job.as_ref().map(|job| job.id) {
+                    self.scroll = 0;
+                }
+                self.job = job;
+            }
+            Action::ScrollNotesDown if self.visible => self.scroll = self.scroll.saturating_add(1),
+            Action::ScrollNotesUp if self.visible => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Down | KeyCode::Char('j') => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Up | KeyCode::Char('k') => self.scroll = self.scroll.saturating_sub(1),
+            KeyCode::PageDown => self.scroll = self.scroll.saturating_add(10),
+            KeyCode::PageUp => self.scroll = self.scroll.saturating_sub(10),
+            KeyCode::Home | KeyCode::Char('g') => self.scroll = 0,
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        if !self.visible {
+            return Ok(());
+        }
+        // the area inside the job list's frame, as it lays itself out
+        let inner = Block::new()
+            .borders(Borders::TOP)
+            .inner(area.inner(Margin::new(2, 2)));
+        let [_, area] = split(inner, self.width);
+
+        let theme = &self.config.theme;
+        let (border, hint) = if self.focused {
+            (theme.focus, "[↑↓] scroll   [tab] back")
+        } else {
+            (theme.border, "[tab] focus   [shift ←→] resize")
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(border))
+            .padding(Padding::horizontal(1))
+            .title("Details")
+            .title_bottom(Line::from(hint).right_aligned());
+        let text = self.text();
+        // stop once the last line is at the top, counting the lines long ones wrap onto
+        let width = block.inner(area).width.max(1) as usize;
+        let rows = text
+            .lines
+            .iter()
+            .map(|line| line.width().div_ceil(width).max(1))
+            .sum::<usize>();
+        self.scroll = self.scroll.min(rows.saturating_sub(1) as u16);
+        frame.render_widget(
+            Paragraph::new(text)
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0))
+                .block(block),
+            area,
+        );
+        Ok(())
+    }
+}
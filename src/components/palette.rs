@@ -0,0 +1,300 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use fuzzy_matcher::{FuzzyMatcher, skim::SkimMatcherV2};
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{self, List, ListItem, ListState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::JobApplication,
+    privacy::Privacy,
+};
+
+// entries shown at most, best match first
+const SHOWN: usize = 50;
+
+// what the palette can run, by the name it's found under
+const COMMANDS: [(&str, Action); 24] = [
+    ("New application", Action::NewJob),
+    ("Quick add application", Action::QuickAdd),
+    ("Import from URL", Action::ImportUrl),
+    ("Export CSV", Action::Export),
+    ("Back up database", Action::Backup),
+    ("Stats", Action::ShowStats),
+    ("Timeline", Action::ShowTimeline),
+    ("Calendar", Action::ShowCalendar),
+    ("Reminders", Action::ShowReminders),
+    ("Interview questions", Action::ShowQuestions),
+    ("Tags", Action::ShowTags),
+    ("Import conflicts", Action::ShowConflicts),
+    ("Category suggestions", Action::ShowCategorySuggestions),
+    ("Weekly digest", Action::Digest),
+    ("Email templates", Action::ShowTemplates),
+    ("Details beside the list", Action::ToggleNotesPreview),
+    ("Widen details", Action::WidenNotesPreview),
+    ("Narrow details", Action::NarrowNotesPreview),
+    ("Refresh", Action::Refresh),
+    ("Toggle privacy", Action::TogglePrivacy),
+    ("Tutorial", Action::ToggleTutorial),
+    ("Lock", Action::Lock),
+    ("Help", Action::Help),
+    ("Quit", Action::Quit),
+];
+
+enum Target {
+    Run(Box<Action>),
+    // by id, as what's shown may be masked
+    Open(i32),
+}
+
+struct Entry {
+    label: String,
+    // shown dimmed after the label, e.g. the application's status
+    detail: String,
+    target: Target,
+}
+
+/// Fuzzy finder over commands and applications, to jump to or run either from anywhere.
+pub struct CommandPalette {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    jobs: Vec<JobApplication>,
+    entries: Vec<Entry>,
+    input: String,
+    // indices into `entries` of those matching the input, with the matched characters
+    matches: Vec<(usize, Vec<usize>)>,
+    selected: ListState,
+    matcher: SkimMatcherV2,
+    privacy: Privacy,
+}
+impl CommandPalette {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            jobs: Vec::new(),
+            entries: Vec::new(),
+            input: String::new(),
+            matches: Vec::new(),
+            selected: ListState::default(),
+            matcher: SkimMatcherV2::default().ignore_case(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    // labels go through the privacy mask before matching, so masked names can't be found
+    fn rebuild(&mut self) {
+        let commands = COMMANDS.iter().map(|(label, action)| Entry {
+            label: label.to_string(),
+            detail: "command".to_string(),
+            target: Target::Run(Box::new(action.clone())),
+        });
+        let jobs = self.jobs.iter().map(|job| {
+            let job = self.privacy.job(job);
+            Entry {
+                label: format!("{} · {}", job.company_name, job.position),
+                detail: job.status.to_string(),
+                target: Target::Open(job.id),
+            }
+        });
+        self.entries = commands.chain(jobs).collect();
+        self.search();
+    }
+
+    fn search(&mut self) {
+        let query = self.input.trim();
+        self.matches = if query.is_empty() {
+            (0..self.entries.len())
+                .map(|idx| (idx, Vec::new()))
+                .collect()
+        } else {
+            let mut scored = self
+                .entries
+                .iter()
+                .enumerate()
+                .filter_map(|(idx, entry)| {
+                    let (score, indices) = self.matcher.fuzzy_indices(&entry.label, query)?;
+                    Some((score, idx, indices))
+                })
+                .collect::<Vec<_>>();
+            scored.sort_by(|a, b| b.0.cmp(&a.0).then(a.1.cmp(&b.1)));
+            scored
+                .into_iter()
+                .map(|(_, idx, indices)| (idx, indices))
+                .collect()
+        };
+        self.matches.truncate(SHOWN);
+        self.selected
+            .select((!self.matches.is_empty()).then_some(0));
+    }
+
+    fn run_selected(&mut self) -> color_eyre::eyre::Result<()> {
+        let Some((idx, _)) = self.selected.selected().and_then(|i| self.matches.get(i)) else {
+            return Ok(());
+        };
+        let action = match &self.entries[*idx].target {
+            Target::Run(action) => *action.clone(),
+            Target::Open(id) => match self.jobs.iter().find(|job| job.id == *id) {
+                Some(job) => Action::OpenJobDetail(job.clone()),
+                None => return Ok(()),
+            },
+        };
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::ExitPopup)?;
+            tx.send(action)?;
+        }
+        Ok(())
+    }
+
+    fn item(&self, entry: &Entry, matched: &[usize]) -> ListItem<'static> {
+        let highlight = Style::default()
+            .fg(self.config.theme.focus)
+            .add_modifier(Modifier::BOLD);
+        let mut spans = entry
+            .label
+            .chars()
+            .enumerate()
+            .map(|(idx, c)| {
+                if matched.contains(&idx) {
+                    Span::styled(c.to_string(), highlight)
+                } else {
+                    Span::raw(c.to_string())
+                }
+            })
+            .collect::<Vec<_>>();
+        spans.push(Span::styled(
+            format!("  {}", entry.detail),
+            Style::default().fg(self.config.theme.muted),
+        ));
+        ListItem::new(Line::from(spans))
+    }
+}
+
+impl Component for CommandPalette {
+    fn mode(&self) -> Mode {
+        Mode::Popup("palette")
+    }
+    fn id(&self) -> String {
+        "Command Palette".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::PopulatePalette(jobs) => {
+                self.jobs = jobs;
+                self.input.clear();
+                self.rebuild();
+            }
+            Action::SetPrivacy(enabled) => {
+                self.privacy = Privacy::new(enabled);
+                self.rebuild();
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Enter => self.run_selected()?,
+            KeyCode::Down | KeyCode::Tab => self.selected.select_next(),
+            KeyCode::Up | KeyCode::BackTab => self.selected.select_previous(),
+            KeyCode::Char('n') if ctrl => self.selected.select_next(),
+            KeyCode::Char('p') if ctrl => self.selected.select_previous(),
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.search();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.input.push(c);
+                self.search();
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let [area] = Layout::vertical([Constraint::Percentage(60)])
+            .flex(Flex::Start)
+            .areas(area.inner(ratatui::layout::Margin::new(0, 2)));
+        let [area] = Layout::horizontal([Constraint::Length(70)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        util::popup(frame, area, &self.config.theme);
+        let theme = &self.config.theme;
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title("Go to")
+            .title_bottom(
+                Line::styled(
+                    "[enter] open or run   [esc] close",
+                    Style::default().fg(theme.muted),
+                )
+                .centered(),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [input, _, results] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+        ])
+        .areas(inner);
+        frame.render_widget(
+            Line::from(vec![
+                Span::styled("> ", Style::default().fg(theme.focus)),
+                Span::raw(self.input.clone()),
+                Span::styled("█", Style::default().fg(theme.muted)),
+            ]),
+            input,
+        );
+        if self.matches.is_empty() {
+            frame.render_widget(
+                Line::styled("Nothing matches", Style::default().fg(theme.muted)),
+                results,
+            );
+            return Ok(());
+        }
+        let items = self
+            .matches
+            .iter()
+            .map(|(idx, matched)| self.item(&self.entries[*idx], matched))
+            .collect::<Vec<_>>();
+        let list = List::new(items).highlight_style(Style::default().bg(theme.selection_bg));
+        frame.render_stateful_widget(list, results, &mut self.selected);
+        Ok(())
+    }
+}
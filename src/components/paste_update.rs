@@ -0,0 +1,266 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Padding, Paragraph, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::{Interview, JobApplication},
+    dates,
+    inference::{self, EmailKind, Inference},
+    privacy::Privacy,
+    tui::Event,
+};
+
+/// Takes a pasted recruiter email and proposes the status change it implies, with any
+/// interview time or deadline it mentions. Nothing is saved until the proposal is confirmed.
+pub struct PasteUpdatePopup<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: Option<JobApplication>,
+    input: TextArea<'a>,
+    inference: Inference,
+    privacy: Privacy,
+}
+impl PasteUpdatePopup<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: None,
+            input: TextArea::default(),
+            inference: Inference::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn text(&self) -> String {
+        self.input.lines().join("\n")
+    }
+
+    fn reinfer(&mut self) {
+        self.inference = inference::infer(&self.text(), &dates::today());
+    }
+
+    /// The actions that apply the proposal: the status change with a note saying where it came
+    /// from, and the interview or follow-up date the email gives.
+    fn apply(&self, job: &JobApplication) -> Option<Vec<Action>> {
+        let kind = self.inference.kind?;
+        let status = self.inference.status(&self.text())?;
+        let mut job = JobApplication {
+            is_active: status.is_active(),
+            status,
+            ..job.clone()
+        };
+        let mut actions = Vec::new();
+        let mut note = format!("From email: {}", kind.label());
+        match (
+            kind,
+            self.inference.first_datetime(),
+            self.inference.dates.first(),
+        ) {
+            (EmailKind::InterviewInvite, Some(starts_at), _) => {
+                note.push_str(&format!(" for {starts_at}"));
+                actions.push(Action::SaveInterview(Interview {
+                    job_id: job.id,
+                    starts_at: starts_at.to_string(),
+                    ..Interview::default()
+                }));
+            }
+            (EmailKind::InterviewInvite | EmailKind::Offer, _, Some(date)) => {
+                note.push_str(&format!(", follow up {}", &date[..10]));
+                job.follow_up_date = Some(date[..10].to_string());
+            }
+            _ => {}
+        }
+        actions.insert(0, Action::SaveStatusChange(job, note));
+        Some(actions)
+    }
+
+    fn proposal(&self, job: &JobApplication) -> Text<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        let (Some(kind), Some(status)) = (self.inference.kind, self.inference.status(&self.text()))
+        else {
+            return Text::from(Line::styled(
+                "Paste an email to see the update it suggests",
+                muted,
+            ));
+        };
+        let mut lines = vec![Line::from(vec![
+            Span::styled("Looks like a ", muted),
+            Span::raw(kind.label()),
+            Span::styled(
+                format!(" (\"{}\")", self.inference.matched.unwrap_or_default()),
+                muted,
+            ),
+        ])];
+        lines.push(Line::from(vec![
+            Span::styled("Status  ", muted),
+            Span::raw(format!("{} → ", job.status)),
+            Span::styled(
+                status.to_string(),
+                self.config
+                    .list
+                    .status_style(&status)
+                    .add_modifier(Modifier::BOLD),
+            ),
+        ]));
+        if !self.inference.dates.is_empty() {
+            lines.push(Line::from(vec![
+                Span::styled("Dates   ", muted),
+                Span::raw(self.inference.dates.join(", ")),
+            ]));
+        }
+        let effect = match (
+            kind,
+            self.inference.first_datetime(),
+            self.inference.dates.first(),
+        ) {
+            (EmailKind::InterviewInvite, Some(starts_at), _) => {
+                Some(format!("Schedules an interview at {starts_at}"))
+            }
+            (EmailKind::InterviewInvite | EmailKind::Offer, _, Some(date)) => {
+                Some(format!("Sets the follow-up to {}", &date[..10]))
+            }
+            _ => None,
+        };
+        if let Some(effect) = effect {
+            lines.push(Line::styled(effect, muted));
+        }
+        Text::from(lines)
+    }
+}
+
+impl Component for PasteUpdatePopup<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("paste_update")
+    }
+    fn id(&self) -> String {
+        "Paste Update".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::PromptPasteUpdate(job) => {
+                self.input = TextArea::default();
+                self.input.set_cursor_line_style(Style::default());
+                self.inference = Inference::default();
+                self.job = Some(job);
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_events(&mut self, event: Option<Event>) -> color_eyre::eyre::Result<Option<Action>> {
+        match event {
+            Some(Event::Paste(text)) => {
+                self.input.insert_str(text.replace("\r\n", "\n"));
+                self.reinfer();
+                Ok(None)
+            }
+            Some(Event::Key(key)) => self.handle_key_event(key),
+            _ => Ok(None),
+        }
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Enter => {
+                let Some(job) = &self.job else {
+                    return Ok(None);
+                };
+                let Some(actions) = self.apply(job) else {
+                    return Ok(Some(Action::Error(
+                        "No status change found in the email".to_string(),
+                    )));
+                };
+                self.job = None;
+                tx.send(Action::ExitPopup)?;
+                for action in actions {
+                    tx.send(action)?;
+                }
+            }
+            _ => {
+                self.input.input(key);
+                self.reinfer();
+            }
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(70),
+            Constraint::Percentage(70),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let title = match &self.job {
+            Some(job) => format!("Update from email: {}", self.privacy.job(job).company_name),
+            None => "Update from email".to_string(),
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title(title)
+            .title_bottom(Line::from("[enter] apply   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [email, proposal] =
+            Layout::vertical([Constraint::Min(3), Constraint::Length(6)]).areas(inner);
+        self.input.set_block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.border))
+                .title("Email"),
+        );
+        self.input
+            .set_placeholder_text("Paste the recruiter's email here");
+        frame.render_widget(&self.input, email);
+        if let Some(job) = &self.job {
+            frame.render_widget(
+                Paragraph::new(self.proposal(job))
+                    .wrap(Wrap { trim: false })
+                    .block(
+                        Block::bordered()
+                            .border_type(BorderType::Rounded)
+                            .title("Proposed"),
+                    ),
+                proposal,
+            );
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,119 @@
+use std::path::PathBuf;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::Style,
+    text::{Line, Text},
+    widgets::{self, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+};
+
+pub struct PresetPreviewPopup {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    path: PathBuf,
+    changes: Vec<String>,
+    scroll: u16,
+}
+impl PresetPreviewPopup {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            path: PathBuf::new(),
+            changes: Vec::new(),
+            scroll: 0,
+        }
+    }
+}
+
+impl Component for PresetPreviewPopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("preset_preview")
+    }
+    fn id(&self) -> String {
+        "Preset Preview".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::PresetPreview(path, changes) = action {
+            self.path = path;
+            self.changes = changes;
+            self.scroll = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Char('y') | KeyCode::Enter => {
+                tx.send(Action::ExitPopup)?;
+                tx.send(Action::ApplyPreset(self.path.clone()))?;
+            }
+            KeyCode::Char('n') | KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Down => self.scroll = self.scroll.saturating_add(1),
+            KeyCode::Up => self.scroll = self.scroll.saturating_sub(1),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let lines = if self.changes.is_empty() {
+            vec![Line::styled(
+                "This preset matches the current configuration.",
+                Style::default().fg(self.config.theme.muted),
+            )]
+        } else {
+            self.changes.iter().map(|c| Line::raw(c.as_str())).collect()
+        };
+
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(60),
+            Constraint::Percentage(60),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title(format!("Apply preset {}?", self.path.display()))
+            .title_bottom(Line::from("[y] apply   [n] cancel   [↑/↓] scroll").centered());
+        frame.render_widget(
+            widgets::Paragraph::new(Text::from(lines))
+                .wrap(Wrap { trim: false })
+                .scroll((self.scroll, 0))
+                .block(block),
+            area,
+        );
+        Ok(())
+    }
+}
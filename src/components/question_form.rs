@@ -0,0 +1,183 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::Line,
+    widgets::{self, Block, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::Question,
+};
+
+const FIELDS: [&str; 3] = ["Question", "Topic, e.g. system design", "Answer notes"];
+
+// the answer notes run over several lines, so Enter starts a new one there
+const ANSWER: usize = 2;
+
+pub struct QuestionForm<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    question: Question,
+    company: String,
+    fields: Vec<TextArea<'a>>,
+    focused: usize,
+}
+impl QuestionForm<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            question: Question::default(),
+            company: String::new(),
+            fields: Self::create_fields(&Question::default()),
+            focused: 0,
+        }
+    }
+
+    fn create_fields<'a>(question: &Question) -> Vec<TextArea<'a>> {
+        let values = [
+            question.question.clone(),
+            question.topic.clone(),
+            question.answer.clone(),
+        ];
+        FIELDS
+            .iter()
+            .zip(values)
+            .map(|(title, value)| {
+                let mut field = TextArea::from(value.lines());
+                field.set_block(
+                    Block::bordered()
+                        .padding(Padding::horizontal(1))
+                        .title(*title),
+                );
+                field
+            })
+            .collect()
+    }
+
+    fn question_from_fields(&self) -> Result<Question, String> {
+        let value = |index: usize| self.fields[index].lines().join("\n").trim().to_string();
+        let question = value(0).replace('\n', " ");
+        if question.is_empty() {
+            return Err("A question can't be empty".to_string());
+        }
+        Ok(Question {
+            question,
+            topic: value(1).replace('\n', " "),
+            answer: value(ANSWER),
+            ..self.question.clone()
+        })
+    }
+
+    fn save(&self) -> color_eyre::eyre::Result<Option<Action>> {
+        match self.question_from_fields() {
+            Ok(question) => {
+                if let Some(tx) = &self.command_tx {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::SaveQuestion(question))?;
+                }
+                Ok(None)
+            }
+            Err(message) => Ok(Some(Action::Error(message))),
+        }
+    }
+}
+
+impl Component for QuestionForm<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("question_form")
+    }
+    fn id(&self) -> String {
+        "Question Form".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::PopulateQuestionForm(question, company) = action {
+            self.fields = Self::create_fields(&question);
+            self.question = question;
+            self.company = company;
+            self.focused = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Tab => self.focused = (self.focused + 1) % FIELDS.len(),
+            KeyCode::BackTab => self.focused = (self.focused + FIELDS.len() - 1) % FIELDS.len(),
+            KeyCode::Char('s') if ctrl => return self.save(),
+            KeyCode::Enter if self.focused != ANSWER => return self.save(),
+            _ => {
+                self.fields[self.focused].input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(60),
+            Constraint::Length(18),
+            &self.config.theme,
+        );
+        let title = match (self.question.id, self.company.as_str()) {
+            (0, "") => format!("New question for application #{}", self.question.job_id),
+            (0, company) => format!("New question asked at {company}"),
+            (_, "") => "Edit question".to_string(),
+            (_, company) => format!("Edit question asked at {company}"),
+        };
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title(title)
+            .title_bottom(Line::from("[tab] next field   [ctrl-s] save   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical([
+            Constraint::Length(3),
+            Constraint::Length(3),
+            Constraint::Fill(1),
+        ])
+        .split(inner);
+        let theme = &self.config.theme;
+        for (i, (field, row)) in self.fields.iter_mut().zip(rows.iter()).enumerate() {
+            let color = if i == self.focused {
+                theme.focus
+            } else {
+                theme.border
+            };
+            if let Some(block) = field.block().cloned() {
+                field.set_block(block.border_style(Style::default().fg(color)));
+            }
+            frame.render_widget(&*field, *row);
+        }
+        Ok(())
+    }
+}
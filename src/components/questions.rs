@@ -0,0 +1,282 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{self, Cell, Paragraph, Row, Table, TableState, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::Question,
+    privacy::Privacy,
+};
+
+/// Interview questions logged against every application, most recently asked first, to go
+/// over before the next interview. Typing narrows them to those containing every word typed,
+/// in the question, answer, topic or company.
+pub struct QuestionBank {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    questions: Vec<(Question, String)>,
+    input: String,
+    // indices into `questions` of those matching the input
+    matches: Vec<usize>,
+    selected: TableState,
+    privacy: Privacy,
+}
+impl QuestionBank {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            questions: Vec::new(),
+            input: String::new(),
+            matches: Vec::new(),
+            selected: TableState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn company(&self, question: &Question, company: &str) -> String {
+        self.privacy
+            .text(company, &format!("Company #{}", question.job_id))
+    }
+
+    // companies go through the privacy mask before matching, so masked names can't be found
+    fn search(&mut self) {
+        let words = self
+            .input
+            .split_whitespace()
+            .map(str::to_lowercase)
+            .collect::<Vec<_>>();
+        self.matches = self
+            .questions
+            .iter()
+            .enumerate()
+            .filter(|(_, (question, company))| {
+                let text = [
+                    question.question.as_str(),
+                    question.answer.as_str(),
+                    question.topic.as_str(),
+                    &self.company(question, company),
+                ]
+                .join(" ")
+                .to_lowercase();
+                words.iter().all(|word| text.contains(word))
+            })
+            .map(|(idx, _)| idx)
+            .collect();
+        let last = self.matches.len().checked_sub(1);
+        self.selected
+            .select(last.map(|last| self.selected.selected().unwrap_or(0).min(last)));
+    }
+
+    fn selected_question(&self) -> Option<&(Question, String)> {
+        self.selected
+            .selected()
+            .and_then(|idx| self.matches.get(idx))
+            .map(|idx| &self.questions[*idx])
+    }
+
+    fn table(&self) -> Table<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        let rows = self.matches.iter().map(|idx| {
+            let (question, company) = &self.questions[*idx];
+            Row::new([
+                Cell::new(question.asked_at.clone()).style(muted),
+                Cell::new(self.company(question, company)),
+                Cell::new(question.topic.clone()).style(muted),
+                Cell::new(question.question.clone()),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Length(20),
+                Constraint::Length(16),
+                Constraint::Min(10),
+            ],
+        )
+        .header(
+            Row::new(["Asked", "Company", "Topic", "Question"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().bg(self.config.theme.selection_bg))
+    }
+
+    // the selected question in full, with the notes on answering it
+    fn preview(&self) -> Text<'static> {
+        let Some((question, _)) = self.selected_question() else {
+            return Text::default();
+        };
+        let mut text = Text::from(Line::styled(
+            question.question.clone(),
+            Style::default().add_modifier(Modifier::BOLD),
+        ));
+        match question.answer.trim() {
+            "" => text.push_line(Line::styled(
+                "No answer notes",
+                Style::default().fg(self.config.theme.muted),
+            )),
+            answer => text.extend(Text::raw(answer.to_string())),
+        }
+        text
+    }
+}
+
+impl Component for QuestionBank {
+    fn mode(&self) -> Mode {
+        Mode::Popup("questions")
+    }
+    fn id(&self) -> String {
+        "Question Bank".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::QuestionResults(questions) => {
+                self.questions = questions;
+                self.search();
+            }
+            Action::SetPrivacy(enabled) => {
+                self.privacy = Privacy::new(enabled);
+                self.search();
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc if !self.input.is_empty() => {
+                self.input.clear();
+                self.search();
+            }
+            KeyCode::Esc => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Down | KeyCode::Tab => self.selected.select_next(),
+            KeyCode::Up | KeyCode::BackTab => self.selected.select_previous(),
+            KeyCode::Char('n') if ctrl => self.selected.select_next(),
+            KeyCode::Char('p') if ctrl => self.selected.select_previous(),
+            KeyCode::Enter => {
+                if let Some((question, company)) = self.selected_question() {
+                    return Ok(Some(Action::OpenQuestionForm(
+                        question.clone(),
+                        self.company(question, company),
+                    )));
+                }
+            }
+            KeyCode::Delete => {
+                if let Some((question, _)) = self.selected_question() {
+                    return Ok(Some(Action::Confirm {
+                        title: "Delete question".into(),
+                        message: format!("Delete \"{}\"?", question.question),
+                        on_yes: Box::new(Action::DeleteQuestion(question.id)),
+                    }));
+                }
+            }
+            KeyCode::Backspace => {
+                self.input.pop();
+                self.search();
+            }
+            KeyCode::Char(c) if !ctrl => {
+                self.input.push(c);
+                self.search();
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(80),
+            Constraint::Percentage(80),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title(format!(
+                "Interview questions ({} of {})",
+                self.matches.len(),
+                self.questions.len()
+            ))
+            .title_bottom(
+                Line::from("type to search   [enter] edit   [del] delete   [esc] close").centered(),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [input, _, results, preview] = Layout::vertical([
+            Constraint::Length(1),
+            Constraint::Length(1),
+            Constraint::Fill(1),
+            Constraint::Length(8),
+        ])
+        .areas(inner);
+        frame.render_widget(
+            Line::from(vec![
+                Span::styled("> ", Style::default().fg(theme.focus)),
+                Span::raw(self.input.clone()),
+                Span::styled("█", Style::default().fg(theme.muted)),
+            ]),
+            input,
+        );
+        if self.matches.is_empty() {
+            let message = if self.questions.is_empty() {
+                "No questions logged. Press I on an application to log one."
+            } else {
+                "Nothing matches"
+            };
+            frame.render_widget(
+                Line::styled(message, Style::default().fg(theme.muted)),
+                results,
+            );
+            return Ok(());
+        }
+        let table = self.table();
+        frame.render_stateful_widget(table, results, &mut self.selected);
+        frame.render_widget(
+            Paragraph::new(self.preview())
+                .wrap(Wrap { trim: false })
+                .block(
+                    widgets::Block::default()
+                        .borders(widgets::Borders::TOP)
+                        .border_style(Style::default().fg(theme.border)),
+                ),
+            preview,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,170 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::Line,
+    widgets::{self, Block, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::{
+        builder::JobApplicationBuilder,
+        schema::{ApplicationStatus, JobApplication},
+    },
+    dates::{self, add_days},
+};
+
+const FIELDS: [&str; 4] = ["Company", "Position", "Link", "Applied (YYYY-MM-DD)"];
+
+/// The few fields needed to log an application in seconds, from anywhere. Everything else
+/// takes its default, to fill in later from the edit form.
+pub struct QuickAdd<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    fields: Vec<TextArea<'a>>,
+    focused: usize,
+}
+impl QuickAdd<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            fields: Self::create_fields(),
+            focused: 0,
+        }
+    }
+
+    fn create_fields<'a>() -> Vec<TextArea<'a>> {
+        let values = [String::new(), String::new(), String::new(), dates::today()];
+        FIELDS
+            .iter()
+            .zip(values)
+            .map(|(title, value)| {
+                let mut field = TextArea::from([value]);
+                field.move_cursor(tui_textarea::CursorMove::End);
+                field.set_block(
+                    Block::bordered()
+                        .padding(Padding::horizontal(1))
+                        .title(*title),
+                );
+                field
+            })
+            .collect()
+    }
+
+    fn field_value(&self, index: usize) -> String {
+        self.fields[index].lines().join(" ").trim().to_string()
+    }
+
+    fn job_from_fields(&self) -> Result<JobApplication, String> {
+        let date = self.field_value(3);
+        let mut job = JobApplicationBuilder::new()
+            .company_name(&self.field_value(0))
+            .position(&self.field_value(1))
+            .url(&self.field_value(2))
+            .application_date(&date)
+            .build()?;
+        // as in the edit form, a posting with no date applied is saved for later
+        if date.is_empty() {
+            job.status = ApplicationStatus::Saved;
+            job.is_active = true;
+        } else {
+            job.follow_up_date = add_days(&date, self.config.config.follow_up_days);
+        }
+        Ok(job)
+    }
+}
+
+impl Component for QuickAdd<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("quick_add")
+    }
+    fn id(&self) -> String {
+        "Quick Add".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if action == Action::ShowQuickAdd {
+            self.fields = Self::create_fields();
+            self.focused = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Tab | KeyCode::Down => self.focused = (self.focused + 1) % FIELDS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + FIELDS.len() - 1) % FIELDS.len();
+            }
+            KeyCode::Enter => match self.job_from_fields() {
+                Ok(job) => {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::SaveJob(job))?;
+                }
+                Err(message) => return Ok(Some(Action::Error(message))),
+            },
+            _ => {
+                self.fields[self.focused].input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(50),
+            Constraint::Length(14),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title("Quick add")
+            .title_bottom(Line::from("[tab] next field   [enter] save   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical([Constraint::Length(3); 4]).split(inner);
+        let theme = &self.config.theme;
+        for (i, (field, row)) in self.fields.iter_mut().zip(rows.iter()).enumerate() {
+            let color = if i == self.focused {
+                theme.focus
+            } else {
+                theme.border
+            };
+            if let Some(block) = field.block().cloned() {
+                field.set_block(block.border_style(Style::default().fg(color)));
+            }
+            frame.render_widget(&*field, *row);
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,199 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{self, List, ListItem, ListState, Paragraph, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    categorize::Suggestion,
+    components::{component::Component, util},
+    config::Config,
+    privacy::Privacy,
+};
+
+/// Category corrections suggested by position titles, grouped by the change so a whole group
+/// can be accepted at once. Handy after an import files everything under one category.
+pub struct RecategorizeAssistant {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    suggestions: Vec<Suggestion>,
+    selected: ListState,
+    privacy: Privacy,
+}
+impl RecategorizeAssistant {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            suggestions: Vec::new(),
+            selected: ListState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn selected_suggestion(&self) -> Option<&Suggestion> {
+        self.suggestions.get(self.selected.selected()?)
+    }
+
+    fn group_line(suggestion: &Suggestion) -> Line<'static> {
+        Line::from(vec![
+            Span::styled(
+                format!("{:>3} ", suggestion.jobs.len()),
+                Style::default().add_modifier(Modifier::BOLD),
+            ),
+            Span::raw(format!("{} → {}", suggestion.from, suggestion.to)),
+            Span::raw(format!(
+                "  titles with \"{}\"",
+                suggestion.keywords.join("\", \"")
+            )),
+        ])
+    }
+}
+
+impl Component for RecategorizeAssistant {
+    fn mode(&self) -> Mode {
+        Mode::Popup("recategorize")
+    }
+    fn id(&self) -> String {
+        "Recategorize".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::CategorySuggestions(suggestions) => {
+                self.suggestions = suggestions;
+                let selected = self.selected.selected().unwrap_or(0);
+                self.selected.select(if self.suggestions.is_empty() {
+                    None
+                } else {
+                    Some(selected.min(self.suggestions.len() - 1))
+                });
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Enter | KeyCode::Char('a') => {
+                if let Some(suggestion) = self.selected_suggestion() {
+                    let ids = suggestion.jobs.iter().map(|job| job.id).collect();
+                    return Ok(Some(Action::Recategorize(ids, suggestion.to.clone())));
+                }
+            }
+            // leave the group as it is for now; it comes back next time
+            KeyCode::Char('s') | KeyCode::Delete => {
+                if let Some(idx) = self.selected.selected()
+                    && idx < self.suggestions.len()
+                {
+                    self.suggestions.remove(idx);
+                    if idx >= self.suggestions.len() {
+                        self.selected.select(self.suggestions.len().checked_sub(1));
+                    }
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(70),
+            Constraint::Percentage(70),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title("Category suggestions")
+            .title_bottom(
+                Line::from("[enter] accept group   [s] skip   [↑/↓] select   [esc] close")
+                    .centered(),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.suggestions.is_empty() {
+            frame.render_widget(
+                Line::styled(
+                    "Every application's category matches its title.",
+                    Style::default().fg(theme.muted),
+                ),
+                inner,
+            );
+            return Ok(());
+        }
+
+        let [groups_area, jobs_area] =
+            Layout::vertical([Constraint::Percentage(40), Constraint::Fill(1)]).areas(inner);
+        let groups = List::new(
+            self.suggestions
+                .iter()
+                .map(|s| ListItem::new(Self::group_line(s))),
+        )
+        .highlight_style(Style::default().bg(theme.selection_bg));
+        frame.render_stateful_widget(groups, groups_area, &mut self.selected);
+
+        let muted = Style::default().fg(theme.muted);
+        let lines = self
+            .selected_suggestion()
+            .map(|suggestion| {
+                suggestion
+                    .jobs
+                    .iter()
+                    .map(|job| {
+                        let job = self.privacy.job(job);
+                        Line::from(vec![
+                            Span::raw(format!("  {}", job.position)),
+                            Span::styled(format!(" @ {}", job.company_name), muted),
+                        ])
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(lines).wrap(Wrap { trim: false }).block(
+                widgets::Block::default()
+                    .borders(widgets::Borders::TOP)
+                    .border_style(muted)
+                    .title("Applications in this group"),
+            ),
+            jobs_area,
+        );
+        Ok(())
+    }
+}
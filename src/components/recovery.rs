@@ -0,0 +1,113 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::Style,
+    text::{Line, Text},
+    widgets::{self, Paragraph, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+};
+
+/// Shown on start when the last run stopped part-way through a write, to run it again or
+/// leave it. Whatever it had written was already rolled back. It has no popup area, so a
+/// stray click can't skip the question.
+pub struct RecoveryPrompt {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    // what the interrupted write was doing, e.g. "importing jobs.csv"
+    description: String,
+}
+impl RecoveryPrompt {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            description: String::new(),
+        }
+    }
+
+    fn close(&mut self, then: Action) -> color_eyre::eyre::Result<()> {
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::ExitPopup)?;
+            tx.send(then)?;
+        }
+        Ok(())
+    }
+}
+
+impl Component for RecoveryPrompt {
+    fn mode(&self) -> Mode {
+        Mode::Popup("recovery")
+    }
+    fn id(&self) -> String {
+        "Recovery Prompt".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::ShowInterrupted(description) = action {
+            self.description = description;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Enter | KeyCode::Char('r') => self.close(Action::ResumeInterrupted)?,
+            KeyCode::Esc | KeyCode::Char('d') => self.close(Action::DiscardInterrupted)?,
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let [area] = Layout::vertical([Constraint::Length(8)])
+            .flex(Flex::Center)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Length(60)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        util::clear_popup(frame, area, &self.config.theme);
+        let theme = &self.config.theme;
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.warning))
+            .padding(widgets::Padding::horizontal(1))
+            .title("Interrupted")
+            .title_bottom(Line::from("[r] run it again   [esc] leave it").centered());
+        let text = Text::from(vec![
+            Line::from(format!("The last run stopped while {}.", self.description)),
+            Line::raw(""),
+            Line::styled(
+                "Nothing it had written was kept.",
+                Style::default().fg(theme.muted),
+            ),
+        ]);
+        frame.render_widget(
+            Paragraph::new(text).wrap(Wrap { trim: true }).block(block),
+            area,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,183 @@
+use std::str::FromStr;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::Style,
+    text::Line,
+    widgets::{self, Block, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::{Recurrence, Reminder},
+    dates::{format_datetime, parse_datetime},
+};
+
+const FIELDS: [&str; 3] = [
+    "Due (YYYY-MM-DD HH:MM)",
+    "Message",
+    "Repeat (once, daily, weekly, monthly)",
+];
+
+pub struct ReminderForm<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    reminder: Reminder,
+    fields: Vec<TextArea<'a>>,
+    focused: usize,
+}
+impl ReminderForm<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            reminder: Reminder::default(),
+            fields: Self::create_fields(&Reminder::default()),
+            focused: 0,
+        }
+    }
+
+    fn create_fields<'a>(reminder: &Reminder) -> Vec<TextArea<'a>> {
+        let values = [
+            reminder.due_at.clone(),
+            reminder.message.clone(),
+            reminder.recurrence.to_string().to_lowercase(),
+        ];
+        FIELDS
+            .iter()
+            .zip(values)
+            .map(|(title, value)| {
+                let mut field = TextArea::from([value]);
+                field.set_block(
+                    Block::bordered()
+                        .padding(Padding::horizontal(1))
+                        .title(*title),
+                );
+                field
+            })
+            .collect()
+    }
+
+    fn field_value(&self, index: usize) -> String {
+        self.fields[index].lines().join(" ").trim().to_string()
+    }
+
+    fn reminder_from_fields(&self) -> Result<Reminder, String> {
+        let due = self.field_value(0);
+        // stored normalised so SQLite can step it on to the next occurrence
+        let due_at = parse_datetime(&due)
+            .map(format_datetime)
+            .ok_or_else(|| format!("Invalid due time: \"{due}\" (expected YYYY-MM-DD HH:MM)"))?;
+        let message = self.field_value(1);
+        if message.is_empty() {
+            return Err("A reminder needs a message".to_string());
+        }
+        let repeat = self.field_value(2);
+        let recurrence =
+            Recurrence::from_str(&repeat).map_err(|_| format!("Invalid repeat: \"{repeat}\""))?;
+        Ok(Reminder {
+            due_at,
+            message,
+            recurrence,
+            ..self.reminder.clone()
+        })
+    }
+}
+
+impl Component for ReminderForm<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("reminder_form")
+    }
+    fn id(&self) -> String {
+        "Reminder Form".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::PopulateReminderForm(reminder) = action {
+            self.fields = Self::create_fields(&reminder);
+            self.reminder = reminder;
+            self.focused = 0;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Tab | KeyCode::Down => self.focused = (self.focused + 1) % FIELDS.len(),
+            KeyCode::BackTab | KeyCode::Up => {
+                self.focused = (self.focused + FIELDS.len() - 1) % FIELDS.len();
+            }
+            KeyCode::Enter => match self.reminder_from_fields() {
+                Ok(reminder) => {
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::SaveReminder(reminder))?;
+                }
+                Err(message) => return Ok(Some(Action::Error(message))),
+            },
+            _ => {
+                self.fields[self.focused].input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(50),
+            Constraint::Length(11),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title(format!(
+                "New reminder for application #{}",
+                self.reminder.job_id
+            ))
+            .title_bottom(Line::from("[tab] next field   [enter] save   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let rows = Layout::vertical([Constraint::Length(3); 3]).split(inner);
+        let theme = &self.config.theme;
+        for (i, (field, row)) in self.fields.iter_mut().zip(rows.iter()).enumerate() {
+            let color = if i == self.focused {
+                theme.focus
+            } else {
+                theme.border
+            };
+            if let Some(block) = field.block().cloned() {
+                field.set_block(block.border_style(Style::default().fg(color)));
+            }
+            frame.render_widget(&*field, *row);
+        }
+        Ok(())
+    }
+}
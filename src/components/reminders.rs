@@ -0,0 +1,163 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    text::Line,
+    widgets::{self, Cell, Row, Table, TableState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::{Recurrence, Reminder},
+    privacy::Privacy,
+};
+
+/// Pending reminders across all applications, soonest first.
+pub struct RemindersPanel {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    reminders: Vec<(Reminder, String)>,
+    selected: TableState,
+    privacy: Privacy,
+}
+impl RemindersPanel {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            reminders: Vec::new(),
+            selected: TableState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn table(&self) -> Table<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        let rows = self.reminders.iter().map(|(reminder, company)| {
+            let repeat = match reminder.recurrence {
+                Recurrence::Once => String::new(),
+                recurrence => recurrence.to_string(),
+            };
+            Row::new([
+                Cell::new(reminder.due_at.clone()),
+                Cell::new(repeat).style(muted),
+                Cell::new(
+                    self.privacy
+                        .text(company, &format!("Company #{}", reminder.job_id)),
+                ),
+                Cell::new(reminder.message.clone()),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Length(16),
+                Constraint::Length(8),
+                Constraint::Length(20),
+                Constraint::Min(10),
+            ],
+        )
+        .header(
+            Row::new(["Due", "Repeats", "Company", "Message"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().bg(self.config.theme.selection_bg))
+    }
+}
+
+impl Component for RemindersPanel {
+    fn mode(&self) -> Mode {
+        Mode::Popup("reminders")
+    }
+    fn id(&self) -> String {
+        "Reminders".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::ReminderResults(reminders) => {
+                self.reminders = reminders;
+                let last = self.reminders.len().checked_sub(1);
+                self.selected
+                    .select(last.map(|last| self.selected.selected().unwrap_or(0).min(last)));
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            // dismissing only removes the reminder, so it isn't confirmed
+            KeyCode::Char('d') | KeyCode::Delete => {
+                let selected = self.selected.selected();
+                if let Some((reminder, _)) = selected.and_then(|i| self.reminders.get(i)) {
+                    return Ok(Some(Action::DeleteReminder(reminder.id)));
+                }
+            }
+            KeyCode::Char('e') => return Ok(Some(Action::ExportReminders)),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(70),
+            Constraint::Percentage(60),
+            &self.config.theme,
+        );
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .title("Reminders")
+            .title_bottom(
+                Line::from("[d] dismiss   [e] export to calendar (.ics)   [esc] close").centered(),
+            );
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.reminders.is_empty() {
+            frame.render_widget(
+                Line::styled(
+                    "No pending reminders. Press m on an application to add one.",
+                    Style::default().fg(self.config.theme.muted),
+                ),
+                inner,
+            );
+            return Ok(());
+        }
+        let table = self.table();
+        frame.render_stateful_widget(table, inner, &mut self.selected);
+        Ok(())
+    }
+}
@@ -0,0 +1,315 @@
+//! Snapshots of what components draw, on a `TestBackend` at a few terminal sizes, so layout
+//! changes show up in review. Only the symbols drawn are compared, not their colours. After
+//! a deliberate change to a layout, accept the new snapshots with `cargo insta review`.
+
+use insta::assert_snapshot;
+use ratatui::{
+    Terminal,
+    backend::TestBackend,
+    layout::{Rect, Size},
+};
+use tokio::sync::mpsc::{self, UnboundedReceiver};
+
+use crate::{
+    action::Action,
+    components::{
+        component::Component,
+        confirm_popup::ConfirmPopup,
+        edit_job::EditJob,
+        error_popup::ErrorPopup,
+        job_detail::JobDetail,
+        job_item::{FocusedField, JobItem, JobListingState},
+        job_list::JobList,
+        question_form::QuestionForm,
+    },
+    config::{Config, ConfirmationLevel},
+    database::{
+        schema::{ApplicationStatus, JobApplication, LocationType, Question},
+        worker::{DbRequest, DbResponse},
+    },
+};
+
+// a cramped terminal, the classic one and a roomy one
+const SIZES: [(u16, u16); 3] = [(60, 20), (80, 24), (140, 40)];
+
+fn job(id: i32, company: &str, position: &str) -> JobApplication {
+    JobApplication {
+        id,
+        company_name: company.into(),
+        position: position.into(),
+        location: "London".into(),
+        location_type: LocationType::Hybrid,
+        application_date: "2024-03-01".into(),
+        notes: Some("Referred by Sam. Follow up after the [[Globex]] interview.".into()),
+        url: Some("https://example.com/jobs/1".into()),
+        salary_min: Some(50_000),
+        salary_max: Some(65_000),
+        currency: Some("GBP".into()),
+        rating: Some(4),
+        ..JobApplication::default()
+    }
+}
+
+fn long_job() -> JobApplication {
+    JobApplication {
+        status: ApplicationStatus::TechnicalInterview,
+        location: "Remote, anywhere within three hours of Greenwich Mean Time".into(),
+        notes: Some(
+            [
+                "First line of notes that goes on far longer than any card has room for, ",
+                "to see where it's cut off.\n",
+                "A second line.\nA third line.\nA fourth line.\nA fifth line.",
+            ]
+            .concat(),
+        ),
+        ..job(
+            2,
+            "Extremely Long Company Name Holdings International Limited",
+            "Senior Principal Staff Distributed Systems Reliability Engineer",
+        )
+    }
+}
+
+fn jobs() -> Vec<JobApplication> {
+    vec![
+        job(1, "Acme", "Backend Engineer"),
+        long_job(),
+        JobApplication {
+            status: ApplicationStatus::Rejected,
+            is_active: false,
+            notes: None,
+            rating: None,
+            ..job(3, "Initech", "Designer")
+        },
+    ]
+}
+
+fn render(width: u16, height: u16, draw: impl FnOnce(&mut ratatui::Frame)) -> String {
+    let mut terminal = Terminal::new(TestBackend::new(width, height)).unwrap();
+    terminal.draw(draw).unwrap();
+    terminal.backend().to_string()
+}
+
+fn draw_component(component: &mut dyn Component, width: u16, height: u16) -> String {
+    render(width, height, |frame| {
+        component.draw(frame, frame.area()).unwrap();
+    })
+}
+
+/// Answer every page the list asks for with `jobs`, as the database task would.
+fn answer_pages(list: &mut JobList, rx: &mut UnboundedReceiver<Action>, jobs: &[JobApplication]) {
+    while let Ok(action) = rx.try_recv() {
+        if let Action::DbRequest(req_id, DbRequest::JobPage(..)) = action {
+            let page = DbResponse::JobPage(0, jobs.len(), jobs.to_vec());
+            list.update(Action::DbResponse(req_id, page)).unwrap();
+        }
+    }
+}
+
+fn draw_job_list(jobs: &[JobApplication], width: u16, height: u16) -> String {
+    let (tx, mut rx) = mpsc::unbounded_channel();
+    let mut list = JobList::new();
+    list.register_action_handler(tx).unwrap();
+    list.register_config_handler(Config::default()).unwrap();
+    list.init(Size::new(width, height)).unwrap();
+    answer_pages(&mut list, &mut rx, jobs);
+    // the first draw finds out how many cards fit, which can ask for another page
+    draw_component(&mut list, width, height);
+    answer_pages(&mut list, &mut rx, jobs);
+    draw_component(&mut list, width, height)
+}
+
+fn draw_job_item(job: JobApplication, state: &mut JobListingState, width: u16) -> String {
+    render(width, 10, |frame| {
+        frame.render_stateful_widget(JobItem::new(job), Rect::new(0, 0, width, 10), state);
+    })
+}
+
+#[test]
+fn job_item_states() {
+    for width in [80, 140] {
+        let mut unfocused = JobListingState {
+            focused: false,
+            focused_field: FocusedField::None,
+            stale: false,
+            closed: false,
+            notes_scroll: 0,
+            badges: Vec::new(),
+        };
+        assert_snapshot!(
+            format!("job_item_unfocused_{width}"),
+            draw_job_item(jobs().remove(0), &mut unfocused, width)
+        );
+        let mut focused = JobListingState {
+            focused: true,
+            focused_field: FocusedField::Notes,
+            stale: true,
+            closed: true,
+            notes_scroll: 0,
+            badges: Vec::new(),
+        };
+        assert_snapshot!(
+            format!("job_item_focused_{width}"),
+            draw_job_item(jobs().remove(0), &mut focused, width)
+        );
+        assert_snapshot!(
+            format!("job_item_long_text_{width}"),
+            draw_job_item(long_job(), &mut focused, width)
+        );
+        // scrolled further than the notes go, which stops at their last line
+        let mut scrolled = JobListingState {
+            notes_scroll: 20,
+            ..focused.clone()
+        };
+        assert_snapshot!(
+            format!("job_item_notes_scrolled_{width}"),
+            draw_job_item(long_job(), &mut scrolled, width)
+        );
+        // words too long to share a row wrap onto more rows than their width alone needs
+        let long_words = JobApplication {
+            notes: Some("Onboarding documentation ".repeat(12)),
+            ..job(4, "Globex", "Platform Engineer")
+        };
+        let mut scrolled = JobListingState {
+            notes_scroll: 40,
+            ..focused.clone()
+        };
+        assert_snapshot!(
+            format!("job_item_notes_long_words_{width}"),
+            draw_job_item(long_words, &mut scrolled, width)
+        );
+        assert_snapshot!(
+            format!("job_item_empty_{width}"),
+            draw_job_item(JobApplication::default(), &mut unfocused, width)
+        );
+    }
+}
+
+#[test]
+fn job_list() {
+    for (width, height) in SIZES {
+        assert_snapshot!(
+            format!("job_list_{width}x{height}"),
+            draw_job_list(&jobs(), width, height)
+        );
+        assert_snapshot!(
+            format!("job_list_empty_{width}x{height}"),
+            draw_job_list(&[], width, height)
+        );
+    }
+}
+
+// salaries and compensation are masked along with companies, links and contacts
+#[test]
+fn privacy_mode() {
+    let job = JobApplication {
+        compensation_notes: Some("Bonus of 10%, equity".into()),
+        ..job(1, "Acme", "Backend Engineer")
+    };
+    for (width, height) in SIZES {
+        let (tx, mut rx) = mpsc::unbounded_channel();
+        let mut list = JobList::new();
+        list.register_action_handler(tx.clone()).unwrap();
+        list.register_config_handler(Config::default()).unwrap();
+        list.update(Action::SetPrivacy(true)).unwrap();
+        list.init(Size::new(width, height)).unwrap();
+        answer_pages(&mut list, &mut rx, std::slice::from_ref(&job));
+        draw_component(&mut list, width, height);
+        answer_pages(&mut list, &mut rx, std::slice::from_ref(&job));
+        assert_snapshot!(
+            format!("job_list_private_{width}x{height}"),
+            draw_component(&mut list, width, height)
+        );
+
+        let mut detail = JobDetail::new();
+        detail.register_action_handler(tx).unwrap();
+        detail.register_config_handler(Config::default()).unwrap();
+        detail.update(Action::SetPrivacy(true)).unwrap();
+        detail
+            .update(Action::ShowJobDetail(job.clone(), Vec::new()))
+            .unwrap();
+        assert_snapshot!(
+            format!("job_detail_private_{width}x{height}"),
+            draw_component(&mut detail, width, height)
+        );
+    }
+}
+
+#[test]
+fn edit_job() {
+    for (width, height) in SIZES {
+        let mut form = EditJob::new();
+        assert_snapshot!(
+            format!("edit_job_new_{width}x{height}"),
+            draw_component(&mut form, width, height)
+        );
+        form.update(Action::PopulateEditJobForm(long_job()))
+            .unwrap();
+        form.update(Action::FocusNext).unwrap();
+        assert_snapshot!(
+            format!("edit_job_long_text_{width}x{height}"),
+            draw_component(&mut form, width, height)
+        );
+    }
+}
+
+#[test]
+fn popups() {
+    for (width, height) in SIZES {
+        let mut confirm = ConfirmPopup::new();
+        confirm
+            .update(Action::ShowConfirmation(
+                ConfirmationLevel::Confirm,
+                "Delete application".into(),
+                "Delete Acme - Backend Engineer? This can't be undone.".into(),
+                Box::new(Action::Quit),
+            ))
+            .unwrap();
+        assert_snapshot!(
+            format!("confirm_popup_{width}x{height}"),
+            draw_component(&mut confirm, width, height)
+        );
+
+        let mut error = ErrorPopup::new();
+        error
+            .update(Action::Error(
+                "Failed to save application: disk full".into(),
+            ))
+            .unwrap();
+        assert_snapshot!(
+            format!("error_popup_{width}x{height}"),
+            draw_component(&mut error, width, height)
+        );
+        error
+            .update(Action::Error(
+                "Failed to read from the database: no such table: questions. ".repeat(4),
+            ))
+            .unwrap();
+        error
+            .handle_key_event(crossterm::event::KeyCode::Enter.into())
+            .unwrap();
+        assert_snapshot!(
+            format!("error_popup_long_text_{width}x{height}"),
+            draw_component(&mut error, width, height)
+        );
+
+        let mut question = QuestionForm::new();
+        question
+            .update(Action::PopulateQuestionForm(
+                Question {
+                    id: 1,
+                    job_id: 1,
+                    question: "Design a rate limiter for a public API".into(),
+                    topic: "system design".into(),
+                    answer: "Token bucket per key.\nMention Redis and clock skew.".into(),
+                    asked_at: "2024-03-04".into(),
+                },
+                "Acme".into(),
+            ))
+            .unwrap();
+        assert_snapshot!(
+            format!("question_form_{width}x{height}"),
+            draw_component(&mut question, width, height)
+        );
+    }
+}
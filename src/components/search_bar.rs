@@ -0,0 +1,194 @@
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    state::State,
+};
+
+// queries remembered between runs
+const HISTORY_LENGTH: usize = 100;
+
+/// A one-line query for the job list, with shell-like editing: Up/Down step through earlier
+/// queries, which are kept in the state file, and Ctrl+U/Ctrl+W cut back to the start of the
+/// line or the previous word, which Ctrl+Y pastes back.
+pub struct SearchBar<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    input: TextArea<'a>,
+    // the query the list is currently filtered by, restored when the bar is cancelled
+    applied: String,
+    history: Vec<String>,
+    // position in `history` while stepping through it, and what was typed before that
+    browsing: Option<usize>,
+    draft: String,
+}
+impl SearchBar<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            input: Self::create_input(""),
+            applied: String::new(),
+            history: Vec::new(),
+            browsing: None,
+            draft: String::new(),
+        }
+    }
+
+    fn create_input<'a>(text: &str) -> TextArea<'a> {
+        let mut input = TextArea::from([text.to_string()]);
+        input.set_cursor_line_style(Style::default());
+        input.move_cursor(CursorMove::End);
+        input
+    }
+
+    fn text(&self) -> String {
+        self.input.lines().join(" ")
+    }
+
+    fn show_history_entry(&mut self, browsing: Option<usize>) {
+        let text = match browsing {
+            Some(idx) => self.history[idx].clone(),
+            None => std::mem::take(&mut self.draft),
+        };
+        self.browsing = browsing;
+        self.input = Self::create_input(&text);
+    }
+
+    fn older(&mut self) {
+        let idx = match self.browsing {
+            Some(0) => return,
+            Some(idx) => idx - 1,
+            None if self.history.is_empty() => return,
+            None => {
+                self.draft = self.text();
+                self.history.len() - 1
+            }
+        };
+        self.show_history_entry(Some(idx));
+    }
+
+    fn newer(&mut self) {
+        if let Some(idx) = self.browsing {
+            self.show_history_entry((idx + 1 < self.history.len()).then_some(idx + 1));
+        }
+    }
+
+    /// Move `query` to the end of the history and save it, keeping the newest entries.
+    fn remember(&mut self, query: &str) -> color_eyre::eyre::Result<()> {
+        self.history.retain(|entry| entry != query);
+        self.history.push(query.to_string());
+        let excess = self.history.len().saturating_sub(HISTORY_LENGTH);
+        self.history.drain(..excess);
+
+        let data_dir = &self.config.config.data_dir;
+        let mut state = State::load(data_dir);
+        state.search_history = self.history.clone();
+        state.save(data_dir)
+    }
+}
+
+impl Component for SearchBar<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("search")
+    }
+    fn id(&self) -> String {
+        "Search".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.history = State::load(&config.config.data_dir).search_history;
+        self.config = config;
+        Ok(())
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = self.command_tx.clone() else {
+            return Ok(None);
+        };
+        let ctrl = key.modifiers.contains(KeyModifiers::CONTROL);
+        match key.code {
+            KeyCode::Esc => {
+                self.browsing = None;
+                self.input = Self::create_input(&self.applied);
+                tx.send(Action::ExitPopup)?;
+            }
+            KeyCode::Enter => {
+                let query = self.text().trim().to_string();
+                self.browsing = None;
+                self.input = Self::create_input(&query);
+                self.applied = query.clone();
+                if !query.is_empty()
+                    && let Err(err) = self.remember(&query)
+                {
+                    tx.send(Action::Error(format!(
+                        "Failed to save search history: {err}"
+                    )))?;
+                }
+                tx.send(Action::ExitPopup)?;
+                tx.send(Action::ApplySearch(query))?;
+            }
+            KeyCode::Up => self.older(),
+            KeyCode::Down => self.newer(),
+            KeyCode::Char('u') if ctrl => {
+                self.input.delete_line_by_head();
+            }
+            KeyCode::Char('w') if ctrl => {
+                self.input.delete_word();
+            }
+            KeyCode::Char('z') if ctrl => {
+                self.input.undo();
+            }
+            _ => {
+                self.input.input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        // just below the job list's title, so the results stay in view
+        let [_, area] =
+            Layout::vertical([Constraint::Length(1), Constraint::Length(3)]).areas(area);
+        let [area] = Layout::horizontal([Constraint::Percentage(60)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        util::popup(frame, area, &self.config.theme);
+        let theme = &self.config.theme;
+        let title = match self.browsing {
+            Some(idx) => format!("Search ({}/{})", idx + 1, self.history.len()),
+            None => "Search".to_string(),
+        };
+        self.input.set_block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.focus))
+                .padding(Padding::horizontal(1))
+                .title(title)
+                .title_bottom(
+                    Line::from("[enter] search   [↑/↓] history   [esc] cancel").centered(),
+                ),
+        );
+        frame.render_widget(&self.input, area);
+        Ok(())
+    }
+}
@@ -0,0 +1,157 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::Style,
+    text::{Line, Span},
+    widgets::{self, List, ListItem, ListState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    spelling,
+};
+
+/// Suggestions for a misspelled word, to replace it with or to add it to the dictionary. The
+/// first nine can be picked by number.
+pub struct SpellingPopup {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    word: String,
+    suggestions: Vec<String>,
+    selected: ListState,
+}
+impl SpellingPopup {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            word: String::new(),
+            suggestions: Vec::new(),
+            selected: ListState::default(),
+        }
+    }
+
+    fn pick(&mut self, index: usize) -> color_eyre::eyre::Result<()> {
+        let Some(replacement) = self.suggestions.get(index).cloned() else {
+            return Ok(());
+        };
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::ExitPopup)?;
+            tx.send(Action::ReplaceWord(self.word.clone(), replacement))?;
+        }
+        Ok(())
+    }
+}
+
+impl Component for SpellingPopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("spelling")
+    }
+    fn id(&self) -> String {
+        "Spelling Popup".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::ShowSpelling(word, suggestions) = action {
+            self.selected.select((!suggestions.is_empty()).then_some(0));
+            self.word = word;
+            self.suggestions = suggestions;
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Enter => {
+                if let Some(index) = self.selected.selected() {
+                    self.pick(index)?;
+                }
+            }
+            KeyCode::Char(digit @ '1'..='9') => self.pick(digit as usize - '1' as usize)?,
+            KeyCode::Char('a') => {
+                let result = match spelling::remember_word(&self.word, &self.config) {
+                    Ok(()) => Action::Notify(format!("Added \"{}\" to the dictionary", self.word)),
+                    Err(err) => Action::Error(format!("Failed to add \"{}\": {err}", self.word)),
+                };
+                if let Some(tx) = &self.command_tx {
+                    tx.send(Action::ExitPopup)?;
+                    if matches!(result, Action::Notify(_)) {
+                        tx.send(Action::WordAdded(self.word.clone()))?;
+                    }
+                    tx.send(result)?;
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let rows = self.suggestions.len().max(1) as u16;
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(48),
+            Constraint::Length(rows + 2),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title(format!("Spelling of \"{}\"", self.word))
+            .title_bottom(
+                Line::from("[1-9] or [enter] replace   [a] add   [esc] cancel").centered(),
+            );
+        let items = if self.suggestions.is_empty() {
+            vec![ListItem::new(Line::styled(
+                "No suggestions",
+                Style::default().fg(theme.muted),
+            ))]
+        } else {
+            self.suggestions
+                .iter()
+                .enumerate()
+                .map(|(index, suggestion)| {
+                    let number = if index < 9 {
+                        format!("{} ", index + 1)
+                    } else {
+                        "  ".to_string()
+                    };
+                    ListItem::new(Line::from(vec![
+                        Span::styled(number, Style::default().fg(theme.muted)),
+                        Span::raw(suggestion.as_str()),
+                    ]))
+                })
+                .collect()
+        };
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(theme.selection_bg));
+        frame.render_stateful_widget(list, area, &mut self.selected);
+        Ok(())
+    }
+}
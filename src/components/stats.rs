@@ -0,0 +1,472 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Direction, Layout, Margin},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{self, Bar, BarChart, BarGroup, Cell, Row, Sparkline, Table, TableState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    analytics::Insights,
+    app::Mode,
+    components::component::Component,
+    config::{Config, DestructiveAction},
+    database::{
+        schema::ApplicationStatus,
+        worker::{DbRequest, DbResponse, RequestId},
+    },
+    privacy::Privacy,
+    stats::{Stats, format_amount},
+};
+
+/// Dashboard of application figures and job-search expenses.
+pub struct StatsView {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    stats: Stats,
+    selected: TableState,
+    privacy: Privacy,
+    pending: Option<RequestId>,
+    // whether the stats on their way were asked for by a refresh, to say once they're in
+    refreshing: bool,
+    // the profiles compared, by name, while they're shown instead of the charts
+    comparison: Option<Vec<(String, Result<Stats, String>)>>,
+}
+impl StatsView {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            stats: Stats::default(),
+            selected: TableState::default(),
+            privacy: Privacy::default(),
+            pending: None,
+            refreshing: false,
+            comparison: None,
+        }
+    }
+
+    fn request(&mut self) -> Action {
+        let req_id = RequestId::next();
+        self.pending = Some(req_id);
+        let request = match self.comparison {
+            Some(_) => DbRequest::CompareProfiles(
+                self.config
+                    .profiles
+                    .iter()
+                    .map(|(name, path)| (name.clone(), path.clone()))
+                    .collect(),
+            ),
+            None => DbRequest::Stats,
+        };
+        Action::DbRequest(req_id, request)
+    }
+
+    fn profiles(&self) -> Table<'static> {
+        let theme = &self.config.theme;
+        let muted = Style::default().fg(theme.muted);
+        let percent = |rate: Option<f64>| {
+            rate.map_or("–".to_string(), |rate| format!("{:.0}%", rate * 100.0))
+        };
+        let rows = self
+            .comparison
+            .iter()
+            .flatten()
+            .map(|(name, stats)| match stats {
+                Ok(stats) => Row::new([
+                    Cell::new(name.clone()),
+                    Cell::new(
+                        stats
+                            .weekly_applications
+                            .first()
+                            .map_or(String::new(), |(week, _)| week.clone()),
+                    )
+                    .style(muted),
+                    Cell::new(stats.total_applications().to_string()),
+                    Cell::new(percent(stats.response_rate())),
+                    Cell::new(
+                        (stats.count(&ApplicationStatus::Offered)
+                            + stats.count(&ApplicationStatus::Accepted))
+                        .to_string(),
+                    ),
+                    Cell::new(percent(stats.offer_rate())),
+                    Cell::new(
+                        stats
+                            .average_days_to_rejection
+                            .map_or("–".to_string(), |days| format!("{days} days")),
+                    ),
+                ]),
+                Err(error) => Row::new([
+                    Cell::new(name.clone()),
+                    Cell::new(error.clone()).style(Style::default().fg(theme.error)),
+                ]),
+            });
+        Table::new(
+            rows,
+            [
+                Constraint::Min(12),
+                Constraint::Length(11),
+                Constraint::Length(12),
+                Constraint::Length(13),
+                Constraint::Length(6),
+                Constraint::Length(10),
+                Constraint::Length(17),
+            ],
+        )
+        .header(
+            Row::new([
+                "Profile",
+                "Since",
+                "Applications",
+                "Response rate",
+                "Offers",
+                "Offer rate",
+                "Avg. to rejection",
+            ])
+            .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title("Profiles"),
+        )
+    }
+
+    fn summary(&self) -> Line<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        let response_rate = self
+            .stats
+            .response_rate()
+            .map_or("–".to_string(), |rate| format!("{:.0}%", rate * 100.0));
+        let rejection = self
+            .stats
+            .average_days_to_rejection
+            .map_or("–".to_string(), |days| format!("{days} days"));
+        Line::from(vec![
+            Span::styled("Applications ", muted),
+            Span::raw(self.stats.total_applications().to_string()),
+            Span::styled("   Response rate ", muted),
+            Span::raw(response_rate),
+            Span::styled("   Avg. time to rejection ", muted),
+            Span::raw(rejection),
+        ])
+    }
+
+    fn activity_by_weekday(&self) -> BarChart<'static> {
+        let insights = &self.stats.insights;
+        let bars = Insights::WEEKDAYS
+            .iter()
+            .zip(insights.by_weekday)
+            .map(|(day, count)| Bar::default().label(Line::from(*day)).value(count as u64))
+            .collect::<Vec<_>>();
+        let busiest = insights
+            .busiest_weekday()
+            .map_or(String::new(), |day| format!("busiest {day}"));
+        BarChart::default()
+            .bar_width(3)
+            .bar_gap(1)
+            .bar_style(Style::default().fg(self.config.theme.focus))
+            .data(BarGroup::default().bars(&bars))
+            .block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .title("Activity by weekday")
+                    .title_bottom(Line::from(busiest).right_aligned()),
+            )
+    }
+
+    fn most_edited(&self) -> Table<'static> {
+        let insights = &self.stats.insights;
+        let muted = Style::default().fg(self.config.theme.muted);
+        let rows = insights
+            .most_edited
+            .iter()
+            .map(|(id, company, position, edits)| {
+                Row::new([
+                    Cell::new(edits.to_string()),
+                    Cell::new(position.clone()),
+                    Cell::new(self.privacy.text(company, &format!("Company #{id}"))),
+                ])
+            });
+        let average = insights
+            .average_edits_per_job()
+            .map_or("–".to_string(), |edits| format!("{edits:.1}"));
+        let busiest = insights
+            .busiest_days
+            .iter()
+            .map(|(day, count)| format!("{day} ({count})"))
+            .collect::<Vec<_>>()
+            .join("  ");
+        Table::new(
+            rows,
+            [
+                Constraint::Length(6),
+                Constraint::Min(10),
+                Constraint::Min(10),
+            ],
+        )
+        .header(
+            Row::new(["Edits", "Position", "Company"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .block(
+            widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title("Most edited")
+                .title_top(Line::from(format!("avg. {average} edits per job")).right_aligned())
+                .title_bottom(
+                    Line::from(vec![
+                        Span::styled("busiest days  ", muted),
+                        Span::raw(busiest),
+                    ])
+                    .right_aligned(),
+                ),
+        )
+    }
+
+    fn status_counts(&self) -> BarChart<'static> {
+        let bars = ApplicationStatus::all()
+            .iter()
+            .map(|status| {
+                Bar::default()
+                    .label(Line::from(status.to_string()))
+                    .value(self.stats.count(status) as u64)
+            })
+            .collect::<Vec<_>>();
+        BarChart::default()
+            .direction(Direction::Horizontal)
+            .bar_width(1)
+            .bar_gap(0)
+            .bar_style(Style::default().fg(self.config.theme.focus))
+            .data(BarGroup::default().bars(&bars))
+            .block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .title("By status"),
+            )
+    }
+
+    fn monthly_expenses(&self) -> Table<'static> {
+        let heading = Style::default().add_modifier(Modifier::BOLD);
+        let rows = self
+            .stats
+            .monthly_expenses
+            .iter()
+            .map(|(month, cents)| Row::new([month.clone(), format_amount(*cents)]));
+        Table::new(rows, [Constraint::Length(10), Constraint::Min(10)])
+            .header(Row::new(["Month", "Spent"]).style(heading))
+            .footer(
+                Row::new([
+                    "Total".to_string(),
+                    format_amount(self.stats.total_expenses()),
+                ])
+                .style(heading),
+            )
+            .block(
+                widgets::Block::bordered()
+                    .border_type(widgets::BorderType::Rounded)
+                    .title("Expenses per month"),
+            )
+    }
+
+    fn recent_expenses(&self) -> Table<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        let rows = self.stats.expenses.iter().map(|expense| {
+            Row::new([
+                Cell::new(expense.date.clone()).style(muted),
+                Cell::new(expense.category.to_string()),
+                Cell::new(self.privacy.text(&expense.description, "Hidden")),
+                Cell::new(format_amount(expense.amount_cents)),
+            ])
+        });
+        Table::new(
+            rows,
+            [
+                Constraint::Length(10),
+                Constraint::Length(14),
+                Constraint::Min(10),
+                Constraint::Length(10),
+            ],
+        )
+        .header(
+            Row::new(["Date", "Category", "Description", "Amount"])
+                .style(Style::default().add_modifier(Modifier::BOLD)),
+        )
+        .row_highlight_style(Style::default().bg(self.config.theme.selection_bg))
+        .block(
+            widgets::Block::bordered()
+                .border_type(widgets::BorderType::Rounded)
+                .title("Expenses"),
+        )
+    }
+}
+
+impl Component for StatsView {
+    fn mode(&self) -> Mode {
+        Mode::Stats
+    }
+    fn id(&self) -> String {
+        "Stats".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy, Subscription::Db]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::DispatchStats | Action::Refresh => {
+                self.refreshing = action == Action::Refresh;
+                return Ok(Some(self.request()));
+            }
+            Action::CompareProfiles => {
+                if self.comparison.take().is_none() {
+                    if self.config.profiles.is_empty() {
+                        return Ok(Some(Action::Notify(
+                            "No profiles to compare with, add some under \"profiles\" in the config"
+                                .into(),
+                        )));
+                    }
+                    self.comparison = Some(Vec::new());
+                }
+                return Ok(Some(self.request()));
+            }
+            Action::DbResponse(req_id, DbResponse::ProfileComparison(profiles))
+                if self.pending == Some(req_id) =>
+            {
+                self.pending = None;
+                self.comparison = Some(profiles);
+                if std::mem::take(&mut self.refreshing) {
+                    return Ok(Some(Action::Notify("Refreshed".into())));
+                }
+            }
+            Action::DbResponse(req_id, DbResponse::Stats(stats))
+                if self.pending == Some(req_id) =>
+            {
+                self.pending = None;
+                self.stats = stats;
+                let last = self.stats.expenses.len().checked_sub(1);
+                self.selected
+                    .select(last.map(|last| self.selected.selected().unwrap_or(0).min(last)));
+                if std::mem::take(&mut self.refreshing) {
+                    return Ok(Some(Action::Notify("Refreshed".into())));
+                }
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            Action::IndexNext => self.selected.select_next(),
+            Action::IndexPrevious => self.selected.select_previous(),
+            Action::Delete => {
+                let selected = self.selected.selected();
+                if let Some(expense) = selected.and_then(|i| self.stats.expenses.get(i)) {
+                    return Ok(Some(Action::ConfirmDestructive(
+                        DestructiveAction::Delete,
+                        Box::new(Action::DeleteExpense(expense.id)),
+                    )));
+                }
+            }
+            Action::NewExpense => return Ok(Some(Action::OpenExpenseForm(None))),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') if self.comparison.is_some() => {
+                Ok(Some(Action::CompareProfiles))
+            }
+            KeyCode::Esc | KeyCode::Char('q') => Ok(Some(Action::ChangeMode(Mode::Home))),
+            _ => Ok(None),
+        }
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let hints = match self.comparison {
+            Some(_) => "[p] or [esc] back to charts   [r] refresh",
+            None => "[a] add expense   [del] delete expense   [p] compare profiles   [esc] back",
+        };
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Thick)
+            .borders(widgets::Borders::TOP)
+            .title_top(Line::from("Stats").centered())
+            .title_bottom(Line::from(hints).centered());
+        let region = area.inner(Margin::new(2, 2));
+        let inner = block.inner(region);
+        frame.render_widget(block, region);
+        if self.comparison.is_some() {
+            frame.render_widget(self.profiles(), inner);
+            return Ok(());
+        }
+
+        let [summary, charts, activity, bottom] = Layout::vertical([
+            Constraint::Length(2),
+            Constraint::Length(8),
+            Constraint::Length(9),
+            Constraint::Min(6),
+        ])
+        .areas(inner);
+        frame.render_widget(self.summary(), summary);
+
+        let [by_status, per_week] =
+            Layout::horizontal([Constraint::Length(40), Constraint::Min(20)])
+                .spacing(2)
+                .areas(charts);
+        frame.render_widget(self.status_counts(), by_status);
+        let weekly_block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .title("Applications per week");
+        // keep the most recent weeks when there are more than fit
+        let width = weekly_block.inner(per_week).width as usize;
+        let weekly = &self.stats.weekly_applications;
+        let shown = &weekly[weekly.len().saturating_sub(width)..];
+        let data = shown
+            .iter()
+            .map(|(_, count)| *count as u64)
+            .collect::<Vec<_>>();
+        let weekly_block = match (shown.first(), shown.last()) {
+            (Some((first, _)), Some((last, _))) => {
+                weekly_block.title_bottom(Line::from(format!("{first} – {last}")).right_aligned())
+            }
+            _ => weekly_block,
+        };
+        frame.render_widget(
+            Sparkline::default()
+                .data(&data)
+                .style(Style::default().fg(self.config.theme.focus))
+                .block(weekly_block),
+            per_week,
+        );
+
+        let [by_weekday, most_edited] =
+            Layout::horizontal([Constraint::Length(40), Constraint::Min(20)])
+                .spacing(2)
+                .areas(activity);
+        frame.render_widget(self.activity_by_weekday(), by_weekday);
+        frame.render_widget(self.most_edited(), most_edited);
+
+        let [monthly, expenses] = Layout::horizontal([Constraint::Length(30), Constraint::Min(30)])
+            .spacing(2)
+            .areas(bottom);
+        frame.render_widget(self.monthly_expenses(), monthly);
+        let table = self.recent_expenses();
+        frame.render_stateful_widget(table, expenses, &mut self.selected);
+        Ok(())
+    }
+}
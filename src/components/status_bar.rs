@@ -0,0 +1,135 @@
+use std::time::{Duration, Instant};
+
+use ratatui::{
+    layout::{Constraint, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{self, Paragraph},
+};
+
+use crate::{action::Action, app::Mode, components::component::Component, config::Config};
+
+/// How long a message stays on screen.
+const MESSAGE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Actions worth a hint, in the order they are listed when bound in the current mode.
+const HINTS: [(Action, &str); 9] = [
+    (Action::Save, "save"),
+    (Action::NewJob, "new"),
+    (Action::ViewJob, "details"),
+    (Action::Delete, "delete"),
+    (Action::NewExpense, "expense"),
+    (Action::ShowStats, "stats"),
+    (Action::Export, "export"),
+    (Action::Help, "help"),
+    (Action::Quit, "quit"),
+];
+
+/// Bar along the bottom of the screen showing the current mode, then either the latest
+/// notification or warning, or a summary of the keys available. Errors get the error popup
+/// instead.
+pub struct StatusBar {
+    config: Config,
+    mode: Mode,
+    // the latest message, whether it's a warning, and when it was shown
+    message: Option<(String, bool, Instant)>,
+    // a message timed out since the last frame
+    redraw: bool,
+}
+impl StatusBar {
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            mode: Mode::default(),
+            message: None,
+            redraw: false,
+        }
+    }
+
+    fn mode_name(&self) -> String {
+        match self.mode {
+            Mode::Popup(name) => name.replace('_', " "),
+            mode => format!("{mode:?}"),
+        }
+    }
+
+    fn key_hints(&self) -> Vec<Span<'static>> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        HINTS
+            .iter()
+            .filter_map(|(action, label)| {
+                let keys = self.config.keybindings.keys_for(self.mode, action)?;
+                Some([
+                    Span::raw(format!("  {keys} ")),
+                    Span::styled(label.to_string(), muted),
+                ])
+            })
+            .flatten()
+            .collect()
+    }
+}
+
+impl Component for StatusBar {
+    fn mode(&self) -> Mode {
+        Mode::Global
+    }
+    fn id(&self) -> String {
+        "Status Bar".into()
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::ModeChanged(mode) => self.mode = mode,
+            Action::Notify(message) => self.message = Some((message, false, Instant::now())),
+            Action::Warning(message) => self.message = Some((message, true, Instant::now())),
+            Action::Tick
+                if self
+                    .message
+                    .as_ref()
+                    .is_some_and(|(_, _, shown)| shown.elapsed() > MESSAGE_TIMEOUT) =>
+            {
+                self.message = None;
+                self.redraw = true;
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn take_redraw(&mut self) -> bool {
+        std::mem::take(&mut self.redraw)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let theme = &self.config.theme;
+        let [_, bar] = Layout::vertical([Constraint::Min(0), Constraint::Length(1)]).areas(area);
+
+        let mut spans = vec![Span::styled(
+            format!(" {} ", self.mode_name().to_uppercase()),
+            Style::default()
+                .fg(theme.background)
+                .bg(theme.focus)
+                .add_modifier(Modifier::BOLD),
+        )];
+        match &self.message {
+            Some((message, false, _)) => spans.push(Span::styled(
+                format!(" {message}"),
+                Style::default().fg(theme.text),
+            )),
+            Some((message, true, _)) => spans.push(Span::styled(
+                format!(" ⚠ {message}"),
+                Style::default().fg(theme.warning),
+            )),
+            None => spans.extend(self.key_hints()),
+        }
+
+        frame.render_widget(widgets::Clear, bar);
+        frame.render_widget(Paragraph::new(Line::from(spans)), bar);
+        Ok(())
+    }
+}
@@ -0,0 +1,132 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::TextArea;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::JobApplication,
+    privacy::Privacy,
+};
+
+/// Asks for a line about a status change before the application is saved, e.g. "recruiter said
+/// 2-week delay". Leaving it empty saves without one.
+pub struct StatusNotePopup<'a> {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: Option<JobApplication>,
+    input: TextArea<'a>,
+    privacy: Privacy,
+}
+impl StatusNotePopup<'_> {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: None,
+            input: TextArea::default(),
+            privacy: Privacy::default(),
+        }
+    }
+}
+
+impl Component for StatusNotePopup<'_> {
+    fn mode(&self) -> Mode {
+        Mode::Popup("status_note")
+    }
+    fn id(&self) -> String {
+        "Status Note".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::PromptStatusNote(job) => {
+                self.input = TextArea::default();
+                self.input.set_cursor_line_style(Style::default());
+                self.job = Some(job);
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            // back to the form, which still has the edits
+            KeyCode::Esc => tx.send(Action::ExitPopup)?,
+            KeyCode::Enter => {
+                let Some(job) = self.job.take() else {
+                    return Ok(None);
+                };
+                let note = self.input.lines().join(" ").trim().to_string();
+                tx.send(Action::ExitPopup)?;
+                tx.send(if note.is_empty() {
+                    Action::SaveJob(job)
+                } else {
+                    Action::SaveStatusChange(job, note)
+                })?;
+                tx.send(Action::ChangeMode(Mode::Home))?;
+            }
+            _ => {
+                self.input.input(key);
+            }
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(60),
+            Constraint::Length(3),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let title = match &self.job {
+            Some(job) => format!("{} → {}", self.privacy.job(job).company_name, job.status),
+            None => "Status note".to_string(),
+        };
+        self.input.set_block(
+            Block::bordered()
+                .border_type(BorderType::Rounded)
+                .border_style(Style::default().fg(theme.focus))
+                .padding(Padding::horizontal(1))
+                .title(title)
+                .title_bottom(
+                    Line::from("[enter] save, with a note if given   [esc] back").centered(),
+                ),
+        );
+        self.input
+            .set_placeholder_text("Note about the change (optional)");
+        frame.render_widget(&self.input, area);
+        Ok(())
+    }
+}
@@ -0,0 +1,169 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::Constraint,
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{self, List, ListItem, ListState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::{ApplicationStatus, JobApplication},
+    privacy::Privacy,
+};
+
+/// The application moved to `status`, saved as it is otherwise, or a note when it's already
+/// there. Shared by the picker and the job list's number keys.
+pub fn change_status(job: &JobApplication, status: ApplicationStatus) -> Action {
+    if job.status == status {
+        return Action::Notify(format!("Already {status}"));
+    }
+    Action::SaveJob(JobApplication {
+        is_active: status.is_active(),
+        status,
+        ..job.clone()
+    })
+}
+
+/// Pick a new status for an application without going through the edit form. The first
+/// nine can be picked by number.
+pub struct StatusPicker {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: JobApplication,
+    statuses: Vec<ApplicationStatus>,
+    selected: ListState,
+    privacy: Privacy,
+}
+impl StatusPicker {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: JobApplication::default(),
+            statuses: Vec::new(),
+            selected: ListState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn pick(&mut self, index: usize) -> color_eyre::eyre::Result<()> {
+        let Some(status) = self.statuses.get(index).cloned() else {
+            return Ok(());
+        };
+        if let Some(tx) = &self.command_tx {
+            tx.send(Action::ExitPopup)?;
+            tx.send(change_status(&self.job, status))?;
+        }
+        Ok(())
+    }
+}
+
+impl Component for StatusPicker {
+    fn mode(&self) -> Mode {
+        Mode::Popup("status_picker")
+    }
+    fn id(&self) -> String {
+        "Status Picker".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::PickStatus(job) => {
+                // read each time, as statuses can be added while the app runs
+                self.statuses = ApplicationStatus::all();
+                let current = self
+                    .statuses
+                    .iter()
+                    .position(|status| *status == job.status);
+                self.selected.select(current.or(Some(0)));
+                self.job = job;
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ExitPopup)),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Enter => {
+                if let Some(index) = self.selected.selected() {
+                    self.pick(index)?;
+                }
+            }
+            KeyCode::Char(digit @ '1'..='9') => self.pick(digit as usize - '1' as usize)?,
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(40),
+            Constraint::Length(self.statuses.len() as u16 + 2),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let company = self.privacy.text(&self.job.company_name, "application");
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title(format!("Move {company} to"))
+            .title_bottom(Line::from("[1-9] or [enter] pick   [esc] cancel").centered());
+        let items = self
+            .statuses
+            .iter()
+            .enumerate()
+            .map(|(index, status)| {
+                let number = if index < 9 {
+                    format!("{} ", index + 1)
+                } else {
+                    "  ".to_string()
+                };
+                let mut style = self.config.list.status_style(status);
+                if *status == self.job.status {
+                    style = style.add_modifier(Modifier::BOLD);
+                }
+                ListItem::new(Line::from(vec![
+                    Span::styled(number, Style::default().fg(theme.muted)),
+                    Span::styled(status.to_string(), style),
+                ]))
+            })
+            .collect::<Vec<_>>();
+        let list = List::new(items)
+            .block(block)
+            .highlight_style(Style::default().bg(theme.selection_bg));
+        frame.render_stateful_widget(list, area, &mut self.selected);
+        Ok(())
+    }
+}
@@ -0,0 +1,279 @@
+use std::collections::HashMap;
+
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Rect},
+    style::{Modifier, Style},
+    text::{Line, Span},
+    widgets::{Block, BorderType, List, ListItem, ListState, Padding},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::{Config, DestructiveAction},
+};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum TagSort {
+    Usage,
+    Name,
+}
+
+// what the keys are doing: moving through the list, typing a new name, or picking the tag
+// to merge into
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Editing {
+    None,
+    Rename(String),
+    Merge(String),
+}
+
+/// Every tag in use with how many applications have it, for renaming, merging and deleting
+/// them. Tags that differ only in case or punctuation are grouped together, as they are
+/// usually the same tag typed two ways.
+pub struct TagBrowser {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    // in the order shown
+    tags: Vec<(String, u32)>,
+    selected: ListState,
+    sort: TagSort,
+    editing: Editing,
+}
+impl TagBrowser {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            tags: Vec::new(),
+            selected: ListState::default(),
+            sort: TagSort::Usage,
+            editing: Editing::None,
+        }
+    }
+
+    // what near-duplicate tags have in common, e.g. `Front-end` and `frontend`
+    fn group_key(tag: &str) -> String {
+        tag.chars()
+            .filter(|c| c.is_alphanumeric())
+            .flat_map(char::to_lowercase)
+            .collect()
+    }
+
+    /// Order the tags by the sort, keeping each group of similar tags together.
+    fn arrange(&mut self, tags: Vec<(String, u32)>) {
+        let mut groups = HashMap::<String, Vec<(String, u32)>>::new();
+        for (tag, count) in tags {
+            groups
+                .entry(Self::group_key(&tag))
+                .or_default()
+                .push((tag, count));
+        }
+        let mut groups = groups.into_values().collect::<Vec<_>>();
+        for group in &mut groups {
+            group.sort_by(|a, b| b.1.cmp(&a.1).then_with(|| a.0.cmp(&b.0)));
+        }
+        match self.sort {
+            TagSort::Usage => groups.sort_by_key(|group| {
+                let total = group.iter().map(|(_, count)| count).sum::<u32>();
+                (std::cmp::Reverse(total), group[0].0.to_lowercase())
+            }),
+            TagSort::Name => groups.sort_by_key(|group| group[0].0.to_lowercase()),
+        }
+        let selected = self.selected_tag().map(str::to_string);
+        self.tags = groups.into_iter().flatten().collect();
+        let idx = selected
+            .and_then(|selected| self.tags.iter().position(|(tag, _)| *tag == selected))
+            .or_else(|| {
+                let last = self.tags.len().checked_sub(1)?;
+                Some(self.selected.selected().unwrap_or(0).min(last))
+            });
+        self.selected.select(idx);
+    }
+
+    fn selected_tag(&self) -> Option<&str> {
+        self.selected
+            .selected()
+            .and_then(|idx| self.tags.get(idx))
+            .map(|(tag, _)| tag.as_str())
+    }
+
+    fn similar(&self, idx: usize) -> bool {
+        let key = Self::group_key(&self.tags[idx].0);
+        [idx.checked_sub(1), Some(idx + 1)]
+            .into_iter()
+            .flatten()
+            .filter_map(|other| self.tags.get(other))
+            .any(|(other, _)| Self::group_key(other) == key)
+    }
+
+    fn hint(&self) -> Line<'static> {
+        let muted = Style::default().fg(self.config.theme.muted);
+        match &self.editing {
+            Editing::None => Line::styled(
+                "[r] rename   [m] merge   [d] delete   [s] sort   [esc] close",
+                muted,
+            ),
+            Editing::Rename(name) => Line::from(vec![
+                Span::raw("New name: "),
+                Span::styled(name.clone(), Style::default().fg(self.config.theme.warning)),
+                Span::styled("   [enter] rename   [esc] cancel", muted),
+            ]),
+            Editing::Merge(tag) => Line::from(vec![
+                Span::raw(format!("Merge \"{tag}\" into the selected tag")),
+                Span::styled("   [enter] merge   [esc] cancel", muted),
+            ]),
+        }
+    }
+}
+
+impl Component for TagBrowser {
+    fn mode(&self) -> Mode {
+        Mode::Popup("tag_browser")
+    }
+    fn id(&self) -> String {
+        "Tag Browser".into()
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        if let Action::TagResults(tags) = action {
+            self.editing = Editing::None;
+            self.arrange(tags);
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let selected = self.selected_tag().map(str::to_string);
+        match (&mut self.editing, key.code) {
+            (Editing::None, KeyCode::Esc | KeyCode::Char('q')) => {
+                return Ok(Some(Action::ExitPopup));
+            }
+            (_, KeyCode::Esc) => self.editing = Editing::None,
+            (Editing::Rename(name), KeyCode::Enter) => {
+                let name = name.trim().to_string();
+                self.editing = Editing::None;
+                if let Some(tag) = selected.filter(|tag| !name.is_empty() && *tag != name) {
+                    return Ok(Some(Action::RenameTag(tag, name)));
+                }
+            }
+            (Editing::Rename(name), KeyCode::Backspace) => {
+                name.pop();
+            }
+            (Editing::Rename(name), KeyCode::Char(c)) => name.push(c),
+            (Editing::Rename(_), _) => {}
+            (Editing::Merge(from), KeyCode::Enter) => {
+                let from = from.clone();
+                if let Some(into) = selected.filter(|into| *into != from) {
+                    self.editing = Editing::None;
+                    return Ok(Some(Action::RenameTag(from, into)));
+                }
+            }
+            (_, KeyCode::Down | KeyCode::Char('j')) => self.selected.select_next(),
+            (_, KeyCode::Up | KeyCode::Char('k')) => self.selected.select_previous(),
+            (Editing::None, KeyCode::Char('r')) => {
+                if let Some(tag) = selected {
+                    self.editing = Editing::Rename(tag);
+                }
+            }
+            (Editing::None, KeyCode::Char('m')) => {
+                if let Some(tag) = selected {
+                    self.editing = Editing::Merge(tag);
+                }
+            }
+            (Editing::None, KeyCode::Char('d') | KeyCode::Delete) => {
+                return Ok(selected.map(|tag| {
+                    Action::ConfirmDestructive(
+                        DestructiveAction::DeleteTag,
+                        Box::new(Action::DeleteTag(tag)),
+                    )
+                }));
+            }
+            (Editing::None, KeyCode::Char('s')) => {
+                self.sort = match self.sort {
+                    TagSort::Usage => TagSort::Name,
+                    TagSort::Name => TagSort::Usage,
+                };
+                let tags = std::mem::take(&mut self.tags);
+                self.arrange(tags);
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Length(60),
+            Constraint::Percentage(70),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let sort = match self.sort {
+            TagSort::Usage => "by usage",
+            TagSort::Name => "by name",
+        };
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title(format!("Tags ({sort})"))
+            .title_bottom(self.hint().centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        if self.tags.is_empty() {
+            frame.render_widget(
+                Line::styled("No tags yet", Style::default().fg(theme.muted)),
+                inner,
+            );
+            return Ok(());
+        }
+        let merging = match &self.editing {
+            Editing::Merge(tag) => Some(tag.as_str()),
+            _ => None,
+        };
+        let items = (0..self.tags.len())
+            .map(|idx| {
+                let (tag, count) = &self.tags[idx];
+                let mut spans = vec![
+                    Span::raw(format!("{tag:<32}")),
+                    Span::styled(format!("{count:>5}"), Style::default().fg(theme.muted)),
+                ];
+                if self.similar(idx) {
+                    spans.push(Span::styled(
+                        "  ≈ similar",
+                        Style::default().fg(theme.warning),
+                    ));
+                }
+                let mut item = ListItem::new(Line::from(spans));
+                if merging == Some(tag.as_str()) {
+                    item = item.style(Style::default().add_modifier(Modifier::BOLD));
+                }
+                item
+            })
+            .collect::<Vec<_>>();
+        frame.render_stateful_widget(
+            List::new(items).highlight_style(Style::default().bg(theme.selection_bg)),
+            inner,
+            &mut self.selected,
+        );
+        Ok(())
+    }
+}
@@ -0,0 +1,163 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Rect},
+    style::Style,
+    text::Line,
+    widgets::{Block, BorderType, List, ListItem, ListState, Padding, Paragraph, Wrap},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+    database::schema::JobApplication,
+    privacy::Privacy,
+    templates,
+};
+
+/// Pick one of the configured templates and see it filled in for an application, to copy
+/// into an email.
+pub struct TemplatePopup {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    job: JobApplication,
+    selected: ListState,
+    privacy: Privacy,
+}
+impl TemplatePopup {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            job: JobApplication::default(),
+            selected: ListState::default(),
+            privacy: Privacy::default(),
+        }
+    }
+
+    fn template(&self) -> Option<&String> {
+        self.config
+            .templates
+            .values()
+            .nth(self.selected.selected()?)
+    }
+}
+
+impl Component for TemplatePopup {
+    fn mode(&self) -> Mode {
+        Mode::Popup("templates")
+    }
+    fn id(&self) -> String {
+        "Templates".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::PickTemplate(job) => {
+                self.job = job;
+                if self
+                    .selected
+                    .selected()
+                    .is_none_or(|i| i >= self.config.templates.len())
+                {
+                    self.selected.select(Some(0));
+                }
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        let Some(tx) = &self.command_tx else {
+            return Ok(None);
+        };
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => tx.send(Action::ExitPopup)?,
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Enter | KeyCode::Char('y') => {
+                if let Some(template) = self.template() {
+                    // copied as stored, whatever is masked on screen
+                    let text = templates::render(template, &self.job);
+                    tx.send(Action::ExitPopup)?;
+                    tx.send(Action::CopyToClipboard(text))?;
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(&mut self, frame: &mut ratatui::Frame, area: Rect) -> color_eyre::eyre::Result<()> {
+        let area = util::centered_popup(
+            frame,
+            area,
+            Constraint::Percentage(70),
+            Constraint::Percentage(60),
+            &self.config.theme,
+        );
+        let theme = &self.config.theme;
+        let job = self.privacy.job(&self.job);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title(format!("Templates for {}", job.company_name))
+            .title_bottom(Line::from("[enter] or [y] copy   [esc] cancel").centered());
+        let inner = block.inner(area);
+        frame.render_widget(block, area);
+
+        let [names, preview] = Layout::horizontal([Constraint::Length(24), Constraint::Min(20)])
+            .spacing(1)
+            .areas(inner);
+        let items = self
+            .config
+            .templates
+            .keys()
+            .map(|name| ListItem::new(name.clone()))
+            .collect::<Vec<_>>();
+        let list = List::new(items)
+            .block(
+                Block::bordered()
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border)),
+            )
+            .highlight_style(Style::default().bg(theme.selection_bg));
+        frame.render_stateful_widget(list, names, &mut self.selected);
+
+        let text = self
+            .template()
+            .map(|template| templates::render(template, &job))
+            .unwrap_or_default();
+        frame.render_widget(
+            Paragraph::new(text).wrap(Wrap { trim: false }).block(
+                Block::bordered()
+                    .border_type(BorderType::Rounded)
+                    .border_style(Style::default().fg(theme.border))
+                    .padding(Padding::horizontal(1))
+                    .title("Preview"),
+            ),
+            preview,
+        );
+        Ok(())
+    }
+}
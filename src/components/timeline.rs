@@ -0,0 +1,379 @@
+use crossterm::event::KeyCode;
+use ratatui::{
+    layout::{Constraint, Layout, Margin},
+    style::Style,
+    text::{Line, Span},
+    widgets::{self, List, ListItem, ListState},
+};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, Subscription},
+    app::Mode,
+    components::component::Component,
+    config::Config,
+    database::{
+        schema::{ApplicationStatus, JobApplication, StatusChange},
+        worker::{DbRequest, DbResponse, RequestId},
+    },
+    dates,
+    privacy::Privacy,
+};
+
+// columns taken by the company and position in front of each bar
+const LABEL_WIDTH: usize = 32;
+const MONTHS: [&str; 12] = [
+    "Jan", "Feb", "Mar", "Apr", "May", "Jun", "Jul", "Aug", "Sep", "Oct", "Nov", "Dec",
+];
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+enum Zoom {
+    Week,
+    Month,
+}
+impl Zoom {
+    fn days_per_column(self) -> i64 {
+        match self {
+            Zoom::Week => 1,
+            Zoom::Month => 4,
+        }
+    }
+
+    // how far one press of left or right scrolls
+    fn step(self) -> i64 {
+        match self {
+            Zoom::Week => 7,
+            Zoom::Month => 30,
+        }
+    }
+}
+
+/// One application's span, from the day it was applied for to today, or to its last status
+/// change once it's closed.
+struct Bar {
+    job: JobApplication,
+    start: i64,
+    end: i64,
+    // the day each status began, in order
+    segments: Vec<(i64, ApplicationStatus)>,
+}
+impl Bar {
+    fn new(job: JobApplication, history: &[StatusChange], today: i64) -> Option<Self> {
+        let segments = history
+            .iter()
+            .filter_map(|change| {
+                Some((
+                    dates::day_of(&dates::local_time(&change.changed_at))?,
+                    change.status.clone(),
+                ))
+            })
+            .collect::<Vec<_>>();
+        let first_change = segments.first().map(|(day, _)| *day);
+        let start = dates::day_of(&job.application_date)
+            .into_iter()
+            .chain(first_change)
+            .min()?;
+        let end = if job.is_active {
+            today
+        } else {
+            segments
+                .last()
+                .map(|(day, _)| *day)
+                .or_else(|| dates::day_of(&dates::local_time(job.last_updated.as_deref()?)))
+                .unwrap_or(start)
+        };
+        Some(Self {
+            job,
+            start,
+            end: end.max(start),
+            segments,
+        })
+    }
+
+    // days before the first recorded change show the status it started in
+    fn status_on(&self, day: i64) -> &ApplicationStatus {
+        self.segments
+            .iter()
+            .rev()
+            .find(|(start, _)| *start <= day)
+            .or(self.segments.first())
+            .map_or(&self.job.status, |(_, status)| status)
+    }
+}
+
+/// Every application as a bar across the calendar, coloured by the statuses it went through,
+/// for an overview of the whole search.
+pub struct TimelineView {
+    command_tx: Option<UnboundedSender<Action>>,
+    config: Config,
+    bars: Vec<Bar>,
+    show_closed: bool,
+    selected: ListState,
+    zoom: Zoom,
+    // days scrolled back from the view ending today
+    scroll: i64,
+    privacy: Privacy,
+    pending: Option<RequestId>,
+    // whether the timeline on its way was asked for by a refresh, to say once it's in
+    refreshing: bool,
+}
+impl TimelineView {
+    pub fn new() -> Self {
+        Self {
+            command_tx: None,
+            config: Config::default(),
+            bars: Vec::new(),
+            show_closed: false,
+            selected: ListState::default(),
+            zoom: Zoom::Week,
+            scroll: 0,
+            privacy: Privacy::default(),
+            pending: None,
+            refreshing: false,
+        }
+    }
+
+    fn shown(&self) -> Vec<&Bar> {
+        self.bars
+            .iter()
+            .filter(|bar| self.show_closed || bar.job.is_active)
+            .collect()
+    }
+
+    fn clamp_selection(&mut self) {
+        let last = self.shown().len().checked_sub(1);
+        self.selected
+            .select(last.map(|last| self.selected.selected().unwrap_or(0).min(last)));
+    }
+
+    fn label(&self, job: &JobApplication) -> String {
+        let company = self
+            .privacy
+            .text(&job.company_name, &format!("Company #{}", job.id));
+        let label = format!("{company} · {}", job.position);
+        let mut label = label.chars().take(LABEL_WIDTH - 2).collect::<String>();
+        label.push_str(&" ".repeat(LABEL_WIDTH - label.chars().count()));
+        label
+    }
+
+    // dates along the top: each Monday when zoomed to weeks, each month when zoomed out
+    fn scale(&self, first_day: i64, columns: usize) -> Line<'static> {
+        let per_column = self.zoom.days_per_column();
+        let mut scale = vec![' '; columns];
+        let mut free_from = 0;
+        for column in 0..columns {
+            let day = first_day + column as i64 * per_column;
+            let label = match self.zoom {
+                // 1970-01-01 was a Thursday
+                Zoom::Week if (day + 3).rem_euclid(7) == 0 => dates::date_of(day)[5..].to_string(),
+                Zoom::Month => (day..day + per_column)
+                    .map(dates::date_of)
+                    .find(|date| date.ends_with("-01"))
+                    .and_then(|date| date[5..7].parse::<usize>().ok())
+                    .map(|month| MONTHS[month - 1].to_string())
+                    .unwrap_or_default(),
+                _ => String::new(),
+            };
+            if label.is_empty() || column < free_from || column + label.len() >= columns {
+                continue;
+            }
+            // a tick over the column, with the label running on from it
+            scale[column] = '╵';
+            for (offset, c) in label.chars().enumerate() {
+                scale[column + 1 + offset] = c;
+            }
+            free_from = column + label.len() + 2;
+        }
+        Line::from(vec![
+            Span::raw(" ".repeat(LABEL_WIDTH)),
+            Span::styled(
+                scale.into_iter().collect::<String>(),
+                Style::default().fg(self.config.theme.muted),
+            ),
+        ])
+    }
+
+    fn bar_line(&self, bar: &Bar, first_day: i64, columns: usize, today: i64) -> Line<'static> {
+        let per_column = self.zoom.days_per_column();
+        let muted = Style::default().fg(self.config.theme.muted);
+        let mut spans = vec![Span::raw(self.label(&bar.job))];
+        let mut run = String::new();
+        let mut run_style = Style::default();
+        for column in 0..columns {
+            let from = first_day + column as i64 * per_column;
+            let to = from + per_column - 1;
+            let (symbol, style) = if to >= bar.start && from <= bar.end {
+                let day = from.clamp(bar.start, bar.end);
+                ('█', self.config.list.status_style(bar.status_on(day)))
+            } else if (from..=to).contains(&today) {
+                ('┊', muted)
+            } else {
+                (' ', Style::default())
+            };
+            if style != run_style && !run.is_empty() {
+                spans.push(Span::styled(std::mem::take(&mut run), run_style));
+            }
+            run_style = style;
+            run.push(symbol);
+        }
+        spans.push(Span::styled(run, run_style));
+        Line::from(spans)
+    }
+
+    fn request(&mut self) -> Option<Action> {
+        let req_id = RequestId::next();
+        self.pending = Some(req_id);
+        Some(Action::DbRequest(req_id, DbRequest::Timeline))
+    }
+}
+
+impl Component for TimelineView {
+    fn mode(&self) -> Mode {
+        Mode::Timeline
+    }
+    fn id(&self) -> String {
+        "Timeline".into()
+    }
+    fn subscriptions(&self) -> Vec<Subscription> {
+        vec![Subscription::Privacy, Subscription::Db]
+    }
+    fn register_action_handler(
+        &mut self,
+        tx: UnboundedSender<Action>,
+    ) -> color_eyre::eyre::Result<()> {
+        self.command_tx = Some(tx);
+        Ok(())
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.privacy = Privacy::new(config.config.privacy_mode);
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match action {
+            Action::DispatchTimeline | Action::Refresh => {
+                self.refreshing = action == Action::Refresh;
+                return Ok(self.request());
+            }
+            Action::DbResponse(req_id, DbResponse::Timeline(jobs, histories))
+                if self.pending == Some(req_id) =>
+            {
+                self.pending = None;
+                let today = dates::current_day();
+                self.bars = jobs
+                    .into_iter()
+                    .filter_map(|job| {
+                        let history = histories.get(&job.id).map_or(&[][..], Vec::as_slice);
+                        Bar::new(job, history, today)
+                    })
+                    .collect();
+                self.bars.sort_by_key(|bar| (bar.start, bar.job.id));
+                self.clamp_selection();
+                if std::mem::take(&mut self.refreshing) {
+                    return Ok(Some(Action::Notify("Refreshed".into())));
+                }
+            }
+            Action::SetPrivacy(enabled) => self.privacy = Privacy::new(enabled),
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn repeats(&self, key: crossterm::event::KeyEvent) -> bool {
+        matches!(
+            key.code,
+            KeyCode::Left
+                | KeyCode::Right
+                | KeyCode::Up
+                | KeyCode::Down
+                | KeyCode::Char('h' | 'l' | 'j' | 'k')
+        )
+    }
+    fn handle_key_event(
+        &mut self,
+        key: crossterm::event::KeyEvent,
+    ) -> color_eyre::eyre::Result<Option<Action>> {
+        match key.code {
+            KeyCode::Esc | KeyCode::Char('q') => return Ok(Some(Action::ChangeMode(Mode::Home))),
+            KeyCode::Down | KeyCode::Char('j') => self.selected.select_next(),
+            KeyCode::Up | KeyCode::Char('k') => self.selected.select_previous(),
+            KeyCode::Left | KeyCode::Char('h') => self.scroll += self.zoom.step(),
+            KeyCode::Right | KeyCode::Char('l') => {
+                self.scroll = (self.scroll - self.zoom.step()).max(0)
+            }
+            KeyCode::Char('t') => self.scroll = 0,
+            KeyCode::Char('z') => {
+                self.zoom = match self.zoom {
+                    Zoom::Week => Zoom::Month,
+                    Zoom::Month => Zoom::Week,
+                };
+            }
+            KeyCode::Char('a') => {
+                self.show_closed = !self.show_closed;
+                self.clamp_selection();
+            }
+            KeyCode::Enter => {
+                let shown = self.shown();
+                if let Some(bar) = self.selected.selected().and_then(|i| shown.get(i)) {
+                    return Ok(Some(Action::OpenJobDetail(bar.job.clone())));
+                }
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let shown = self.shown();
+        let zoom = match self.zoom {
+            Zoom::Week => "by week",
+            Zoom::Month => "by month",
+        };
+        let which = if self.show_closed { "all" } else { "active" };
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Thick)
+            .borders(widgets::Borders::TOP)
+            .title_top(
+                Line::from(format!("Timeline · {} {which}, {zoom}", shown.len())).centered(),
+            )
+            .title_bottom(
+                Line::from(
+                    "[h/l] scroll   [t] today   [z] zoom   [a] show closed   [enter] open   [esc] back",
+                )
+                .centered(),
+            );
+        let region = area.inner(Margin::new(2, 2));
+        let inner = block.inner(region);
+        frame.render_widget(block, region);
+
+        if shown.is_empty() {
+            frame.render_widget(
+                Line::styled(
+                    "No applications to show. Press a to include closed ones.",
+                    Style::default().fg(self.config.theme.muted),
+                ),
+                inner,
+            );
+            return Ok(());
+        }
+        let [scale, rows] =
+            Layout::vertical([Constraint::Length(1), Constraint::Fill(1)]).areas(inner);
+        let columns = (inner.width as usize).saturating_sub(LABEL_WIDTH + 1);
+        let today = dates::current_day();
+        // today sits in the last column until scrolled back
+        let first_day =
+            today - self.scroll - (columns as i64 - 1).max(0) * self.zoom.days_per_column();
+
+        frame.render_widget(self.scale(first_day, columns), scale);
+        let items = shown
+            .iter()
+            .map(|bar| ListItem::new(self.bar_line(bar, first_day, columns, today)))
+            .collect::<Vec<_>>();
+        let list =
+            List::new(items).highlight_style(Style::default().bg(self.config.theme.selection_bg));
+        frame.render_stateful_widget(list, rows, &mut self.selected);
+        Ok(())
+    }
+}
@@ -0,0 +1,156 @@
+use ratatui::{
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Text},
+    widgets::{self, Wrap},
+};
+
+use crate::{
+    action::Action,
+    app::Mode,
+    components::{component::Component, util},
+    config::Config,
+};
+
+/// A tutorial step is completed when its action is dispatched, however it was triggered.
+struct Step {
+    mode: Mode,
+    action: Action,
+    description: &'static str,
+}
+
+fn steps() -> Vec<Step> {
+    vec![
+        Step {
+            mode: Mode::Home,
+            action: Action::IndexNext,
+            description: "to move down the list of applications",
+        },
+        Step {
+            mode: Mode::Home,
+            action: Action::FocusNext,
+            description: "to move between the fields of the selected application",
+        },
+        Step {
+            mode: Mode::Home,
+            action: Action::NewJob,
+            description: "to add a new application",
+        },
+        Step {
+            mode: Mode::EditJob,
+            action: Action::FocusNext,
+            description: "to move to the next field of the form",
+        },
+        Step {
+            mode: Mode::EditJob,
+            action: Action::Save,
+            description: "to save the application",
+        },
+        Step {
+            mode: Mode::Home,
+            action: Action::Export,
+            description: "to export your applications to CSV",
+        },
+        Step {
+            mode: Mode::Home,
+            action: Action::Help,
+            description: "to see every keybinding. The tutorial can be restarted from there",
+        },
+    ]
+}
+
+pub struct Tutorial {
+    config: Config,
+    steps: Vec<Step>,
+    current: Option<usize>,
+}
+impl Tutorial {
+    pub fn new() -> Self {
+        Self {
+            config: Config::default(),
+            steps: steps(),
+            current: None,
+        }
+    }
+
+    fn keys(&self, mode: Mode, action: &Action) -> String {
+        self.config
+            .keybindings
+            .keys_for(mode, action)
+            .unwrap_or_else(|| format!("the key bound to {action}"))
+    }
+}
+
+impl Component for Tutorial {
+    fn mode(&self) -> Mode {
+        Mode::Global
+    }
+    fn id(&self) -> String {
+        "Tutorial".into()
+    }
+    fn register_config_handler(&mut self, config: Config) -> color_eyre::eyre::Result<()> {
+        self.config = config;
+        Ok(())
+    }
+    fn update(&mut self, action: Action) -> color_eyre::eyre::Result<Option<Action>> {
+        match (action, self.current) {
+            (Action::ToggleTutorial, Some(_)) => self.current = None,
+            (Action::ToggleTutorial, None) => self.current = Some(0),
+            (action, Some(current)) if self.steps[current].action == action => {
+                self.current = Some(current + 1).filter(|next| *next < self.steps.len());
+            }
+            _ => {}
+        }
+        Ok(None)
+    }
+    fn draw(
+        &mut self,
+        frame: &mut ratatui::Frame,
+        area: ratatui::prelude::Rect,
+    ) -> color_eyre::eyre::Result<()> {
+        let Some(current) = self.current else {
+            return Ok(());
+        };
+        let step = &self.steps[current];
+
+        let [area] = Layout::vertical([Constraint::Length(7)])
+            .flex(Flex::End)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Length(48)])
+            .flex(Flex::End)
+            .areas(area);
+
+        let highlight = Style::default()
+            .fg(self.config.theme.focus)
+            .add_modifier(Modifier::BOLD);
+        let text = Text::from(vec![
+            Line::from(vec![
+                "Press ".into(),
+                ratatui::text::Span::styled(self.keys(step.mode, &step.action), highlight),
+                format!(" {}", step.description).into(),
+            ]),
+            Line::raw(""),
+            Line::styled(
+                format!(
+                    "{} to leave the tutorial",
+                    self.keys(Mode::Global, &Action::ToggleTutorial)
+                ),
+                Style::default().fg(self.config.theme.muted),
+            ),
+        ]);
+
+        util::clear_popup(frame, area, &self.config.theme);
+        let block = widgets::Block::bordered()
+            .border_type(widgets::BorderType::Rounded)
+            .border_style(Style::default().fg(self.config.theme.focus))
+            .padding(widgets::Padding::horizontal(1))
+            .title(format!("Tutorial {}/{}", current + 1, self.steps.len()));
+        frame.render_widget(
+            widgets::Paragraph::new(text)
+                .wrap(Wrap { trim: true })
+                .block(block),
+            area,
+        );
+        Ok(())
+    }
+}
@@ -1,20 +1,25 @@
-use ratatui::style::Color;
+use std::cell::Cell;
 
-use crate::{components::job_item::JobListingState, database::schema::ApplicationStatus};
+use ratatui::{
+    Frame,
+    buffer::Buffer,
+    layout::{Constraint, Flex, Layout, Position, Rect},
+    style::{Color, Style},
+    widgets::{Block, Clear},
+};
 
+use crate::{
+    components::job_item::JobListingState, database::schema::ApplicationStatus, theme::Theme,
+};
+
+/// The colour the status is defined with in the `statuses` table.
 pub fn status_colour(status: ApplicationStatus) -> ratatui::style::Color {
-    match status {
-        ApplicationStatus::Applied => ratatui::style::Color::Cyan,
-        ApplicationStatus::Interviewing => ratatui::style::Color::Blue,
-        ApplicationStatus::Offered => ratatui::style::Color::Green,
-        ApplicationStatus::Rejected => ratatui::style::Color::Red,
-        ApplicationStatus::Withdrawn => ratatui::style::Color::Magenta,
-        ApplicationStatus::Accepted => ratatui::style::Color::Blue,
-    }
+    crate::statuses::colour(&status)
 }
 
 pub fn is_focused_field_to_fg_color(
     state: &JobListingState,
+    theme: &Theme,
     desired_field: i8,
     more_than: bool,
 ) -> Color {
@@ -22,17 +27,18 @@ pub fn is_focused_field_to_fg_color(
         if state.focused_field as i8 == desired_field
             || (more_than && state.focused_field as i8 > desired_field)
         {
-            Color::Blue
+            theme.focus
         } else {
-            Color::White
+            theme.text
         }
     } else {
-        Color::DarkGray
+        theme.muted
     }
 }
 
 pub fn is_focused_field_to_bg_color(
     state: &JobListingState,
+    theme: &Theme,
     desired_field: i8,
     more_than: bool,
 ) -> Color {
@@ -40,11 +46,66 @@ pub fn is_focused_field_to_bg_color(
         if state.focused_field as i8 == desired_field
             || (more_than && state.focused_field as i8 > desired_field)
         {
-            Color::DarkGray
+            theme.selection_bg
         } else {
-            Color::Reset
+            theme.background
         }
     } else {
-        Color::Reset
+        theme.background
+    }
+}
+
+/// Clear the cells under a popup and fill them with the theme's popup background.
+pub fn clear_popup(frame: &mut Frame, area: Rect, theme: &Theme) {
+    frame.render_widget(Clear, area);
+    frame.render_widget(
+        Block::new().style(Style::default().bg(theme.popup_bg)),
+        area,
+    );
+}
+
+thread_local! {
+    // the popup drawn by the component being drawn, taken by the app once it's done
+    static DRAWN_POPUP: Cell<Option<Rect>> = const { Cell::new(None) };
+}
+
+/// Clear `area` for a popup and record it as drawn, so a click outside it closes it as Esc
+/// would.
+pub fn popup(frame: &mut Frame, area: Rect, theme: &Theme) {
+    clear_popup(frame, area, theme);
+    DRAWN_POPUP.set(Some(area));
+}
+
+/// Centre a `width` by `height` popup in `area` and draw it as [`popup`] does, returning where
+/// it went.
+pub fn centered_popup(
+    frame: &mut Frame,
+    area: Rect,
+    width: Constraint,
+    height: Constraint,
+    theme: &Theme,
+) -> Rect {
+    let [area] = Layout::vertical([height]).flex(Flex::Center).areas(area);
+    let [area] = Layout::horizontal([width]).flex(Flex::Center).areas(area);
+    popup(frame, area, theme);
+    area
+}
+
+/// The popup drawn since the last call, if any.
+pub fn take_drawn_popup() -> Option<Rect> {
+    DRAWN_POPUP.take()
+}
+
+/// Wrap text already drawn at `pos` in an OSC 8 hyperlink to `url`. The escape sequence is
+/// split across the text two cells at a time, as that is the width ratatui measures each
+/// chunk at; text must be single-width.
+pub fn hyperlink(buf: &mut Buffer, pos: Position, text: &str, url: &str) {
+    let chars = text.chars().collect::<Vec<_>>();
+    for (i, chunk) in chars.chunks(2).enumerate() {
+        let x = pos.x + i as u16 * 2;
+        if let Some(cell) = buf.cell_mut(Position::new(x, pos.y)) {
+            let chunk = chunk.iter().collect::<String>();
+            cell.set_symbol(&format!("\x1b]8;;{url}\x07{chunk}\x1b]8;;\x07"));
+        }
     }
 }
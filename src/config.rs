@@ -1,18 +1,38 @@
 #![allow(dead_code)] // Remove this once you start using the code
 
-use std::{collections::HashMap, env, path::PathBuf};
+use std::{
+    collections::{BTreeMap, HashMap},
+    env,
+    path::PathBuf,
+    str::FromStr,
+};
 
 use color_eyre::Result;
 use crossterm::event::{KeyCode, KeyEvent, KeyModifiers};
 use derive_deref::{Deref, DerefMut};
 use directories::ProjectDirs;
 use lazy_static::lazy_static;
-use ratatui::style::{Color, Modifier, Style};
-use serde::{Deserialize, de::Deserializer};
+use ratatui::{
+    style::{Color, Modifier, Style},
+    text::Span,
+};
+use serde::{
+    Deserialize, Serialize, Serializer,
+    de::{self, Deserializer, IntoDeserializer},
+    ser::SerializeMap,
+};
 use tracing::error;
 use tui_textarea::Key;
 
-use crate::{action::Action, app::Mode};
+use crate::{
+    action::Action,
+    app::Mode,
+    components::util::status_colour,
+    database::schema::{ApplicationStatus, JobApplication},
+    preset::ACTIVE_PRESET_FILE,
+    theme::{Theme, ThemeConfig},
+    views::{Field, Filter},
+};
 
 const CONFIG: &str = include_str!("../.config/config.json5");
 
@@ -22,18 +42,172 @@ pub struct AppConfig {
     pub data_dir: PathBuf,
     #[serde(default)]
     pub config_dir: PathBuf,
+    /// Start with company names and contacts masked.
+    #[serde(default)]
+    pub privacy_mode: bool,
+    /// Days after applying, or after snoozing one, that an application is due a follow-up.
+    #[serde(default = "default_follow_up_days")]
+    pub follow_up_days: u32,
+    /// Ask before adding the Nth active application at one company, as some employers reject
+    /// candidates who apply for several roles at once. 0 never asks.
+    #[serde(default = "default_company_application_limit")]
+    pub company_application_limit: u32,
+    /// Days without a change after which an active application is shown as stale. 0 never
+    /// marks any.
+    #[serde(default = "default_stale_after_days")]
+    pub stale_after_days: u32,
+    /// Capture the mouse, to click and scroll through the app. Off leaves the terminal's own
+    /// text selection working.
+    #[serde(default = "default_mouse")]
+    pub mouse: bool,
+    /// Ticks per second, which drive timers such as reminders and the idle lock.
+    #[serde(default = "default_tick_rate")]
+    pub tick_rate: f64,
+    /// Most frames drawn per second.
+    #[serde(default = "default_frame_rate")]
+    pub frame_rate: f64,
+}
+
+fn default_mouse() -> bool {
+    true
+}
+
+fn default_tick_rate() -> f64 {
+    4.0
 }
 
-#[derive(Clone, Debug, Default)]
+fn default_frame_rate() -> f64 {
+    60.0
+}
+
+fn default_follow_up_days() -> u32 {
+    7
+}
+
+fn default_company_application_limit() -> u32 {
+    3
+}
+
+fn default_stale_after_days() -> u32 {
+    30
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
 pub struct Config {
+    #[serde(default, flatten)]
+    pub config: AppConfig,
+    #[serde(default)]
     pub keybindings: KeyBindings,
+    #[serde(default)]
+    pub confirmations: Confirmations,
+    #[serde(default, rename = "theme")]
+    pub theme_config: ThemeConfig,
+    #[serde(default)]
+    pub digest: DigestConfig,
+    #[serde(default)]
+    pub interviews: InterviewConfig,
+    #[serde(default)]
+    pub reminders: ReminderConfig,
+    #[serde(default)]
+    pub postings: PostingConfig,
+    #[serde(default)]
+    pub calendar: CalendarConfig,
+    #[serde(default)]
+    pub lock: LockConfig,
+    #[serde(default)]
+    pub encryption: EncryptionConfig,
+    #[serde(default)]
+    pub spelling: SpellingConfig,
+    #[serde(default)]
+    pub list: ListConfig,
+    #[serde(default)]
+    pub priority: PriorityConfig,
+    #[serde(default)]
+    pub views: Vec<ViewConfig>,
+    /// Command to open attached files with, by extension, e.g. `pdf` → `zathura`. Files
+    /// without one, and links, open with the platform's default handler.
+    #[serde(default)]
+    pub openers: HashMap<String, String>,
+    /// Other databases, e.g. last year's search, by name, to compare figures with from the
+    /// stats view. They are only ever read.
+    #[serde(default)]
+    pub profiles: BTreeMap<String, PathBuf>,
+    /// Message skeletons by name, e.g. a follow-up email, filled in for an application with
+    /// placeholders such as `{company}`.
+    #[serde(default)]
+    pub templates: BTreeMap<String, String>,
+    #[serde(skip)]
+    pub theme: Theme,
+    /// Readability problems found while building the theme, shown once on startup.
+    #[serde(skip)]
+    pub theme_warnings: Vec<String>,
+}
+
+lazy_static! {
+    pub static ref PROJECT_NAME: String = env!("CARGO_CRATE_NAME").to_uppercase().to_string();
+    pub static ref DATA_FOLDER: Option<PathBuf> =
+        env::var(format!("{}_DATA", PROJECT_NAME.clone()))
+            .ok()
+            .map(PathBuf::from);
+    pub static ref CONFIG_FOLDER: Option<PathBuf> =
+        env::var(format!("{}_CONFIG", PROJECT_NAME.clone()))
+            .ok()
+            .map(PathBuf::from);
 }
 
 impl Config {
     pub fn new() -> Result<Self, config::ConfigError> {
-        Ok(Self {
-            keybindings: KeyBindings::default(),
-        })
+        let data_dir = get_data_dir();
+        let config_dir = get_config_dir();
+        let builder = config::Config::builder()
+            .set_default("data_dir", data_dir.to_str().unwrap_or_default())?
+            .set_default("config_dir", config_dir.to_str().unwrap_or_default())?
+            .add_source(config::File::from_str(CONFIG, config::FileFormat::Json5))
+            .add_source(
+                config::File::from(config_dir.join("config.json5"))
+                    .format(config::FileFormat::Json5)
+                    .required(false),
+            )
+            .add_source(
+                config::File::from(config_dir.join(ACTIVE_PRESET_FILE))
+                    .format(config::FileFormat::Json5)
+                    .required(false),
+            );
+
+        let mut cfg: Self = builder.build()?.try_deserialize()?;
+        (cfg.theme, cfg.theme_warnings) = cfg.theme_config.build();
+
+        Ok(cfg)
+    }
+
+    /// How to confirm `action`: with the PIN whenever one is set, otherwise as configured,
+    /// falling back to typing "yes" for actions set to need a PIN there isn't.
+    pub fn confirmation_level(&self, action: DestructiveAction) -> ConfirmationLevel {
+        match (self.lock.pin(), self.confirmations.level(action)) {
+            (Some(_), _) => ConfirmationLevel::Pin,
+            (None, ConfirmationLevel::Pin) => ConfirmationLevel::TypeToConfirm,
+            (None, level) => level,
+        }
+    }
+}
+
+pub fn get_data_dir() -> PathBuf {
+    if let Some(s) = DATA_FOLDER.clone() {
+        s
+    } else if let Some(proj_dirs) = project_directory() {
+        proj_dirs.data_local_dir().to_path_buf()
+    } else {
+        PathBuf::from(".").join(".data")
+    }
+}
+
+pub fn get_config_dir() -> PathBuf {
+    if let Some(s) = CONFIG_FOLDER.clone() {
+        s
+    } else if let Some(proj_dirs) = project_directory() {
+        proj_dirs.config_local_dir().to_path_buf()
+    } else {
+        PathBuf::from(".").join(".config")
     }
 }
 
@@ -41,8 +215,356 @@ fn project_directory() -> Option<ProjectDirs> {
     ProjectDirs::from("com", "kdheepak", env!("CARGO_PKG_NAME"))
 }
 
+/// Actions that lose data, or are easily regretted, and can be guarded by a confirmation prompt.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DestructiveAction {
+    Delete,
+    BulkStatusChange,
+    OverwriteImport,
+    ExceedCompanyLimit,
+    DeleteTag,
+}
+impl DestructiveAction {
+    /// What the confirmation asks before the action runs.
+    pub fn question(self) -> &'static str {
+        match self {
+            DestructiveAction::Delete => "Delete this application?",
+            DestructiveAction::BulkStatusChange => {
+                "Change the status of all selected applications?"
+            }
+            DestructiveAction::OverwriteImport => {
+                "Overwrite the stored application with the imported fields picked?"
+            }
+            DestructiveAction::DeleteTag => "Remove this tag from every application?",
+            DestructiveAction::ExceedCompanyLimit => {
+                "This reaches your limit of active applications at this company. Add it anyway?"
+            }
+        }
+    }
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ConfirmationLevel {
+    /// Run the action straight away.
+    None,
+    /// Ask for a y/n confirmation.
+    #[default]
+    Confirm,
+    /// Require typing "yes" before running the action.
+    TypeToConfirm,
+    /// Require the lock PIN. Every destructive action asks for it while a PIN is set.
+    Pin,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum DigestFormat {
+    #[default]
+    Text,
+    Html,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct DigestConfig {
+    pub format: DigestFormat,
+    /// Command the digest is piped to on stdin, e.g. `sendmail partner@example.com`. When
+    /// unset the digest is written to the data directory instead.
+    pub command: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct InterviewConfig {
+    /// Minimum free time wanted between two interviews. Closer ones are flagged as
+    /// back-to-back when scheduling.
+    pub buffer_minutes: u32,
+}
+impl Default for InterviewConfig {
+    fn default() -> Self {
+        Self { buffer_minutes: 15 }
+    }
+}
+
+/// Alerts added to interviews and deadlines exported as iCalendar.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CalendarConfig {
+    /// Minutes before an interview to be alerted; 0 for no alert.
+    pub interview_alarm_minutes: u32,
+    /// Days before a deadline to be alerted; 0 for no alert.
+    pub deadline_alarm_days: u32,
+}
+impl Default for CalendarConfig {
+    fn default() -> Self {
+        Self {
+            interview_alarm_minutes: 60,
+            deadline_alarm_days: 1,
+        }
+    }
+}
+
+/// A PIN guarding the app on a shared machine. Once set, the app locks after a while without
+/// input, or on request, until the PIN is entered, and destructive actions ask for it too.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct LockConfig {
+    pub pin: Option<String>,
+    /// Lock after this many minutes without a key press or click; 0 only locks on request.
+    pub idle_minutes: u32,
+}
+impl Default for LockConfig {
+    fn default() -> Self {
+        Self {
+            pin: None,
+            idle_minutes: 10,
+        }
+    }
+}
+impl LockConfig {
+    /// The PIN, unless none is set.
+    pub fn pin(&self) -> Option<&str> {
+        self.pin.as_deref().filter(|pin| !pin.is_empty())
+    }
+    /// How long without input before locking, while there is a PIN to unlock with.
+    pub fn idle_timeout(&self) -> Option<std::time::Duration> {
+        self.pin()?;
+        (self.idle_minutes > 0)
+            .then(|| std::time::Duration::from_secs(u64::from(self.idle_minutes) * 60))
+    }
+}
+
+/// The database encrypted at rest with SQLCipher, unlocked by a passphrase asked for at
+/// startup. Needs a build with the `encryption` feature.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct EncryptionConfig {
+    pub enabled: bool,
+}
+
+/// Spell-checking of notes against a Hunspell dictionary, e.g.
+/// `/usr/share/hunspell/en_GB.dic` with its `.aff` beside it, or a plain word list.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct SpellingConfig {
+    pub enabled: bool,
+    /// The `.dic` file or word list, or the first of the usual places that has one.
+    pub dictionary: Option<PathBuf>,
+    /// Words to accept besides the list's, e.g. company names. Words added from the
+    /// suggestions are kept in `words.txt` in the data directory instead.
+    pub words: Vec<String>,
+}
+impl Default for SpellingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            dictionary: None,
+            words: Vec::new(),
+        }
+    }
+}
+
+/// How much each signal counts towards an application's place in the priority order. Each
+/// signal is scored from 0 to 1 before being weighted, so only the ratios matter; 0 ignores it.
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct PriorityConfig {
+    /// A follow-up that is due, or coming up.
+    pub follow_up: f64,
+    /// An interview coming up.
+    pub interview: f64,
+    /// Time since the application last changed, up to `stale_after_days`.
+    pub staleness: f64,
+}
+impl Default for PriorityConfig {
+    fn default() -> Self {
+        Self {
+            follow_up: 3.0,
+            interview: 2.0,
+            staleness: 1.0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct ReminderConfig {
+    /// Also raise due reminders as desktop notifications, not just in the status bar.
+    pub desktop_notifications: bool,
+}
+impl Default for ReminderConfig {
+    fn default() -> Self {
+        Self {
+            desktop_notifications: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+#[serde(default)]
+pub struct PostingConfig {
+    /// Check whether the Greenhouse and Lever postings of active applications are still up.
+    pub check_closed: bool,
+    /// Hours between checks while the app is open.
+    pub interval_hours: u64,
+}
+impl Default for PostingConfig {
+    fn default() -> Self {
+        Self {
+            check_closed: false,
+            interval_hours: 12,
+        }
+    }
+}
+
+/// How each application card in the job list is drawn.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum CardStyle {
+    /// Info, notes and links side by side.
+    #[default]
+    Detailed,
+    /// One line per application.
+    Compact,
+    /// A short card framed in the status colour.
+    Status,
+    /// A table row per application under column headings.
+    Table,
+}
+
+/// The order applications are listed in.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SortOrder {
+    /// Oldest added first.
+    #[default]
+    Added,
+    /// Most pressing first, weighted by `priority`.
+    Priority,
+    /// Most recently applied first.
+    Date,
+    /// By company name.
+    Company,
+    /// Highest rated first, then by nearest deadline.
+    Rating,
+}
+impl SortOrder {
+    pub fn next(self) -> Self {
+        match self {
+            SortOrder::Added => SortOrder::Priority,
+            SortOrder::Priority => SortOrder::Date,
+            SortOrder::Date => SortOrder::Company,
+            SortOrder::Company => SortOrder::Rating,
+            SortOrder::Rating => SortOrder::Added,
+        }
+    }
+}
+
+/// A named tab over the job list, showing only the applications its filter matches.
+#[derive(Clone, Debug, Deserialize)]
+pub struct ViewConfig {
+    pub name: String,
+    /// See `views` for the filter language.
+    #[serde(default)]
+    pub filter: Filter,
+    #[serde(default)]
+    pub sort: SortOrder,
+    /// Falls back to the list's own card style.
+    #[serde(default)]
+    pub style: Option<CardStyle>,
+    /// Columns of the table view, when not the usual ones.
+    #[serde(default)]
+    pub fields: Vec<Field>,
+}
+
+/// Override for how one status is shown in the job list.
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct StatusStyle {
+    pub fg: Option<Color>,
+    pub bg: Option<Color>,
+    pub bold: bool,
+    pub dim: bool,
+}
+
+/// A symbol shown on the cards of applications matching `when`, e.g. `⚑` for an overdue
+/// follow-up with `when: "active and follow_up < today"`.
+#[derive(Clone, Debug, Deserialize)]
+pub struct Badge {
+    pub symbol: String,
+    /// In the filter language of `views`.
+    pub when: Filter,
+    #[serde(default)]
+    pub fg: Option<Color>,
+}
+
+#[derive(Clone, Debug, Default, Deserialize)]
+#[serde(default)]
+pub struct ListConfig {
+    pub card_style: CardStyle,
+    /// Keyed by lowercase status name, e.g. `rejected`.
+    pub status_styles: HashMap<String, StatusStyle>,
+    /// Make the links block clickable with OSC 8 hyperlinks.
+    pub hyperlinks: bool,
+    /// Show company logos on cards. Needs the `logos` feature and a kitty graphics terminal.
+    pub logos: bool,
+    pub badges: Vec<Badge>,
+    /// Start with the selected application's details shown beside the list.
+    pub notes_preview: bool,
+    /// Percentage of the list's width the details take until they're resized, a third when
+    /// unset.
+    pub notes_preview_width: Option<u16>,
+}
+impl ListConfig {
+    /// The style a status is drawn in, falling back to its usual colour.
+    pub fn status_style(&self, status: &ApplicationStatus) -> Style {
+        let mut style = Style::default().fg(status_colour(status.clone()));
+        if let Some(custom) = self.status_styles.get(&status.to_string().to_lowercase()) {
+            if let Some(fg) = custom.fg {
+                style = style.fg(fg);
+            }
+            if let Some(bg) = custom.bg {
+                style = style.bg(bg);
+            }
+            if custom.bold {
+                style = style.add_modifier(Modifier::BOLD);
+            }
+            if custom.dim {
+                style = style.add_modifier(Modifier::DIM);
+            }
+        }
+        style
+    }
+
+    /// The symbols of the badges `job` matches, in the order configured, each followed by a
+    /// space. `today` is the local `YYYY-MM-DD` date, as from `dates::today`, so a badge
+    /// agrees with a view of the same filter.
+    pub fn badges(&self, job: &JobApplication, today: &str) -> Vec<Span<'static>> {
+        self.badges
+            .iter()
+            .filter(|badge| badge.when.matches(job, today))
+            .map(|badge| {
+                let style = badge
+                    .fg
+                    .map_or_else(Style::default, |fg| Style::default().fg(fg));
+                Span::styled(format!("{} ", badge.symbol), style)
+            })
+            .collect()
+    }
+}
+
+#[derive(Clone, Debug, Default, Deref, DerefMut, Deserialize)]
+pub struct Confirmations(pub HashMap<DestructiveAction, ConfirmationLevel>);
+impl Confirmations {
+    pub fn level(&self, action: DestructiveAction) -> ConfirmationLevel {
+        self.get(&action).copied().unwrap_or_default()
+    }
+}
+
 #[derive(Clone, Debug, Deref, DerefMut)]
-pub struct KeyBindings(pub HashMap<Vec<KeyEvent>, Action>);
+pub struct KeyBindings(pub HashMap<Mode, HashMap<Vec<KeyEvent>, Action>>);
 impl Default for KeyBindings {
     fn default() -> Self {
         let mut map = HashMap::new();
@@ -56,6 +578,218 @@ impl Default for KeyBindings {
             Action::Quit,
         );
 
-        KeyBindings(map)
+        KeyBindings(HashMap::from([(Mode::Home, map)]))
+    }
+}
+
+impl KeyBindings {
+    pub fn action(&self, mode: Mode, keys: &[KeyEvent]) -> Option<&Action> {
+        self.get(&mode)
+            .and_then(|bindings| bindings.get(keys))
+            .or_else(|| self.get(&Mode::Global)?.get(keys))
+    }
+    /// The key sequence bound to `action` in `mode` (or globally) formatted for display,
+    /// preferring the shortest binding when there are several.
+    pub fn keys_for(&self, mode: Mode, action: &Action) -> Option<String> {
+        [mode, Mode::Global]
+            .iter()
+            .filter_map(|mode| self.get(mode))
+            .find_map(|bindings| {
+                bindings
+                    .iter()
+                    .filter(|(_, bound)| *bound == action)
+                    .map(|(keys, _)| key_sequence_to_string(keys))
+                    .min_by_key(|keys| (keys.len(), keys.clone()))
+            })
     }
+    /// Whether a single key press is bound in `mode`, so components can avoid also treating it
+    /// as text input.
+    pub fn is_bound(&self, mode: Mode, key: KeyEvent) -> bool {
+        self.action(mode, &[key]).is_some()
+    }
+}
+
+// Modes and actions are parsed from owned strings since both contain `&'static str` variants,
+// which rules out deserializing them straight from the config source. Modes are matched
+// case-insensitively because the config loader lowercases map keys.
+impl<'de> Deserialize<'de> for KeyBindings {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let parsed_map = HashMap::<String, HashMap<String, String>>::deserialize(deserializer)?;
+
+        let mut keybindings = HashMap::new();
+        for (mode, bindings) in parsed_map {
+            let mode = Mode::from_str(&mode)
+                .map_err(|err| de::Error::custom(format!("invalid mode `{mode}`: {err}")))?;
+            let bindings = bindings
+                .into_iter()
+                .map(|(keys, action)| {
+                    let key_sequence = parse_key_sequence(&keys).map_err(de::Error::custom)?;
+                    let action = Action::deserialize(action.clone().into_deserializer()).map_err(
+                        |err: serde::de::value::Error| {
+                            de::Error::custom(format!(
+                                "invalid action `{action}` for {keys}: {err}"
+                            ))
+                        },
+                    )?;
+                    Ok((key_sequence, action))
+                })
+                .collect::<Result<HashMap<_, _>, D::Error>>()?;
+            keybindings.insert(mode, bindings);
+        }
+
+        Ok(KeyBindings(keybindings))
+    }
+}
+
+impl Serialize for KeyBindings {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        // popups are keyed by a runtime name and can't be bound from config
+        let mut map = serializer.serialize_map(None)?;
+        for (mode, bindings) in self.iter() {
+            if matches!(mode, Mode::Popup(_)) {
+                continue;
+            }
+            let bindings = bindings
+                .iter()
+                .map(|(keys, action)| (key_sequence_to_string(keys), action.to_string()))
+                .collect::<HashMap<_, _>>();
+            map.serialize_entry(&format!("{mode:?}"), &bindings)?;
+        }
+        map.end()
+    }
+}
+
+pub fn key_event_to_string(key_event: &KeyEvent) -> String {
+    let code = match key_event.code {
+        KeyCode::Char(' ') => "space".to_string(),
+        KeyCode::Char(c) => c.to_string(),
+        KeyCode::F(n) => format!("f{n}"),
+        KeyCode::BackTab => "backtab".to_string(),
+        code => format!("{code:?}").to_ascii_lowercase(),
+    };
+    let mut modifiers = Vec::new();
+    if key_event.modifiers.contains(KeyModifiers::CONTROL) {
+        modifiers.push("ctrl");
+    }
+    if key_event.modifiers.contains(KeyModifiers::ALT) {
+        modifiers.push("alt");
+    }
+    if key_event.modifiers.contains(KeyModifiers::SHIFT) && key_event.code != KeyCode::BackTab {
+        modifiers.push("shift");
+    }
+    modifiers.push(&code);
+    modifiers.join("-")
+}
+
+pub fn key_sequence_to_string(keys: &[KeyEvent]) -> String {
+    keys.iter()
+        .map(|key| format!("<{}>", key_event_to_string(key)))
+        .collect()
+}
+
+fn parse_key_event(raw: &str) -> Result<KeyEvent, String> {
+    let raw_lower = raw.to_ascii_lowercase();
+    let (remaining, modifiers) = extract_modifiers(&raw_lower);
+    parse_key_code_with_modifiers(remaining, modifiers)
+}
+
+fn extract_modifiers(raw: &str) -> (&str, KeyModifiers) {
+    let mut modifiers = KeyModifiers::empty();
+    let mut current = raw;
+
+    loop {
+        match current {
+            rest if rest.starts_with("ctrl-") => {
+                modifiers.insert(KeyModifiers::CONTROL);
+                current = &rest[5..];
+            }
+            rest if rest.starts_with("alt-") => {
+                modifiers.insert(KeyModifiers::ALT);
+                current = &rest[4..];
+            }
+            rest if rest.starts_with("shift-") => {
+                modifiers.insert(KeyModifiers::SHIFT);
+                current = &rest[6..];
+            }
+            _ => break,
+        };
+    }
+
+    (current, modifiers)
+}
+
+fn parse_key_code_with_modifiers(
+    raw: &str,
+    mut modifiers: KeyModifiers,
+) -> Result<KeyEvent, String> {
+    let c = match raw {
+        "esc" => KeyCode::Esc,
+        "enter" => KeyCode::Enter,
+        "left" => KeyCode::Left,
+        "right" => KeyCode::Right,
+        "up" => KeyCode::Up,
+        "down" => KeyCode::Down,
+        "home" => KeyCode::Home,
+        "end" => KeyCode::End,
+        "pageup" => KeyCode::PageUp,
+        "pagedown" => KeyCode::PageDown,
+        "backtab" => {
+            modifiers.insert(KeyModifiers::SHIFT);
+            KeyCode::BackTab
+        }
+        "backspace" => KeyCode::Backspace,
+        "delete" => KeyCode::Delete,
+        "insert" => KeyCode::Insert,
+        "f1" => KeyCode::F(1),
+        "f2" => KeyCode::F(2),
+        "f3" => KeyCode::F(3),
+        "f4" => KeyCode::F(4),
+        "f5" => KeyCode::F(5),
+        "f6" => KeyCode::F(6),
+        "f7" => KeyCode::F(7),
+        "f8" => KeyCode::F(8),
+        "f9" => KeyCode::F(9),
+        "f10" => KeyCode::F(10),
+        "f11" => KeyCode::F(11),
+        "f12" => KeyCode::F(12),
+        "space" => KeyCode::Char(' '),
+        "hyphen" | "minus" => KeyCode::Char('-'),
+        "tab" => KeyCode::Tab,
+        c if c.chars().count() == 1 => {
+            let mut c = c.chars().next().unwrap_or_default();
+            if modifiers.contains(KeyModifiers::SHIFT) {
+                c = c.to_ascii_uppercase();
+            }
+            KeyCode::Char(c)
+        }
+        _ => return Err(format!("Unable to parse {raw}")),
+    };
+    Ok(KeyEvent::new(c, modifiers))
+}
+
+/// Parse a key sequence such as `"<ctrl-s>"` or `"<g><g>"` into the key events it is made of.
+pub fn parse_key_sequence(raw: &str) -> Result<Vec<KeyEvent>, String> {
+    if raw.chars().filter(|c| *c == '>').count() != raw.chars().filter(|c| *c == '<').count() {
+        return Err(format!("Unable to parse `{raw}`"));
+    }
+    let raw = if !raw.contains("><") {
+        let raw = raw.strip_prefix('<').unwrap_or(raw);
+        raw.strip_suffix('>').unwrap_or(raw)
+    } else {
+        raw
+    };
+    raw.split("><")
+        .map(|seq| {
+            seq.strip_prefix('<')
+                .or_else(|| seq.strip_suffix('>'))
+                .unwrap_or(seq)
+        })
+        .map(parse_key_event)
+        .collect()
 }
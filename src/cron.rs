@@ -0,0 +1,103 @@
+//! Maintenance run from crontab, so backups, reminders and the digest don't need the app open.
+//! Each run appends what it did to `cron.log` in the data directory and prints nothing unless
+//! a task fails, so cron only mails about failures.
+
+use std::{fs::OpenOptions, io::Write};
+
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    app,
+    config::Config,
+    database::{db::Database, query},
+    dates,
+    export::{self, digest::Digest},
+    privacy::Privacy,
+    state::State,
+    statuses, unlock,
+};
+
+// days between scheduled digests
+const DIGEST_INTERVAL_DAYS: u32 = 7;
+
+/// Back up the database, fire reminders that have come due, count stale applications and
+/// send the weekly digest when a week has passed since the last one sent from here. Every
+/// task is attempted; the run fails if any of them did.
+pub fn run() -> Result<()> {
+    let config = Config::new()?;
+    let data_dir = config.config.data_dir.clone();
+    let mut database = Database::default();
+    unlock::from_env(&mut database, &config)?;
+    database.create()?;
+    statuses::load(&database);
+
+    let started = query::now(&database);
+    let mut done = Vec::new();
+    let mut failed = Vec::new();
+
+    let path = data_dir.join("backup.json");
+    match export::backup::write_backup(&path, &database) {
+        Ok(count) => done.push(format!(
+            "Backed up {count} application(s) to {}",
+            path.display()
+        )),
+        Err(err) => failed.push(format!("Failed to back up to {}: {err}", path.display())),
+    }
+
+    match query::fire_due_reminders(&database) {
+        Ok(due) => {
+            let privacy = Privacy::new(config.config.privacy_mode);
+            for (reminder, company) in due {
+                let company = privacy.text(&company, &format!("Company #{}", reminder.job_id));
+                if config.reminders.desktop_notifications
+                    && let Err(err) = app::notify_desktop(&company, &reminder.message)
+                {
+                    failed.push(format!("Failed to show desktop notification: {err}"));
+                }
+                done.push(format!("Reminder: {company} — {}", reminder.message));
+            }
+        }
+        Err(err) => failed.push(format!("Failed to check reminders: {err}")),
+    }
+
+    let days = config.config.stale_after_days;
+    if days > 0 {
+        let stale = query::get_stale_applications(days, &database).len();
+        done.push(format!(
+            "{stale} application(s) unchanged for {days} day(s) or more"
+        ));
+    }
+
+    let today = started[..10].to_string();
+    let mut state = State::load(&data_dir);
+    let digest_due = state.cron_digest_sent.as_deref().is_none_or(|sent| {
+        dates::add_days(sent, DIGEST_INTERVAL_DAYS).is_none_or(|next| next <= today)
+    });
+    if digest_due {
+        let digest = Digest::collect(&database).render(config.digest.format);
+        match export::digest::deliver(&digest, &config.digest, &data_dir) {
+            Ok(destination) => {
+                done.push(format!("Weekly digest {destination}"));
+                state.cron_digest_sent = Some(today);
+                if let Err(err) = state.save(&data_dir) {
+                    failed.push(format!("Failed to save when the digest was sent: {err}"));
+                }
+            }
+            Err(err) => failed.push(format!("Failed to deliver digest: {err}")),
+        }
+    }
+
+    std::fs::create_dir_all(&data_dir)?;
+    let mut log = OpenOptions::new()
+        .create(true)
+        .append(true)
+        .open(data_dir.join("cron.log"))?;
+    for line in done.iter().chain(failed.iter()) {
+        writeln!(log, "{started}  {line}")?;
+    }
+    if failed.is_empty() {
+        Ok(())
+    } else {
+        Err(eyre!(failed.join("\n")))
+    }
+}
@@ -0,0 +1,174 @@
+use color_eyre::Result;
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Layout, Margin},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Borders, Paragraph},
+};
+
+use crate::{
+    config::Config,
+    database::{
+        db::Database,
+        query,
+        schema::{Interview, JobApplication},
+    },
+    privacy::Privacy,
+    stats::Stats,
+    statuses,
+    tui::{Event, Tui},
+    unlock,
+};
+
+/// Everything the dashboard shows, reloaded from the database on every refresh.
+struct Summary {
+    stats: Stats,
+    upcoming: Vec<(Interview, String)>,
+    overdue: Vec<JobApplication>,
+}
+
+impl Summary {
+    fn load(db: &Database) -> Self {
+        Summary {
+            stats: Stats::collect(db),
+            upcoming: query::get_upcoming_interviews(db),
+            overdue: query::get_overdue_follow_ups(db),
+        }
+    }
+}
+
+/// Run the read-only dashboard until `q`, `Esc` or `Ctrl-c` is pressed. Other input is
+/// ignored.
+pub async fn run(refresh_secs: u64) -> Result<()> {
+    let config = Config::new()?;
+    let privacy = Privacy::new(config.config.privacy_mode);
+    let mut database = Database::default();
+    unlock::prompt(&mut database, &config).await?;
+    database.create()?;
+    statuses::load(&database);
+
+    let mut tui = Tui::new()?
+        .mouse(false)
+        .paste(false)
+        .tick_rate(1.0 / refresh_secs.max(1) as f64)
+        .frame_rate(1.0);
+    tui.enter()?;
+
+    let mut summary = Summary::load(&database);
+    while let Some(event) = tui.next_event().await {
+        match event {
+            Event::Tick => {
+                // the app writes from another process, so nothing cached here can be trusted
+                database.invalidate();
+                summary = Summary::load(&database);
+            }
+            Event::Render | Event::Resize(_, _) => {
+                tui.draw(|frame| draw(frame, &summary, &config, privacy))?;
+            }
+            Event::Key(key)
+                if matches!(key.code, KeyCode::Char('q') | KeyCode::Esc)
+                    || (key.code == KeyCode::Char('c')
+                        && key.modifiers.contains(KeyModifiers::CONTROL)) =>
+            {
+                break;
+            }
+            Event::Quit | Event::Closed => break,
+            _ => {}
+        }
+    }
+    tui.exit()?;
+    Ok(())
+}
+
+fn draw(frame: &mut Frame, summary: &Summary, config: &Config, privacy: Privacy) {
+    let theme = &config.theme;
+    let muted = Style::default().fg(theme.muted);
+    let heading = Style::default().add_modifier(Modifier::BOLD);
+    let section = |title: &'static str| {
+        Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .title(title)
+    };
+
+    let outer = Block::bordered()
+        .border_type(BorderType::Thick)
+        .borders(Borders::TOP)
+        .title_top(Line::from("Job search").centered())
+        .title_bottom(Line::from("[q] quit").centered());
+    let area = frame.area().inner(Margin::new(1, 1));
+    let inner = outer.inner(area);
+    frame.render_widget(outer, area);
+
+    let [counts, lists] =
+        Layout::vertical([Constraint::Length(5), Constraint::Min(4)]).areas(inner);
+
+    let stats = &summary.stats;
+    let response_rate = stats
+        .response_rate()
+        .map_or("–".to_string(), |rate| format!("{:.0}%", rate * 100.0));
+    let mut count_line = vec![
+        Span::styled("Total ", muted),
+        Span::styled(stats.total_applications().to_string(), heading),
+        Span::styled("   Response rate ", muted),
+        Span::styled(response_rate, heading),
+    ];
+    for (status, count) in &stats.status_counts {
+        count_line.push(Span::styled(format!("   {status} "), muted));
+        count_line.push(Span::raw(count.to_string()));
+    }
+    frame.render_widget(
+        Paragraph::new(Line::from(count_line)).block(section("Applications")),
+        counts,
+    );
+
+    let [upcoming, overdue] =
+        Layout::horizontal([Constraint::Percentage(50), Constraint::Percentage(50)])
+            .spacing(1)
+            .areas(lists);
+    let company = |name: &str, job_id: i32| privacy.text(name, &format!("Company #{job_id}"));
+
+    let upcoming_lines = if summary.upcoming.is_empty() {
+        vec![Line::styled("No interviews scheduled", muted)]
+    } else {
+        summary
+            .upcoming
+            .iter()
+            .map(|(interview, name)| {
+                Line::from(vec![
+                    Span::styled(format!("{}  ", interview.starts_at), muted),
+                    Span::raw(company(name, interview.job_id)),
+                ])
+            })
+            .collect()
+    };
+    frame.render_widget(
+        Paragraph::new(Text::from(upcoming_lines)).block(section("Upcoming interviews")),
+        upcoming,
+    );
+
+    let overdue_lines = if summary.overdue.is_empty() {
+        vec![Line::styled("Nothing to follow up on", muted)]
+    } else {
+        summary
+            .overdue
+            .iter()
+            .map(|job| {
+                Line::from(vec![
+                    Span::styled(format!("{}  ", job.application_date), muted),
+                    Span::raw(format!(
+                        "{} – {}",
+                        company(&job.company_name, job.id),
+                        job.position
+                    )),
+                ])
+            })
+            .collect()
+    };
+    frame.render_widget(
+        Paragraph::new(Text::from(overdue_lines)).block(section("Follow-ups due")),
+        overdue,
+    );
+}
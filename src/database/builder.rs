@@ -0,0 +1,392 @@
+//! Turns text typed into the edit form or read from an import into a `JobApplication`, so
+//! every way of creating one parses and validates fields the same way.
+
+use std::str::FromStr;
+
+use crate::{
+    database::schema::{
+        ApplicationStatus, Contact, Files, JobApplication, LocationType, PositionCategory, WorkType,
+    },
+    dates,
+};
+
+/// Builds an application from strings, trimming each. Empty values leave the field as it
+/// was, and the first value that doesn't parse is reported by `build`.
+#[derive(Debug, Clone)]
+pub struct JobApplicationBuilder {
+    job: JobApplication,
+    active: Option<bool>,
+    error: Option<String>,
+}
+
+impl Default for JobApplicationBuilder {
+    fn default() -> Self {
+        Self::from(JobApplication::default())
+    }
+}
+
+/// Start from an existing application, e.g. to edit it or to validate one deserialized
+/// from an import.
+impl From<JobApplication> for JobApplicationBuilder {
+    fn from(job: JobApplication) -> Self {
+        JobApplicationBuilder {
+            active: Some(job.is_active),
+            job,
+            error: None,
+        }
+    }
+}
+
+impl JobApplicationBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn parse<T: FromStr>(&mut self, name: &str, value: &str) -> Option<T> {
+        let value = value.trim();
+        if value.is_empty() {
+            return None;
+        }
+        let parsed = T::from_str(value).ok();
+        if parsed.is_none() && self.error.is_none() {
+            self.error = Some(format!("invalid {name} \"{value}\""));
+        }
+        parsed
+    }
+
+    fn optional(value: &str) -> Option<String> {
+        Some(value.trim().to_string()).filter(|value| !value.is_empty())
+    }
+
+    pub fn uuid(mut self, value: &str) -> Self {
+        self.job.uuid = value.trim().to_string();
+        self
+    }
+
+    pub fn company_name(mut self, value: &str) -> Self {
+        self.job.company_name = value.trim().to_string();
+        self
+    }
+
+    pub fn position(mut self, value: &str) -> Self {
+        self.job.position = value.trim().to_string();
+        self
+    }
+
+    pub fn position_category(mut self, value: &str) -> Self {
+        if let Some(category) = self.parse::<PositionCategory>("position_category", value) {
+            self.job.position_category = category;
+        }
+        self
+    }
+
+    pub fn work_type(mut self, value: &str) -> Self {
+        if let Some(work_type) = self.parse::<WorkType>("work_type", value) {
+            self.job.work_type = work_type;
+        }
+        self
+    }
+
+    pub fn location(mut self, value: &str) -> Self {
+        self.job.location = value.trim().to_string();
+        self
+    }
+
+    pub fn location_type(mut self, value: &str) -> Self {
+        if let Some(location_type) = self.parse::<LocationType>("location_type", value) {
+            self.job.location_type = location_type;
+        }
+        self
+    }
+
+    /// A `YYYY-MM-DD` date.
+    pub fn application_date(mut self, value: &str) -> Self {
+        self.job.application_date = value.trim().to_string();
+        self
+    }
+
+    /// Also makes the application active or finished to match the status, unless `active` is
+    /// given after it.
+    pub fn status(mut self, value: &str) -> Self {
+        if let Some(status) = self.parse::<ApplicationStatus>("status", value) {
+            self.job.status = status;
+            self.active = None;
+        }
+        self
+    }
+
+    /// `true`/`false`, `yes`/`no` or `1`/`0`.
+    pub fn active(mut self, value: &str) -> Self {
+        match value.trim().to_lowercase().as_str() {
+            "" => {}
+            "true" | "1" | "yes" => self.active = Some(true),
+            "false" | "0" | "no" => self.active = Some(false),
+            other => {
+                self.error
+                    .get_or_insert_with(|| format!("invalid is_active \"{other}\""));
+            }
+        }
+        self
+    }
+
+    pub fn notes(mut self, value: &str) -> Self {
+        self.job.notes = Self::optional(value);
+        self
+    }
+
+    pub fn url(mut self, value: &str) -> Self {
+        self.job.url = Self::optional(value);
+        self
+    }
+
+    pub fn description(mut self, value: &str) -> Self {
+        self.job.description = Self::optional(value);
+        self
+    }
+
+    pub fn files(mut self, files: Files) -> Self {
+        self.job.files = files;
+        self
+    }
+
+    pub fn contacts(mut self, contacts: Vec<Contact>) -> Self {
+        self.job.contacts = contacts;
+        self
+    }
+
+    /// Comma separated, e.g. `rust, referral`.
+    pub fn tags(mut self, value: &str) -> Self {
+        self.job.tags = value
+            .split(',')
+            .map(str::trim)
+            .filter(|tag| !tag.is_empty())
+            .map(str::to_string)
+            .collect();
+        self
+    }
+
+    /// A whole number, which may be written with thousands separators. Empty clears it.
+    pub fn salary_min(mut self, value: &str) -> Self {
+        self.job.salary_min = self.parse("salary_min", &value.replace(',', ""));
+        self
+    }
+
+    /// A whole number, which may be written with thousands separators. Empty clears it.
+    pub fn salary_max(mut self, value: &str) -> Self {
+        self.job.salary_max = self.parse("salary_max", &value.replace(',', ""));
+        self
+    }
+
+    pub fn currency(mut self, value: &str) -> Self {
+        self.job.currency = Self::optional(value).map(|currency| currency.to_uppercase());
+        self
+    }
+
+    pub fn compensation_notes(mut self, value: &str) -> Self {
+        self.job.compensation_notes = Self::optional(value);
+        self
+    }
+
+    /// A `YYYY-MM-DD` date. Empty clears it.
+    pub fn follow_up_date(mut self, value: &str) -> Self {
+        self.job.follow_up_date = Self::optional(value);
+        self
+    }
+
+    /// A `YYYY-MM-DD` date. Empty clears it.
+    pub fn deadline(mut self, value: &str) -> Self {
+        self.job.deadline = Self::optional(value);
+        self
+    }
+
+    /// A whole number from 1 to 5, or stars as shown, e.g. `★★★☆☆` or `***`. Empty clears
+    /// it.
+    pub fn rating(mut self, value: &str) -> Self {
+        let value = value.trim();
+        self.job.rating = match value.chars().all(|c| matches!(c, '*' | '★' | '☆')) {
+            true if !value.is_empty() => Some(
+                value
+                    .chars()
+                    .filter(|c| *c != '☆')
+                    .count()
+                    .min(u8::MAX.into()) as u8,
+            ),
+            _ => self.parse("rating", value),
+        };
+        self
+    }
+
+    /// The application, or a message naming the first field that is missing or invalid.
+    pub fn build(self) -> Result<JobApplication, String> {
+        if let Some(error) = self.error {
+            return Err(error);
+        }
+        let mut job = self.job;
+        if job.company_name.is_empty() || job.position.is_empty() {
+            return Err("company_name and position are required".to_string());
+        }
+        let is_date = |date: &str| dates::parse_datetime(&format!("{date} 00:00")).is_some();
+        if !job.application_date.is_empty() && !is_date(&job.application_date) {
+            return Err(format!(
+                "invalid application_date \"{}\"",
+                job.application_date
+            ));
+        }
+        if let Some(date) = job.follow_up_date.as_deref().filter(|date| !is_date(date)) {
+            return Err(format!("invalid follow_up_date \"{date}\""));
+        }
+        if let Some(date) = job.deadline.as_deref().filter(|date| !is_date(date)) {
+            return Err(format!("invalid deadline \"{date}\""));
+        }
+        if let Some(rating) = job.rating.filter(|rating| !(1..=5).contains(rating)) {
+            return Err(format!("invalid rating \"{rating}\", expected 1 to 5"));
+        }
+        if let (Some(min), Some(max)) = (job.salary_min, job.salary_max)
+            && min > max
+        {
+            return Err("salary_min is above salary_max".to_string());
+        }
+        job.is_active = self.active.unwrap_or_else(|| job.status.is_active());
+        Ok(job)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn acme() -> JobApplicationBuilder {
+        JobApplicationBuilder::new()
+            .company_name("Acme")
+            .position("Backend Engineer")
+    }
+
+    #[test]
+    fn requires_company_and_position() {
+        let missing = "company_name and position are required";
+        assert_eq!(JobApplicationBuilder::new().build().unwrap_err(), missing);
+        assert_eq!(
+            JobApplicationBuilder::new()
+                .company_name("Acme")
+                .position("  ")
+                .build()
+                .unwrap_err(),
+            missing
+        );
+        assert!(acme().build().is_ok());
+    }
+
+    #[test]
+    fn parses_dates() {
+        let job = acme()
+            .application_date("2024-03-01")
+            .follow_up_date(" 2024-03-08 ")
+            .deadline("")
+            .build()
+            .unwrap();
+        assert_eq!(job.application_date, "2024-03-01");
+        assert_eq!(job.follow_up_date.as_deref(), Some("2024-03-08"));
+        assert_eq!(job.deadline, None);
+
+        assert_eq!(
+            acme().application_date("01/03/2024").build().unwrap_err(),
+            "invalid application_date \"01/03/2024\""
+        );
+        assert_eq!(
+            acme().follow_up_date("2024-13-01").build().unwrap_err(),
+            "invalid follow_up_date \"2024-13-01\""
+        );
+        assert_eq!(
+            acme().deadline("soon").build().unwrap_err(),
+            "invalid deadline \"soon\""
+        );
+    }
+
+    #[test]
+    fn parses_enums() {
+        let job = acme()
+            .position_category("Data Science")
+            .work_type("Part Time")
+            .location_type("On Site")
+            .status("Technical Interview")
+            .build()
+            .unwrap();
+        assert_eq!(job.position_category, PositionCategory::DataScience);
+        assert_eq!(job.work_type, WorkType::PartTime);
+        assert_eq!(job.location_type, LocationType::OnSite);
+        assert_eq!(job.status, ApplicationStatus::TechnicalInterview);
+
+        assert_eq!(
+            acme().work_type("Gig").build().unwrap_err(),
+            "invalid work_type \"Gig\""
+        );
+        assert_eq!(
+            acme().location_type("Moon").build().unwrap_err(),
+            "invalid location_type \"Moon\""
+        );
+        // the first invalid field is the one reported
+        assert_eq!(
+            acme()
+                .status("Nonsense")
+                .position_category("Sales")
+                .build()
+                .unwrap_err(),
+            "invalid status \"Nonsense\""
+        );
+        assert_eq!(
+            acme().active("maybe").build().unwrap_err(),
+            "invalid is_active \"maybe\""
+        );
+    }
+
+    #[test]
+    fn builds_the_application() {
+        let job = acme()
+            .company_name("  Acme Ltd ")
+            .location(" London ")
+            .status("Rejected")
+            .notes("   ")
+            .url(" https://acme.com/jobs/1 ")
+            .tags("rust, , referral")
+            .salary_min("50,000")
+            .salary_max("65000")
+            .currency("gbp")
+            .rating("★★★★☆")
+            .build()
+            .unwrap();
+        assert_eq!(job.company_name, "Acme Ltd");
+        assert_eq!(job.location, "London");
+        assert_eq!(job.notes, None);
+        assert_eq!(job.url.as_deref(), Some("https://acme.com/jobs/1"));
+        assert_eq!(job.tags, ["rust", "referral"]);
+        assert_eq!(
+            (job.salary_min, job.salary_max),
+            (Some(50_000), Some(65_000))
+        );
+        assert_eq!(job.currency.as_deref(), Some("GBP"));
+        assert_eq!(job.rating, Some(4));
+        // a finished status makes the application inactive, unless told otherwise after it
+        assert!(!job.is_active);
+        assert!(
+            acme()
+                .status("Rejected")
+                .active("yes")
+                .build()
+                .unwrap()
+                .is_active
+        );
+
+        assert_eq!(
+            acme()
+                .salary_min("70000")
+                .salary_max("60000")
+                .build()
+                .unwrap_err(),
+            "salary_min is above salary_max"
+        );
+        assert_eq!(
+            acme().rating("6").build().unwrap_err(),
+            "invalid rating \"6\", expected 1 to 5"
+        );
+    }
+}
@@ -0,0 +1,11 @@
+use crate::database::schema::ApplicationStatus;
+
+/// Aggregate reads that views repeat far more often than the data changes, kept until the
+/// next write through the same `Database`. Each slot is filled on first use.
+#[derive(Debug, Default)]
+pub struct QueryCache {
+    pub status_counts: Option<Vec<(ApplicationStatus, u32)>>,
+    pub weekly_application_counts: Option<Vec<(String, u32)>>,
+    pub average_days_to_rejection: Option<Option<f64>>,
+    pub monthly_expense_totals: Option<Vec<(String, i64)>>,
+}
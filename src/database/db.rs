@@ -1,8 +1,28 @@
-use color_eyre::Result;
+use std::{
+    cell::RefCell,
+    fs::File,
+    io::Read,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::{Result, eyre::eyre};
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::{
+    action::{Action, JobChange},
+    database::{cache::QueryCache, migrations, query, schema::JobApplication},
+};
+
+// how every plain SQLite file starts; SQLCipher's look like random bytes from the first
+const PLAIN_HEADER: &[u8; 16] = b"SQLite format 3\0";
 
 #[derive(Debug)]
 pub struct Database {
     connection: rusqlite::Connection,
+    change_tx: Option<UnboundedSender<Action>>,
+    cache: RefCell<QueryCache>,
+    // changes made by a write whose transaction is still open, sent once it commits
+    held: RefCell<Option<Vec<JobChange>>>,
 }
 impl Default for Database {
     fn default() -> Self {
@@ -13,32 +33,162 @@ impl Default for Database {
 impl Database {
     pub fn new(db_path: &str) -> rusqlite::Result<Self> {
         let connection = rusqlite::Connection::open(db_path)?;
-        Ok(Database { connection })
+        Ok(Database {
+            connection,
+            change_tx: None,
+            cache: RefCell::default(),
+            held: RefCell::default(),
+        })
     }
-    pub fn create(&self) -> Result<()> {
-        self.connection.execute_batch(
-            "
-                CREATE TABLE IF NOT EXISTS job_applications (
-                    id INTEGER PRIMARY KEY,
-                    company_name TEXT NOT NULL,
-                    position TEXT NOT NULL,
-                    position_category TEXT NOT NULL,
-                    work_type TEXT NOT NULL,
-                    location TEXT NOT NULL,
-                    location_type TEXT NOT NULL,
-                    application_date TEXT NOT NULL,
-                    status TEXT NOT NULL,
-                    is_active BOOLEAN NOT NULL,
-                    notes TEXT,
-                    contact_info TEXT,
-                    url TEXT,
-                    files TEXT NOT NULL
-                );
-            ",
+    /// A new database with the schema created that lives in memory, gone once dropped, for
+    /// `--demo`, `--replay` and tests that mustn't touch the real file.
+    pub fn in_memory() -> Result<Self> {
+        let database = Database {
+            connection: rusqlite::Connection::open_in_memory()?,
+            change_tx: None,
+            cache: RefCell::default(),
+            held: RefCell::default(),
+        };
+        database.create()?;
+        Ok(database)
+    }
+    /// Open another database only to read from it, e.g. a profile's to compare against. It
+    /// isn't created when missing nor migrated.
+    pub fn open_read_only(db_path: &Path) -> rusqlite::Result<Self> {
+        let connection = rusqlite::Connection::open_with_flags(
+            db_path,
+            rusqlite::OpenFlags::SQLITE_OPEN_READ_ONLY,
         )?;
+        Ok(Database {
+            connection,
+            change_tx: None,
+            cache: RefCell::default(),
+            held: RefCell::default(),
+        })
+    }
+    /// Whether the file holds something other than a plain SQLite database, i.e. one encrypted
+    /// with SQLCipher. A database not written to yet is neither.
+    pub fn is_encrypted(&self) -> bool {
+        let Some(path) = self.connection.path().filter(|path| !path.is_empty()) else {
+            return false;
+        };
+        let mut header = [0; 16];
+        match File::open(path).and_then(|mut file| file.read_exact(&mut header)) {
+            Ok(()) => &header != PLAIN_HEADER,
+            Err(_) => false,
+        }
+    }
+    /// Hand SQLCipher the passphrase, before anything else reads or writes. A plain database
+    /// from before encryption was turned on is encrypted with it in place.
+    pub fn unlock(&mut self, passphrase: &str) -> Result<()> {
+        if !self.is_encrypted()
+            && let Some(path) = self.connection.path().filter(|path| !path.is_empty())
+            && std::fs::metadata(path).is_ok_and(|file| file.len() > 0)
+        {
+            let path = PathBuf::from(path);
+            let encrypted = PathBuf::from(format!("{}.encrypting", path.display()));
+            let _ = std::fs::remove_file(&encrypted);
+            self.connection.execute(
+                "ATTACH DATABASE ?1 AS encrypted KEY ?2",
+                (encrypted.to_string_lossy(), passphrase),
+            )?;
+            self.connection
+                .query_row("SELECT sqlcipher_export('encrypted')", [], |_| Ok(()))?;
+            // the export leaves out the schema version the migrations go by
+            let version: i64 = self
+                .connection
+                .pragma_query_value(None, "user_version", |row| row.get(0))?;
+            self.connection
+                .pragma_update(Some("encrypted"), "user_version", version)?;
+            self.connection.execute("DETACH DATABASE encrypted", [])?;
+            // Windows won't replace a file that's still open
+            self.connection = rusqlite::Connection::open_in_memory()?;
+            std::fs::rename(&encrypted, &path)?;
+            self.connection = rusqlite::Connection::open(&path)?;
+        }
+        self.connection.pragma_update(None, "key", passphrase)?;
+        let readable = self
+            .connection
+            .query_row("SELECT count(*) FROM sqlite_master", [], |_| Ok(()));
+        if readable.is_err() {
+            // a connection keeps the first key it's given, so the next try needs a fresh one
+            if let Some(path) = self
+                .connection
+                .path()
+                .filter(|path| !path.is_empty())
+                .map(PathBuf::from)
+            {
+                self.connection = rusqlite::Connection::open(path)?;
+            }
+            return Err(eyre!("Wrong passphrase"));
+        }
         Ok(())
     }
+    /// Create the schema, or migrate an existing database up to the current one.
+    pub fn create(&self) -> Result<()> {
+        migrations::run(&self.connection)
+    }
     pub fn connection(&self) -> &rusqlite::Connection {
         &self.connection
     }
+    /// Register a sender that receives an `Action::JobChanged` for every write made through
+    /// this database. Each write is also recorded in the activity log.
+    pub fn register_change_handler(&mut self, tx: UnboundedSender<Action>) {
+        self.change_tx = Some(tx);
+    }
+    /// Pass on a write, with the application as it was before an update so the activity log
+    /// can record which fields changed.
+    pub fn notify(&self, change: JobChange, previous: Option<&JobApplication>) {
+        self.invalidate();
+        query::log_activity(&change, previous, self);
+        if let Some(held) = self.held.borrow_mut().as_mut() {
+            held.push(change);
+            return;
+        }
+        if let Some(tx) = &self.change_tx {
+            let _ = tx.send(Action::JobChanged(change));
+        }
+    }
+    /// Hold back the changes of writes from here on until `release_changes`, so the interface
+    /// never shows a change whose transaction goes on to be rolled back.
+    pub fn hold_changes(&self) {
+        *self.held.borrow_mut() = Some(Vec::new());
+    }
+    /// Send the changes held since `hold_changes` when their transaction was committed, or
+    /// drop them when it was rolled back.
+    pub fn release_changes(&self, committed: bool) {
+        let held = self.held.borrow_mut().take().unwrap_or_default();
+        if let (true, Some(tx)) = (committed, &self.change_tx) {
+            for change in held {
+                let _ = tx.send(Action::JobChanged(change));
+            }
+        }
+    }
+    /// Warn of a failure that was recovered from, e.g. a query that read as empty because it
+    /// couldn't run. Without a change handler, as outside the interface, it's only logged.
+    pub fn report(&self, message: String) {
+        match &self.change_tx {
+            Some(tx) => {
+                let _ = tx.send(Action::Warning(message));
+            }
+            None => tracing::error!("{message}"),
+        }
+    }
+    /// The value in `slot` of the query cache, loading it first if there is none.
+    pub fn cached<T: Clone>(
+        &self,
+        slot: fn(&mut QueryCache) -> &mut Option<T>,
+        load: impl FnOnce() -> T,
+    ) -> T {
+        if let Some(value) = slot(&mut self.cache.borrow_mut()) {
+            return value.clone();
+        }
+        let value = load();
+        *slot(&mut self.cache.borrow_mut()) = Some(value.clone());
+        value
+    }
+    /// Drop every cached read, after a write or when another process may have written.
+    pub fn invalidate(&self) {
+        *self.cache.borrow_mut() = QueryCache::default();
+    }
 }
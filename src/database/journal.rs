@@ -0,0 +1,88 @@
+//! Write-ahead journal for database writes. A write is recorded beside the database before it
+//! runs and cleared once its transaction commits, so a journal left behind means the last run
+//! stopped part-way through one. SQLite rolls back the uncommitted transaction when the
+//! database is next opened; the journal says what that write was, to offer running it again.
+
+use std::{
+    fs::File,
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use color_eyre::Result;
+
+use crate::database::{db::Database, worker::DbRequest};
+
+#[derive(Debug, Clone)]
+pub struct Journal {
+    path: PathBuf,
+}
+
+impl Journal {
+    /// The journal beside the database's file, or `None` for an in-memory database, which
+    /// has nothing to recover.
+    pub fn for_database(db: &Database) -> Option<Self> {
+        let db_path = db.connection().path().filter(|path| !path.is_empty())?;
+        Some(Self {
+            path: PathBuf::from(format!("{db_path}.pending")),
+        })
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// The write a previous run didn't finish, if any. A journal too damaged to read was cut
+    /// short while being written, before its write started, so there is nothing to offer.
+    pub fn pending(&self) -> Option<DbRequest> {
+        let contents = std::fs::read_to_string(&self.path).ok()?;
+        serde_json::from_str(&contents).ok()
+    }
+
+    /// Record the write about to run, synced to disk before returning.
+    pub fn begin(&self, request: &DbRequest) -> Result<()> {
+        let mut file = File::create(&self.path)?;
+        serde_json::to_writer(&mut file, request)?;
+        file.flush()?;
+        file.sync_all()?;
+        Ok(())
+    }
+
+    /// Clear the journal once its write is committed, or given up on.
+    pub fn finish(&self) -> Result<()> {
+        match std::fs::remove_file(&self.path) {
+            Err(err) if err.kind() != std::io::ErrorKind::NotFound => Err(err.into()),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// What an interrupted write was doing, e.g. "importing jobs.csv", to ask about it.
+pub fn describe(request: &DbRequest) -> String {
+    let job = |job: &crate::database::schema::JobApplication| {
+        format!("{} - {}", job.company_name, job.position)
+    };
+    match request {
+        DbRequest::SaveJob(saved) | DbRequest::SaveStatusChange(saved, _) => {
+            format!("saving {}", job(saved))
+        }
+        DbRequest::DeleteJob(id) => format!("deleting application {id}"),
+        DbRequest::Import(path) => format!("importing {}", path.display()),
+        DbRequest::Restore(path) => format!("restoring {}", path.display()),
+        DbRequest::SetStatus(ids, status) => {
+            format!("moving {} application(s) to {}", ids.len(), status)
+        }
+        DbRequest::Recategorize(ids, category) => {
+            format!("moving {} application(s) to {category}", ids.len())
+        }
+        DbRequest::RenameTag(tag, to) => format!("renaming tag \"{tag}\" to \"{to}\""),
+        DbRequest::DeleteTag(tag) => format!("removing tag \"{tag}\""),
+        DbRequest::ResolveConflict(..) => "resolving an import conflict".into(),
+        DbRequest::SetFollowUp(..) => "changing a follow-up".into(),
+        DbRequest::SaveInterview(_) => "saving an interview".into(),
+        DbRequest::SaveExpense(_) | DbRequest::DeleteExpense(_) => "changing an expense".into(),
+        DbRequest::SaveReminder(_) | DbRequest::DeleteReminder(_) => "changing a reminder".into(),
+        DbRequest::SaveContact(_) | DbRequest::DeleteContact(_) => "changing a contact".into(),
+        _ => "writing to the database".into(),
+    }
+}
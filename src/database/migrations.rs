@@ -0,0 +1,531 @@
+use std::str::FromStr;
+
+use color_eyre::{Result, eyre::eyre};
+use rusqlite::{Connection, params};
+use uuid::Uuid;
+
+use crate::database::schema::Files;
+
+type Migration = fn(&Connection) -> rusqlite::Result<()>;
+
+/// Every schema change, oldest first. A database's `user_version` is the number of steps
+/// already applied to it, so steps must only ever be appended, never edited or reordered.
+///
+/// Databases from before versioning report version 0 but may already have some of these
+/// tables, which is why the early steps tolerate what they create already existing.
+const MIGRATIONS: [Migration; 23] = [
+    create_job_applications,
+    add_description,
+    create_status_history,
+    create_interviews,
+    create_expenses,
+    create_reminders,
+    move_files_to_table,
+    add_compensation,
+    create_conflicts,
+    move_contacts_to_table,
+    add_uuids,
+    add_follow_up_dates,
+    add_status_notes,
+    add_last_updated,
+    create_statuses,
+    create_activity,
+    create_application_tags,
+    add_deadlines,
+    add_saved_status,
+    add_created_at,
+    create_drafts,
+    add_ratings,
+    create_questions,
+];
+
+/// The schema version this build expects.
+pub const CURRENT_VERSION: u32 = MIGRATIONS.len() as u32;
+
+/// Bring the database up to `CURRENT_VERSION`, applying each outstanding step in its own
+/// transaction so a failure leaves it at the last good version.
+pub fn run(connection: &Connection) -> Result<()> {
+    let version = version(connection)?;
+    if version > CURRENT_VERSION {
+        return Err(eyre!(
+            "database schema is version {version}, newer than this build supports ({CURRENT_VERSION})"
+        ));
+    }
+    for (step, migration) in MIGRATIONS.iter().enumerate().skip(version as usize) {
+        let transaction = connection.unchecked_transaction()?;
+        migration(&transaction)
+            .map_err(|err| eyre!("migration to schema version {} failed: {err}", step + 1))?;
+        transaction.pragma_update(None, "user_version", step as u32 + 1)?;
+        transaction.commit()?;
+    }
+    Ok(())
+}
+
+pub fn version(connection: &Connection) -> rusqlite::Result<u32> {
+    connection.pragma_query_value(None, "user_version", |row| row.get(0))
+}
+
+fn create_job_applications(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS job_applications (
+                id INTEGER PRIMARY KEY,
+                company_name TEXT NOT NULL,
+                position TEXT NOT NULL,
+                position_category TEXT NOT NULL,
+                work_type TEXT NOT NULL,
+                location TEXT NOT NULL,
+                location_type TEXT NOT NULL,
+                application_date TEXT NOT NULL,
+                status TEXT NOT NULL,
+                is_active BOOLEAN NOT NULL,
+                notes TEXT,
+                contact_info TEXT,
+                url TEXT,
+                files TEXT NOT NULL
+            );
+        ",
+    )
+}
+
+fn add_description(connection: &Connection) -> rusqlite::Result<()> {
+    let has_description = connection
+        .prepare("SELECT 1 FROM pragma_table_info('job_applications') WHERE name = 'description'")?
+        .exists([])?;
+    if !has_description {
+        connection.execute(
+            "ALTER TABLE job_applications ADD COLUMN description TEXT",
+            [],
+        )?;
+    }
+    Ok(())
+}
+
+fn create_status_history(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS status_history (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                status TEXT NOT NULL,
+                changed_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+            CREATE INDEX IF NOT EXISTS status_history_job_id ON status_history (job_id);
+        ",
+    )
+}
+
+fn create_interviews(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS interviews (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                starts_at TEXT NOT NULL,
+                duration_minutes INTEGER NOT NULL,
+                notes TEXT NOT NULL
+            );
+        ",
+    )
+}
+
+fn create_expenses(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS expenses (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER,
+                date TEXT NOT NULL,
+                category TEXT NOT NULL,
+                description TEXT NOT NULL,
+                amount_cents INTEGER NOT NULL
+            );
+        ",
+    )
+}
+
+fn create_reminders(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS reminders (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                due_at TEXT NOT NULL,
+                message TEXT NOT NULL,
+                recurrence TEXT NOT NULL,
+                done BOOLEAN NOT NULL DEFAULT 0
+            );
+        ",
+    )
+}
+
+// Documents used to be a single comma-joined column, which broke paths containing commas.
+// The old column stays, emptied, since dropping columns needs a newer SQLite.
+fn move_files_to_table(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS application_files (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                kind TEXT NOT NULL,
+                path TEXT NOT NULL,
+                position INTEGER NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS application_files_job_id ON application_files (job_id);
+        ",
+    )?;
+    let jobs = connection
+        .prepare("SELECT id, files FROM job_applications")?
+        .query_map([], |row| {
+            Ok((row.get::<_, i32>(0)?, row.get::<_, Option<String>>(1)?))
+        })?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    let mut insert = connection.prepare(
+        "INSERT INTO application_files (job_id, kind, path, position) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (job_id, files) in jobs {
+        let files = Files::from_str(&files.unwrap_or_default()).unwrap_or_default();
+        for (position, (kind, path)) in files.entries().into_iter().enumerate() {
+            insert.execute(params![job_id, kind, path, position])?;
+        }
+    }
+    connection.execute("UPDATE job_applications SET files = ''", [])?;
+    Ok(())
+}
+
+fn add_compensation(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            ALTER TABLE job_applications ADD COLUMN salary_min INTEGER;
+            ALTER TABLE job_applications ADD COLUMN salary_max INTEGER;
+            ALTER TABLE job_applications ADD COLUMN currency TEXT;
+            ALTER TABLE job_applications ADD COLUMN compensation_notes TEXT;
+        ",
+    )
+}
+
+fn create_conflicts(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS conflicts (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                incoming TEXT NOT NULL,
+                source TEXT NOT NULL,
+                created_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+        ",
+    )
+}
+
+// The free-text contact becomes the name of the application's first contact. The old
+// column stays, emptied, as with documents.
+fn move_contacts_to_table(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS contacts (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                name TEXT NOT NULL,
+                role TEXT NOT NULL,
+                email TEXT NOT NULL,
+                phone TEXT NOT NULL,
+                linkedin TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS contacts_job_id ON contacts (job_id);
+            INSERT INTO contacts (job_id, name, role, email, phone, linkedin)
+                SELECT id, trim(contact_info), '', '', '', '' FROM job_applications
+                WHERE trim(coalesce(contact_info, '')) != '';
+            UPDATE job_applications SET contact_info = NULL;
+        ",
+    )
+}
+
+// Existing applications get a fresh UUID each; new ones are given theirs when inserted.
+fn add_uuids(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch("ALTER TABLE job_applications ADD COLUMN uuid TEXT;")?;
+    let ids = connection
+        .prepare("SELECT id FROM job_applications")?
+        .query_map([], |row| row.get::<_, i32>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    for id in ids {
+        connection.execute(
+            "UPDATE job_applications SET uuid = ?1 WHERE id = ?2",
+            params![Uuid::new_v4().to_string(), id],
+        )?;
+    }
+    connection.execute_batch(
+        "CREATE UNIQUE INDEX IF NOT EXISTS job_applications_uuid ON job_applications (uuid);",
+    )
+}
+
+// applications still waiting on a reply are due a follow-up a week after applying, the
+// default wait before this was stored per application
+fn add_follow_up_dates(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            ALTER TABLE job_applications ADD COLUMN follow_up_date TEXT;
+            UPDATE job_applications SET follow_up_date = date(application_date, '+7 days')
+                WHERE status = 'Applied' AND is_active;
+        ",
+    )
+}
+
+fn add_status_notes(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch("ALTER TABLE status_history ADD COLUMN note TEXT;")
+}
+
+// existing applications were last touched when their status last changed, as far as is known,
+// or the day they were applied to; like every timestamp, in UTC
+fn add_last_updated(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            ALTER TABLE job_applications ADD COLUMN last_updated TEXT;
+            UPDATE job_applications SET last_updated = COALESCE(
+                (SELECT MAX(changed_at) FROM status_history WHERE job_id = job_applications.id),
+                datetime(application_date)
+            );
+        ",
+    )
+}
+
+// the built-in statuses are listed too, so their colours can be changed like any other
+fn create_statuses(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS statuses (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                name TEXT NOT NULL UNIQUE,
+                color TEXT NOT NULL,
+                is_active BOOLEAN NOT NULL DEFAULT 1
+            );
+            INSERT INTO statuses (name, color, is_active) VALUES
+                ('Applied', 'cyan', 1),
+                ('Phone Screen', 'light_cyan', 1),
+                ('Interviewing', 'blue', 1),
+                ('Technical Interview', 'light_blue', 1),
+                ('Offered', 'green', 1),
+                ('Rejected', 'red', 0),
+                ('Withdrawn', 'magenta', 0),
+                ('Accepted', 'blue', 0),
+                ('Ghosted', 'dark_gray', 0);
+        ",
+    )
+}
+
+// the status history is the only record of earlier edits, its first entry being the creation
+fn create_activity(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS activity (
+                id INTEGER PRIMARY KEY AUTOINCREMENT,
+                job_id INTEGER NOT NULL,
+                kind TEXT NOT NULL,
+                at TEXT NOT NULL DEFAULT (datetime('now')),
+                field TEXT,
+                old_value TEXT,
+                new_value TEXT
+            );
+            CREATE INDEX IF NOT EXISTS activity_job_id ON activity (job_id);
+            INSERT INTO activity (job_id, kind, at, field, old_value, new_value)
+                SELECT job_id,
+                    CASE WHEN previous IS NULL THEN 'created' ELSE 'updated' END,
+                    changed_at,
+                    CASE WHEN previous IS NULL THEN NULL ELSE 'status' END,
+                    previous, status
+                FROM (
+                    SELECT id, job_id, status, changed_at,
+                        LAG(status) OVER (PARTITION BY job_id ORDER BY changed_at, id) AS previous
+                    FROM status_history
+                )
+                ORDER BY changed_at, id;
+        ",
+    )
+}
+
+fn create_application_tags(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS application_tags (
+                job_id INTEGER NOT NULL,
+                tag TEXT NOT NULL,
+                PRIMARY KEY (job_id, tag)
+            );
+            CREATE INDEX IF NOT EXISTS application_tags_tag ON application_tags (tag);
+        ",
+    )
+}
+
+fn add_deadlines(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch("ALTER TABLE job_applications ADD COLUMN deadline TEXT;")
+}
+
+// a status of the same name defined before it was built in keeps its colour
+fn add_saved_status(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "INSERT OR IGNORE INTO statuses (name, color, is_active) VALUES ('Saved', 'yellow', 1);",
+    )
+}
+
+// older applications are taken to have been stored when their first status was recorded, or
+// the day they were applied to
+fn add_created_at(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            ALTER TABLE job_applications ADD COLUMN created_at TEXT;
+            UPDATE job_applications SET created_at = COALESCE(
+                (SELECT MIN(changed_at) FROM status_history WHERE job_id = job_applications.id),
+                datetime(application_date)
+            );
+        ",
+    )
+}
+
+// an application's id, or 0 for a new one, with the edit form's fields as a JSON list
+fn create_drafts(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS drafts (
+                job_id INTEGER PRIMARY KEY,
+                fields TEXT NOT NULL,
+                saved_at TEXT NOT NULL DEFAULT (datetime('now'))
+            );
+        ",
+    )
+}
+
+// how much an application appeals, 1 to 5, or NULL when it hasn't been rated
+fn add_ratings(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch("ALTER TABLE job_applications ADD COLUMN rating INTEGER;")
+}
+
+fn create_questions(connection: &Connection) -> rusqlite::Result<()> {
+    connection.execute_batch(
+        "
+            CREATE TABLE IF NOT EXISTS questions (
+                id INTEGER PRIMARY KEY,
+                job_id INTEGER NOT NULL,
+                question TEXT NOT NULL,
+                answer TEXT NOT NULL DEFAULT '',
+                topic TEXT NOT NULL DEFAULT '',
+                asked_at TEXT NOT NULL
+            );
+            CREATE INDEX IF NOT EXISTS questions_job ON questions (job_id);
+        ",
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::{db::Database, query};
+
+    // a database from before versioning: documents comma-joined, the contact free text and
+    // the status history without notes
+    const LEGACY_SCHEMA: &str = "
+        CREATE TABLE job_applications (
+            id INTEGER PRIMARY KEY,
+            company_name TEXT NOT NULL,
+            position TEXT NOT NULL,
+            position_category TEXT NOT NULL,
+            work_type TEXT NOT NULL,
+            location TEXT NOT NULL,
+            location_type TEXT NOT NULL,
+            application_date TEXT NOT NULL,
+            status TEXT NOT NULL,
+            is_active BOOLEAN NOT NULL,
+            notes TEXT,
+            contact_info TEXT,
+            url TEXT,
+            files TEXT NOT NULL
+        );
+        CREATE TABLE status_history (
+            id INTEGER PRIMARY KEY,
+            job_id INTEGER NOT NULL,
+            status TEXT NOT NULL,
+            changed_at TEXT NOT NULL DEFAULT (datetime('now'))
+        );
+        INSERT INTO job_applications VALUES
+            (1, 'Acme', 'Backend Engineer', 'Engineering', 'Full Time', 'London', 'Hybrid',
+                '2024-03-01', 'Interviewing', 1, 'Good team', ' Sam Smith ', NULL,
+                'cv.pdf,cover.pdf,portfolio.pdf,references.pdf'),
+            (2, 'Initech', 'Designer', 'Design', 'Full Time', 'Austin', 'On Site',
+                '2024-02-10', 'Applied', 1, NULL, '', NULL, '');
+        INSERT INTO status_history (job_id, status, changed_at) VALUES
+            (1, 'Applied', '2024-03-01 09:00:00'),
+            (1, 'Interviewing', '2024-03-08 14:30:00');
+    ";
+
+    /// A database at `path` holding `LEGACY_SCHEMA`, removed again when dropped.
+    struct Fixture(std::path::PathBuf);
+    impl Fixture {
+        fn new() -> (Self, Database) {
+            let path = std::env::temp_dir().join(format!("job-tracker-{}.db", Uuid::new_v4()));
+            let db = Database::new(path.to_str().unwrap()).unwrap();
+            db.connection().execute_batch(LEGACY_SCHEMA).unwrap();
+            (Fixture(path), db)
+        }
+    }
+    impl Drop for Fixture {
+        fn drop(&mut self) {
+            let _ = std::fs::remove_file(&self.0);
+        }
+    }
+
+    #[test]
+    fn migrates_a_legacy_database_forward() {
+        let (_fixture, db) = Fixture::new();
+        run(db.connection()).unwrap();
+        assert_eq!(version(db.connection()).unwrap(), CURRENT_VERSION);
+
+        let acme = query::get_application_by_id(1, &db).unwrap();
+        assert_eq!(acme.contacts.len(), 1);
+        assert_eq!(acme.contacts[0].name, "Sam Smith");
+        assert_eq!(acme.files.cv, "cv.pdf");
+        assert_eq!(acme.files.cover_letter, "cover.pdf");
+        assert_eq!(
+            acme.files.additional_documents,
+            ["portfolio.pdf", "references.pdf"]
+        );
+        assert!(acme.tags.is_empty());
+        assert!(!acme.uuid.is_empty());
+        assert_eq!(acme.created_at.as_deref(), Some("2024-03-01 09:00:00"));
+        assert_eq!(acme.last_updated.as_deref(), Some("2024-03-08 14:30:00"));
+        // the status history is the activity known from before the log
+        let history = query::get_field_changes(1, &db)
+            .into_iter()
+            .map(|change| (change.field, change.old_value, change.new_value))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            history,
+            [
+                (
+                    "status".to_string(),
+                    Some("Applied".to_string()),
+                    Some("Interviewing".to_string())
+                ),
+                ("created".to_string(), None, Some("Applied".to_string())),
+            ]
+        );
+        assert_eq!(query::get_activity(&db).len(), 2);
+
+        // without a status history, both fall back to the day applied
+        let initech = query::get_application_by_id(2, &db).unwrap();
+        assert!(initech.contacts.is_empty());
+        assert_eq!(initech.files.entries(), []);
+        assert_eq!(initech.created_at.as_deref(), Some("2024-02-10 00:00:00"));
+        assert_eq!(initech.last_updated.as_deref(), Some("2024-02-10 00:00:00"));
+        assert_eq!(initech.follow_up_date.as_deref(), Some("2024-02-17"));
+    }
+
+    #[test]
+    fn running_again_changes_nothing() {
+        let (_fixture, db) = Fixture::new();
+        run(db.connection()).unwrap();
+        let before = query::get_all_applications(&db);
+        run(db.connection()).unwrap();
+        assert_eq!(version(db.connection()).unwrap(), CURRENT_VERSION);
+        db.invalidate();
+        assert_eq!(query::get_all_applications(&db), before);
+    }
+}
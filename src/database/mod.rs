@@ -1,3 +1,8 @@
+pub mod builder;
+pub mod cache;
 pub mod db;
+pub mod journal;
+pub mod migrations;
 pub mod query;
 pub mod schema;
+pub mod worker;
@@ -1,7 +1,18 @@
+use crate::action::JobChange;
+use crate::config::SortOrder;
 use crate::database::db::Database;
-use crate::database::schema::{ApplicationStatus, JobApplication, PositionCategory}; // Assuming you have a Db type for your database connection/context
-use color_eyre::Result;
-use rusqlite::{ToSql, params};
+use crate::database::schema::{
+    Activity, ApplicationStatus, Conflict, Contact, Draft, Expense, FieldChange, FileKind, Files,
+    Interview, JobApplication, JobQuery, PositionCategory, PrioritySignals, Question, Reminder,
+    Resolution, Stage, StatusChange, StatusDefinition,
+}; // Assuming you have a Db type for your database connection/context
+use crate::links::{self, NoteLink};
+use crate::postings::Board;
+use color_eyre::{Result, eyre::eyre};
+use rusqlite::{Params, Row, ToSql, params, params_from_iter};
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use uuid::Uuid;
 
 //
 // ---------------
@@ -9,45 +20,213 @@ use rusqlite::{ToSql, params};
 // ---------------
 //
 pub fn get_all_applications(db: &Database) -> Vec<JobApplication> {
+    let mut jobs = read_rows(
+        db,
+        "SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications",
+        [],
+        JobApplication::from_row,
+    );
+    attach_files(jobs.iter_mut(), db);
+    attach_contacts(jobs.iter_mut(), db);
+    attach_tags(jobs.iter_mut(), db);
+    jobs
+}
+
+/// Up to `limit` of the applications matching `query`, from `offset` on in its order, and how
+/// many match in all.
+pub fn get_job_page(
+    query: &JobQuery,
+    offset: usize,
+    limit: usize,
+    db: &Database,
+) -> (usize, Vec<JobApplication>) {
+    let mut values = vec![query.search.clone()];
+    let filter = query.filter.to_sql(&mut values);
+    let stage = match query.stage {
+        Stage::All => "1",
+        Stage::Applications => "status != 'Saved'",
+        Stage::Saved => "status = 'Saved'",
+    };
+    let conditions = format!(
+        "WHERE instr(lower(company_name || ' ' || position || ' ' || location || ' ' || COALESCE(notes, '')), lower(?1)) > 0 AND ({filter}) AND {stage}"
+    );
     let conn = db.connection();
-    let mut stmt = conn
-        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, contact_info, url, files FROM job_applications")
-        .unwrap();
-    let rows = stmt
-        .query_map([], |row| JobApplication::from_row(row))
-        .unwrap();
-    rows.filter_map(Result::ok).collect()
+    let total = conn
+        .query_row(
+            &format!("SELECT COUNT(*) FROM job_applications {conditions}"),
+            params_from_iter(&values),
+            |row| row.get::<_, i64>(0),
+        )
+        .map_or(0, |count| count as usize);
+    let order = match query.sort {
+        // applications synced or restored from elsewhere fall in by when they were first stored
+        SortOrder::Added => "created_at, id".to_string(),
+        SortOrder::Date => "application_date DESC, id".to_string(),
+        SortOrder::Company => "lower(company_name), id".to_string(),
+        // unrated applications and those with no deadline come last
+        SortOrder::Rating => {
+            "rating IS NULL, rating DESC, deadline IS NULL, deadline, id".to_string()
+        }
+        SortOrder::Priority => {
+            // ids ranked earlier are found earlier in the list, e.g. ",7,3,12,"
+            let ranking = query
+                .ranking
+                .iter()
+                .map(|id| id.to_string())
+                .collect::<Vec<_>>()
+                .join(",");
+            values.push(format!(",{ranking},"));
+            let position = format!("instr(?{}, ',' || id || ',')", values.len());
+            format!("{position} = 0, {position}, id")
+        }
+    };
+    let Ok(mut stmt) = conn.prepare(&format!(
+        "SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications {conditions} ORDER BY {order} LIMIT {limit} OFFSET {offset}"
+    )) else {
+        return (total, Vec::new());
+    };
+    let mut jobs = stmt
+        .query_map(params_from_iter(&values), JobApplication::from_row)
+        .map(|rows| rows.filter_map(Result::ok).collect::<Vec<_>>())
+        .unwrap_or_default();
+    attach_files(jobs.iter_mut(), db);
+    attach_contacts(jobs.iter_mut(), db);
+    attach_tags(jobs.iter_mut(), db);
+    (total, jobs)
 }
 
-pub fn get_applications_by_status(status: ApplicationStatus, db: &Database) -> Vec<JobApplication> {
+pub fn get_application_by_company(company_name: &str, db: &Database) -> Option<JobApplication> {
     let conn = db.connection();
     let mut stmt = conn
-        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, contact_info, url, files FROM job_applications WHERE status = ?1")
-        .unwrap();
-    let rows = stmt
-        .query_map(params![status.to_string()], |row| {
-            JobApplication::from_row(row)
+        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE company_name = ?1")
+        .ok()?;
+    let mut job = stmt
+        .query_row(params![company_name], JobApplication::from_row)
+        .ok()?;
+    attach_files([&mut job], db);
+    attach_contacts([&mut job], db);
+    attach_tags([&mut job], db);
+    Some(job)
+}
+
+/// How many active applications there are at the company, ignoring case.
+pub fn count_active_at_company(company_name: &str, db: &Database) -> usize {
+    db.connection()
+        .query_row(
+            "SELECT COUNT(*) FROM job_applications WHERE is_active AND company_name = ?1 COLLATE NOCASE",
+            params![company_name.trim()],
+            |row| row.get::<_, i64>(0),
+        )
+        .map_or(0, |count| count as usize)
+}
+
+/// Applications already stored at `company_name` whose position reads like `position`, e.g.
+/// `Backend Engineer` and `Back-end engineer`, or `Software Engineer` and `Senior Software
+/// Engineer`. Checked before adding an application, in case it was entered before.
+pub fn find_similar_applications(
+    company_name: &str,
+    position: &str,
+    db: &Database,
+) -> Vec<JobApplication> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE company_name = ?1 COLLATE NOCASE ORDER BY application_date DESC")
+    else {
+        return Vec::new();
+    };
+    let Ok(rows) = stmt.query_map(params![company_name.trim()], JobApplication::from_row) else {
+        return Vec::new();
+    };
+    let mut jobs = rows
+        .filter_map(Result::ok)
+        .filter(|job| similar_titles(&job.position, position))
+        .collect::<Vec<_>>();
+    attach_files(jobs.iter_mut(), db);
+    attach_contacts(jobs.iter_mut(), db);
+    attach_tags(jobs.iter_mut(), db);
+    jobs
+}
+
+// lowercase words, with punctuation dropped so `Back-end` and `backend` agree
+fn title_words(title: &str) -> Vec<String> {
+    title
+        .split_whitespace()
+        .map(|word| {
+            word.chars()
+                .filter(|c| c.is_alphanumeric())
+                .flat_map(char::to_lowercase)
+                .collect::<String>()
         })
-        .unwrap();
-    rows.filter_map(Result::ok).collect()
+        .filter(|word| !word.is_empty())
+        .collect()
 }
 
-pub fn get_application_by_company(company_name: &str, db: &Database) -> Option<JobApplication> {
+// one title's words all appear in the other, or the two are a few typos apart
+fn similar_titles(a: &str, b: &str) -> bool {
+    let (a, b) = (title_words(a), title_words(b));
+    if a.is_empty() || b.is_empty() {
+        return a == b;
+    }
+    let (shorter, longer) = if a.len() <= b.len() {
+        (&a, &b)
+    } else {
+        (&b, &a)
+    };
+    if shorter.iter().all(|word| longer.contains(word)) {
+        return true;
+    }
+    let (a, b) = (a.concat(), b.concat());
+    let longest = a.chars().count().max(b.chars().count());
+    levenshtein(&a, &b) * 4 <= longest
+}
+
+fn levenshtein(a: &str, b: &str) -> usize {
+    let b = b.chars().collect::<Vec<_>>();
+    let mut row = (0..=b.len()).collect::<Vec<_>>();
+    for (i, ca) in a.chars().enumerate() {
+        let mut diagonal = row[0];
+        row[0] = i + 1;
+        for (j, cb) in b.iter().enumerate() {
+            let above = row[j + 1];
+            row[j + 1] = (diagonal + usize::from(ca != *cb))
+                .min(above + 1)
+                .min(row[j] + 1);
+            diagonal = above;
+        }
+    }
+    row[b.len()]
+}
+
+pub fn get_application_by_id(application_id: i32, db: &Database) -> Option<JobApplication> {
     let conn = db.connection();
     let mut stmt = conn
-        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, contact_info, url, files FROM job_applications WHERE company_name = ?1")
+        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE id = ?1")
         .ok()?;
-    stmt.query_row(params![company_name], |row| JobApplication::from_row(row))
-        .ok()
+    let mut job = stmt
+        .query_row(params![application_id], JobApplication::from_row)
+        .ok()?;
+    attach_files([&mut job], db);
+    attach_contacts([&mut job], db);
+    attach_tags([&mut job], db);
+    Some(job)
 }
 
-pub fn get_application_by_id(application_id: i32, db: &Database) -> Option<JobApplication> {
+pub fn get_application_by_uuid(uuid: &str, db: &Database) -> Option<JobApplication> {
     let conn = db.connection();
     let mut stmt = conn
-        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, contact_info, url, files FROM job_applications WHERE id = ?1")
+        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE uuid = ?1")
         .ok()?;
-    stmt.query_row(params![application_id], |row| JobApplication::from_row(row))
-        .ok()
+    let mut job = stmt
+        .query_row(params![uuid], JobApplication::from_row)
+        .ok()?;
+    attach_files([&mut job], db);
+    attach_contacts([&mut job], db);
+    attach_tags([&mut job], db);
+    Some(job)
+}
+
+/// An application by UUID, or by id when `key` is a number.
+pub fn find_application(key: &str, db: &Database) -> Option<JobApplication> {
+    get_application_by_uuid(key, db).or_else(|| get_application_by_id(key.parse().ok()?, db))
 }
 
 pub fn get_application_by_position(
@@ -56,12 +235,387 @@ pub fn get_application_by_position(
 ) -> Option<JobApplication> {
     let conn = db.connection();
     let mut stmt = conn
-        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, contact_info, url, files FROM job_applications WHERE position_category = ?1")
+        .prepare("SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE position_category = ?1")
         .ok()?;
-    stmt.query_row(params![position.to_string()], |row| {
-        JobApplication::from_row(row)
+    let mut job = stmt
+        .query_row(params![position.to_string()], |row| {
+            JobApplication::from_row(row)
+        })
+        .ok()?;
+    attach_files([&mut job], db);
+    attach_contacts([&mut job], db);
+    attach_tags([&mut job], db);
+    Some(job)
+}
+
+/// Every write in the activity log, oldest first, with days and weekdays in local time. The
+/// rows of the fields one update changed count as one write.
+pub fn get_activity(db: &Database) -> Vec<Activity> {
+    read_rows(
+        db,
+        "SELECT job_id, kind, date(at, 'localtime'), CAST(strftime('%w', at, 'localtime') AS INTEGER)
+             FROM activity GROUP BY job_id, kind, at ORDER BY at, MIN(id)",
+        [],
+        |row| {
+            Ok(Activity {
+                job_id: row.get(0)?,
+                kind: row.get(1)?,
+                day: row.get(2)?,
+                weekday: row.get(3)?,
+            })
+        },
+    )
+}
+
+/// Every status an application has been in, oldest first.
+/// An application's activity log, latest change first.
+pub fn get_field_changes(job_id: i32, db: &Database) -> Vec<FieldChange> {
+    read_rows(
+        db,
+        "SELECT job_id, COALESCE(field, kind) AS field, old_value, new_value, at AS changed_at
+             FROM activity WHERE job_id = ?1 AND (field IS NOT NULL OR kind = 'created')
+             ORDER BY at DESC, id DESC",
+        params![job_id],
+        FieldChange::from_row,
+    )
+}
+
+pub fn get_status_history(job_id: i32, db: &Database) -> Vec<StatusChange> {
+    read_rows(
+        db,
+        "SELECT job_id, status, changed_at, note FROM status_history WHERE job_id = ?1 ORDER BY changed_at, id",
+        params![job_id],
+        StatusChange::from_row,
+    )
+}
+
+/// What is coming up for each active application, for the priority order.
+pub fn get_priority_signals(db: &Database) -> Vec<PrioritySignals> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT j.id,
+                CAST(julianday(date(j.follow_up_date)) - julianday(date('now', 'localtime')) AS INTEGER),
+                (SELECT CAST(julianday(date(MIN(i.starts_at))) - julianday(date('now', 'localtime')) AS INTEGER)
+                 FROM interviews i WHERE i.job_id = j.id AND i.starts_at >= strftime('%Y-%m-%d %H:%M', 'now', 'localtime')),
+                CAST(julianday(date('now', 'localtime')) - julianday(date(j.last_updated, 'localtime')) AS INTEGER)
+         FROM job_applications j WHERE j.is_active",
+    ) else {
+        return Vec::new();
+    };
+    stmt.query_map([], |row| {
+        Ok(PrioritySignals {
+            job_id: row.get(0)?,
+            follow_up_in: row.get(1)?,
+            interview_in: row.get(2)?,
+            idle_days: row.get(3)?,
+        })
+    })
+    .map(|rows| rows.filter_map(Result::ok).collect())
+    .unwrap_or_default()
+}
+
+/// Every status definition, built-in ones first and the rest in the order they were added.
+pub fn get_statuses(db: &Database) -> Vec<StatusDefinition> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare("SELECT name, color, is_active FROM statuses ORDER BY id")
+    else {
+        return Vec::new();
+    };
+    stmt.query_map([], StatusDefinition::from_row)
+        .map(|rows| rows.filter_map(Result::ok).collect())
+        .unwrap_or_default()
+}
+
+/// Every application's statuses in the order it went through them, keyed by application.
+pub fn get_status_trails(db: &Database) -> HashMap<i32, Vec<ApplicationStatus>> {
+    let rows = read_rows(
+        db,
+        "SELECT job_id, status, changed_at, note FROM status_history ORDER BY job_id, changed_at, id",
+        [],
+        StatusChange::from_row,
+    );
+    let mut trails = HashMap::<i32, Vec<ApplicationStatus>>::new();
+    for change in rows {
+        trails.entry(change.job_id).or_default().push(change.status);
+    }
+    trails
+}
+
+/// Every application's status changes, oldest first, keyed by application.
+pub fn get_status_histories(db: &Database) -> HashMap<i32, Vec<StatusChange>> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT job_id, status, changed_at, note FROM status_history ORDER BY job_id, changed_at, id",
+    ) else {
+        return HashMap::new();
+    };
+    let Ok(rows) = stmt.query_map([], StatusChange::from_row) else {
+        return HashMap::new();
+    };
+    let mut histories = HashMap::<i32, Vec<StatusChange>>::new();
+    for change in rows.filter_map(Result::ok) {
+        histories.entry(change.job_id).or_default().push(change);
+    }
+    histories
+}
+
+pub fn get_expenses(db: &Database) -> Vec<Expense> {
+    read_rows(
+        db,
+        "SELECT id, job_id, date, category, description, amount_cents FROM expenses ORDER BY date DESC, id DESC",
+        [],
+        Expense::from_row,
+    )
+}
+
+/// Total spent per month as `(YYYY-MM, cents)`, oldest month first.
+pub fn get_monthly_expense_totals(db: &Database) -> Vec<(String, i64)> {
+    db.cached(|cache| &mut cache.monthly_expense_totals, || {
+        read_rows(
+            db,
+            "SELECT substr(date, 1, 7) AS month, SUM(amount_cents) FROM expenses GROUP BY month ORDER BY month",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
+    })
+}
+
+/// Applications submitted in the last `days` days, newest first.
+pub fn get_applications_since(days: u32, db: &Database) -> Vec<JobApplication> {
+    let mut jobs = read_rows(
+        db,
+        "SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE application_date >= date('now', ?1) ORDER BY application_date DESC",
+        params![format!("-{days} days")],
+        JobApplication::from_row,
+    );
+    attach_files(jobs.iter_mut(), db);
+    attach_contacts(jobs.iter_mut(), db);
+    attach_tags(jobs.iter_mut(), db);
+    jobs
+}
+
+/// Status changes recorded in the last `days` days along with their application, oldest
+/// first. Initial statuses of newly added applications are left out.
+pub fn get_status_changes_since(days: u32, db: &Database) -> Vec<(JobApplication, StatusChange)> {
+    let mut changes = read_rows(
+        db,
+        "SELECT j.id, j.company_name, j.position, j.position_category, j.work_type, j.location, j.location_type, j.application_date, j.status, j.is_active, j.notes, j.url, j.description, j.salary_min, j.salary_max, j.currency, j.compensation_notes, j.uuid, j.follow_up_date, j.deadline, j.rating, j.created_at, j.last_updated,
+                    h.job_id, h.status AS changed_status, h.changed_at, h.note
+             FROM status_history h JOIN job_applications j ON j.id = h.job_id
+             WHERE h.changed_at >= datetime('now', ?1)
+               AND h.id != (SELECT MIN(id) FROM status_history WHERE job_id = h.job_id)
+             ORDER BY h.changed_at, h.id",
+        params![format!("-{days} days")],
+        |row| {
+            let change = StatusChange {
+                job_id: row.get("job_id")?,
+                status: ApplicationStatus::from(row.get::<_, String>("changed_status")?),
+                changed_at: row.get("changed_at")?,
+                note: row.get("note")?,
+            };
+            Ok((JobApplication::from_row(row)?, change))
+        },
+    );
+    attach_files(changes.iter_mut().map(|(job, _)| job), db);
+    attach_contacts(changes.iter_mut().map(|(job, _)| job), db);
+    attach_tags(changes.iter_mut().map(|(job, _)| job), db);
+    changes
+}
+
+/// Every interview along with the company it is for, ordered by start time.
+pub fn get_interviews(db: &Database) -> Vec<(Interview, String)> {
+    read_rows(
+        db,
+        "SELECT i.id, i.job_id, i.starts_at, i.duration_minutes, i.notes, j.company_name FROM interviews i LEFT JOIN job_applications j ON j.id = i.job_id ORDER BY i.starts_at",
+        [],
+        |row| {
+            let company: Option<String> = row.get("company_name")?;
+            Ok((Interview::from_row(row)?, company.unwrap_or_default()))
+        },
+    )
+}
+
+/// Interviews that haven't started yet, soonest first, with their company.
+pub fn get_upcoming_interviews(db: &Database) -> Vec<(Interview, String)> {
+    read_rows(
+        db,
+        "SELECT i.id, i.job_id, i.starts_at, i.duration_minutes, i.notes, j.company_name FROM interviews i LEFT JOIN job_applications j ON j.id = i.job_id WHERE i.starts_at >= strftime('%Y-%m-%d %H:%M', 'now', 'localtime') ORDER BY i.starts_at",
+        [],
+        |row| {
+            let company: Option<String> = row.get("company_name")?;
+            Ok((Interview::from_row(row)?, company.unwrap_or_default()))
+        },
+    )
+}
+
+/// Interviews starting in the next `days` days, soonest first, with their company.
+pub fn get_interviews_within(days: u32, db: &Database) -> Vec<(Interview, String)> {
+    read_rows(
+        db,
+        "SELECT i.id, i.job_id, i.starts_at, i.duration_minutes, i.notes, j.company_name FROM interviews i LEFT JOIN job_applications j ON j.id = i.job_id WHERE i.starts_at >= strftime('%Y-%m-%d %H:%M', 'now', 'localtime') AND i.starts_at < strftime('%Y-%m-%d %H:%M', 'now', 'localtime', ?1) ORDER BY i.starts_at",
+        params![format!("+{days} days")],
+        |row| {
+            let company: Option<String> = row.get("company_name")?;
+            Ok((Interview::from_row(row)?, company.unwrap_or_default()))
+        },
+    )
+}
+
+/// Reminders that are still pending, soonest first, with their company.
+pub fn get_reminders(db: &Database) -> Vec<(Reminder, String)> {
+    read_rows(
+        db,
+        "SELECT r.id, r.job_id, r.due_at, r.message, r.recurrence, j.company_name FROM reminders r LEFT JOIN job_applications j ON j.id = r.job_id WHERE r.done = 0 ORDER BY r.due_at",
+        [],
+        |row| {
+            let company: Option<String> = row.get("company_name")?;
+            Ok((Reminder::from_row(row)?, company.unwrap_or_default()))
+        },
+    )
+}
+
+/// Every logged interview question with its company, most recently asked first.
+pub fn get_questions(db: &Database) -> Vec<(Question, String)> {
+    read_rows(
+        db,
+        "SELECT q.id, q.job_id, q.question, q.answer, q.topic, q.asked_at, j.company_name FROM questions q LEFT JOIN job_applications j ON j.id = q.job_id ORDER BY q.asked_at DESC, q.id DESC",
+        [],
+        |row| {
+            let company: Option<String> = row.get("company_name")?;
+            Ok((Question::from_row(row)?, company.unwrap_or_default()))
+        },
+    )
+}
+
+pub fn get_contacts(job_id: i32, db: &Database) -> Vec<Contact> {
+    read_rows(
+        db,
+        "SELECT id, job_id, name, role, email, phone, linkedin FROM contacts WHERE job_id = ?1 ORDER BY id",
+        params![job_id],
+        Contact::from_row,
+    )
+}
+
+/// Unresolved conflicts, oldest first. Ones whose stored application has since gone are
+/// left out.
+pub fn get_conflicts(db: &Database) -> Vec<Conflict> {
+    let rows = read_rows(
+        db,
+        "SELECT id, job_id, incoming, source FROM conflicts ORDER BY id",
+        [],
+        |row| {
+            Ok((
+                row.get::<_, i32>("id")?,
+                row.get::<_, i32>("job_id")?,
+                row.get::<_, String>("incoming")?,
+                row.get::<_, String>("source")?,
+            ))
+        },
+    );
+    rows.into_iter()
+        .filter_map(|(id, job_id, incoming, source)| {
+            Some(Conflict {
+                id,
+                local: get_application_by_id(job_id, db)?,
+                incoming: serde_json::from_str(&incoming).ok()?,
+                source,
+            })
+        })
+        .collect()
+}
+
+/// Active applications whose follow-up date has come, longest overdue first.
+pub fn get_overdue_follow_ups(db: &Database) -> Vec<JobApplication> {
+    let mut jobs = read_rows(
+        db,
+        "SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE is_active AND follow_up_date <= date('now', 'localtime') ORDER BY follow_up_date, application_date",
+        [],
+        JobApplication::from_row,
+    );
+    attach_files(jobs.iter_mut(), db);
+    attach_contacts(jobs.iter_mut(), db);
+    attach_tags(jobs.iter_mut(), db);
+    jobs
+}
+
+/// Other applications whose notes link to `job`, by its id or its company.
+pub fn get_backlinks(job: &JobApplication, db: &Database) -> Vec<JobApplication> {
+    let rows = read_rows(
+        db,
+        "SELECT id, company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated FROM job_applications WHERE id != ?1 AND instr(notes, '[[') > 0 ORDER BY application_date DESC",
+        params![job.id],
+        JobApplication::from_row,
+    );
+    rows.into_iter()
+        .filter(|other| {
+            links::find(other.notes.as_deref().unwrap_or_default())
+                .into_iter()
+                .any(|(_, link)| match link {
+                    NoteLink::Application(id) => id == job.id,
+                    NoteLink::Company(company) => company.eq_ignore_ascii_case(&job.company_name),
+                })
+        })
+        .collect()
+}
+
+/// Number of applications in each status.
+pub fn get_status_counts(db: &Database) -> Vec<(ApplicationStatus, u32)> {
+    db.cached(
+        |cache| &mut cache.status_counts,
+        || {
+            let rows = read_rows(
+                db,
+                "SELECT status, COUNT(*) FROM job_applications GROUP BY status",
+                [],
+                |row| Ok((row.get::<_, String>(0)?, row.get(1)?)),
+            );
+            rows.into_iter()
+                .map(|(status, count)| (ApplicationStatus::from(status), count))
+                .collect()
+        },
+    )
+}
+
+/// Applications per week as `(monday, count)`, oldest first, including weeks with none.
+pub fn get_weekly_application_counts(db: &Database) -> Vec<(String, u32)> {
+    db.cached(|cache| &mut cache.weekly_application_counts, || {
+        read_rows(
+            db,
+            "WITH RECURSIVE
+                     applied(week) AS (
+                         SELECT date(application_date, 'weekday 0', '-6 days') FROM job_applications
+                     ),
+                     weeks(start) AS (
+                         SELECT MIN(week) FROM applied
+                         UNION ALL
+                         SELECT date(start, '+7 days') FROM weeks WHERE start < (SELECT MAX(week) FROM applied)
+                     )
+                 SELECT start, (SELECT COUNT(*) FROM applied WHERE week = start)
+                 FROM weeks WHERE start IS NOT NULL",
+            [],
+            |row| Ok((row.get(0)?, row.get(1)?)),
+        )
     })
-    .ok()
+}
+
+/// Mean number of days between applying and being rejected, for applications whose
+/// rejection was recorded in the status history.
+pub fn get_average_days_to_rejection(db: &Database) -> Option<f64> {
+    db.cached(
+        |cache| &mut cache.average_days_to_rejection,
+        || {
+            db.connection()
+                .query_row(
+                    "SELECT AVG(julianday(h.changed_at) - julianday(j.application_date))
+                 FROM status_history h JOIN job_applications j ON j.id = h.job_id
+                 WHERE h.status = ?1",
+                    params![ApplicationStatus::Rejected.to_string()],
+                    |row| row.get(0),
+                )
+                .ok()
+                .flatten()
+        },
+    )
 }
 
 //
@@ -72,9 +626,15 @@ pub fn get_application_by_position(
 
 pub fn add_application(application: JobApplication, db: &Database) -> Result<()> {
     let conn = db.connection();
+    // imports from another database keep the identity they were given there
+    let uuid = if application.uuid.is_empty() {
+        Uuid::new_v4().to_string()
+    } else {
+        application.uuid.clone()
+    };
     conn.execute(
-        "INSERT INTO job_applications (company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, contact_info, url, files)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        "INSERT INTO job_applications (company_name, position, position_category, work_type, location, location_type, application_date, status, is_active, notes, url, files, description, salary_min, salary_max, currency, compensation_notes, uuid, follow_up_date, deadline, rating, created_at, last_updated)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, '', ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, COALESCE(?21, ?22, datetime('now')), COALESCE(?22, datetime('now')))",
         params![
             application.company_name,
             application.position,
@@ -86,18 +646,54 @@ pub fn add_application(application: JobApplication, db: &Database) -> Result<()>
             application.status.to_string(),
             application.is_active,
             application.notes,
-            application.contact_info,
             application.url,
-            application.files,
+            application.description,
+            application.salary_min,
+            application.salary_max,
+            application.currency,
+            application.compensation_notes,
+            uuid,
+            application.follow_up_date,
+            application.deadline,
+            application.rating,
+            application.created_at,
+            application.last_updated,
         ],
     )?;
+    let id = conn.last_insert_rowid() as i32;
+    save_files(id, &application.files, db)?;
+    save_tags(id, &application.tags, db)?;
+    let contacts = application
+        .contacts
+        .iter()
+        .map(|contact| {
+            insert_contact(
+                Contact {
+                    job_id: id,
+                    ..contact.clone()
+                },
+                db,
+            )
+        })
+        .collect::<Result<Vec<_>>>()?;
+    record_status(id, &application.status, db)?;
+    db.notify(
+        JobChange::Created(JobApplication {
+            id,
+            uuid,
+            contacts,
+            ..application
+        }),
+        None,
+    );
     Ok(())
 }
 
 pub fn update_application(application: JobApplication, db: &Database) -> Result<()> {
     let conn = db.connection();
+    let previous = get_application_by_id(application.id, db);
     conn.execute(
-        "UPDATE job_applications SET company_name = ?1, position = ?2, position_category = ?3, work_type = ?4, location = ?5, location_type = ?6, application_date = ?7, status = ?8, is_active = ?9, notes = ?10, contact_info = ?11, url = ?12, files = ?13 WHERE id = ?14",
+        "UPDATE job_applications SET company_name = ?1, position = ?2, position_category = ?3, work_type = ?4, location = ?5, location_type = ?6, application_date = ?7, status = ?8, is_active = ?9, notes = ?10, url = ?11, description = ?12, salary_min = ?13, salary_max = ?14, currency = ?15, compensation_notes = ?16, follow_up_date = ?17, deadline = ?18, rating = ?19, last_updated = datetime('now') WHERE id = ?20",
         params![
             application.company_name,
             application.position,
@@ -109,12 +705,33 @@ pub fn update_application(application: JobApplication, db: &Database) -> Result<
             application.status.to_string(),
             application.is_active,
             application.notes,
-            application.contact_info,
             application.url,
-            application.files,
+            application.description,
+            application.salary_min,
+            application.salary_max,
+            application.currency,
+            application.compensation_notes,
+            application.follow_up_date,
+            application.deadline,
+            application.rating,
             application.id,
         ],
     )?;
+    save_files(application.id, &application.files, db)?;
+    save_tags(application.id, &application.tags, db)?;
+    if previous
+        .as_ref()
+        .is_some_and(|previous| previous.status != application.status)
+    {
+        record_status(application.id, &application.status, db)?;
+    }
+    // contacts are edited on their own, so pass on what is stored rather than a stale copy
+    let contacts = get_contacts(application.id, db);
+    let application = JobApplication {
+        contacts,
+        ..application
+    };
+    db.notify(JobChange::Updated(application), previous.as_ref());
     Ok(())
 }
 
@@ -124,5 +741,759 @@ pub fn delete_application(application_id: i32, db: &Database) -> Result<()> {
         "DELETE FROM job_applications WHERE id = ?1",
         params![application_id],
     )?;
+    conn.execute(
+        "DELETE FROM status_history WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM interviews WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM reminders WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM questions WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM conflicts WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM contacts WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM application_files WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    conn.execute(
+        "DELETE FROM application_tags WHERE job_id = ?1",
+        params![application_id],
+    )?;
+    delete_draft(application_id, db)?;
+    db.notify(JobChange::Deleted(application_id), None);
+    Ok(())
+}
+
+/// File the given applications under `category`, returning how many were changed.
+pub fn set_position_category(
+    application_ids: &[i32],
+    category: &PositionCategory,
+    db: &Database,
+) -> Result<usize> {
+    let conn = db.connection();
+    let mut changed = 0;
+    for id in application_ids {
+        let previous = get_application_by_id(*id, db);
+        changed += conn.execute(
+            "UPDATE job_applications SET position_category = ?1, last_updated = datetime('now') WHERE id = ?2",
+            params![category.to_string(), id],
+        )?;
+        notify_updated(previous, db)?;
+    }
+    Ok(changed)
+}
+
+/// Every tag in use and how many applications have it, most used first.
+pub fn get_tag_counts(db: &Database) -> Vec<(String, u32)> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT tag, COUNT(*) FROM application_tags GROUP BY tag ORDER BY COUNT(*) DESC, tag COLLATE NOCASE",
+    ) else {
+        return Vec::new();
+    };
+    stmt.query_map([], |row| Ok((row.get(0)?, row.get(1)?)))
+        .map(|rows| rows.filter_map(Result::ok).collect())
+        .unwrap_or_default()
+}
+
+/// Rename `tag` to `to` on every application, merging it into `to` where that is already
+/// in use. Returns how many applications were changed.
+pub fn rename_tag(tag: &str, to: &str, db: &Database) -> Result<usize> {
+    let to = to.trim();
+    if to.is_empty() {
+        return Err(eyre!("A tag can't be empty"));
+    }
+    retag(tag, Some(to), db)
+}
+
+/// Take `tag` off every application, returning how many had it.
+pub fn delete_tag(tag: &str, db: &Database) -> Result<usize> {
+    retag(tag, None, db)
+}
+
+// applications that had `tag` are changed together; the database task runs every write in a
+// transaction, so a failure leaves every one as it was
+fn retag(tag: &str, to: Option<&str>, db: &Database) -> Result<usize> {
+    let conn = db.connection();
+    let ids = conn
+        .prepare("SELECT job_id FROM application_tags WHERE tag = ?1")?
+        .query_map(params![tag], |row| row.get::<_, i32>(0))?
+        .collect::<rusqlite::Result<Vec<_>>>()?;
+    let previous = ids
+        .iter()
+        .filter_map(|id| get_application_by_id(*id, db))
+        .collect::<Vec<_>>();
+    if let Some(to) = to {
+        // merged where the application already has the new tag, in any case
+        conn.execute(
+            "INSERT OR IGNORE INTO application_tags (job_id, tag)
+             SELECT job_id, ?2 FROM application_tags t WHERE tag = ?1
+               AND NOT EXISTS (SELECT 1 FROM application_tags o
+                               WHERE o.job_id = t.job_id AND o.tag = ?2 COLLATE NOCASE AND o.tag != ?1)",
+            params![tag, to],
+        )?;
+    }
+    if to != Some(tag) {
+        conn.execute("DELETE FROM application_tags WHERE tag = ?1", params![tag])?;
+    }
+    conn.execute(
+        &format!(
+            "UPDATE job_applications SET last_updated = datetime('now') WHERE id IN ({})",
+            ids.iter()
+                .map(i32::to_string)
+                .collect::<Vec<_>>()
+                .join(", ")
+        ),
+        [],
+    )?;
+    for job in previous {
+        notify_updated(Some(job), db)?;
+    }
+    Ok(ids.len())
+}
+
+/// Ids of active applications not changed in the last `days` days.
+pub fn get_stale_applications(days: u32, db: &Database) -> HashSet<i32> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT id FROM job_applications
+         WHERE is_active AND status != 'Saved'
+           AND last_updated < datetime('now', '-' || ?1 || ' days')",
+    ) else {
+        return HashSet::new();
+    };
+    stmt.query_map(params![days], |row| row.get(0))
+        .map(|rows| rows.filter_map(|id| id.ok()).collect())
+        .unwrap_or_default()
+}
+
+/// Ids and links of active applications whose posting is on a Greenhouse or Lever board, to
+/// check it's still up.
+pub fn get_board_postings(db: &Database) -> Vec<(i32, Board)> {
+    let conn = db.connection();
+    let Ok(mut stmt) = conn.prepare(
+        "SELECT id, url FROM job_applications
+         WHERE is_active AND (url LIKE '%greenhouse.io/%' OR url LIKE '%lever.co/%')",
+    ) else {
+        return Vec::new();
+    };
+    stmt.query_map([], |row| Ok((row.get(0)?, row.get::<_, String>(1)?)))
+        .map(|rows| {
+            rows.filter_map(Result::ok)
+                .filter_map(|(id, url)| Some((id, Board::from_url(&url)?)))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Move the given applications to `status`, returning how many were changed.
+pub fn set_status(
+    application_ids: &[i32],
+    status: &ApplicationStatus,
+    db: &Database,
+) -> Result<usize> {
+    let mut changed = 0;
+    for id in application_ids {
+        let Some(job) = get_application_by_id(*id, db) else {
+            continue;
+        };
+        if job.status != *status {
+            update_application(
+                JobApplication {
+                    status: status.clone(),
+                    is_active: status.is_active(),
+                    ..job
+                },
+                db,
+            )?;
+            changed += 1;
+        }
+    }
+    Ok(changed)
+}
+
+/// Add a status, or change the colour and activeness of one with the same name.
+pub fn save_status(status: &StatusDefinition, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "INSERT INTO statuses (name, color, is_active) VALUES (?1, ?2, ?3)
+         ON CONFLICT (name) DO UPDATE SET color = excluded.color, is_active = excluded.is_active",
+        params![status.name, status.color, status.is_active],
+    )?;
+    Ok(())
+}
+
+/// Remove a user-defined status, returning whether there was one. Applications in it keep it.
+pub fn delete_status(name: &str, db: &Database) -> Result<bool> {
+    if ApplicationStatus::built_in()
+        .iter()
+        .any(|status| status.to_string() == name)
+    {
+        return Err(eyre!("{name} is built in and can't be removed"));
+    }
+    let removed = db
+        .connection()
+        .execute("DELETE FROM statuses WHERE name = ?1", params![name])?;
+    Ok(removed > 0)
+}
+
+/// Replace an application's status timeline, e.g. with one restored from a backup.
+pub fn set_status_history(job_id: i32, history: &[StatusChange], db: &Database) -> Result<()> {
+    let conn = db.connection();
+    conn.execute(
+        "DELETE FROM status_history WHERE job_id = ?1",
+        params![job_id],
+    )?;
+    for change in history {
+        conn.execute(
+            "INSERT INTO status_history (job_id, status, changed_at, note) VALUES (?1, ?2, ?3, ?4)",
+            params![
+                job_id,
+                change.status.to_string(),
+                change.changed_at,
+                change.note
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+/// Set when an application was last changed, e.g. to keep the time a copy synced from
+/// another database was changed there rather than the time of the sync.
+pub fn set_last_updated(job_id: i32, last_updated: &str, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "UPDATE job_applications SET last_updated = ?1 WHERE id = ?2",
+        params![last_updated, job_id],
+    )?;
+    db.invalidate();
+    Ok(())
+}
+
+/// Move an application's follow-up `days` days from today, or clear it once followed up.
+pub fn set_follow_up(job_id: i32, days: Option<u32>, db: &Database) -> Result<()> {
+    let previous = get_application_by_id(job_id, db);
+    db.connection().execute(
+        "UPDATE job_applications SET follow_up_date = CASE WHEN ?2 IS NULL THEN NULL ELSE date('now', 'localtime', '+' || ?2 || ' days') END, last_updated = datetime('now') WHERE id = ?1",
+        params![job_id, days],
+    )?;
+    notify_updated(previous, db)
+}
+
+/// Attach a note to the application's latest status change.
+pub fn set_status_note(job_id: i32, note: &str, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "UPDATE status_history SET note = ?2
+         WHERE id = (SELECT id FROM status_history WHERE job_id = ?1 ORDER BY changed_at DESC, id DESC LIMIT 1)",
+        params![job_id, note],
+    )?;
+    log_detail(job_id, "status note", note, db);
+    Ok(())
+}
+
+pub fn add_interview(interview: Interview, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "INSERT INTO interviews (job_id, starts_at, duration_minutes, notes) VALUES (?1, ?2, ?3, ?4)",
+        params![
+            interview.job_id,
+            interview.starts_at,
+            interview.duration_minutes,
+            interview.notes,
+        ],
+    )?;
+    log_detail(interview.job_id, "interview", &interview.starts_at, db);
+    Ok(())
+}
+
+/// Insert an expense, dated today when no date is given.
+pub fn add_expense(expense: Expense, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "INSERT INTO expenses (job_id, date, category, description, amount_cents)
+         VALUES (?1, COALESCE(NULLIF(?2, ''), date('now', 'localtime')), ?3, ?4, ?5)",
+        params![
+            expense.job_id,
+            expense.date,
+            expense.category.to_string(),
+            expense.description,
+            expense.amount_cents,
+        ],
+    )?;
+    db.invalidate();
+    Ok(())
+}
+
+pub fn delete_expense(expense_id: i32, db: &Database) -> Result<()> {
+    db.connection()
+        .execute("DELETE FROM expenses WHERE id = ?1", params![expense_id])?;
+    db.invalidate();
+    Ok(())
+}
+
+pub fn add_reminder(reminder: Reminder, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "INSERT INTO reminders (job_id, due_at, message, recurrence) VALUES (?1, ?2, ?3, ?4)",
+        params![
+            reminder.job_id,
+            reminder.due_at,
+            reminder.message,
+            reminder.recurrence,
+        ],
+    )?;
+    Ok(())
+}
+
+pub fn delete_reminder(reminder_id: i32, db: &Database) -> Result<()> {
+    db.connection()
+        .execute("DELETE FROM reminders WHERE id = ?1", params![reminder_id])?;
+    Ok(())
+}
+
+/// Add a question, or update it when it has an id.
+pub fn save_question(question: Question, db: &Database) -> Result<()> {
+    let conn = db.connection();
+    if question.id == 0 {
+        conn.execute(
+            "INSERT INTO questions (job_id, question, answer, topic, asked_at) VALUES (?1, ?2, ?3, ?4, COALESCE(NULLIF(?5, ''), date('now', 'localtime')))",
+            params![
+                question.job_id,
+                question.question,
+                question.answer,
+                question.topic,
+                question.asked_at,
+            ],
+        )?;
+    } else {
+        conn.execute(
+            "UPDATE questions SET question = ?1, answer = ?2, topic = ?3, asked_at = COALESCE(NULLIF(?4, ''), asked_at) WHERE id = ?5",
+            params![
+                question.question,
+                question.answer,
+                question.topic,
+                question.asked_at,
+                question.id,
+            ],
+        )?;
+    }
+    Ok(())
+}
+
+pub fn delete_question(question_id: i32, db: &Database) -> Result<()> {
+    db.connection()
+        .execute("DELETE FROM questions WHERE id = ?1", params![question_id])?;
+    Ok(())
+}
+
+/// Add or update a contact, returning it as stored.
+pub fn save_contact(contact: Contact, db: &Database) -> Result<Contact> {
+    let previous = get_application_by_id(contact.job_id, db);
+    let contact = if contact.id == 0 {
+        insert_contact(contact, db)?
+    } else {
+        db.connection().execute(
+            "UPDATE contacts SET name = ?1, role = ?2, email = ?3, phone = ?4, linkedin = ?5 WHERE id = ?6",
+            params![
+                contact.name,
+                contact.role,
+                contact.email,
+                contact.phone,
+                contact.linkedin,
+                contact.id,
+            ],
+        )?;
+        contact
+    };
+    notify_updated(previous, db)?;
+    Ok(contact)
+}
+
+pub fn delete_contact(contact: &Contact, db: &Database) -> Result<()> {
+    let previous = get_application_by_id(contact.job_id, db);
+    db.connection()
+        .execute("DELETE FROM contacts WHERE id = ?1", params![contact.id])?;
+    notify_updated(previous, db)
+}
+
+/// Hold `incoming` back as a conflict with the stored application `job_id`. Returns whether
+/// it was added, which it isn't when the same copy is already waiting to be resolved.
+pub fn add_conflict(
+    job_id: i32,
+    incoming: &JobApplication,
+    source: &str,
+    db: &Database,
+) -> Result<bool> {
+    let incoming = serde_json::to_string(incoming)?;
+    let added = db.connection().execute(
+        "INSERT INTO conflicts (job_id, incoming, source)
+         SELECT ?1, ?2, ?3 WHERE NOT EXISTS (SELECT 1 FROM conflicts WHERE job_id = ?1 AND incoming = ?2)",
+        params![job_id, incoming, source],
+    )?;
+    Ok(added > 0)
+}
+
+/// Keep the edit form's fields for the application, replacing any earlier draft of it.
+pub fn save_draft(job_id: i32, fields: &[String], db: &Database) -> Result<()> {
+    db.connection().execute(
+        "INSERT INTO drafts (job_id, fields, saved_at) VALUES (?1, ?2, datetime('now'))
+         ON CONFLICT(job_id) DO UPDATE SET fields = excluded.fields, saved_at = excluded.saved_at",
+        params![job_id, serde_json::to_string(fields)?],
+    )?;
+    Ok(())
+}
+
+pub fn get_draft(job_id: i32, db: &Database) -> Option<Draft> {
+    db.connection()
+        .query_row(
+            "SELECT fields, saved_at FROM drafts WHERE job_id = ?1",
+            params![job_id],
+            |row| Ok((row.get::<_, String>(0)?, row.get::<_, String>(1)?)),
+        )
+        .ok()
+        .and_then(|(fields, saved_at)| {
+            Some(Draft {
+                job_id,
+                fields: serde_json::from_str(&fields).ok()?,
+                saved_at,
+            })
+        })
+}
+
+pub fn delete_draft(job_id: i32, db: &Database) -> Result<()> {
+    db.connection()
+        .execute("DELETE FROM drafts WHERE job_id = ?1", params![job_id])?;
+    Ok(())
+}
+
+pub fn resolve_conflict(conflict_id: i32, resolution: Resolution, db: &Database) -> Result<()> {
+    let incoming: Option<String> = db
+        .connection()
+        .query_row(
+            "SELECT incoming FROM conflicts WHERE id = ?1",
+            params![conflict_id],
+            |row| row.get(0),
+        )
+        .ok();
+    match resolution {
+        Resolution::KeepBoth => {
+            if let Some(incoming) = incoming {
+                let incoming: JobApplication = serde_json::from_str(&incoming)?;
+                // a separate application now, so it can't share the stored one's identity
+                add_application(
+                    JobApplication {
+                        id: 0,
+                        uuid: String::new(),
+                        ..incoming
+                    },
+                    db,
+                )?;
+            }
+        }
+        Resolution::Merge(job) => {
+            // contacts from both copies are kept, skipping people already stored
+            if let Some(incoming) = incoming {
+                let incoming: JobApplication = serde_json::from_str(&incoming)?;
+                for contact in incoming.contacts {
+                    if !job.contacts.iter().any(|c| c.same_person(&contact)) {
+                        insert_contact(
+                            Contact {
+                                job_id: job.id,
+                                ..contact
+                            },
+                            db,
+                        )?;
+                    }
+                }
+            }
+            update_application(*job, db)?
+        }
+        Resolution::Discard => {}
+    }
+    db.connection()
+        .execute("DELETE FROM conflicts WHERE id = ?1", params![conflict_id])?;
+    Ok(())
+}
+
+/// Pending reminders that have come due, as they were before firing. Recurring ones are moved
+/// on to their next occurrence in the future and the rest are marked done.
+pub fn fire_due_reminders(db: &Database) -> Result<Vec<(Reminder, String)>> {
+    let now = now(db);
+    let due = get_reminders(db)
+        .into_iter()
+        .take_while(|(reminder, _)| reminder.due_at <= now)
+        .collect::<Vec<_>>();
+    let conn = db.connection();
+    for (reminder, _) in &due {
+        match reminder.recurrence.interval() {
+            // skips occurrences missed while the app wasn't running
+            Some(interval) => while conn.execute(
+                "UPDATE reminders SET due_at = strftime('%Y-%m-%d %H:%M', due_at, ?1) WHERE id = ?2 AND due_at <= strftime('%Y-%m-%d %H:%M', 'now', 'localtime')",
+                params![interval, reminder.id],
+            )? > 0 {},
+            None => {
+                conn.execute(
+                    "UPDATE reminders SET done = 1 WHERE id = ?1",
+                    params![reminder.id],
+                )?;
+            }
+        }
+    }
+    Ok(due)
+}
+
+/// Local time in the `YYYY-MM-DD HH:MM` format used for interviews and reminders.
+pub fn now(db: &Database) -> String {
+    db.connection()
+        .query_row(
+            "SELECT strftime('%Y-%m-%d %H:%M', 'now', 'localtime')",
+            [],
+            |row| row.get(0),
+        )
+        .unwrap_or_default()
+}
+
+/// Replace an application's documents with `files`.
+fn save_files(job_id: i32, files: &Files, db: &Database) -> Result<()> {
+    let conn = db.connection();
+    conn.execute(
+        "DELETE FROM application_files WHERE job_id = ?1",
+        params![job_id],
+    )?;
+    let mut insert = conn.prepare(
+        "INSERT INTO application_files (job_id, kind, path, position) VALUES (?1, ?2, ?3, ?4)",
+    )?;
+    for (position, (kind, path)) in files.entries().into_iter().enumerate() {
+        insert.execute(params![job_id, kind, path, position])?;
+    }
+    Ok(())
+}
+
+/// Rows of `sql` read with `from_row`, leaving out any that can't be read. A query that fails
+/// outright is reported and reads as no rows, so one bad table doesn't take the app down.
+fn read_rows<T>(
+    db: &Database,
+    sql: &str,
+    params: impl Params,
+    from_row: impl FnMut(&Row) -> rusqlite::Result<T>,
+) -> Vec<T> {
+    let rows = db.connection().prepare(sql).and_then(|mut stmt| {
+        stmt.query_map(params, from_row)
+            .map(|rows| rows.filter_map(Result::ok).collect())
+    });
+    rows.unwrap_or_else(|err| {
+        db.report(format!("Failed to read from the database: {err}"));
+        Vec::new()
+    })
+}
+
+// Documents live in `application_files`, so fill them in on applications read from
+// `job_applications`.
+fn attach_files<'a>(jobs: impl IntoIterator<Item = &'a mut JobApplication>, db: &Database) {
+    let mut jobs = jobs.into_iter().collect::<Vec<_>>();
+    if jobs.is_empty() {
+        return;
+    }
+    let ids = jobs
+        .iter()
+        .map(|job| job.id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let rows = read_rows(
+        db,
+        &format!(
+            "SELECT job_id, kind, path FROM application_files WHERE job_id IN ({ids}) ORDER BY job_id, position"
+        ),
+        [],
+        |row| {
+            Ok((
+                row.get::<_, i32>("job_id")?,
+                row.get::<_, String>("kind")?,
+                row.get::<_, String>("path")?,
+            ))
+        },
+    );
+    let mut files = HashMap::<i32, Files>::new();
+    for (job_id, kind, path) in rows {
+        if let Ok(kind) = FileKind::from_str(&kind) {
+            files.entry(job_id).or_default().push(kind, path);
+        }
+    }
+    for job in jobs.iter_mut() {
+        job.files = files.remove(&job.id).unwrap_or_default();
+    }
+}
+
+/// Replace an application's tags with `tags`, dropping repeats that differ only in case.
+fn save_tags(job_id: i32, tags: &[String], db: &Database) -> Result<()> {
+    let conn = db.connection();
+    conn.execute(
+        "DELETE FROM application_tags WHERE job_id = ?1",
+        params![job_id],
+    )?;
+    let mut seen = HashSet::new();
+    let mut insert = conn.prepare("INSERT INTO application_tags (job_id, tag) VALUES (?1, ?2)")?;
+    for tag in tags.iter().map(|tag| tag.trim()) {
+        if !tag.is_empty() && seen.insert(tag.to_lowercase()) {
+            insert.execute(params![job_id, tag])?;
+        }
+    }
+    Ok(())
+}
+
+// Tags live in `application_tags`, loaded the same way as documents
+fn attach_tags<'a>(jobs: impl IntoIterator<Item = &'a mut JobApplication>, db: &Database) {
+    let mut jobs = jobs.into_iter().collect::<Vec<_>>();
+    if jobs.is_empty() {
+        return;
+    }
+    let ids = jobs
+        .iter()
+        .map(|job| job.id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let rows = read_rows(
+        db,
+        &format!(
+            "SELECT job_id, tag FROM application_tags WHERE job_id IN ({ids}) ORDER BY job_id, tag COLLATE NOCASE"
+        ),
+        [],
+        |row| Ok((row.get::<_, i32>("job_id")?, row.get::<_, String>("tag")?)),
+    );
+    let mut tags = HashMap::<i32, Vec<String>>::new();
+    for (job_id, tag) in rows {
+        tags.entry(job_id).or_default().push(tag);
+    }
+    for job in jobs.iter_mut() {
+        job.tags = tags.remove(&job.id).unwrap_or_default();
+    }
+}
+
+// Contacts live in `contacts`, loaded the same way as documents
+fn attach_contacts<'a>(jobs: impl IntoIterator<Item = &'a mut JobApplication>, db: &Database) {
+    let mut jobs = jobs.into_iter().collect::<Vec<_>>();
+    if jobs.is_empty() {
+        return;
+    }
+    let ids = jobs
+        .iter()
+        .map(|job| job.id.to_string())
+        .collect::<Vec<_>>()
+        .join(", ");
+    let rows = read_rows(
+        db,
+        &format!(
+            "SELECT id, job_id, name, role, email, phone, linkedin FROM contacts WHERE job_id IN ({ids}) ORDER BY job_id, id"
+        ),
+        [],
+        Contact::from_row,
+    );
+    let mut contacts = HashMap::<i32, Vec<Contact>>::new();
+    for contact in rows {
+        contacts.entry(contact.job_id).or_default().push(contact);
+    }
+    for job in jobs.iter_mut() {
+        job.contacts = contacts.remove(&job.id).unwrap_or_default();
+    }
+}
+
+fn insert_contact(contact: Contact, db: &Database) -> Result<Contact> {
+    let conn = db.connection();
+    conn.execute(
+        "INSERT INTO contacts (job_id, name, role, email, phone, linkedin) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![
+            contact.job_id,
+            contact.name,
+            contact.role,
+            contact.email,
+            contact.phone,
+            contact.linkedin,
+        ],
+    )?;
+    Ok(Contact {
+        id: conn.last_insert_rowid() as i32,
+        ..contact
+    })
+}
+
+// after a write that changed part of `previous`: pass the application on as it is now, so
+// lists showing it pick up the change and the activity log records what changed
+fn notify_updated(previous: Option<JobApplication>, db: &Database) -> Result<()> {
+    let Some(previous) = previous else {
+        return Ok(());
+    };
+    if let Some(job) = get_application_by_id(previous.id, db) {
+        db.notify(JobChange::Updated(job), Some(&previous));
+    }
+    Ok(())
+}
+
+/// Add a write to the activity log: a row for each field an update changed from `previous`,
+/// or a single row for anything else, with empty values stored as NULL. A new application's
+/// row holds the status it was added in. The log only feeds the stats and the history in the
+/// detail view, so failing to record a write isn't worth failing the write over.
+pub fn log_activity(change: &JobChange, previous: Option<&JobApplication>, db: &Database) {
+    let value = |value: String| Some(value).filter(|value| !value.is_empty());
+    let (kind, rows) = match change {
+        JobChange::Created(job) => ("created", vec![(None, None, value(job.status.to_string()))]),
+        JobChange::Updated(job) => {
+            let fields = previous
+                .map(JobApplication::field_values)
+                .unwrap_or_default();
+            let changed = fields
+                .into_iter()
+                .zip(job.field_values())
+                .filter(|((_, old), (_, new))| old != new)
+                .map(|((field, old), (_, new))| (Some(field), value(old), value(new)))
+                .collect::<Vec<_>>();
+            match changed.is_empty() {
+                true => ("updated", vec![(None, None, None)]),
+                false => ("updated", changed),
+            }
+        }
+        JobChange::Deleted(_) => ("deleted", vec![(None, None, None)]),
+    };
+    for (field, old, new) in rows {
+        insert_activity(change.id(), kind, field, old, new, db);
+    }
+}
+
+/// Add something recorded against an application that isn't one of its fields, such as an
+/// interview, to its activity log as an update.
+fn log_detail(job_id: i32, field: &'static str, value: &str, db: &Database) {
+    let value = Some(value.to_string()).filter(|value| !value.is_empty());
+    insert_activity(job_id, "updated", Some(field), None, value, db);
+}
+
+fn insert_activity(
+    job_id: i32,
+    kind: &str,
+    field: Option<&str>,
+    old: Option<String>,
+    new: Option<String>,
+    db: &Database,
+) {
+    if let Err(err) = db.connection().execute(
+        "INSERT INTO activity (job_id, kind, field, old_value, new_value) VALUES (?1, ?2, ?3, ?4, ?5)",
+        params![job_id, kind, field, old, new],
+    ) {
+        tracing::warn!("Failed to record activity for #{job_id}: {err}");
+    }
+}
+
+fn record_status(job_id: i32, status: &ApplicationStatus, db: &Database) -> Result<()> {
+    db.connection().execute(
+        "INSERT INTO status_history (job_id, status) VALUES (?1, ?2)",
+        params![job_id, status.to_string()],
+    )?;
     Ok(())
 }
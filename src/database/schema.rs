@@ -3,18 +3,23 @@ use serde::{Deserialize, Serialize};
 use std::str::FromStr;
 use strum::Display;
 
+use crate::{config::SortOrder, views::Filter};
+
 #[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum PositionCategory {
     Engineering,
     Development,
     Support,
+    #[strum(to_string = "Data Science")]
     DataScience,
     Analyst,
     Design,
 }
 #[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum WorkType {
+    #[strum(to_string = "Full Time")]
     FullTime,
+    #[strum(to_string = "Part Time")]
     PartTime,
     Internship,
     Contract,
@@ -25,36 +30,125 @@ pub enum WorkType {
 #[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
 pub enum LocationType {
     Remote,
+    #[strum(to_string = "On Site")]
     OnSite,
     Hybrid,
 }
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+/// Where an application has got to. Stored by name, so statuses added by the user in the
+/// `statuses` table round-trip as `Custom`.
+#[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
+#[serde(from = "String", into = "String")]
 pub enum ApplicationStatus {
+    // a posting kept to apply to later
+    Saved,
     Applied,
+    #[strum(to_string = "Phone Screen")]
+    PhoneScreen,
     Interviewing,
+    #[strum(to_string = "Technical Interview")]
+    TechnicalInterview,
     Offered,
     Rejected,
     Withdrawn,
     Accepted,
+    // never heard back
+    Ghosted,
+    #[strum(default)]
+    Custom(String),
 }
 
-#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
+pub enum ExpenseCategory {
+    Travel,
+    Certification,
+    #[strum(serialize = "Resume Service")]
+    ResumeService,
+    Other,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Display, Serialize, Deserialize)]
+pub enum Recurrence {
+    #[default]
+    Once,
+    Daily,
+    Weekly,
+    Monthly,
+}
+
+/// Which document an `application_files` row holds.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Display, Serialize, Deserialize)]
+#[strum(serialize_all = "snake_case")]
+pub enum FileKind {
+    Cv,
+    CoverLetter,
+    Additional,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
 pub struct Files {
     pub cv: String,
     pub cover_letter: String,
     pub additional_documents: Vec<String>,
 }
-impl ToSql for Files {
-    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
-        Ok(rusqlite::types::ToSqlOutput::from(
-            self.cv.clone() + "," + &self.cover_letter + "," + &self.additional_documents.join(","),
-        ))
+impl Files {
+    /// Every document that is set, as stored in `application_files`.
+    pub fn entries(&self) -> Vec<(FileKind, &str)> {
+        let mut entries = vec![
+            (FileKind::Cv, self.cv.as_str()),
+            (FileKind::CoverLetter, self.cover_letter.as_str()),
+        ];
+        entries.extend(
+            self.additional_documents
+                .iter()
+                .map(|path| (FileKind::Additional, path.as_str())),
+        );
+        entries.retain(|(_, path)| !path.is_empty());
+        entries
+    }
+    pub fn push(&mut self, kind: FileKind, path: String) {
+        match kind {
+            FileKind::Cv => self.cv = path,
+            FileKind::CoverLetter => self.cover_letter = path,
+            FileKind::Additional => self.additional_documents.push(path),
+        }
+    }
+}
+impl FromStr for Files {
+    type Err = ();
+
+    // Example: "cv.pdf,cover_letter.pdf,doc1.pdf,doc2.pdf"
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let parts = s
+            .split(',')
+            .map(|s| s.trim().to_string())
+            .collect::<Vec<_>>();
+        Ok(Files {
+            cv: parts.first().cloned().unwrap_or_default(),
+            cover_letter: parts.get(1).cloned().unwrap_or_default(),
+            additional_documents: parts
+                .iter()
+                .skip(2)
+                .filter(|s| !s.is_empty())
+                .cloned()
+                .collect(),
+        })
+    }
+}
+impl std::fmt::Display for Files {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = vec![self.cv.as_str(), self.cover_letter.as_str()];
+        parts.extend(self.additional_documents.iter().map(String::as_str));
+        write!(f, "{}", parts.join(", "))
     }
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 pub struct JobApplication {
     pub id: i32,
+    /// Identifies the application across databases, so copies made on other machines can be
+    /// matched up where their `id`s would collide. Given one when first stored.
+    #[serde(default)]
+    pub uuid: String,
     pub company_name: String,
     pub position: String,
     pub position_category: PositionCategory,
@@ -65,15 +159,409 @@ pub struct JobApplication {
     pub status: ApplicationStatus,
     pub is_active: bool, // whether status isn't final
     pub notes: Option<String>,
-    pub contact_info: Option<String>,
     pub url: Option<String>,
     pub files: Files, // list of file paths for extra documents
+    /// People involved in the application, stored in `contacts`.
+    #[serde(default)]
+    pub contacts: Vec<Contact>,
+    /// Free-form labels, e.g. `rust` or `referral`, stored in `application_tags`.
+    #[serde(default)]
+    pub tags: Vec<String>,
+    /// The posting's job description, as pasted in.
+    #[serde(default)]
+    pub description: Option<String>,
+    /// Advertised or offered salary bounds, in whole units of `currency`.
+    #[serde(default)]
+    pub salary_min: Option<i64>,
+    #[serde(default)]
+    pub salary_max: Option<i64>,
+    /// Currency code the salary is in, e.g. `GBP`.
+    #[serde(default)]
+    pub currency: Option<String>,
+    /// Bonus, equity, benefits and anything else that doesn't fit the range.
+    #[serde(default)]
+    pub compensation_notes: Option<String>,
+    /// Day (`YYYY-MM-DD`) the application is due a follow-up, if one is planned.
+    #[serde(default)]
+    pub follow_up_date: Option<String>,
+    /// Day (`YYYY-MM-DD`) applications close, for openings not applied to yet.
+    #[serde(default)]
+    pub deadline: Option<String>,
+    /// How much the application appeals, from 1 to 5, to put the most wanted first.
+    #[serde(default)]
+    pub rating: Option<u8>,
+    /// When the application was first stored, in UTC as `YYYY-MM-DD HH:MM:SS`. Kept as it was
+    /// by imports and syncs from another database.
+    #[serde(default)]
+    pub created_at: Option<String>,
+    /// When the application was last changed, in UTC as `YYYY-MM-DD HH:MM:SS`.
+    #[serde(default)]
+    pub last_updated: Option<String>,
+}
+
+/// Someone involved in an application, such as a recruiter or hiring manager.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Contact {
+    pub id: i32,
+    pub job_id: i32,
+    pub name: String,
+    #[serde(default)]
+    pub role: String,
+    #[serde(default)]
+    pub email: String,
+    #[serde(default)]
+    pub phone: String,
+    #[serde(default)]
+    pub linkedin: String,
+}
+impl Contact {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Contact {
+            id: row.get("id")?,
+            job_id: row.get("job_id")?,
+            name: row.get("name")?,
+            role: row.get("role")?,
+            email: row.get("email")?,
+            phone: row.get("phone")?,
+            linkedin: row.get("linkedin")?,
+        })
+    }
+
+    /// Whether `other` looks like the same person, going by name and email.
+    pub fn same_person(&self, other: &Contact) -> bool {
+        self.name.eq_ignore_ascii_case(&other.name) && self.email.eq_ignore_ascii_case(&other.email)
+    }
+}
+
+/// `name | role | email | phone | linkedin`, leaving off empty trailing parts.
+impl std::fmt::Display for Contact {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts = [
+            self.name.as_str(),
+            self.role.as_str(),
+            self.email.as_str(),
+            self.phone.as_str(),
+            self.linkedin.as_str(),
+        ];
+        let len = parts
+            .iter()
+            .rposition(|part| !part.is_empty())
+            .map_or(0, |i| i + 1);
+        write!(f, "{}", parts[..len].join(" | "))
+    }
+}
+impl FromStr for Contact {
+    type Err = String;
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut parts = s.split('|').map(|part| part.trim().to_string());
+        let name = parts.next().unwrap_or_default();
+        if name.is_empty() {
+            return Err(format!("contact \"{s}\" has no name"));
+        }
+        Ok(Contact {
+            name,
+            role: parts.next().unwrap_or_default(),
+            email: parts.next().unwrap_or_default(),
+            phone: parts.next().unwrap_or_default(),
+            linkedin: parts.next().unwrap_or_default(),
+            ..Contact::default()
+        })
+    }
+}
+
+/// A scheduled interview for an application.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Interview {
+    pub id: i32,
+    pub job_id: i32,
+    /// Local time as `YYYY-MM-DD HH:MM`.
+    pub starts_at: String,
+    pub duration_minutes: u32,
+    pub notes: String,
+}
+impl Default for Interview {
+    fn default() -> Self {
+        Interview {
+            id: 0,
+            job_id: 0,
+            starts_at: String::new(),
+            duration_minutes: 60,
+            notes: String::new(),
+        }
+    }
+}
+impl Interview {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Interview {
+            id: row.get("id")?,
+            job_id: row.get("job_id")?,
+            starts_at: row.get("starts_at")?,
+            duration_minutes: row.get("duration_minutes")?,
+            notes: row.get("notes")?,
+        })
+    }
+}
+
+/// Something to be reminded of about an application, repeated according to its recurrence.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Reminder {
+    pub id: i32,
+    pub job_id: i32,
+    /// Local time as `YYYY-MM-DD HH:MM`. Moved to the next occurrence once a recurring
+    /// reminder fires.
+    pub due_at: String,
+    pub message: String,
+    pub recurrence: Recurrence,
+}
+impl Reminder {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Reminder {
+            id: row.get("id")?,
+            job_id: row.get("job_id")?,
+            due_at: row.get("due_at")?,
+            message: row.get("message")?,
+            recurrence: Recurrence::from_str(&row.get::<_, String>("recurrence")?).map_err(
+                |_| {
+                    rusqlite::Error::InvalidColumnType(
+                        0,
+                        "recurrence".to_string(),
+                        rusqlite::types::Type::Text,
+                    )
+                },
+            )?,
+        })
+    }
+}
+
+/// An incoming application that matches a stored one but differs from it, held back so
+/// neither copy is lost until it is resolved.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Conflict {
+    pub id: i32,
+    pub local: JobApplication,
+    pub incoming: JobApplication,
+    /// Where the incoming copy came from, e.g. the imported file.
+    pub source: String,
+}
+
+/// A question asked in an interview for an application, kept to prepare for the next one.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Question {
+    pub id: i32,
+    pub job_id: i32,
+    pub question: String,
+    /// Notes on how it was answered, or how it should have been.
+    #[serde(default)]
+    pub answer: String,
+    /// What it was about, e.g. `system design` or `behavioural`.
+    #[serde(default)]
+    pub topic: String,
+    /// Day (`YYYY-MM-DD`) it was asked. Set to the day it's logged when left empty.
+    #[serde(default)]
+    pub asked_at: String,
+}
+impl Question {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Question {
+            id: row.get("id")?,
+            job_id: row.get("job_id")?,
+            question: row.get("question")?,
+            answer: row.get("answer")?,
+            topic: row.get("topic")?,
+            asked_at: row.get("asked_at")?,
+        })
+    }
+}
+
+/// The edit form's contents for an application, kept as it's typed so they outlive a crash.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Draft {
+    /// The application edited, or 0 for a new one.
+    pub job_id: i32,
+    /// Each field's text, in the form's order.
+    pub fields: Vec<String>,
+    /// When it was saved, in UTC.
+    pub saved_at: String,
+}
+
+/// What to do with a conflict's incoming copy.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Resolution {
+    /// Add it as an application of its own.
+    KeepBoth,
+    /// Replace the stored application with this combination of the two.
+    Merge(Box<JobApplication>),
+    /// Drop it, leaving the stored application as it is.
+    Discard,
+}
+
+/// One write to an application, as recorded in the activity log.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Activity {
+    pub job_id: i32,
+    /// `created`, `updated` or `deleted`.
+    pub kind: String,
+    /// `YYYY-MM-DD`, in local time.
+    pub day: String,
+    /// 0 for Sunday through 6 for Saturday.
+    pub weekday: u32,
+}
+
+/// A change to one field of an application, as recorded in its activity log. Adding the
+/// application is recorded as `created`, with the status it was added in.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FieldChange {
+    pub job_id: i32,
+    pub field: String,
+    pub old_value: Option<String>,
+    pub new_value: Option<String>,
+    pub changed_at: String,
+}
+impl FieldChange {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(FieldChange {
+            job_id: row.get("job_id")?,
+            field: row.get("field")?,
+            old_value: row.get("old_value")?,
+            new_value: row.get("new_value")?,
+            changed_at: row.get("changed_at")?,
+        })
+    }
+}
+
+/// A single entry in an application's status timeline.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusChange {
+    pub job_id: i32,
+    pub status: ApplicationStatus,
+    pub changed_at: String,
+    /// A line about the change, e.g. "recruiter said 2-week delay".
+    #[serde(default)]
+    pub note: Option<String>,
+}
+impl StatusChange {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(StatusChange {
+            job_id: row.get("job_id")?,
+            status: ApplicationStatus::from(row.get::<_, String>("status")?),
+            changed_at: row.get("changed_at")?,
+            note: row.get("note")?,
+        })
+    }
+}
+
+/// A status from the `statuses` table: one of the built-in statuses, whose colour can be
+/// changed, or one the user has added.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct StatusDefinition {
+    pub name: String,
+    /// Any colour ratatui can parse, e.g. `light_blue`, `#ff8800` or `208`.
+    pub color: String,
+    /// Whether applications in this status are still in progress.
+    pub is_active: bool,
+}
+impl StatusDefinition {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(StatusDefinition {
+            name: row.get("name")?,
+            color: row.get("color")?,
+            is_active: row.get("is_active")?,
+        })
+    }
+}
+
+/// How soon an active application needs something doing, in whole days from today, for
+/// ranking the worklist. Negative follow-up days are overdue.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PrioritySignals {
+    pub job_id: i32,
+    pub follow_up_in: Option<i64>,
+    /// Days until the next interview still to come.
+    pub interview_in: Option<i64>,
+    /// Days since the application last changed.
+    pub idle_days: Option<i64>,
+}
+
+/// Whether the job list shows applications, postings saved to apply to later, or both.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Stage {
+    #[default]
+    All,
+    Applications,
+    Saved,
+}
+impl Stage {
+    pub fn next(self) -> Self {
+        match self {
+            Stage::All => Stage::Applications,
+            Stage::Applications => Stage::Saved,
+            Stage::Saved => Stage::All,
+        }
+    }
+}
+
+/// Which applications the job list shows, and in what order, fetched a page at a time.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct JobQuery {
+    /// Text the company, position, location or notes contain, ignoring case.
+    pub search: String,
+    pub stage: Stage,
+    pub filter: Filter,
+    pub sort: SortOrder,
+    /// Ids most pressing first, for `SortOrder::Priority`. Applications not in it follow in
+    /// the order they were added.
+    pub ranking: Vec<i32>,
+}
+
+/// Money spent on the search, optionally tied to the application it was for.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Expense {
+    pub id: i32,
+    pub job_id: Option<i32>,
+    pub date: String,
+    pub category: ExpenseCategory,
+    pub description: String,
+    /// Stored in cents to keep totals exact.
+    pub amount_cents: i64,
+}
+impl Default for Expense {
+    fn default() -> Self {
+        Expense {
+            id: 0,
+            job_id: None,
+            date: String::new(),
+            category: ExpenseCategory::Other,
+            description: String::new(),
+            amount_cents: 0,
+        }
+    }
+}
+impl Expense {
+    pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
+        Ok(Expense {
+            id: row.get("id")?,
+            job_id: row.get("job_id")?,
+            date: row.get("date")?,
+            category: ExpenseCategory::from_str(&row.get::<_, String>("category")?).map_err(
+                |_| {
+                    rusqlite::Error::InvalidColumnType(
+                        0,
+                        "category".to_string(),
+                        rusqlite::types::Type::Text,
+                    )
+                },
+            )?,
+            description: row.get("description")?,
+            amount_cents: row.get("amount_cents")?,
+        })
+    }
 }
 
 impl Default for JobApplication {
     fn default() -> Self {
         JobApplication {
             id: 0,
+            uuid: String::new(),
             company_name: String::new(),
             position: String::new(),
             position_category: PositionCategory::Engineering,
@@ -84,13 +572,24 @@ impl Default for JobApplication {
             status: ApplicationStatus::Applied,
             is_active: true,
             notes: None,
-            contact_info: None,
             url: None,
             files: Files {
                 cv: String::new(),
                 cover_letter: String::new(),
                 additional_documents: Vec::new(),
             },
+            contacts: Vec::new(),
+            tags: Vec::new(),
+            description: None,
+            salary_min: None,
+            salary_max: None,
+            currency: None,
+            compensation_notes: None,
+            follow_up_date: None,
+            deadline: None,
+            rating: None,
+            created_at: None,
+            last_updated: None,
         }
     }
 }
@@ -99,6 +598,7 @@ impl JobApplication {
     pub fn from_row(row: &rusqlite::Row) -> rusqlite::Result<Self> {
         Ok(JobApplication {
             id: row.get("id")?,
+            uuid: row.get("uuid")?,
             company_name: row.get("company_name")?,
             position: row.get("position")?,
             position_category: PositionCategory::from_str(
@@ -128,64 +628,104 @@ impl JobApplication {
                     )
                 })?,
             application_date: row.get("application_date")?,
-            status: ApplicationStatus::from_str(&row.get::<_, String>("status")?).map_err(
-                |_| {
-                    rusqlite::Error::InvalidColumnType(
-                        0,
-                        "status".to_string(),
-                        rusqlite::types::Type::Text,
-                    )
-                },
-            )?,
+            status: ApplicationStatus::from(row.get::<_, String>("status")?),
             is_active: row.get("is_active")?,
             notes: row.get("notes")?,
-            contact_info: row.get("contact_info")?,
             url: row.get("url")?,
-            files: {
-                // Example: "cv.pdf,cover_letter.pdf,doc1.pdf,doc2.pdf"
-                let files_str = row.get::<_, Option<String>>("files")?.unwrap_or_default();
-                let mut parts = files_str
-                    .split(',')
-                    .map(|s| s.trim().to_string())
-                    .collect::<Vec<_>>();
-                let cv = parts.get(0).cloned().unwrap_or_default();
-                let cover_letter = parts.get(1).cloned().unwrap_or_default();
-                let additional_documents = if parts.len() > 2 {
-                    parts[2..].to_vec()
-                } else {
-                    Vec::new()
-                };
-                Files {
-                    cv,
-                    cover_letter,
-                    additional_documents,
-                }
-            },
+            // stored in `application_files` and `contacts`, loaded separately
+            files: Files::default(),
+            contacts: Vec::new(),
+            tags: Vec::new(),
+            description: row.get("description")?,
+            salary_min: row.get("salary_min")?,
+            salary_max: row.get("salary_max")?,
+            currency: row.get("currency")?,
+            compensation_notes: row.get("compensation_notes")?,
+            follow_up_date: row.get("follow_up_date")?,
+            deadline: row.get("deadline")?,
+            rating: row.get("rating")?,
+            created_at: row.get("created_at")?,
+            last_updated: row.get("last_updated")?,
         })
     }
 
-    pub fn test(id: i32) -> Self {
-        JobApplication {
-            id,
-            company_name: "Acme Inc ".to_string() + &id.to_string(),
-            position: "Backend Developer ".to_string() + &id.to_string(),
-            position_category: PositionCategory::Engineering,
-            work_type: WorkType::FullTime,
-            location: "San Francisco, CA".to_string(),
-            location_type: LocationType::Remote,
-            application_date: "2024-05-20".to_string(),
-            status: ApplicationStatus::Interviewing,
-            is_active: true,
-            notes: Some("Interview scheduled for next week.".to_string()),
-            contact_info: Some("recruiter@acme.com".to_string()),
-            url: Some("https://acme.com/jobs/123".to_string()),
-            files: Files {
-                cv: "acme_cv.pdf".to_string(),
-                cover_letter: "acme_cover.pdf".to_string(),
-                additional_documents: vec!["portfolio.pdf".to_string()],
-            },
+    /// The fields the activity log tracks, by the name it records them under, with their
+    /// values as shown. Unset fields are empty.
+    pub fn field_values(&self) -> Vec<(&'static str, String)> {
+        let text = |value: &Option<String>| value.clone().unwrap_or_default();
+        let number = |value: Option<i64>| value.map(|value| value.to_string()).unwrap_or_default();
+        vec![
+            ("company", self.company_name.clone()),
+            ("position", self.position.clone()),
+            ("category", self.position_category.to_string()),
+            ("work type", self.work_type.to_string()),
+            ("location", self.location.clone()),
+            ("location type", self.location_type.to_string()),
+            ("applied", self.application_date.clone()),
+            ("status", self.status.to_string()),
+            ("url", text(&self.url)),
+            ("notes", text(&self.notes)),
+            ("description", text(&self.description)),
+            ("salary min", number(self.salary_min)),
+            ("salary max", number(self.salary_max)),
+            ("currency", text(&self.currency)),
+            ("compensation", text(&self.compensation_notes)),
+            ("follow up", text(&self.follow_up_date)),
+            ("deadline", text(&self.deadline)),
+            ("rating", self.stars().unwrap_or_default()),
+            ("files", self.files.to_string()),
+            ("tags", self.tags.join(", ")),
+            (
+                "contacts",
+                self.contacts
+                    .iter()
+                    .map(Contact::to_string)
+                    .collect::<Vec<_>>()
+                    .join(", "),
+            ),
+        ]
+    }
+
+    /// The salary range with its currency, e.g. `GBP 40,000–50,000`, or `None` when neither
+    /// bound is recorded.
+    pub fn salary_range(&self) -> Option<String> {
+        let range = match (self.salary_min, self.salary_max) {
+            (Some(min), Some(max)) if min == max => group_thousands(min),
+            (Some(min), Some(max)) => {
+                format!("{}–{}", group_thousands(min), group_thousands(max))
+            }
+            (Some(min), None) => format!("{}+", group_thousands(min)),
+            (None, Some(max)) => format!("up to {}", group_thousands(max)),
+            (None, None) => return None,
+        };
+        Some(match &self.currency {
+            Some(currency) => format!("{currency} {range}"),
+            None => range,
+        })
+    }
+
+    /// The rating as filled and empty stars, e.g. `★★★☆☆`, or `None` when it isn't rated.
+    pub fn stars(&self) -> Option<String> {
+        self.rating.map(|rating| {
+            let filled = usize::from(rating.min(5));
+            format!("{}{}", "★".repeat(filled), "☆".repeat(5 - filled))
+        })
+    }
+}
+
+fn group_thousands(value: i64) -> String {
+    let digits = value.unsigned_abs().to_string();
+    let mut grouped = String::new();
+    for (idx, digit) in digits.chars().enumerate() {
+        if idx > 0 && (digits.len() - idx).is_multiple_of(3) {
+            grouped.push(',');
         }
+        grouped.push(digit);
+    }
+    if value < 0 {
+        grouped.insert(0, '-');
     }
+    grouped
 }
 
 //
@@ -193,19 +733,6 @@ impl JobApplication {
 // --- Conversion and parsing implementations below ---
 // ----------------------------------------------------
 //
-impl PositionCategory {
-    pub fn to_string(&self) -> String {
-        match self {
-            PositionCategory::Engineering => "Engineering".to_string(),
-            PositionCategory::Development => "Development".to_string(),
-            PositionCategory::Support => "Support".to_string(),
-            PositionCategory::DataScience => "Data Science".to_string(),
-            PositionCategory::Analyst => "Analyst".to_string(),
-            PositionCategory::Design => "Design".to_string(),
-        }
-    }
-}
-
 impl FromStr for PositionCategory {
     type Err = ();
 
@@ -222,20 +749,6 @@ impl FromStr for PositionCategory {
     }
 }
 
-impl WorkType {
-    pub fn to_string(&self) -> String {
-        match self {
-            WorkType::FullTime => "Full Time".to_string(),
-            WorkType::PartTime => "Part Time".to_string(),
-            WorkType::Internship => "Internship".to_string(),
-            WorkType::Contract => "Contract".to_string(),
-            WorkType::Temporary => "Temporary".to_string(),
-            WorkType::Volunteer => "Volunteer".to_string(),
-            WorkType::Other => "Other".to_string(),
-        }
-    }
-}
-
 impl FromStr for WorkType {
     type Err = ();
 
@@ -258,15 +771,6 @@ impl ToSql for WorkType {
     }
 }
 
-impl LocationType {
-    pub fn to_string(&self) -> String {
-        match self {
-            LocationType::Remote => "Remote".to_string(),
-            LocationType::OnSite => "On Site".to_string(),
-            LocationType::Hybrid => "Hybrid".to_string(),
-        }
-    }
-}
 impl ToSql for LocationType {
     fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
         Ok(rusqlite::types::ToSqlOutput::from(self.to_string()))
@@ -286,14 +790,42 @@ impl FromStr for LocationType {
 }
 
 impl ApplicationStatus {
-    pub fn to_string(&self) -> String {
+    /// Statuses that come with the app, in the order an application usually moves through them.
+    pub fn built_in() -> [ApplicationStatus; 10] {
+        [
+            ApplicationStatus::Saved,
+            ApplicationStatus::Applied,
+            ApplicationStatus::PhoneScreen,
+            ApplicationStatus::Interviewing,
+            ApplicationStatus::TechnicalInterview,
+            ApplicationStatus::Offered,
+            ApplicationStatus::Rejected,
+            ApplicationStatus::Withdrawn,
+            ApplicationStatus::Accepted,
+            ApplicationStatus::Ghosted,
+        ]
+    }
+
+    /// The built-in statuses followed by those the user has defined.
+    pub fn all() -> Vec<ApplicationStatus> {
+        let mut all = Self::built_in().to_vec();
+        all.extend(crate::statuses::custom());
+        all
+    }
+}
+impl ApplicationStatus {
+    /// Whether an application with this status is still in progress. User-defined statuses
+    /// say so in their definition.
+    pub fn is_active(&self) -> bool {
         match self {
-            ApplicationStatus::Applied => "Applied".to_string(),
-            ApplicationStatus::Interviewing => "Interviewing".to_string(),
-            ApplicationStatus::Offered => "Offered".to_string(),
-            ApplicationStatus::Rejected => "Rejected".to_string(),
-            ApplicationStatus::Withdrawn => "Withdrawn".to_string(),
-            ApplicationStatus::Accepted => "Accepted".to_string(),
+            ApplicationStatus::Rejected
+            | ApplicationStatus::Withdrawn
+            | ApplicationStatus::Accepted
+            | ApplicationStatus::Ghosted => false,
+            ApplicationStatus::Custom(name) => {
+                crate::statuses::find(name).is_none_or(|status| status.is_active)
+            }
+            _ => true,
         }
     }
 }
@@ -303,17 +835,117 @@ impl ToSql for ApplicationStatus {
     }
 }
 
+/// Statuses as typed by the user: built-in ones, or ones defined in the `statuses` table.
 impl FromStr for ApplicationStatus {
     type Err = ();
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
         match s {
+            "Saved" | "Wishlist" => Ok(ApplicationStatus::Saved),
             "Applied" => Ok(ApplicationStatus::Applied),
+            "Phone Screen" | "PhoneScreen" => Ok(ApplicationStatus::PhoneScreen),
             "Interviewing" => Ok(ApplicationStatus::Interviewing),
+            "Technical Interview" | "TechnicalInterview" => {
+                Ok(ApplicationStatus::TechnicalInterview)
+            }
             "Offered" => Ok(ApplicationStatus::Offered),
             "Rejected" => Ok(ApplicationStatus::Rejected),
             "Withdrawn" => Ok(ApplicationStatus::Withdrawn),
             "Accepted" => Ok(ApplicationStatus::Accepted),
+            "Ghosted" => Ok(ApplicationStatus::Ghosted),
+            _ => crate::statuses::find(s)
+                .map(|status| ApplicationStatus::Custom(status.name))
+                .ok_or(()),
+        }
+    }
+}
+
+/// Statuses as stored, which are kept even when their definition has since been removed.
+impl From<String> for ApplicationStatus {
+    fn from(name: String) -> Self {
+        match name.as_str() {
+            "Saved" => ApplicationStatus::Saved,
+            "Applied" => ApplicationStatus::Applied,
+            "Phone Screen" => ApplicationStatus::PhoneScreen,
+            "Interviewing" => ApplicationStatus::Interviewing,
+            "Technical Interview" => ApplicationStatus::TechnicalInterview,
+            "Offered" => ApplicationStatus::Offered,
+            "Rejected" => ApplicationStatus::Rejected,
+            "Withdrawn" => ApplicationStatus::Withdrawn,
+            "Accepted" => ApplicationStatus::Accepted,
+            "Ghosted" => ApplicationStatus::Ghosted,
+            _ => ApplicationStatus::Custom(name),
+        }
+    }
+}
+impl From<ApplicationStatus> for String {
+    fn from(status: ApplicationStatus) -> Self {
+        status.to_string()
+    }
+}
+
+impl ToSql for ExpenseCategory {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(self.to_string()))
+    }
+}
+impl FromStr for ExpenseCategory {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "Travel" => Ok(ExpenseCategory::Travel),
+            "Certification" => Ok(ExpenseCategory::Certification),
+            "Resume Service" => Ok(ExpenseCategory::ResumeService),
+            "Other" => Ok(ExpenseCategory::Other),
+            _ => Err(()),
+        }
+    }
+}
+
+impl Recurrence {
+    /// SQLite date modifier that moves a due date to the next occurrence.
+    pub fn interval(&self) -> Option<&'static str> {
+        match self {
+            Recurrence::Once => None,
+            Recurrence::Daily => Some("+1 day"),
+            Recurrence::Weekly => Some("+7 days"),
+            Recurrence::Monthly => Some("+1 month"),
+        }
+    }
+}
+impl ToSql for Recurrence {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(self.to_string()))
+    }
+}
+impl FromStr for Recurrence {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "once" | "" => Ok(Recurrence::Once),
+            "daily" => Ok(Recurrence::Daily),
+            "weekly" => Ok(Recurrence::Weekly),
+            "monthly" => Ok(Recurrence::Monthly),
+            _ => Err(()),
+        }
+    }
+}
+
+impl ToSql for FileKind {
+    fn to_sql(&self) -> rusqlite::Result<rusqlite::types::ToSqlOutput<'_>> {
+        Ok(rusqlite::types::ToSqlOutput::from(self.to_string()))
+    }
+}
+impl FromStr for FileKind {
+    type Err = ();
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "cv" => Ok(FileKind::Cv),
+            "cover_letter" => Ok(FileKind::CoverLetter),
+            "additional" => Ok(FileKind::Additional),
             _ => Err(()),
         }
     }
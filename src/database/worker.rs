@@ -0,0 +1,496 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::PathBuf,
+    sync::atomic::{AtomicU64, Ordering},
+};
+
+use serde::{Deserialize, Serialize};
+use tokio::sync::mpsc::{self, UnboundedSender};
+
+use crate::{
+    action::Action,
+    categorize::{self, Suggestion},
+    config::{CalendarConfig, DigestFormat},
+    database::{
+        db::Database,
+        journal::Journal,
+        query,
+        schema::{
+            ApplicationStatus, Conflict, Contact, Draft, Expense, FieldChange, Interview,
+            JobApplication, JobQuery, PositionCategory, PrioritySignals, Question, Reminder,
+            Resolution, StatusChange,
+        },
+    },
+    export::{self, digest::Digest},
+    import::{self, ImportSummary},
+    postings::Board,
+    stats::{self, Stats},
+};
+
+/// Correlates a `DbResponse` with the `DbRequest` it answers, so whoever sent a request can
+/// pick out its own response and ignore everyone else's.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub struct RequestId(u64);
+impl RequestId {
+    pub fn next() -> Self {
+        static NEXT: AtomicU64 = AtomicU64::new(1);
+        RequestId(NEXT.fetch_add(1, Ordering::Relaxed))
+    }
+}
+
+/// Work for the database task, sent as `Action::DbRequest`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DbRequest {
+    // Applications matching the query, from an offset and up to a limit
+    JobPage(JobQuery, usize, usize),
+    StatusHistory(JobApplication),
+    // An application looked up by UUID or id, answered with its status history
+    JobWithHistory(String),
+    // Other applications whose notes link to this one
+    Backlinks(JobApplication),
+    // Every recorded change to the application with this id
+    ActivityLog(i32),
+    // Active applications whose follow-up date has come
+    FollowUps,
+    // Active applications not changed for this many days
+    Stale(u32),
+    // Active applications with a Greenhouse or Lever link, to check the posting is still up
+    BoardPostings,
+    // What is coming up for each active application, to order them by priority
+    PrioritySignals,
+    // Snooze the application's follow-up by this many days, or clear it once done
+    SetFollowUp(i32, Option<u32>),
+    // Every application's statuses in order, for the list's progress trails
+    StatusTrails,
+    // Every application with its status changes, for the timeline
+    Timeline,
+    // Applications with their deadlines and every interview, for the calendar
+    Calendar,
+    // Existing interviews to check the new one, for the given application, against
+    Interviews(i32),
+    Stats,
+    // Headline figures of this database and of each named one, opened read-only
+    CompareProfiles(Vec<(String, PathBuf)>),
+    SaveJob(JobApplication),
+    // Active applications already at the new application's company, checked before adding it
+    ActiveAtCompany(JobApplication),
+    // Stored applications that look like the new one, checked before adding it
+    SimilarApplications(JobApplication),
+    // An application whose status changed, with a note for the new status
+    SaveStatusChange(JobApplication, String),
+    DeleteJob(i32),
+    SaveInterview(Interview),
+    SaveExpense(Expense),
+    DeleteExpense(i32),
+    Import(PathBuf),
+    ExportCsv(PathBuf),
+    Backup(PathBuf),
+    Restore(PathBuf),
+    Digest(DigestFormat),
+    Reminders,
+    SaveReminder(Reminder),
+    DeleteReminder(i32),
+    // Fire every reminder that has come due
+    FireReminders,
+    ExportReminders(PathBuf),
+    // Every logged interview question, for the question bank
+    Questions,
+    SaveQuestion(Question),
+    DeleteQuestion(i32),
+    // Upcoming interviews and deadlines, with alerts as configured
+    ExportCalendar(PathBuf, CalendarConfig),
+    Conflicts,
+    ResolveConflict(i32, Resolution),
+    SaveContact(Contact),
+    DeleteContact(Contact),
+    CategorySuggestions,
+    // Applications to file under the category
+    Recategorize(Vec<i32>, PositionCategory),
+    // Move the applications to the status, e.g. to close stale ones
+    SetStatus(Vec<i32>, ApplicationStatus),
+    // Every tag in use, with how many applications have it
+    Tags,
+    // Every application, for the command palette to search
+    AllJobs,
+    // Rename the first tag to the second everywhere, merging where that is already used
+    RenameTag(String, String),
+    DeleteTag(String),
+    // The edit form's draft of the application, 0 for a new one, kept until it's saved
+    Draft(i32),
+    SaveDraft(i32, Vec<String>),
+    DeleteDraft(i32),
+    // Drop cached reads, so requests after it see writes made from outside the app
+    Invalidate,
+}
+
+impl DbRequest {
+    /// Whether the request writes to the database, and so runs in a transaction under the
+    /// journal. Firing reminders is left out, as it runs again by itself on the next start,
+    /// and so are drafts, being only a fallback themselves.
+    pub fn is_write(&self) -> bool {
+        matches!(
+            self,
+            DbRequest::SetFollowUp(..)
+                | DbRequest::SaveJob(_)
+                | DbRequest::SaveStatusChange(..)
+                | DbRequest::DeleteJob(_)
+                | DbRequest::SaveInterview(_)
+                | DbRequest::SaveExpense(_)
+                | DbRequest::DeleteExpense(_)
+                | DbRequest::Import(_)
+                | DbRequest::Restore(_)
+                | DbRequest::SaveReminder(_)
+                | DbRequest::DeleteReminder(_)
+                | DbRequest::SaveQuestion(_)
+                | DbRequest::DeleteQuestion(_)
+                | DbRequest::ResolveConflict(..)
+                | DbRequest::SaveContact(_)
+                | DbRequest::DeleteContact(_)
+                | DbRequest::Recategorize(..)
+                | DbRequest::SetStatus(..)
+                | DbRequest::RenameTag(..)
+                | DbRequest::DeleteTag(_)
+        )
+    }
+}
+
+/// Result of a `DbRequest`, sent back as `Action::DbResponse`.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum DbResponse {
+    // The offset a page starts at, how many applications match in all, and the page
+    JobPage(usize, usize, Vec<JobApplication>),
+    StatusHistory(JobApplication, Vec<StatusChange>),
+    StatusTrails(HashMap<i32, Vec<ApplicationStatus>>),
+    Timeline(Vec<JobApplication>, HashMap<i32, Vec<StatusChange>>),
+    Calendar(Vec<JobApplication>, Vec<Interview>),
+    Backlinks(Vec<JobApplication>),
+    ActivityLog(i32, Vec<FieldChange>),
+    FollowUps(Vec<JobApplication>),
+    Stale(HashSet<i32>),
+    BoardPostings(Vec<(i32, Board)>),
+    PrioritySignals(Vec<PrioritySignals>),
+    FollowUpSet(i32, Option<u32>),
+    Interviews(i32, Vec<(Interview, String)>),
+    Stats(Stats),
+    ProfileComparison(Vec<(String, Result<Stats, String>)>),
+    ActiveAtCompany(JobApplication, usize),
+    SimilarApplications(JobApplication, Vec<JobApplication>),
+    JobSaved(JobApplication),
+    JobDeleted(i32),
+    InterviewSaved(Interview),
+    ExpenseSaved(Expense),
+    ExpenseDeleted(i32),
+    Imported(ImportSummary),
+    // Path written to and the number of applications in it
+    Exported(PathBuf, usize),
+    // Path written to and the number of applications in it
+    BackedUp(PathBuf, usize),
+    // The rendered digest
+    Digest(String),
+    Reminders(Vec<(Reminder, String)>),
+    ReminderSaved(Reminder),
+    ReminderDeleted(i32),
+    // Reminders that just came due, with their company
+    RemindersDue(Vec<(Reminder, String)>),
+    RemindersExported(PathBuf, usize),
+    Questions(Vec<(Question, String)>),
+    QuestionSaved(Question),
+    QuestionDeleted(i32),
+    CalendarExported(PathBuf, usize),
+    Conflicts(Vec<Conflict>),
+    ConflictResolved(i32, Resolution),
+    ContactSaved(Contact),
+    ContactDeleted(Contact),
+    CategorySuggestions(Vec<Suggestion>),
+    // The new category and how many applications were moved to it
+    Recategorized(PositionCategory, usize),
+    // The new status and how many applications were moved to it
+    StatusSet(ApplicationStatus, usize),
+    Invalidated,
+    Tags(Vec<(String, u32)>),
+    AllJobs(Vec<JobApplication>),
+    // What was done to the tags, to report
+    TagsChanged(String),
+    Draft(i32, Option<Draft>),
+    DraftSaved(i32),
+    DraftDiscarded(i32),
+    Failed(String),
+}
+
+/// Move the database onto a blocking task of its own so large queries and writes never stall
+/// rendering or input handling. Requests are handled one at a time, in the order sent.
+/// Writes are recorded in `journal`, when there is one, until they are committed.
+pub fn spawn(
+    database: Database,
+    journal: Option<Journal>,
+    action_tx: UnboundedSender<Action>,
+) -> UnboundedSender<(RequestId, DbRequest)> {
+    let (request_tx, mut request_rx) = mpsc::unbounded_channel::<(RequestId, DbRequest)>();
+    tokio::task::spawn_blocking(move || {
+        while let Some((req_id, request)) = request_rx.blocking_recv() {
+            let response = if request.is_write() {
+                write(request, &database, journal.as_ref())
+            } else {
+                handle(request, &database)
+            };
+            if action_tx
+                .send(Action::DbResponse(req_id, response))
+                .is_err()
+            {
+                break;
+            }
+        }
+    });
+    request_tx
+}
+
+/// Run a write in a transaction, so it is kept whole or not at all, holding it in the journal
+/// until it's committed. A failed write is rolled back; one cut short by a crash is rolled
+/// back by SQLite when the database is next opened, and left in the journal to offer again.
+fn write(request: DbRequest, db: &Database, journal: Option<&Journal>) -> DbResponse {
+    if let Some(journal) = journal
+        && let Err(err) = journal.begin(&request)
+    {
+        return DbResponse::Failed(format!("Failed to write the journal: {err}"));
+    }
+    let response = match db.connection().unchecked_transaction() {
+        Ok(transaction) => {
+            db.hold_changes();
+            let response = match handle(request, db) {
+                // dropping the transaction rolls it back
+                failed @ DbResponse::Failed(_) => {
+                    drop(transaction);
+                    db.invalidate();
+                    failed
+                }
+                response => match transaction.commit() {
+                    Ok(()) => response,
+                    Err(err) => {
+                        db.invalidate();
+                        DbResponse::Failed(format!("Failed to save changes: {err}"))
+                    }
+                },
+            };
+            db.release_changes(!matches!(response, DbResponse::Failed(_)));
+            response
+        }
+        Err(err) => DbResponse::Failed(format!("Failed to start a transaction: {err}")),
+    };
+    if let Some(journal) = journal
+        && let Err(err) = journal.finish()
+    {
+        tracing::warn!("Failed to clear {}: {err}", journal.path().display());
+    }
+    response
+}
+
+fn handle(request: DbRequest, db: &Database) -> DbResponse {
+    match request {
+        DbRequest::JobPage(job_query, offset, limit) => {
+            let (total, jobs) = query::get_job_page(&job_query, offset, limit, db);
+            DbResponse::JobPage(offset, total, jobs)
+        }
+        DbRequest::FollowUps => DbResponse::FollowUps(query::get_overdue_follow_ups(db)),
+        DbRequest::PrioritySignals => DbResponse::PrioritySignals(query::get_priority_signals(db)),
+        DbRequest::Stale(days) => DbResponse::Stale(query::get_stale_applications(days, db)),
+        DbRequest::BoardPostings => DbResponse::BoardPostings(query::get_board_postings(db)),
+        DbRequest::SetFollowUp(id, days) => match query::set_follow_up(id, days, db) {
+            Ok(()) => DbResponse::FollowUpSet(id, days),
+            Err(err) => DbResponse::Failed(format!("Failed to update follow-up: {err}")),
+        },
+        DbRequest::StatusHistory(job) => {
+            let history = query::get_status_history(job.id, db);
+            DbResponse::StatusHistory(job, history)
+        }
+        DbRequest::JobWithHistory(key) => match query::find_application(&key, db) {
+            Some(job) => {
+                let history = query::get_status_history(job.id, db);
+                DbResponse::StatusHistory(job, history)
+            }
+            None => DbResponse::Failed(format!("No application with id {key}")),
+        },
+        DbRequest::StatusTrails => DbResponse::StatusTrails(query::get_status_trails(db)),
+        DbRequest::Timeline => DbResponse::Timeline(
+            query::get_all_applications(db),
+            query::get_status_histories(db),
+        ),
+        DbRequest::Calendar => DbResponse::Calendar(
+            query::get_all_applications(db),
+            query::get_interviews(db)
+                .into_iter()
+                .map(|(interview, _)| interview)
+                .collect(),
+        ),
+        DbRequest::Backlinks(job) => DbResponse::Backlinks(query::get_backlinks(&job, db)),
+        DbRequest::Tags => DbResponse::Tags(query::get_tag_counts(db)),
+        DbRequest::AllJobs => DbResponse::AllJobs(query::get_all_applications(db)),
+        DbRequest::RenameTag(tag, to) => match query::rename_tag(&tag, &to, db) {
+            Ok(count) => DbResponse::TagsChanged(format!(
+                "Renamed \"{tag}\" to \"{}\" on {count} application(s)",
+                to.trim()
+            )),
+            Err(err) => DbResponse::Failed(format!("Failed to rename tag: {err}")),
+        },
+        DbRequest::DeleteTag(tag) => match query::delete_tag(&tag, db) {
+            Ok(count) => {
+                DbResponse::TagsChanged(format!("Removed \"{tag}\" from {count} application(s)"))
+            }
+            Err(err) => DbResponse::Failed(format!("Failed to delete tag: {err}")),
+        },
+        DbRequest::Draft(job_id) => DbResponse::Draft(job_id, query::get_draft(job_id, db)),
+        DbRequest::SaveDraft(job_id, fields) => match query::save_draft(job_id, &fields, db) {
+            Ok(()) => DbResponse::DraftSaved(job_id),
+            Err(err) => DbResponse::Failed(format!("Failed to save the draft: {err}")),
+        },
+        DbRequest::DeleteDraft(job_id) => match query::delete_draft(job_id, db) {
+            Ok(()) => DbResponse::DraftDiscarded(job_id),
+            Err(err) => DbResponse::Failed(format!("Failed to discard the draft: {err}")),
+        },
+        DbRequest::Invalidate => {
+            db.invalidate();
+            DbResponse::Invalidated
+        }
+        DbRequest::ActivityLog(job_id) => {
+            DbResponse::ActivityLog(job_id, query::get_field_changes(job_id, db))
+        }
+        DbRequest::Interviews(job_id) => DbResponse::Interviews(job_id, query::get_interviews(db)),
+        DbRequest::Stats => DbResponse::Stats(Stats::collect(db)),
+        DbRequest::CompareProfiles(profiles) => {
+            DbResponse::ProfileComparison(stats::compare_profiles(db, &profiles))
+        }
+        DbRequest::SaveJob(job) => {
+            let saved = if job.id == 0 {
+                query::add_application(job.clone(), db)
+            } else {
+                query::update_application(job.clone(), db)
+            };
+            match saved {
+                Ok(()) => DbResponse::JobSaved(job),
+                Err(err) => DbResponse::Failed(format!("Failed to save application: {err}")),
+            }
+        }
+        DbRequest::ActiveAtCompany(job) => {
+            let count = query::count_active_at_company(&job.company_name, db);
+            DbResponse::ActiveAtCompany(job, count)
+        }
+        DbRequest::SimilarApplications(job) => {
+            let similar = query::find_similar_applications(&job.company_name, &job.position, db);
+            DbResponse::SimilarApplications(job, similar)
+        }
+        DbRequest::SaveStatusChange(job, note) => {
+            match query::update_application(job.clone(), db)
+                .and_then(|()| query::set_status_note(job.id, &note, db))
+            {
+                Ok(()) => DbResponse::JobSaved(job),
+                Err(err) => DbResponse::Failed(format!("Failed to save application: {err}")),
+            }
+        }
+        DbRequest::DeleteJob(id) => match query::delete_application(id, db) {
+            Ok(()) => DbResponse::JobDeleted(id),
+            Err(err) => DbResponse::Failed(format!("Failed to delete application: {err}")),
+        },
+        DbRequest::SaveInterview(interview) => match query::add_interview(interview.clone(), db) {
+            Ok(()) => DbResponse::InterviewSaved(interview),
+            Err(err) => DbResponse::Failed(format!("Failed to save interview: {err}")),
+        },
+        DbRequest::SaveExpense(expense) => match query::add_expense(expense.clone(), db) {
+            Ok(()) => DbResponse::ExpenseSaved(expense),
+            Err(err) => DbResponse::Failed(format!("Failed to save expense: {err}")),
+        },
+        DbRequest::DeleteExpense(id) => match query::delete_expense(id, db) {
+            Ok(()) => DbResponse::ExpenseDeleted(id),
+            Err(err) => DbResponse::Failed(format!("Failed to delete expense: {err}")),
+        },
+        DbRequest::Import(path) => match import::import_file(&path, db) {
+            Ok(summary) => DbResponse::Imported(summary),
+            Err(err) => DbResponse::Failed(format!("Failed to import {}: {err}", path.display())),
+        },
+        DbRequest::ExportCsv(path) => {
+            let jobs = query::get_all_applications(db);
+            match export::csv::export_csv(&path, &jobs) {
+                Ok(()) => DbResponse::Exported(path, jobs.len()),
+                Err(err) => {
+                    DbResponse::Failed(format!("Failed to export to {}: {err}", path.display()))
+                }
+            }
+        }
+        DbRequest::Backup(path) => match export::backup::write_backup(&path, db) {
+            Ok(count) => DbResponse::BackedUp(path, count),
+            Err(err) => {
+                DbResponse::Failed(format!("Failed to back up to {}: {err}", path.display()))
+            }
+        },
+        DbRequest::Restore(path) => match export::backup::restore(&path, db) {
+            Ok(summary) => DbResponse::Imported(summary),
+            Err(err) => DbResponse::Failed(format!("Failed to restore {}: {err}", path.display())),
+        },
+        DbRequest::Digest(format) => DbResponse::Digest(Digest::collect(db).render(format)),
+        DbRequest::Reminders => DbResponse::Reminders(query::get_reminders(db)),
+        DbRequest::SaveReminder(reminder) => match query::add_reminder(reminder.clone(), db) {
+            Ok(()) => DbResponse::ReminderSaved(reminder),
+            Err(err) => DbResponse::Failed(format!("Failed to save reminder: {err}")),
+        },
+        DbRequest::DeleteReminder(id) => match query::delete_reminder(id, db) {
+            Ok(()) => DbResponse::ReminderDeleted(id),
+            Err(err) => DbResponse::Failed(format!("Failed to dismiss reminder: {err}")),
+        },
+        DbRequest::FireReminders => match query::fire_due_reminders(db) {
+            Ok(due) => DbResponse::RemindersDue(due),
+            Err(err) => DbResponse::Failed(format!("Failed to check reminders: {err}")),
+        },
+        DbRequest::ExportReminders(path) => {
+            let reminders = query::get_reminders(db);
+            match export::ics::export_ics(&path, &reminders) {
+                Ok(()) => DbResponse::RemindersExported(path, reminders.len()),
+                Err(err) => {
+                    DbResponse::Failed(format!("Failed to export to {}: {err}", path.display()))
+                }
+            }
+        }
+        DbRequest::Questions => DbResponse::Questions(query::get_questions(db)),
+        DbRequest::SaveQuestion(question) => match query::save_question(question.clone(), db) {
+            Ok(()) => DbResponse::QuestionSaved(question),
+            Err(err) => DbResponse::Failed(format!("Failed to save question: {err}")),
+        },
+        DbRequest::DeleteQuestion(id) => match query::delete_question(id, db) {
+            Ok(()) => DbResponse::QuestionDeleted(id),
+            Err(err) => DbResponse::Failed(format!("Failed to delete question: {err}")),
+        },
+        DbRequest::ExportCalendar(path, alarms) => {
+            match export::ics::export_events(&path, db, &alarms) {
+                Ok(count) => DbResponse::CalendarExported(path, count),
+                Err(err) => {
+                    DbResponse::Failed(format!("Failed to export to {}: {err}", path.display()))
+                }
+            }
+        }
+        DbRequest::Conflicts => DbResponse::Conflicts(query::get_conflicts(db)),
+        DbRequest::ResolveConflict(id, resolution) => {
+            match query::resolve_conflict(id, resolution.clone(), db) {
+                Ok(()) => DbResponse::ConflictResolved(id, resolution),
+                Err(err) => DbResponse::Failed(format!("Failed to resolve conflict: {err}")),
+            }
+        }
+        DbRequest::SaveContact(contact) => match query::save_contact(contact, db) {
+            Ok(contact) => DbResponse::ContactSaved(contact),
+            Err(err) => DbResponse::Failed(format!("Failed to save contact: {err}")),
+        },
+        DbRequest::DeleteContact(contact) => match query::delete_contact(&contact, db) {
+            Ok(()) => DbResponse::ContactDeleted(contact),
+            Err(err) => DbResponse::Failed(format!("Failed to delete contact: {err}")),
+        },
+        DbRequest::CategorySuggestions => DbResponse::CategorySuggestions(categorize::suggestions(
+            &query::get_all_applications(db),
+        )),
+        DbRequest::Recategorize(ids, category) => {
+            match query::set_position_category(&ids, &category, db) {
+                Ok(count) => DbResponse::Recategorized(category, count),
+                Err(err) => DbResponse::Failed(format!("Failed to change categories: {err}")),
+            }
+        }
+        DbRequest::SetStatus(ids, status) => match query::set_status(&ids, &status, db) {
+            Ok(count) => DbResponse::StatusSet(status, count),
+            Err(err) => DbResponse::Failed(format!("Failed to change statuses: {err}")),
+        },
+    }
+}
@@ -0,0 +1,116 @@
+//! Dates and times as they're stored: `YYYY-MM-DD` dates, `YYYY-MM-DD HH:MM` local times for
+//! interviews and reminders, and UTC timestamps for when things were recorded. "Today" is the
+//! local date, the one `date('now', 'localtime')` gives in queries.
+
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+
+/// Minutes since 1970-01-01 00:00 for a `YYYY-MM-DD HH:MM` timestamp.
+pub fn parse_datetime(value: &str) -> Option<i64> {
+    let (date, time) = value.trim().split_once([' ', 'T'])?;
+    let mut date = date.splitn(3, '-').map(|part| part.parse::<i64>().ok());
+    let (year, month, day) = (date.next()??, date.next()??, date.next()??);
+    let (hour, minute) = time.split_once(':')?;
+    let (hour, minute) = (
+        hour.parse::<i64>().ok()?,
+        minute.get(..2)?.parse::<i64>().ok()?,
+    );
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) || hour > 23 || minute > 59 {
+        return None;
+    }
+    Some((days_from_civil(year, month, day) * 24 + hour) * 60 + minute)
+}
+
+/// The `YYYY-MM-DD` date `days` after `date`, or `None` when `date` isn't one.
+pub fn add_days(date: &str, days: u32) -> Option<String> {
+    let minutes = parse_datetime(&format!("{} 00:00", date.trim()))?;
+    let shifted = format_datetime(minutes + days as i64 * 24 * 60);
+    Some(shifted[..10].to_string())
+}
+
+/// Inverse of `parse_datetime`.
+pub fn format_datetime(minutes: i64) -> String {
+    let (days, minute_of_day) = (minutes.div_euclid(24 * 60), minutes.rem_euclid(24 * 60));
+    let (year, month, day) = civil_from_days(days);
+    format!(
+        "{year:04}-{month:02}-{day:02} {:02}:{:02}",
+        minute_of_day / 60,
+        minute_of_day % 60
+    )
+}
+
+/// A stored UTC `YYYY-MM-DD HH:MM:SS` timestamp in local time, for showing it. SQLite converts
+/// it, so daylight saving is applied as it was at that moment; anything it can't read comes back
+/// unchanged.
+pub fn local_time(utc: &str) -> String {
+    in_local_time(utc).unwrap_or_else(|| utc.to_string())
+}
+
+/// Today's local date as `YYYY-MM-DD`, the day `date('now', 'localtime')` gives in queries.
+pub fn today() -> String {
+    match in_local_time("now") {
+        Some(now) => now[..10].to_string(),
+        None => {
+            let now = SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs() as i64 / 60);
+            format_datetime(now)[..10].to_string()
+        }
+    }
+}
+
+/// Days since 1970-01-01 for a `YYYY-MM-DD` date, or a timestamp starting with one.
+pub fn day_of(timestamp: &str) -> Option<i64> {
+    let date = timestamp.get(..10)?;
+    parse_datetime(&format!("{date} 00:00")).map(|minutes| minutes / MINUTES_PER_DAY)
+}
+
+/// Inverse of `day_of`, as a `YYYY-MM-DD` date.
+pub fn date_of(day: i64) -> String {
+    format_datetime(day * MINUTES_PER_DAY)[..10].to_string()
+}
+
+/// Today's local date as days since 1970-01-01.
+pub fn current_day() -> i64 {
+    day_of(&today()).unwrap_or(0)
+}
+
+fn in_local_time(value: &str) -> Option<String> {
+    thread_local! {
+        static CLOCK: Option<rusqlite::Connection> = rusqlite::Connection::open_in_memory().ok();
+    }
+    CLOCK.with(|clock| {
+        clock
+            .as_ref()?
+            .query_row("SELECT datetime(?1, 'localtime')", [value], |row| {
+                row.get::<_, Option<String>>(0)
+            })
+            .ok()
+            .flatten()
+    })
+}
+
+// Days since the epoch for a proleptic Gregorian date (Howard Hinnant's algorithm).
+fn days_from_civil(year: i64, month: i64, day: i64) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year - era * 400;
+    let day_of_year = (153 * (month + if month > 2 { -3 } else { 9 }) + 2) / 5 + day - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146097 + day_of_era - 719468
+}
+
+fn civil_from_days(days: i64) -> (i64, i64, i64) {
+    let days = days + 719468;
+    let era = days.div_euclid(146097);
+    let day_of_era = days - era * 146097;
+    let year_of_era =
+        (day_of_era - day_of_era / 1460 + day_of_era / 36524 - day_of_era / 146096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let mp = (5 * day_of_year + 2) / 153;
+    let day = day_of_year - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = year_of_era + era * 400 + if month <= 2 { 1 } else { 0 };
+    (year, month, day)
+}
@@ -0,0 +1,296 @@
+use std::{
+    collections::{HashMap, HashSet},
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    database::{
+        db::Database,
+        query,
+        schema::{Contact, Expense, Interview, JobApplication, Question, Reminder, StatusChange},
+    },
+    import::{ImportSummary, conflict},
+};
+
+// bumped whenever a backup written by this version can't be read by an older one
+const FORMAT: u32 = 1;
+
+/// Everything in the database as one JSON document. Local row ids are left out so backups of
+/// the same data are identical wherever they were taken; applications are keyed by UUID.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Backup {
+    pub format: u32,
+    pub applications: Vec<BackupEntry>,
+    #[serde(default)]
+    pub expenses: Vec<BackupExpense>,
+}
+
+/// An application with its files and contacts, along with everything recorded against it.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupEntry {
+    #[serde(flatten)]
+    pub application: JobApplication,
+    #[serde(default)]
+    pub history: Vec<StatusChange>,
+    #[serde(default)]
+    pub interviews: Vec<Interview>,
+    #[serde(default)]
+    pub reminders: Vec<Reminder>,
+    #[serde(default)]
+    pub questions: Vec<Question>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BackupExpense {
+    #[serde(flatten)]
+    pub expense: Expense,
+    /// UUID of the application it was for.
+    #[serde(default)]
+    pub application: Option<String>,
+}
+
+/// Collect the whole database into a backup, applications ordered by UUID.
+pub fn collect(db: &Database) -> Backup {
+    let mut interviews: HashMap<i32, Vec<Interview>> = HashMap::new();
+    for (interview, _) in query::get_interviews(db) {
+        interviews
+            .entry(interview.job_id)
+            .or_default()
+            .push(Interview {
+                id: 0,
+                job_id: 0,
+                ..interview
+            });
+    }
+    let mut reminders: HashMap<i32, Vec<Reminder>> = HashMap::new();
+    for (reminder, _) in query::get_reminders(db) {
+        reminders
+            .entry(reminder.job_id)
+            .or_default()
+            .push(Reminder {
+                id: 0,
+                job_id: 0,
+                ..reminder
+            });
+    }
+    let mut questions: HashMap<i32, Vec<Question>> = HashMap::new();
+    for (question, _) in query::get_questions(db) {
+        questions
+            .entry(question.job_id)
+            .or_default()
+            .push(Question {
+                id: 0,
+                job_id: 0,
+                ..question
+            });
+    }
+
+    let jobs = query::get_all_applications(db);
+    let uuids = jobs
+        .iter()
+        .map(|job| (job.id, job.uuid.clone()))
+        .collect::<HashMap<_, _>>();
+    let mut applications = jobs
+        .into_iter()
+        .map(|job| BackupEntry {
+            history: query::get_status_history(job.id, db)
+                .into_iter()
+                .map(|change| StatusChange {
+                    job_id: 0,
+                    ..change
+                })
+                .collect(),
+            interviews: interviews.remove(&job.id).unwrap_or_default(),
+            reminders: reminders.remove(&job.id).unwrap_or_default(),
+            questions: questions.remove(&job.id).unwrap_or_default(),
+            application: JobApplication {
+                id: 0,
+                contacts: job
+                    .contacts
+                    .iter()
+                    .map(|contact| Contact {
+                        id: 0,
+                        job_id: 0,
+                        ..contact.clone()
+                    })
+                    .collect(),
+                ..job
+            },
+        })
+        .collect::<Vec<_>>();
+    applications.sort_by(|a, b| a.application.uuid.cmp(&b.application.uuid));
+
+    let expenses = query::get_expenses(db)
+        .into_iter()
+        .map(|expense| BackupExpense {
+            application: expense.job_id.and_then(|id| uuids.get(&id).cloned()),
+            expense: Expense {
+                id: 0,
+                job_id: None,
+                ..expense
+            },
+        })
+        .collect();
+
+    Backup {
+        format: FORMAT,
+        applications,
+        expenses,
+    }
+}
+
+/// Write the whole database to `path` as pretty-printed JSON. Returns how many applications
+/// were backed up.
+pub fn write_backup(path: &Path, db: &Database) -> Result<usize> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let backup = collect(db);
+    let mut writer = BufWriter::new(File::create(path)?);
+    serde_json::to_writer_pretty(&mut writer, &backup)?;
+    writer.flush()?;
+    Ok(backup.applications.len())
+}
+
+/// Restore a backup into the database. Applications it doesn't have are added with their
+/// history, interviews, reminders and questions; ones it already has are left alone when identical and
+/// otherwise sent to the conflicts inbox, so nothing stored is overwritten.
+pub fn restore(path: &Path, db: &Database) -> Result<ImportSummary> {
+    let backup: Backup = serde_json::from_str(&std::fs::read_to_string(path)?)?;
+    if backup.format > FORMAT {
+        return Err(eyre!(
+            "backup format {} is newer than this version supports",
+            backup.format
+        ));
+    }
+
+    let mut summary = ImportSummary {
+        source: path.display().to_string(),
+        ..Default::default()
+    };
+    let mut ids = HashMap::new();
+    let mut seen = HashSet::new();
+    for entry in backup.applications {
+        let job = entry.application;
+        let label = format!(
+            "{} - {} ({})",
+            job.company_name, job.position, job.application_date
+        );
+        if job.uuid.is_empty() || !seen.insert(job.uuid.clone()) {
+            summary
+                .invalid
+                .push(format!("{label}: missing or repeated UUID"));
+            continue;
+        }
+        if let Some(local) = query::get_application_by_uuid(&job.uuid, db) {
+            ids.insert(job.uuid.clone(), local.id);
+            if !conflict::differing_fields(&local, &job).is_empty()
+                && query::add_conflict(local.id, &job, &summary.source, db)?
+            {
+                summary.conflicts.push(label);
+            } else {
+                summary.duplicates.push(label);
+            }
+            continue;
+        }
+
+        let uuid = job.uuid.clone();
+        let id = add_entry(
+            BackupEntry {
+                application: job,
+                ..entry
+            },
+            db,
+        )?;
+        ids.insert(uuid, id);
+        summary.imported += 1;
+    }
+    add_expenses(backup.expenses, &ids, db)?;
+    Ok(summary)
+}
+
+/// Add an application the database doesn't have yet, along with everything recorded against
+/// it, returning its new id.
+pub fn add_entry(entry: BackupEntry, db: &Database) -> Result<i32> {
+    let uuid = entry.application.uuid.clone();
+    query::add_application(entry.application, db)?;
+    let id = query::get_application_by_uuid(&uuid, db)
+        .map(|job| job.id)
+        .ok_or_else(|| eyre!("restored application {uuid} went missing"))?;
+    if !entry.history.is_empty() {
+        query::set_status_history(id, &entry.history, db)?;
+    }
+    for interview in entry.interviews {
+        query::add_interview(
+            Interview {
+                job_id: id,
+                ..interview
+            },
+            db,
+        )?;
+    }
+    for reminder in entry.reminders {
+        query::add_reminder(
+            Reminder {
+                job_id: id,
+                ..reminder
+            },
+            db,
+        )?;
+    }
+    for question in entry.questions {
+        query::save_question(
+            Question {
+                job_id: id,
+                ..question
+            },
+            db,
+        )?;
+    }
+    Ok(id)
+}
+
+/// Add expenses, attached to the applications `ids` gives for their UUIDs.
+pub fn add_expenses(
+    expenses: Vec<BackupExpense>,
+    ids: &HashMap<String, i32>,
+    db: &Database,
+) -> Result<()> {
+    // expenses have no identity of their own, so skip any already stored exactly as-is
+    let same = |a: &Expense, b: &Expense| {
+        (
+            a.job_id,
+            &a.date,
+            &a.category,
+            &a.description,
+            a.amount_cents,
+        ) == (
+            b.job_id,
+            &b.date,
+            &b.category,
+            &b.description,
+            b.amount_cents,
+        )
+    };
+    let stored = query::get_expenses(db);
+    for BackupExpense {
+        expense,
+        application,
+    } in expenses
+    {
+        let expense = Expense {
+            id: 0,
+            job_id: application.and_then(|uuid| ids.get(&uuid).copied()),
+            ..expense
+        };
+        if !stored.iter().any(|other| same(other, &expense)) {
+            query::add_expense(expense, db)?;
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,133 @@
+use std::{
+    borrow::Cow,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use color_eyre::Result;
+
+use crate::database::schema::{Contact, JobApplication};
+
+pub const HEADER: [&str; 26] = [
+    "id",
+    "uuid",
+    "company_name",
+    "position",
+    "position_category",
+    "work_type",
+    "location",
+    "location_type",
+    "application_date",
+    "status",
+    "is_active",
+    "notes",
+    "tags",
+    "contacts",
+    "url",
+    "cv",
+    "cover_letter",
+    "additional_documents",
+    "description",
+    "salary_min",
+    "salary_max",
+    "currency",
+    "compensation_notes",
+    "follow_up_date",
+    "deadline",
+    "rating",
+];
+
+/// Separator used between additional documents inside their single CSV field.
+pub const DOCUMENT_SEPARATOR: &str = "; ";
+/// Separator used between tags inside their single CSV field, as the tag editor splits them.
+pub const TAG_SEPARATOR: &str = ", ";
+/// Separator used between contacts inside their single CSV field. Each contact is written
+/// as `name | role | email | phone | linkedin`.
+pub const CONTACT_SEPARATOR: &str = "; ";
+
+pub fn export_csv(path: &Path, jobs: &[JobApplication]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_csv(&mut writer, jobs)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_csv<W: Write>(writer: &mut W, jobs: &[JobApplication]) -> Result<()> {
+    write_record(writer, HEADER.iter().map(|h| Cow::Borrowed(*h)))?;
+    for job in jobs {
+        let record: [Cow<str>; 26] = [
+            job.id.to_string().into(),
+            job.uuid.as_str().into(),
+            job.company_name.as_str().into(),
+            job.position.as_str().into(),
+            job.position_category.to_string().into(),
+            job.work_type.to_string().into(),
+            job.location.as_str().into(),
+            job.location_type.to_string().into(),
+            job.application_date.as_str().into(),
+            job.status.to_string().into(),
+            job.is_active.to_string().into(),
+            job.notes.as_deref().unwrap_or_default().into(),
+            job.tags.join(TAG_SEPARATOR).into(),
+            job.contacts
+                .iter()
+                .map(Contact::to_string)
+                .collect::<Vec<_>>()
+                .join(CONTACT_SEPARATOR)
+                .into(),
+            job.url.as_deref().unwrap_or_default().into(),
+            job.files.cv.as_str().into(),
+            job.files.cover_letter.as_str().into(),
+            job.files
+                .additional_documents
+                .join(DOCUMENT_SEPARATOR)
+                .into(),
+            job.description.as_deref().unwrap_or_default().into(),
+            job.salary_min
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+                .into(),
+            job.salary_max
+                .map(|v| v.to_string())
+                .unwrap_or_default()
+                .into(),
+            job.currency.as_deref().unwrap_or_default().into(),
+            job.compensation_notes.as_deref().unwrap_or_default().into(),
+            job.follow_up_date.as_deref().unwrap_or_default().into(),
+            job.deadline.as_deref().unwrap_or_default().into(),
+            job.rating
+                .map(|rating| rating.to_string())
+                .unwrap_or_default()
+                .into(),
+        ];
+        write_record(writer, record.into_iter())?;
+    }
+    Ok(())
+}
+
+fn write_record<'a, W: Write>(
+    writer: &mut W,
+    fields: impl Iterator<Item = Cow<'a, str>>,
+) -> Result<()> {
+    let line = fields
+        .map(|field| escape(&field).into_owned())
+        .collect::<Vec<_>>()
+        .join(",");
+    // RFC 4180 line endings, which spreadsheet applications expect
+    writer.write_all(line.as_bytes())?;
+    writer.write_all(b"\r\n")?;
+    Ok(())
+}
+
+/// Quote a field when it contains a delimiter, quote or line break, doubling embedded quotes.
+pub fn escape(field: &str) -> Cow<'_, str> {
+    if field.contains([',', '"', '\n', '\r']) {
+        Cow::Owned(format!("\"{}\"", field.replace('"', "\"\"")))
+    } else {
+        Cow::Borrowed(field)
+    }
+}
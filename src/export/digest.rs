@@ -0,0 +1,170 @@
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+    process::{Command, Stdio},
+};
+
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    config::{DigestConfig, DigestFormat},
+    database::{
+        db::Database,
+        query,
+        schema::{Interview, JobApplication, StatusChange},
+    },
+};
+
+/// Number of days a digest looks back over.
+pub const DIGEST_DAYS: u32 = 7;
+
+/// What happened over the last week, for sharing with an accountability partner.
+#[derive(Debug, Clone, Default)]
+pub struct Digest {
+    pub new_applications: Vec<JobApplication>,
+    pub status_changes: Vec<(JobApplication, StatusChange)>,
+    /// Interviews coming up in the next week, with their company.
+    pub upcoming_interviews: Vec<(Interview, String)>,
+}
+
+impl Digest {
+    pub fn collect(db: &Database) -> Self {
+        Digest {
+            new_applications: query::get_applications_since(DIGEST_DAYS, db),
+            status_changes: query::get_status_changes_since(DIGEST_DAYS, db),
+            upcoming_interviews: query::get_interviews_within(DIGEST_DAYS, db),
+        }
+    }
+
+    pub fn render(&self, format: DigestFormat) -> String {
+        match format {
+            DigestFormat::Text => self.render_text(),
+            DigestFormat::Html => self.render_html(),
+        }
+    }
+
+    fn sections(&self) -> [(&'static str, Vec<String>); 3] {
+        let job = |job: &JobApplication| format!("{} at {}", job.position, job.company_name);
+        [
+            (
+                "New applications",
+                self.new_applications
+                    .iter()
+                    .map(|j| format!("{} ({})", job(j), j.application_date))
+                    .collect(),
+            ),
+            (
+                "Status changes",
+                self.status_changes
+                    .iter()
+                    .map(|(j, change)| {
+                        let note = change
+                            .note
+                            .as_ref()
+                            .map(|note| format!(" - {note}"))
+                            .unwrap_or_default();
+                        format!(
+                            "{}: {} on {}{note}",
+                            job(j),
+                            change.status,
+                            change.changed_at
+                        )
+                    })
+                    .collect(),
+            ),
+            (
+                "Upcoming interviews",
+                self.upcoming_interviews
+                    .iter()
+                    .map(|(interview, company)| format!("{company} on {}", interview.starts_at))
+                    .collect(),
+            ),
+        ]
+    }
+
+    fn render_text(&self) -> String {
+        let mut out = format!("Job search digest - last {DIGEST_DAYS} days\n");
+        for (title, items) in self.sections() {
+            out.push_str(&format!("\n{title} ({})\n", items.len()));
+            if items.is_empty() {
+                out.push_str("  none\n");
+            }
+            for item in items {
+                out.push_str(&format!("  - {item}\n"));
+            }
+        }
+        out
+    }
+
+    fn render_html(&self) -> String {
+        let mut out = format!(
+            "<!DOCTYPE html>\n<html>\n<body>\n<h1>Job search digest - last {DIGEST_DAYS} days</h1>\n"
+        );
+        for (title, items) in self.sections() {
+            out.push_str(&format!("<h2>{title} ({})</h2>\n", items.len()));
+            if items.is_empty() {
+                out.push_str("<p>none</p>\n");
+                continue;
+            }
+            out.push_str("<ul>\n");
+            for item in items {
+                out.push_str(&format!("  <li>{}</li>\n", escape_html(&item)));
+            }
+            out.push_str("</ul>\n");
+        }
+        out.push_str("</body>\n</html>\n");
+        out
+    }
+}
+
+/// Pipe the digest to the configured command, or write it to `data_dir` when there is none.
+/// Returns a description of where it went.
+pub fn deliver(digest: &str, config: &DigestConfig, data_dir: &Path) -> Result<String> {
+    match &config.command {
+        Some(command) => {
+            let mut child = shell(command).stdin(Stdio::piped()).spawn()?;
+            if let Some(mut stdin) = child.stdin.take() {
+                stdin.write_all(digest.as_bytes())?;
+            }
+            let status = child.wait()?;
+            if !status.success() {
+                return Err(eyre!("`{command}` exited with {status}"));
+            }
+            Ok(format!("sent with `{command}`"))
+        }
+        None => {
+            let path = digest_path(config.format, data_dir);
+            std::fs::create_dir_all(data_dir)?;
+            std::fs::write(&path, digest)?;
+            Ok(format!("written to {}", path.display()))
+        }
+    }
+}
+
+pub fn digest_path(format: DigestFormat, data_dir: &Path) -> PathBuf {
+    let extension = match format {
+        DigestFormat::Text => "txt",
+        DigestFormat::Html => "html",
+    };
+    data_dir.join(format!("weekly_digest.{extension}"))
+}
+
+fn shell(command: &str) -> Command {
+    if cfg!(windows) {
+        let mut cmd = Command::new("cmd");
+        cmd.args(["/C", command]);
+        cmd
+    } else {
+        let mut cmd = Command::new("sh");
+        cmd.args(["-c", command]);
+        cmd
+    }
+}
+
+fn escape_html(value: &str) -> String {
+    value
+        .replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
@@ -0,0 +1,247 @@
+use std::{
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+    time::{SystemTime, UNIX_EPOCH},
+};
+
+use color_eyre::Result;
+
+use crate::{
+    config::{CalendarConfig, Config},
+    database::{
+        db::Database,
+        query,
+        schema::{Recurrence, Reminder},
+    },
+    dates::{add_days, format_datetime, parse_datetime},
+    unlock,
+};
+
+/// Write pending reminders as an iCalendar file so calendar apps can raise them too. Each
+/// reminder becomes an event with an alarm at its due time, repeated per its recurrence.
+pub fn export_ics(path: &Path, reminders: &[(Reminder, String)]) -> Result<()> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(path)?);
+    write_ics(&mut writer, reminders)?;
+    writer.flush()?;
+    Ok(())
+}
+
+pub fn write_ics<W: Write>(writer: &mut W, reminders: &[(Reminder, String)]) -> Result<()> {
+    let stamp = stamp();
+
+    write_line(writer, "BEGIN:VCALENDAR")?;
+    write_line(writer, "VERSION:2.0")?;
+    write_line(writer, "PRODID:-//job-tracker//reminders//EN")?;
+    for (reminder, company) in reminders {
+        let Some(due) = parse_datetime(&reminder.due_at) else {
+            continue;
+        };
+        let summary = escape(&format!("{company}: {}", reminder.message));
+        write_line(writer, "BEGIN:VEVENT")?;
+        write_line(writer, &format!("UID:reminder-{}@job-tracker", reminder.id))?;
+        write_line(writer, &format!("DTSTAMP:{stamp}"))?;
+        // floating time, so it is due at the same wall-clock time wherever it is imported
+        write_line(
+            writer,
+            &format!("DTSTART:{}", timestamp(&format_datetime(due))),
+        )?;
+        write_line(writer, &format!("SUMMARY:{summary}"))?;
+        if let Some(frequency) = frequency(reminder.recurrence) {
+            write_line(writer, &format!("RRULE:FREQ={frequency}"))?;
+        }
+        write_line(writer, "BEGIN:VALARM")?;
+        write_line(writer, "ACTION:DISPLAY")?;
+        write_line(writer, "TRIGGER:PT0M")?;
+        write_line(writer, &format!("DESCRIPTION:{summary}"))?;
+        write_line(writer, "END:VALARM")?;
+        write_line(writer, "END:VEVENT")?;
+    }
+    write_line(writer, "END:VCALENDAR")?;
+    Ok(())
+}
+
+/// The `calendar` subcommand: upcoming events from the usual database to `output`, or stdout.
+pub fn run(output: Option<&Path>) -> Result<()> {
+    let config = Config::new()?;
+    let mut database = Database::default();
+    unlock::from_env(&mut database, &config)?;
+    database.create()?;
+    match output {
+        Some(path) => {
+            let count = export_events(path, &database, &config.calendar)?;
+            println!(
+                "Wrote {count} interview(s) and deadline(s) to {}",
+                path.display()
+            );
+        }
+        None => {
+            let mut stdout = std::io::stdout().lock();
+            write_events(&mut stdout, &database, &config.calendar)?;
+            stdout.flush()?;
+        }
+    }
+    Ok(())
+}
+
+/// Write upcoming interviews and the deadlines of active applications as an iCalendar file,
+/// e.g. to import into Google Calendar or Outlook. Returns how many events were written.
+pub fn export_events(path: &Path, db: &Database, alarms: &CalendarConfig) -> Result<usize> {
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
+    let mut writer = BufWriter::new(File::create(path)?);
+    let count = write_events(&mut writer, db, alarms)?;
+    writer.flush()?;
+    Ok(count)
+}
+
+pub fn write_events<W: Write>(
+    writer: &mut W,
+    db: &Database,
+    alarms: &CalendarConfig,
+) -> Result<usize> {
+    let now = query::now(db);
+    let stamp = stamp();
+    let today = now.get(..10).unwrap_or_default();
+    let mut count = 0;
+
+    write_line(writer, "BEGIN:VCALENDAR")?;
+    write_line(writer, "VERSION:2.0")?;
+    write_line(
+        writer,
+        "PRODID:-//job-tracker//interviews and deadlines//EN",
+    )?;
+    for (interview, company) in query::get_interviews(db) {
+        let Some(start) = parse_datetime(&interview.starts_at) else {
+            continue;
+        };
+        if interview.starts_at.as_str() < now.as_str() {
+            continue;
+        }
+        let end = start + interview.duration_minutes as i64;
+        write_line(writer, "BEGIN:VEVENT")?;
+        write_line(
+            writer,
+            &format!("UID:interview-{}@job-tracker", interview.id),
+        )?;
+        write_line(writer, &format!("DTSTAMP:{stamp}"))?;
+        write_line(
+            writer,
+            &format!("DTSTART:{}", timestamp(&format_datetime(start))),
+        )?;
+        write_line(
+            writer,
+            &format!("DTEND:{}", timestamp(&format_datetime(end))),
+        )?;
+        let summary = escape(&format!("Interview: {company}"));
+        write_line(writer, &format!("SUMMARY:{summary}"))?;
+        if !interview.notes.is_empty() {
+            write_line(writer, &format!("DESCRIPTION:{}", escape(&interview.notes)))?;
+        }
+        if alarms.interview_alarm_minutes > 0 {
+            write_alarm(
+                writer,
+                &format!("-PT{}M", alarms.interview_alarm_minutes),
+                &summary,
+            )?;
+        }
+        write_line(writer, "END:VEVENT")?;
+        count += 1;
+    }
+    for job in query::get_all_applications(db) {
+        let Some(deadline) = job.deadline.as_deref() else {
+            continue;
+        };
+        let Some(next_day) = add_days(deadline, 1) else {
+            continue;
+        };
+        if !job.is_active || deadline < today {
+            continue;
+        }
+        write_line(writer, "BEGIN:VEVENT")?;
+        write_line(writer, &format!("UID:deadline-{}@job-tracker", job.uuid))?;
+        write_line(writer, &format!("DTSTAMP:{stamp}"))?;
+        // all-day, ending the day after as iCalendar end dates are exclusive
+        write_line(
+            writer,
+            &format!("DTSTART;VALUE=DATE:{}", deadline.replace('-', "")),
+        )?;
+        write_line(
+            writer,
+            &format!("DTEND;VALUE=DATE:{}", next_day.replace('-', "")),
+        )?;
+        let summary = escape(&format!(
+            "Deadline: {} - {}",
+            job.company_name, job.position
+        ));
+        write_line(writer, &format!("SUMMARY:{summary}"))?;
+        if let Some(url) = job.url.as_deref().filter(|url| !url.is_empty()) {
+            write_line(writer, &format!("URL:{url}"))?;
+        }
+        if alarms.deadline_alarm_days > 0 {
+            write_alarm(
+                writer,
+                &format!("-P{}D", alarms.deadline_alarm_days),
+                &summary,
+            )?;
+        }
+        write_line(writer, "END:VEVENT")?;
+        count += 1;
+    }
+    write_line(writer, "END:VCALENDAR")?;
+    Ok(count)
+}
+
+// an alert `trigger` before the event starts, e.g. `-PT30M`
+fn write_alarm<W: Write>(writer: &mut W, trigger: &str, summary: &str) -> Result<()> {
+    write_line(writer, "BEGIN:VALARM")?;
+    write_line(writer, "ACTION:DISPLAY")?;
+    write_line(writer, &format!("TRIGGER:{trigger}"))?;
+    write_line(writer, &format!("DESCRIPTION:{summary}"))?;
+    write_line(writer, "END:VALARM")?;
+    Ok(())
+}
+
+fn frequency(recurrence: Recurrence) -> Option<&'static str> {
+    match recurrence {
+        Recurrence::Once => None,
+        Recurrence::Daily => Some("DAILY"),
+        Recurrence::Weekly => Some("WEEKLY"),
+        Recurrence::Monthly => Some("MONTHLY"),
+    }
+}
+
+// when the file was written, in UTC
+fn stamp() -> String {
+    let now = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map_or(0, |elapsed| elapsed.as_secs() as i64 / 60);
+    timestamp(&format_datetime(now)) + "Z"
+}
+
+// `YYYY-MM-DD HH:MM`, as produced by `format_datetime`, to the basic `YYYYMMDDTHHMMSS` form
+fn timestamp(datetime: &str) -> String {
+    let digits = datetime
+        .chars()
+        .filter(char::is_ascii_digit)
+        .collect::<String>();
+    format!("{}T{}00", &digits[..8], &digits[8..12])
+}
+
+fn escape(text: &str) -> String {
+    text.replace('\\', "\\\\")
+        .replace(';', "\\;")
+        .replace(',', "\\,")
+        .replace('\n', "\\n")
+}
+
+// iCalendar lines end in CRLF
+fn write_line<W: Write>(writer: &mut W, line: &str) -> Result<()> {
+    writer.write_all(line.as_bytes())?;
+    writer.write_all(b"\r\n")?;
+    Ok(())
+}
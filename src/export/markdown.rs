@@ -0,0 +1,64 @@
+use crate::database::schema::JobApplication;
+
+/// The application as Markdown, for pasting into an email or notes app. Reads as plain text
+/// too; fields that aren't set are left out.
+pub fn job(job: &JobApplication) -> String {
+    let mut out = format!("## {} at {}\n\n", job.position, job.company_name);
+    let mut field = |label: &str, value: String| {
+        if !value.is_empty() {
+            out.push_str(&format!("- **{label}:** {value}\n"));
+        }
+    };
+    field("Status", job.status.to_string());
+    field("Applied", job.application_date.clone());
+    field(
+        "Location",
+        match job.location.as_str() {
+            "" => job.location_type.to_string(),
+            location => format!("{location} ({})", job.location_type),
+        },
+    );
+    field(
+        "Type",
+        format!("{}, {}", job.work_type, job.position_category),
+    );
+    field("Salary", job.salary_range().unwrap_or_default());
+    field(
+        "Compensation",
+        job.compensation_notes.clone().unwrap_or_default(),
+    );
+    field("Follow up", job.follow_up_date.clone().unwrap_or_default());
+    field("Deadline", job.deadline.clone().unwrap_or_default());
+    field("Rating", job.stars().unwrap_or_default());
+    field("Link", job.url.clone().unwrap_or_default());
+    for contact in &job.contacts {
+        let details = [
+            &contact.role,
+            &contact.email,
+            &contact.phone,
+            &contact.linkedin,
+        ]
+        .into_iter()
+        .filter(|detail| !detail.is_empty())
+        .map(String::as_str)
+        .collect::<Vec<_>>();
+        field(
+            "Contact",
+            if details.is_empty() {
+                contact.name.clone()
+            } else {
+                format!("{} ({})", contact.name, details.join(", "))
+            },
+        );
+    }
+    for (heading, text) in [("Notes", &job.notes), ("Description", &job.description)] {
+        if let Some(text) = text
+            .as_deref()
+            .map(str::trim)
+            .filter(|text| !text.is_empty())
+        {
+            out.push_str(&format!("\n### {heading}\n\n{text}\n"));
+        }
+    }
+    out
+}
@@ -0,0 +1,5 @@
+pub mod backup;
+pub mod csv;
+pub mod digest;
+pub mod ics;
+pub mod markdown;
@@ -0,0 +1,367 @@
+//! Made-up but realistic applications, so the demo, benchmarks and scripts exercising the app
+//! all work from the same data. Everything follows from a seed and the day taken as today:
+//! the same pair always gives the same applications, histories and interviews.
+
+use std::path::Path;
+
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    database::{
+        db::Database,
+        query,
+        schema::{
+            ApplicationStatus, Interview, JobApplication, LocationType, PositionCategory,
+            StatusChange, WorkType,
+        },
+    },
+    dates,
+};
+
+const MINUTES_PER_DAY: i64 = 24 * 60;
+// applications are spread over this many days before today
+const SPAN_DAYS: i64 = 180;
+
+const COMPANIES: [&str; 24] = [
+    "Acme Corp",
+    "Globex",
+    "Initech",
+    "Umbrella Labs",
+    "Hooli",
+    "Stark Industries",
+    "Wayne Enterprises",
+    "Cyberdyne Systems",
+    "Soylent",
+    "Tyrell",
+    "Aperture Science",
+    "Black Mesa",
+    "Vandelay Industries",
+    "Pied Piper",
+    "Massive Dynamic",
+    "Oscorp",
+    "Wonka Industries",
+    "Gringotts",
+    "Monsters Inc",
+    "Dunder Mifflin",
+    "Prestige Worldwide",
+    "Nakatomi Trading",
+    "Blue Sun",
+    "Veridian Dynamics",
+];
+
+const POSITIONS: [(&str, PositionCategory); 12] = [
+    ("Backend Engineer", PositionCategory::Engineering),
+    ("Senior Rust Engineer", PositionCategory::Engineering),
+    ("Platform Engineer", PositionCategory::Engineering),
+    ("Full Stack Developer", PositionCategory::Development),
+    ("Frontend Developer", PositionCategory::Development),
+    ("Mobile Developer", PositionCategory::Development),
+    ("Support Engineer", PositionCategory::Support),
+    ("Data Scientist", PositionCategory::DataScience),
+    ("Machine Learning Engineer", PositionCategory::DataScience),
+    ("Data Analyst", PositionCategory::Analyst),
+    ("Business Analyst", PositionCategory::Analyst),
+    ("Product Designer", PositionCategory::Design),
+];
+
+const LOCATIONS: [&str; 8] = [
+    "London",
+    "Manchester",
+    "Edinburgh",
+    "Berlin",
+    "Amsterdam",
+    "Dublin",
+    "New York",
+    "Remote",
+];
+
+const TAGS: [&str; 8] = [
+    "rust",
+    "referral",
+    "startup",
+    "dream-job",
+    "recruiter",
+    "visa",
+    "fintech",
+    "open-source",
+];
+
+const NOTES: [&str; 6] = [
+    "Found through a friend of a friend.",
+    "Recruiter reached out on LinkedIn.",
+    "Take-home task expected after the first call.",
+    "Salary band not listed, ask at screening.",
+    "Team works mostly async across time zones.",
+    "Applied through the careers page.",
+];
+
+// stages an application moves through before an outcome, in order
+const STAGES: [ApplicationStatus; 5] = [
+    ApplicationStatus::Applied,
+    ApplicationStatus::PhoneScreen,
+    ApplicationStatus::Interviewing,
+    ApplicationStatus::TechnicalInterview,
+    ApplicationStatus::Offered,
+];
+
+/// SplitMix64, so the data doesn't depend on a random number crate's algorithm staying put.
+struct Rng(u64);
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    fn below(&mut self, n: u64) -> u64 {
+        self.next() % n
+    }
+
+    fn range(&mut self, low: i64, high: i64) -> i64 {
+        low + self.below((high - low + 1) as u64) as i64
+    }
+
+    fn percent(&mut self, chance: u64) -> bool {
+        self.below(100) < chance
+    }
+
+    fn pick<'a, T>(&mut self, items: &'a [T]) -> &'a T {
+        &items[self.below(items.len() as u64) as usize]
+    }
+}
+
+/// An application as generated, with what happened to it.
+pub struct Sample {
+    pub application: JobApplication,
+    pub history: Vec<StatusChange>,
+    pub interviews: Vec<Interview>,
+}
+
+pub struct Generator {
+    rng: Rng,
+    // days since 1970-01-01
+    today: i64,
+}
+
+impl Generator {
+    /// `today` is in days since 1970-01-01; nothing is dated after it.
+    pub fn new(seed: u64, today: i64) -> Self {
+        Self {
+            rng: Rng(seed),
+            today,
+        }
+    }
+
+    fn date(day: i64) -> String {
+        dates::date_of(day)
+    }
+
+    fn uuid(&mut self) -> String {
+        let (high, low) = (self.rng.next(), self.rng.next());
+        format!(
+            "{:08x}-{:04x}-4{:03x}-{:04x}-{:012x}",
+            high >> 32,
+            (high >> 16) & 0xffff,
+            high & 0xfff,
+            0x8000 | ((low >> 48) & 0x3fff),
+            low & 0xffff_ffff_ffff
+        )
+    }
+
+    // the statuses reached, with the day each was reached on
+    fn history(&mut self, applied: i64) -> Vec<(ApplicationStatus, i64)> {
+        let mut history = vec![(ApplicationStatus::Applied, applied)];
+        let mut day = applied;
+        for stage in STAGES.iter().skip(1) {
+            // fewer get through each stage
+            if !self.rng.percent(55) {
+                break;
+            }
+            day += self.rng.range(3, 14);
+            if day > self.today {
+                return history;
+            }
+            history.push((stage.clone(), day));
+        }
+        let outcome_day = day + self.rng.range(5, 21);
+        // some are still waiting to hear back, however long it's been
+        if outcome_day > self.today || self.rng.percent(30) {
+            return history;
+        }
+        let outcome = match history.len() {
+            1 if self.rng.percent(50) => ApplicationStatus::Ghosted,
+            len if len == STAGES.len() && self.rng.percent(60) => ApplicationStatus::Accepted,
+            _ if self.rng.percent(15) => ApplicationStatus::Withdrawn,
+            _ => ApplicationStatus::Rejected,
+        };
+        history.push((outcome, outcome_day));
+        history
+    }
+
+    pub fn sample(&mut self) -> Sample {
+        let company = self.rng.pick(&COMPANIES).to_string();
+        let (position, category) = self.rng.pick(&POSITIONS).clone();
+        let location = self.rng.pick(&LOCATIONS).to_string();
+        let location_type = if location == "Remote" {
+            LocationType::Remote
+        } else {
+            self.rng
+                .pick(&[
+                    LocationType::OnSite,
+                    LocationType::Hybrid,
+                    LocationType::Remote,
+                ])
+                .clone()
+        };
+        let work_type = if self.rng.percent(85) {
+            WorkType::FullTime
+        } else {
+            self.rng
+                .pick(&[WorkType::Contract, WorkType::PartTime, WorkType::Internship])
+                .clone()
+        };
+        let applied = self.today - self.rng.range(0, SPAN_DAYS);
+        let history = self.history(applied);
+        let (status, last_day) = history.last().cloned().expect("starts at Applied");
+        let is_active = status.is_active();
+
+        let salary_min = self.rng.percent(60).then(|| self.rng.range(35, 120) * 1000);
+        let mut tags = (0..self.rng.range(0, 2))
+            .map(|_| self.rng.pick(&TAGS).to_string())
+            .collect::<Vec<_>>();
+        tags.sort();
+        tags.dedup();
+        let application = JobApplication {
+            uuid: self.uuid(),
+            company_name: company.clone(),
+            url: Some(format!(
+                "https://careers.{}.example/jobs/{}",
+                company.to_lowercase().replace(' ', "-"),
+                self.rng.range(1000, 9999)
+            )),
+            position: position.to_string(),
+            position_category: category,
+            work_type,
+            location,
+            location_type,
+            application_date: Self::date(applied),
+            is_active,
+            status,
+            notes: self
+                .rng
+                .percent(40)
+                .then(|| self.rng.pick(&NOTES).to_string()),
+            salary_min,
+            salary_max: salary_min.map(|min| min + self.rng.range(5, 30) * 1000),
+            currency: salary_min.map(|_| self.rng.pick(&["GBP", "EUR", "USD"]).to_string()),
+            follow_up_date: (is_active && self.rng.percent(30)).then(|| Self::date(last_day + 7)),
+            rating: self.rng.percent(60).then(|| self.rng.range(1, 5) as u8),
+            tags,
+            ..Default::default()
+        };
+
+        let interviews = history
+            .iter()
+            .filter(|(status, _)| {
+                matches!(
+                    status,
+                    ApplicationStatus::Interviewing | ApplicationStatus::TechnicalInterview
+                )
+            })
+            .map(|(_, day)| Interview {
+                starts_at: dates::format_datetime(
+                    day * MINUTES_PER_DAY + self.rng.range(9, 16) * 60,
+                ),
+                duration_minutes: *self.rng.pick(&[30, 45, 60, 90]),
+                notes: String::new(),
+                ..Default::default()
+            })
+            .collect();
+        let history = history
+            .into_iter()
+            .map(|(status, day)| StatusChange {
+                job_id: 0,
+                status,
+                changed_at: format!(
+                    "{} {:02}:{:02}:00",
+                    Self::date(day),
+                    self.rng.range(8, 18),
+                    self.rng.range(0, 59)
+                ),
+                note: None,
+            })
+            .collect();
+        Sample {
+            application,
+            history,
+            interviews,
+        }
+    }
+}
+
+/// Add `count` generated applications to `db`, with their histories and interviews, and each
+/// one created when its status was first recorded and last updated when it last changed.
+pub fn populate(db: &Database, count: usize, seed: u64, today: i64) -> Result<()> {
+    let mut generator = Generator::new(seed, today);
+    for _ in 0..count {
+        let sample = generator.sample();
+        let uuid = sample.application.uuid.clone();
+        query::add_application(sample.application, db)?;
+        let id = query::get_application_by_uuid(&uuid, db)
+            .map(|job| job.id)
+            .ok_or_else(|| eyre!("generated application {uuid} went missing"))?;
+        query::set_status_history(id, &sample.history, db)?;
+        if let (Some(first), Some(last)) = (sample.history.first(), sample.history.last()) {
+            db.connection().execute(
+                "UPDATE job_applications SET created_at = ?1, last_updated = ?2 WHERE id = ?3",
+                rusqlite::params![first.changed_at, last.changed_at, id],
+            )?;
+        }
+        for interview in sample.interviews {
+            query::add_interview(
+                Interview {
+                    job_id: id,
+                    ..interview
+                },
+                db,
+            )?;
+        }
+    }
+    db.invalidate();
+    Ok(())
+}
+
+/// A new database at `path` holding `count` generated applications. An existing file is
+/// left alone rather than mixed with made-up data.
+pub fn sample_database(path: &Path, count: usize, seed: u64, today: i64) -> Result<Database> {
+    if path.exists() {
+        return Err(eyre!("{} already exists", path.display()));
+    }
+    let database = Database::new(&path.to_string_lossy())?;
+    database.create()?;
+    populate(&database, count, seed, today)?;
+    Ok(database)
+}
+
+/// A throwaway database in memory holding `count` generated applications, for `--demo`.
+pub fn demo_database(count: usize, seed: u64, today: i64) -> Result<Database> {
+    let database = Database::in_memory()?;
+    populate(&database, count, seed, today)?;
+    Ok(database)
+}
+
+/// `today` in days since 1970-01-01, from a `YYYY-MM-DD` date or the clock.
+pub fn day(date: Option<&str>) -> Result<i64> {
+    match date {
+        Some(date) => dates::parse_datetime(&format!("{} 00:00", date.trim()))
+            .map(|minutes| minutes / MINUTES_PER_DAY)
+            .ok_or_else(|| eyre!("{date} isn't a YYYY-MM-DD date")),
+        None => Ok(std::time::SystemTime::now()
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_or(0, |elapsed| {
+                elapsed.as_secs() as i64 / (MINUTES_PER_DAY * 60)
+            })),
+    }
+}
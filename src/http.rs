@@ -0,0 +1,44 @@
+//! Plain HTTP GETs for the little fetched from the web: job postings, the boards' job APIs and
+//! company logos. Every request gives up after `TIMEOUT` and reads no more of a body than it
+//! is allowed, so a slow or huge response can't hang or swamp the app.
+
+use std::time::Duration;
+
+use color_eyre::{Result, eyre::eyre};
+use ureq::Agent;
+
+// some boards turn away clients that don't look like a browser
+const USER_AGENT: &str = "Mozilla/5.0 (X11; Linux x86_64; rv:128.0) Gecko/20100101 Firefox/128.0";
+// longest a request may take, from connecting to reading the last of the body
+const TIMEOUT: Duration = Duration::from_secs(15);
+
+/// The status and body of `url`, following redirects and reading at most `limit` bytes of the
+/// body. Error statuses are returned like any other, for the caller to judge.
+pub fn get(url: &str, limit: u64) -> Result<(u16, Vec<u8>)> {
+    let agent: Agent = Agent::config_builder()
+        .timeout_global(Some(TIMEOUT))
+        .http_status_as_error(false)
+        .user_agent(USER_AGENT)
+        .build()
+        .into();
+    let mut response = agent
+        .get(url)
+        .call()
+        .map_err(|err| eyre!("Failed to fetch {url}: {err}"))?;
+    let status = response.status().as_u16();
+    let body = response
+        .body_mut()
+        .with_config()
+        .limit(limit)
+        .read_to_vec()
+        .map_err(|err| eyre!("Failed to read {url}: {err}"))?;
+    Ok((status, body))
+}
+
+/// The body of `url` when it answers with success, and an error naming the status otherwise.
+pub fn get_ok(url: &str, limit: u64) -> Result<Vec<u8>> {
+    match get(url, limit)? {
+        (200..=299, body) => Ok(body),
+        (status, _) => Err(eyre!("Failed to fetch {url}: HTTP {status}")),
+    }
+}
@@ -0,0 +1,80 @@
+use crate::database::schema::JobApplication;
+
+/// A field that can differ between a stored application and an incoming copy of it.
+pub struct ConflictField {
+    pub name: &'static str,
+    /// The field's value as shown side by side.
+    pub text: fn(&JobApplication) -> String,
+    /// Copy the field from the second application into the first.
+    pub take: fn(&mut JobApplication, &JobApplication),
+}
+
+macro_rules! field {
+    ($name:literal, $field:ident) => {
+        ConflictField {
+            name: $name,
+            text: |job| job.$field.to_string(),
+            take: |into, from| into.$field = from.$field.clone(),
+        }
+    };
+    ($name:literal, $field:ident, optional) => {
+        ConflictField {
+            name: $name,
+            text: |job| {
+                job.$field
+                    .as_ref()
+                    .map(|value| value.to_string())
+                    .unwrap_or_default()
+            },
+            take: |into, from| into.$field = from.$field.clone(),
+        }
+    };
+}
+
+pub const FIELDS: [ConflictField; 18] = [
+    field!("Company", company_name),
+    field!("Position", position),
+    field!("Category", position_category),
+    field!("Work type", work_type),
+    field!("Location", location),
+    field!("Location type", location_type),
+    field!("Applied", application_date),
+    field!("Status", status),
+    field!("URL", url, optional),
+    field!("Documents", files),
+    field!("Notes", notes, optional),
+    field!("Description", description, optional),
+    field!("Salary min", salary_min, optional),
+    field!("Salary max", salary_max, optional),
+    field!("Currency", currency, optional),
+    field!("Compensation", compensation_notes, optional),
+    field!("Deadline", deadline, optional),
+    field!("Rating", rating, optional),
+];
+
+/// Indices into `FIELDS` of the fields `local` and `incoming` disagree on.
+pub fn differing_fields(local: &JobApplication, incoming: &JobApplication) -> Vec<usize> {
+    FIELDS
+        .iter()
+        .enumerate()
+        .filter(|(_, field)| (field.text)(local) != (field.text)(incoming))
+        .map(|(idx, _)| idx)
+        .collect()
+}
+
+/// `local` with the fields picked in `take_incoming` replaced by those of `incoming`. The
+/// status decides whether the result is active, as it does when editing.
+pub fn merge(
+    local: &JobApplication,
+    incoming: &JobApplication,
+    take_incoming: &[usize],
+) -> JobApplication {
+    let mut merged = local.clone();
+    for idx in take_incoming {
+        if let Some(field) = FIELDS.get(*idx) {
+            (field.take)(&mut merged, incoming);
+        }
+    }
+    merged.is_active = merged.status.is_active();
+    merged
+}
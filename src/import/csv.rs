@@ -0,0 +1,142 @@
+use std::{collections::HashMap, str::FromStr};
+
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    database::{
+        builder::JobApplicationBuilder,
+        schema::{Contact, Files, JobApplication},
+    },
+    export::csv::{CONTACT_SEPARATOR, DOCUMENT_SEPARATOR, HEADER},
+};
+
+const REQUIRED_COLUMNS: [&str; 4] = ["company_name", "position", "application_date", "status"];
+// Older exports had a single free-text contact, read in as a contact's name
+const LEGACY_COLUMNS: [&str; 1] = ["contact_info"];
+
+/// Parse a CSV file laid out like `export::csv`, returning one result per data row. Columns are
+/// matched by header name, so they may be reordered and optional ones left out.
+pub fn parse(input: &str) -> Result<Vec<Result<JobApplication, String>>> {
+    let mut records = parse_records(input)?.into_iter();
+    let header = records.next().ok_or_else(|| eyre!("CSV file is empty"))?;
+    let columns = header
+        .iter()
+        .enumerate()
+        .map(|(idx, name)| (name.trim().to_lowercase(), idx))
+        .collect::<HashMap<_, _>>();
+    if let Some(missing) = REQUIRED_COLUMNS.iter().find(|c| !columns.contains_key(**c)) {
+        return Err(eyre!("CSV file is missing the `{missing}` column"));
+    }
+    if let Some(unknown) = columns
+        .keys()
+        .find(|c| !HEADER.contains(&c.as_str()) && !LEGACY_COLUMNS.contains(&c.as_str()))
+    {
+        return Err(eyre!("CSV file has an unknown column `{unknown}`"));
+    }
+
+    Ok(records
+        .enumerate()
+        // row numbers are 1-based and count the header
+        .map(|(idx, record)| {
+            job_from_record(&columns, &record).map_err(|e| format!("Row {}: {e}", idx + 2))
+        })
+        .collect())
+}
+
+fn job_from_record(
+    columns: &HashMap<String, usize>,
+    record: &[String],
+) -> std::result::Result<JobApplication, String> {
+    let field = |name: &str| {
+        columns
+            .get(name)
+            .and_then(|idx| record.get(*idx))
+            .map(|value| value.trim().to_string())
+            .unwrap_or_default()
+    };
+    let mut contacts = field("contacts")
+        .split(CONTACT_SEPARATOR.trim())
+        .filter(|contact| !contact.trim().is_empty())
+        .map(Contact::from_str)
+        .collect::<std::result::Result<Vec<_>, _>>()?;
+    let contact_info = field("contact_info");
+    if !contact_info.is_empty() {
+        contacts.push(Contact {
+            name: contact_info,
+            ..Contact::default()
+        });
+    }
+    JobApplicationBuilder::new()
+        .uuid(&field("uuid"))
+        .company_name(&field("company_name"))
+        .position(&field("position"))
+        .position_category(&field("position_category"))
+        .work_type(&field("work_type"))
+        .location(&field("location"))
+        .location_type(&field("location_type"))
+        .application_date(&field("application_date"))
+        .status(&field("status"))
+        .active(&field("is_active"))
+        .notes(&field("notes"))
+        .tags(&field("tags"))
+        .url(&field("url"))
+        .files(Files {
+            cv: field("cv"),
+            cover_letter: field("cover_letter"),
+            additional_documents: field("additional_documents")
+                .split(DOCUMENT_SEPARATOR.trim())
+                .map(|doc| doc.trim().to_string())
+                .filter(|doc| !doc.is_empty())
+                .collect(),
+        })
+        .contacts(contacts)
+        .description(&field("description"))
+        .salary_min(&field("salary_min"))
+        .salary_max(&field("salary_max"))
+        .currency(&field("currency"))
+        .compensation_notes(&field("compensation_notes"))
+        .follow_up_date(&field("follow_up_date"))
+        .deadline(&field("deadline"))
+        .rating(&field("rating"))
+        .build()
+}
+
+/// Split RFC 4180 CSV into records, handling quoted fields with embedded delimiters, doubled
+/// quotes and line breaks.
+fn parse_records(input: &str) -> Result<Vec<Vec<String>>> {
+    let mut records = Vec::new();
+    let mut record = Vec::new();
+    let mut field = String::new();
+    let mut in_quotes = false;
+    let mut chars = input.trim_start_matches('\u{feff}').chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match (c, in_quotes) {
+            ('"', true) if chars.peek() == Some(&'"') => {
+                field.push('"');
+                chars.next();
+            }
+            ('"', true) => in_quotes = false,
+            ('"', false) if field.is_empty() => in_quotes = true,
+            (',', false) => record.push(std::mem::take(&mut field)),
+            ('\r', false) if chars.peek() == Some(&'\n') => {}
+            ('\n', false) => {
+                record.push(std::mem::take(&mut field));
+                if record.iter().any(|f| !f.is_empty()) {
+                    records.push(std::mem::take(&mut record));
+                } else {
+                    record.clear();
+                }
+            }
+            (c, _) => field.push(c),
+        }
+    }
+    if in_quotes {
+        return Err(eyre!("CSV file ends inside a quoted field"));
+    }
+    record.push(field);
+    if record.iter().any(|f| !f.is_empty()) {
+        records.push(record);
+    }
+    Ok(records)
+}
@@ -0,0 +1,19 @@
+use color_eyre::Result;
+
+use crate::database::{builder::JobApplicationBuilder, schema::JobApplication};
+
+/// Parse a JSON array of applications, validating each entry on its own so one bad entry
+/// doesn't reject the whole file.
+pub fn parse(input: &str) -> Result<Vec<Result<JobApplication, String>>> {
+    let values: Vec<serde_json::Value> = serde_json::from_str(input)?;
+    Ok(values
+        .into_iter()
+        .enumerate()
+        .map(|(idx, value)| {
+            serde_json::from_value::<JobApplication>(value)
+                .map_err(|err| err.to_string())
+                .and_then(|job| JobApplicationBuilder::from(job).build())
+                .map_err(|err| format!("Entry {}: {err}", idx + 1))
+        })
+        .collect())
+}
@@ -0,0 +1,104 @@
+pub mod conflict;
+pub mod csv;
+pub mod json;
+pub mod posting;
+pub mod quick;
+pub mod sync;
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+use crate::database::{db::Database, query, schema::JobApplication};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ImportSummary {
+    pub source: String,
+    pub imported: usize,
+    pub duplicates: Vec<String>,
+    /// Matches of stored applications that differ from them, sent to the conflicts inbox.
+    #[serde(default)]
+    pub conflicts: Vec<String>,
+    pub invalid: Vec<String>,
+}
+
+/// Applications are considered the same when company, position and application date match,
+/// ignoring case and surrounding whitespace.
+fn duplicate_key(job: &JobApplication) -> (String, String, String) {
+    (
+        job.company_name.trim().to_lowercase(),
+        job.position.trim().to_lowercase(),
+        job.application_date.trim().to_lowercase(),
+    )
+}
+
+fn label(job: &JobApplication) -> String {
+    format!(
+        "{} - {} ({})",
+        job.company_name, job.position, job.application_date
+    )
+}
+
+/// Import a CSV or JSON export (chosen by extension), inserting every valid application that
+/// isn't already in the database or earlier in the same file. Applications are matched by
+/// UUID where they have one, otherwise by `duplicate_key`. Matches of stored applications
+/// that differ from them are held as conflicts instead of being skipped or overwritten.
+pub fn import_file(path: &Path, db: &Database) -> Result<ImportSummary> {
+    let contents = std::fs::read_to_string(path)?;
+    let rows = match path.extension().and_then(|ext| ext.to_str()) {
+        Some(ext) if ext.eq_ignore_ascii_case("json") => json::parse(&contents)?,
+        _ => csv::parse(&contents)?,
+    };
+
+    let stored = query::get_all_applications(db);
+    let by_key = stored
+        .iter()
+        .map(|job| (duplicate_key(job), job))
+        .collect::<HashMap<_, _>>();
+    let by_uuid = stored
+        .iter()
+        .map(|job| (job.uuid.as_str(), job))
+        .collect::<HashMap<_, _>>();
+    let find_stored = |job: &JobApplication| {
+        by_uuid
+            .get(job.uuid.as_str())
+            .or_else(|| by_key.get(&duplicate_key(job)))
+            .copied()
+    };
+    let mut seen = HashSet::new();
+    let mut seen_uuids = HashSet::new();
+    let mut summary = ImportSummary {
+        source: path.display().to_string(),
+        ..Default::default()
+    };
+    for row in rows {
+        match row {
+            Ok(job)
+                if !seen.insert(duplicate_key(&job))
+                    || (!job.uuid.is_empty() && !seen_uuids.insert(job.uuid.clone())) =>
+            {
+                summary.duplicates.push(label(&job))
+            }
+            Ok(job) if find_stored(&job).is_some() => {
+                let local = find_stored(&job).unwrap();
+                if !conflict::differing_fields(local, &job).is_empty()
+                    && query::add_conflict(local.id, &job, &summary.source, db)?
+                {
+                    summary.conflicts.push(label(&job));
+                } else {
+                    summary.duplicates.push(label(&job));
+                }
+            }
+            Ok(job) => {
+                query::add_application(JobApplication { id: 0, ..job }, db)?;
+                summary.imported += 1;
+            }
+            Err(message) => summary.invalid.push(message),
+        }
+    }
+    Ok(summary)
+}
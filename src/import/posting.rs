@@ -0,0 +1,335 @@
+//! A new application filled in from a job posting's page. Sites describe postings for search
+//! engines as schema.org `JobPosting` JSON-LD, which gives the most; OpenGraph tags and the
+//! page title stand in where it's missing.
+
+use color_eyre::{Result, eyre::eyre};
+use serde_json::Value;
+
+use crate::{
+    categorize,
+    database::schema::{ApplicationStatus, JobApplication, LocationType, WorkType},
+    http,
+};
+
+// largest page read, well above any posting's
+const MAX_PAGE_BYTES: u64 = 5_000_000;
+
+/// The page at `url`, following redirects.
+pub fn fetch(url: &str) -> Result<String> {
+    if !url.starts_with("http://") && !url.starts_with("https://") {
+        return Err(eyre!("{url} isn't a web link"));
+    }
+    let page = http::get_ok(url, MAX_PAGE_BYTES)?;
+    Ok(String::from_utf8_lossy(&page).into_owned())
+}
+
+/// A posting saved for later with what the page gives, or `None` when it names neither a
+/// position nor a company.
+pub fn parse(html: &str, url: &str) -> Option<JobApplication> {
+    let mut job = json_ld(html)
+        .map(|posting| from_json_ld(&posting))
+        .unwrap_or_default();
+    if job.position.is_empty() || job.company_name.is_empty() {
+        let (position, company, location) = from_title(html);
+        fill(&mut job.position, position);
+        fill(&mut job.company_name, company);
+        fill(&mut job.location, location);
+    }
+    if job.description.is_none() {
+        job.description = meta(html, "og:description")
+            .or_else(|| meta(html, "description"))
+            .filter(|description| !description.is_empty());
+    }
+    if job.position.is_empty() && job.company_name.is_empty() {
+        return None;
+    }
+    if let Some((category, _)) = categorize::category_for(&job.position) {
+        job.position_category = category;
+    }
+    Some(JobApplication {
+        url: Some(url.to_string()),
+        status: ApplicationStatus::Saved,
+        is_active: true,
+        ..job
+    })
+}
+
+fn fill(field: &mut String, value: Option<String>) {
+    if field.is_empty()
+        && let Some(value) = value
+    {
+        *field = value;
+    }
+}
+
+/// The first `JobPosting` among the page's JSON-LD blocks, which may hold it in a list or a
+/// `@graph`.
+fn json_ld(html: &str) -> Option<Value> {
+    fn find(value: Value) -> Option<Value> {
+        match value {
+            Value::Array(items) => items.into_iter().find_map(find),
+            Value::Object(ref object) => {
+                let is_posting = match object.get("@type") {
+                    Some(Value::String(kind)) => kind == "JobPosting",
+                    Some(Value::Array(kinds)) => kinds.iter().any(|kind| kind == "JobPosting"),
+                    _ => false,
+                };
+                if is_posting {
+                    return Some(value);
+                }
+                object.get("@graph").cloned().and_then(find)
+            }
+            _ => None,
+        }
+    }
+    let lower = html.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(start) = lower[from..].find("application/ld+json") {
+        let start = from + start;
+        let body = start + lower[start..].find('>')? + 1;
+        let end = body + lower[body..].find("</script")?;
+        if let Some(posting) = serde_json::from_str(&html[body..end]).ok().and_then(find) {
+            return Some(posting);
+        }
+        from = end;
+    }
+    None
+}
+
+fn text(value: Option<&Value>) -> Option<String> {
+    let text = match value? {
+        Value::String(text) => decode_entities(text.trim()),
+        Value::Number(number) => number.to_string(),
+        Value::Object(object) => return text(object.get("name")),
+        Value::Array(items) => return items.iter().find_map(|item| text(Some(item))),
+        _ => return None,
+    };
+    (!text.is_empty()).then_some(text)
+}
+
+fn from_json_ld(posting: &Value) -> JobApplication {
+    let mut job = JobApplication {
+        position: text(posting.get("title")).unwrap_or_default(),
+        company_name: text(posting.get("hiringOrganization")).unwrap_or_default(),
+        description: text(posting.get("description")).map(|html| strip_tags(&html)),
+        ..JobApplication::default()
+    };
+
+    let address = posting
+        .get("jobLocation")
+        .map(|location| match location {
+            Value::Array(locations) => locations.first().unwrap_or(&Value::Null),
+            location => location,
+        })
+        .and_then(|location| location.get("address"));
+    job.location = ["addressLocality", "addressRegion", "addressCountry"]
+        .iter()
+        .filter_map(|key| text(address.and_then(|address| address.get(key))))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let remote = text(posting.get("jobLocationType"))
+        .is_some_and(|kind| kind.eq_ignore_ascii_case("TELECOMMUTE"));
+    job.location_type = if remote || job.location.to_lowercase().contains("remote") {
+        LocationType::Remote
+    } else if job.location.is_empty() {
+        job.location_type
+    } else {
+        LocationType::OnSite
+    };
+
+    if let Some(kind) = text(posting.get("employmentType")) {
+        job.work_type = match kind.to_uppercase().replace(['-', ' '], "_").as_str() {
+            "PART_TIME" => WorkType::PartTime,
+            "CONTRACTOR" | "CONTRACT" | "TEMPORARY" => WorkType::Contract,
+            "INTERN" | "INTERNSHIP" => WorkType::Internship,
+            _ => WorkType::FullTime,
+        };
+    }
+
+    if let Some(salary) = posting.get("baseSalary") {
+        let value = salary.get("value");
+        let field = |key| {
+            text(value.and_then(|value| value.get(key)))
+                .and_then(|amount| amount.parse::<f64>().ok())
+                .map(|amount| amount.round() as i64)
+        };
+        // hourly or monthly rates would read as a tiny salary
+        let yearly = text(value.and_then(|value| value.get("unitText")))
+            .is_none_or(|unit| unit.eq_ignore_ascii_case("YEAR"));
+        if yearly {
+            job.salary_min = field("minValue").or_else(|| field("value"));
+            job.salary_max = field("maxValue");
+            job.currency = text(salary.get("currency"));
+        }
+    }
+
+    job.deadline = text(posting.get("validThrough"))
+        .and_then(|date| date.get(..10).map(str::to_string))
+        .filter(|date| {
+            date.bytes().enumerate().all(|(i, b)| {
+                if i == 4 || i == 7 {
+                    b == b'-'
+                } else {
+                    b.is_ascii_digit()
+                }
+            })
+        });
+    job
+}
+
+/// Position, company and location from the page's title, as boards word it: LinkedIn's
+/// "Acme hiring Engineer in London | LinkedIn", or "Engineer - Acme" elsewhere.
+fn from_title(html: &str) -> (Option<String>, Option<String>, Option<String>) {
+    let Some(title) = meta(html, "og:title").or_else(|| tag(html, "title")) else {
+        return (None, None, None);
+    };
+    let site = meta(html, "og:site_name");
+    // drop the site's name from the end
+    let title = match title.rsplit_once(" | ") {
+        Some((title, _)) => title.trim().to_string(),
+        None => title,
+    };
+    if let Some((company, rest)) = title.split_once(" hiring ") {
+        let (position, location) = match rest.rsplit_once(" in ") {
+            Some((position, location)) => (position, Some(location.trim().to_string())),
+            None => (rest, None),
+        };
+        return (
+            Some(position.trim().to_string()),
+            Some(company.trim().to_string()),
+            location,
+        );
+    }
+    match title.split_once(" - ").or_else(|| title.split_once(" at ")) {
+        Some((position, company)) => (
+            Some(position.trim().to_string()),
+            Some(company.trim().to_string()),
+            None,
+        ),
+        None => (Some(title), site, None),
+    }
+}
+
+/// The `content` of the first `<meta>` tag whose `property` or `name` is `key`.
+fn meta(html: &str, key: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(start) = lower[from..].find("<meta") {
+        let start = from + start;
+        let end = start + lower[start..].find('>')?;
+        let tag = &html[start..end];
+        let named = ["property", "name"]
+            .iter()
+            .any(|attribute| self::attribute(tag, attribute).is_some_and(|name| name == key));
+        if named {
+            return attribute(tag, "content").map(|content| decode_entities(content.trim()));
+        }
+        from = end;
+    }
+    None
+}
+
+/// The value of `name` in a tag's attributes, quoted either way.
+fn attribute<'a>(tag: &'a str, name: &str) -> Option<&'a str> {
+    let lower = tag.to_ascii_lowercase();
+    let mut from = 0;
+    while let Some(found) = lower[from..].find(name) {
+        let at = from + found;
+        from = at + name.len();
+        // a whole attribute name, e.g. not the "name" in "data-name"
+        if at > 0 && !lower.as_bytes()[at - 1].is_ascii_whitespace() {
+            continue;
+        }
+        let rest = tag[from..].trim_start();
+        let Some(rest) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let rest = rest.trim_start();
+        let quote = rest.chars().next().filter(|c| *c == '"' || *c == '\'')?;
+        return rest[1..].split(quote).next();
+    }
+    None
+}
+
+/// The text of the first `<name>` element.
+fn tag(html: &str, name: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let start = lower.find(&format!("<{name}"))?;
+    let body = start + lower[start..].find('>')? + 1;
+    let end = body + lower[body..].find(&format!("</{name}"))?;
+    let text = decode_entities(html[body..end].trim());
+    (!text.is_empty()).then_some(text)
+}
+
+/// Plain text from a description given as HTML, keeping paragraphs and list items on their
+/// own lines.
+fn strip_tags(html: &str) -> String {
+    let mut text = String::new();
+    let mut rest = html;
+    while let Some(start) = rest.find('<') {
+        text.push_str(&rest[..start]);
+        let Some(end) = rest[start..].find('>') else {
+            rest = &rest[start..];
+            break;
+        };
+        let tag = rest[start + 1..start + end].to_lowercase();
+        let name = tag.trim_start_matches('/').split([' ', '/']).next();
+        match name {
+            Some("br" | "p" | "div" | "h1" | "h2" | "h3" | "h4" | "ul" | "ol") => text.push('\n'),
+            Some("li") if !tag.starts_with('/') => text.push_str("\n- "),
+            _ => {}
+        }
+        rest = &rest[start + end + 1..];
+    }
+    text.push_str(rest);
+    let text = decode_entities(&text);
+    let mut lines = Vec::new();
+    for line in text.lines().map(str::trim) {
+        // at most one blank line between paragraphs
+        if !line.is_empty() || lines.last().is_some_and(|last: &&str| !last.is_empty()) {
+            lines.push(line);
+        }
+    }
+    lines.join("\n").trim().to_string()
+}
+
+fn decode_entities(text: &str) -> String {
+    let mut decoded = String::with_capacity(text.len());
+    let mut rest = text;
+    while let Some(start) = rest.find('&') {
+        decoded.push_str(&rest[..start]);
+        rest = &rest[start..];
+        let entity = rest[1..]
+            .find(';')
+            .filter(|end| *end <= 8)
+            .map(|end| &rest[1..end + 1]);
+        let character = entity.and_then(|entity| match entity {
+            "amp" => Some('&'),
+            "lt" => Some('<'),
+            "gt" => Some('>'),
+            "quot" => Some('"'),
+            "apos" => Some('\''),
+            "nbsp" => Some(' '),
+            _ => match entity
+                .strip_prefix("#x")
+                .or_else(|| entity.strip_prefix("#X"))
+            {
+                Some(hex) => u32::from_str_radix(hex, 16).ok(),
+                None => entity.strip_prefix('#').and_then(|dec| dec.parse().ok()),
+            }
+            .and_then(char::from_u32),
+        });
+        match (entity, character) {
+            (Some(entity), Some(character)) => {
+                decoded.push(character);
+                rest = &rest[entity.len() + 2..];
+            }
+            _ => {
+                decoded.push('&');
+                rest = &rest[1..];
+            }
+        }
+    }
+    decoded.push_str(rest);
+    decoded
+}
@@ -0,0 +1,155 @@
+//! Logging an application from one line typed on the command line, such as
+//! `Backend Engineer @ Acme, remote, London, https://acme.com/jobs/42`. The line is split on
+//! commas and each part is recognised by its shape: a link, a location type, the
+//! `position @ company` pair, and anything left over is taken as the location.
+
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    config::Config,
+    database::{builder::JobApplicationBuilder, db::Database, query, schema::JobApplication},
+    dates::{self, add_days},
+    statuses, unlock,
+};
+
+/// The location type a part names, in the form the builder parses.
+fn location_type(part: &str) -> Option<&'static str> {
+    match part.to_lowercase().replace(['-', '_'], " ").as_str() {
+        "remote" | "wfh" | "work from home" => Some("Remote"),
+        "hybrid" => Some("Hybrid"),
+        "on site" | "onsite" | "in office" | "office" => Some("On Site"),
+        _ => None,
+    }
+}
+
+fn is_url(part: &str) -> bool {
+    part.contains("://") || part.starts_with("www.")
+}
+
+/// Split `position @ company` or `position at company`, trying `@` first since it can't be
+/// part of a job title.
+fn position_and_company(part: &str) -> Option<(&str, &str)> {
+    if let Some((position, company)) = part.split_once('@') {
+        return Some((position, company));
+    }
+    let at = part.to_ascii_lowercase().rfind(" at ")?;
+    Some((&part[..at], &part[at + 4..]))
+}
+
+/// Build an application from `text`, applied to on `today`. Without an `@` or `at`, the first
+/// unrecognised part is the position and the next the company.
+pub fn parse(text: &str, today: &str, follow_up_days: u32) -> Result<JobApplication, String> {
+    let mut builder = JobApplicationBuilder::new();
+    let mut names = None;
+    let mut rest = Vec::new();
+    for part in text
+        .split(',')
+        .map(str::trim)
+        .filter(|part| !part.is_empty())
+    {
+        if is_url(part) {
+            builder = builder.url(part);
+        } else if let Some(kind) = location_type(part) {
+            builder = builder.location_type(kind);
+        } else if names.is_none() && position_and_company(part).is_some() {
+            names = position_and_company(part);
+        } else {
+            rest.push(part);
+        }
+    }
+    let (position, company) = match names {
+        Some(names) => names,
+        None if rest.len() >= 2 => (rest.remove(0), rest.remove(0)),
+        None => {
+            return Err("expected a position and company, as \"Position @ Company\"".to_string());
+        }
+    };
+    let mut job = builder
+        .position(position)
+        .company_name(company)
+        .location(&rest.join(", "))
+        .application_date(today)
+        .build()?;
+    job.follow_up_date = add_days(today, follow_up_days);
+    Ok(job)
+}
+
+/// The `add` subcommand: parse `text` into an application applied to today, add it to the
+/// usual database and print the id it was given.
+pub fn run(text: &str) -> Result<()> {
+    let config = Config::new()?;
+    let mut database = Database::default();
+    unlock::from_env(&mut database, &config)?;
+    database.create()?;
+    statuses::load(&database);
+
+    let mut job =
+        parse(text, &dates::today(), config.config.follow_up_days).map_err(|err| eyre!(err))?;
+    let similar = query::find_similar_applications(&job.company_name, &job.position, &database);
+    job.uuid = uuid::Uuid::new_v4().to_string();
+    query::add_application(job.clone(), &database)?;
+    let id = query::get_application_by_uuid(&job.uuid, &database)
+        .map(|job| job.id)
+        .ok_or_else(|| eyre!("the new application wasn't found after adding it"))?;
+
+    println!("Added {} - {} as #{id}", job.company_name, job.position);
+    for other in similar {
+        println!(
+            "  similar to #{}: {} - {} ({}, {})",
+            other.id, other.company_name, other.position, other.application_date, other.status
+        );
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::database::schema::LocationType;
+
+    #[test]
+    fn parses_each_part_by_its_shape() {
+        let job = parse(
+            "Backend Engineer @ Acme, remote, London, https://acme.com/jobs/42",
+            "2024-03-01",
+            7,
+        )
+        .unwrap();
+        assert_eq!(job.position, "Backend Engineer");
+        assert_eq!(job.company_name, "Acme");
+        assert_eq!(job.location_type, LocationType::Remote);
+        assert_eq!(job.location, "London");
+        assert_eq!(job.url.as_deref(), Some("https://acme.com/jobs/42"));
+        assert_eq!(job.follow_up_date.as_deref(), Some("2024-03-08"));
+    }
+
+    #[test]
+    fn takes_position_and_company_in_order_without_a_separator() {
+        let job = parse("Data Analyst at Globex, hybrid", "2024-03-01", 7).unwrap();
+        assert_eq!(
+            (job.position.as_str(), job.company_name.as_str()),
+            ("Data Analyst", "Globex")
+        );
+        assert_eq!(job.location_type, LocationType::Hybrid);
+
+        let job = parse("Designer, Initech, on-site", "2024-03-01", 7).unwrap();
+        assert_eq!(
+            (job.position.as_str(), job.company_name.as_str()),
+            ("Designer", "Initech")
+        );
+        assert_eq!(job.location_type, LocationType::OnSite);
+
+        assert!(parse("just a position", "2024-03-01", 7).is_err());
+    }
+
+    #[test]
+    fn adds_to_an_in_memory_database() {
+        let db = Database::in_memory().unwrap();
+        let job = parse("Backend Engineer @ Acme, remote", "2024-03-01", 7).unwrap();
+        query::add_application(job, &db).unwrap();
+        let stored = query::get_all_applications(&db);
+        assert_eq!(stored.len(), 1);
+        assert_eq!(stored[0].company_name, "Acme");
+        assert_eq!(stored[0].application_date, "2024-03-01");
+    }
+}
@@ -0,0 +1,227 @@
+//! Merging applications in from another copy of the database, e.g. the one on a second
+//! machine. Copies of an application are matched by UUID and the more recently updated one
+//! wins; when neither can be told to be newer, the other copy goes to the conflicts inbox.
+//! Interviews, reminders, contacts and status history are added to rather than replaced, so
+//! nothing is ever deleted by a sync.
+
+use std::{
+    collections::{HashMap, HashSet},
+    path::Path,
+};
+
+use color_eyre::{Result, eyre::eyre};
+
+use crate::{
+    config::Config,
+    database::{
+        db::Database,
+        query,
+        schema::{Contact, Interview, JobApplication, Reminder, StatusChange},
+    },
+    export::backup::{self, BackupEntry},
+    import::conflict,
+    statuses, unlock,
+};
+
+#[derive(Debug, Default)]
+pub struct SyncSummary {
+    pub added: usize,
+    pub unchanged: usize,
+    /// Applications the other copy had newer, with the fields taken from it.
+    pub updated: Vec<String>,
+    /// Applications this copy had newer, with the fields the other disagreed on.
+    pub kept: Vec<String>,
+    /// Applications changed in both with no telling which is newer, sent to the inbox.
+    pub conflicts: Vec<String>,
+    pub invalid: Vec<String>,
+}
+
+/// Merge everything in `theirs` into `db`. `source` names it in the conflicts inbox.
+pub fn sync(theirs: &Database, source: &str, db: &Database) -> Result<SyncSummary> {
+    let theirs = backup::collect(theirs);
+    let mut summary = SyncSummary::default();
+    let mut ids = HashMap::new();
+    let mut seen = HashSet::new();
+    for entry in theirs.applications {
+        let job = &entry.application;
+        let label = format!(
+            "{} - {} ({})",
+            job.company_name, job.position, job.application_date
+        );
+        if job.uuid.is_empty() || !seen.insert(job.uuid.clone()) {
+            summary
+                .invalid
+                .push(format!("{label}: missing or repeated UUID"));
+            continue;
+        }
+        let Some(local) = query::get_application_by_uuid(&job.uuid, db) else {
+            let uuid = job.uuid.clone();
+            ids.insert(uuid, backup::add_entry(entry, db)?);
+            summary.added += 1;
+            continue;
+        };
+        ids.insert(job.uuid.clone(), local.id);
+
+        let differing = conflict::differing_fields(&local, job);
+        let fields = differing
+            .iter()
+            .map(|&idx| conflict::FIELDS[idx].name)
+            .collect::<Vec<_>>()
+            .join(", ");
+        let history = query::get_status_history(local.id, db);
+        let mut took_theirs = false;
+        // timestamps are written the same way everywhere, so they sort as text
+        match (&local.last_updated, &job.last_updated) {
+            _ if differing.is_empty() => summary.unchanged += 1,
+            (Some(ours), Some(newer)) if newer > ours => {
+                query::update_application(
+                    JobApplication {
+                        id: local.id,
+                        contacts: local.contacts.clone(),
+                        ..job.clone()
+                    },
+                    db,
+                )?;
+                query::set_last_updated(local.id, newer, db)?;
+                took_theirs = true;
+                summary.updated.push(format!("{label}: {fields}"));
+            }
+            (Some(ours), Some(older)) if older < ours => {
+                summary.kept.push(format!("{label}: {fields}"));
+            }
+            _ => {
+                if query::add_conflict(local.id, job, source, db)? {
+                    summary.conflicts.push(format!("{label}: {fields}"));
+                }
+            }
+        }
+        merge_records(&local, &history, entry, took_theirs, db)?;
+    }
+    backup::add_expenses(theirs.expenses, &ids, db)?;
+    Ok(summary)
+}
+
+/// Add what the other copy recorded against `local` that this one lacks. Interviews are the
+/// same one when they start at the same time and reminders when they say the same thing, as
+/// either may have been edited or moved on since. `history` is `local`'s from before the sync,
+/// which `took_theirs` says has since been overwritten.
+fn merge_records(
+    local: &JobApplication,
+    history: &[StatusChange],
+    entry: BackupEntry,
+    took_theirs: bool,
+    db: &Database,
+) -> Result<()> {
+    let same_change =
+        |a: &StatusChange, b: &StatusChange| a.status == b.status && a.changed_at == b.changed_at;
+    let missing = entry
+        .history
+        .into_iter()
+        .filter(|change| !history.iter().any(|ours| same_change(ours, change)))
+        .collect::<Vec<_>>();
+    // rewriting it also drops the change `update_application` recorded for the sync itself
+    if !missing.is_empty() || took_theirs {
+        let mut merged = history.iter().cloned().chain(missing).collect::<Vec<_>>();
+        merged.sort_by(|a, b| a.changed_at.cmp(&b.changed_at));
+        query::set_status_history(local.id, &merged, db)?;
+    }
+
+    for contact in entry.application.contacts {
+        if !local.contacts.iter().any(|ours| ours.same_person(&contact)) {
+            query::save_contact(
+                Contact {
+                    id: 0,
+                    job_id: local.id,
+                    ..contact
+                },
+                db,
+            )?;
+        }
+    }
+    let interviews = query::get_interviews(db)
+        .into_iter()
+        .filter(|(interview, _)| interview.job_id == local.id)
+        .map(|(interview, _)| interview.starts_at)
+        .collect::<HashSet<_>>();
+    for interview in entry.interviews {
+        if !interviews.contains(&interview.starts_at) {
+            query::add_interview(
+                Interview {
+                    job_id: local.id,
+                    ..interview
+                },
+                db,
+            )?;
+        }
+    }
+    let reminders = query::get_reminders(db)
+        .into_iter()
+        .filter(|(reminder, _)| reminder.job_id == local.id)
+        .map(|(reminder, _)| reminder.message)
+        .collect::<HashSet<_>>();
+    for reminder in entry.reminders {
+        if !reminders.contains(&reminder.message) {
+            query::add_reminder(
+                Reminder {
+                    job_id: local.id,
+                    ..reminder
+                },
+                db,
+            )?;
+        }
+    }
+    Ok(())
+}
+
+/// The `sync` subcommand: merge the database at `path` into the usual one and report what
+/// changed. The other database is read from a copy, brought up to this version's schema, so
+/// the file itself is left untouched.
+pub fn run(path: &Path) -> Result<()> {
+    if !path.is_file() {
+        return Err(eyre!("{} isn't a database file", path.display()));
+    }
+    let config = Config::new()?;
+    let mut database = Database::default();
+    unlock::from_env(&mut database, &config)?;
+    database.create()?;
+    statuses::load(&database);
+
+    let copy = std::env::temp_dir().join(format!("job-tracker-sync-{}.db", uuid::Uuid::new_v4()));
+    std::fs::copy(path, &copy)?;
+    let summary = (|| {
+        let mut theirs = Database::new(&copy.to_string_lossy())?;
+        // encrypted copies are expected to share the passphrase
+        if theirs.is_encrypted() {
+            unlock::from_env(&mut theirs, &config)?;
+        }
+        theirs.create()?;
+        sync(&theirs, &path.display().to_string(), &database)
+    })();
+    let _ = std::fs::remove_file(&copy);
+    let summary = summary?;
+
+    println!(
+        "Synced from {}: {} added, {} updated, {} unchanged",
+        path.display(),
+        summary.added,
+        summary.updated.len(),
+        summary.unchanged
+    );
+    for (heading, lines) in [
+        ("Newer there, so updated here:", &summary.updated),
+        ("Newer here, so kept:", &summary.kept),
+        (
+            "Changed in both with no telling which is newer, sent to the conflicts inbox:",
+            &summary.conflicts,
+        ),
+        ("Skipped:", &summary.invalid),
+    ] {
+        if !lines.is_empty() {
+            println!("{heading}");
+            for line in lines {
+                println!("  {line}");
+            }
+        }
+    }
+    Ok(())
+}
@@ -0,0 +1,230 @@
+//! Guesses what a recruiter's email means for an application from the phrases it uses, and
+//! picks out the dates it mentions. Only a proposal: the paste-update popup shows it for
+//! confirmation before anything is changed.
+
+use crate::{database::schema::ApplicationStatus, dates};
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum EmailKind {
+    Rejection,
+    InterviewInvite,
+    Offer,
+}
+
+impl EmailKind {
+    pub fn label(&self) -> &'static str {
+        match self {
+            EmailKind::Rejection => "rejection",
+            EmailKind::InterviewInvite => "interview invite",
+            EmailKind::Offer => "offer",
+        }
+    }
+}
+
+const REJECTION_PHRASES: [&str; 12] = [
+    "unfortunately",
+    "regret to inform",
+    "not be moving forward",
+    "not moving forward",
+    "decided to move forward with other",
+    "decided to pursue other",
+    "will not be progressing",
+    "not be progressing",
+    "position has been filled",
+    "not been successful",
+    "other candidates whose",
+    "not selected",
+];
+const OFFER_PHRASES: [&str; 8] = [
+    "pleased to offer",
+    "happy to offer",
+    "extend an offer",
+    "extend you an offer",
+    "offer letter",
+    "job offer",
+    "offer of employment",
+    "compensation package",
+];
+const INTERVIEW_PHRASES: [&str; 11] = [
+    "invite you to",
+    "schedule an interview",
+    "schedule a call",
+    "like to interview",
+    "next round",
+    "next stage",
+    "phone screen",
+    "technical interview",
+    "coding challenge",
+    "your availability",
+    "would like to speak",
+];
+
+const MONTHS: [&str; 12] = [
+    "jan", "feb", "mar", "apr", "may", "jun", "jul", "aug", "sep", "oct", "nov", "dec",
+];
+
+/// What an email seems to say, and the dates it mentions.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Inference {
+    pub kind: Option<EmailKind>,
+    /// The phrase the kind was decided on, to show why.
+    pub matched: Option<&'static str>,
+    /// `YYYY-MM-DD`, or `YYYY-MM-DD HH:MM` when a time follows the date, in order of mention.
+    pub dates: Vec<String>,
+}
+
+impl Inference {
+    /// The status the application would move to.
+    pub fn status(&self, text: &str) -> Option<ApplicationStatus> {
+        let text = text.to_lowercase();
+        Some(match self.kind? {
+            EmailKind::Rejection => ApplicationStatus::Rejected,
+            EmailKind::Offer => ApplicationStatus::Offered,
+            EmailKind::InterviewInvite if text.contains("phone screen") => {
+                ApplicationStatus::PhoneScreen
+            }
+            EmailKind::InterviewInvite if text.contains("technical") || text.contains("coding") => {
+                ApplicationStatus::TechnicalInterview
+            }
+            EmailKind::InterviewInvite => ApplicationStatus::Interviewing,
+        })
+    }
+
+    /// The first date mentioned with a time, e.g. when an interview starts.
+    pub fn first_datetime(&self) -> Option<&str> {
+        self.dates
+            .iter()
+            .find(|date| date.len() > 10)
+            .map(String::as_str)
+    }
+}
+
+/// Read `text` as of `today` (`YYYY-MM-DD`), which years are filled in from for dates
+/// written without one.
+pub fn infer(text: &str, today: &str) -> Inference {
+    let lower = text.to_lowercase();
+    let mut best: Option<(usize, EmailKind, &'static str)> = None;
+    // on a tie the earlier kind wins, as rejections often thank you for interviewing
+    for (kind, phrases) in [
+        (EmailKind::Rejection, &REJECTION_PHRASES[..]),
+        (EmailKind::Offer, &OFFER_PHRASES[..]),
+        (EmailKind::InterviewInvite, &INTERVIEW_PHRASES[..]),
+    ] {
+        let matches = phrases
+            .iter()
+            .filter(|phrase| lower.contains(**phrase))
+            .collect::<Vec<_>>();
+        if let Some(first) = matches.first()
+            && best.is_none_or(|(count, _, _)| matches.len() > count)
+        {
+            best = Some((matches.len(), kind, first));
+        }
+    }
+    Inference {
+        kind: best.map(|(_, kind, _)| kind),
+        matched: best.map(|(_, _, phrase)| phrase),
+        dates: dates(&lower, today),
+    }
+}
+
+fn words(text: &str) -> Vec<&str> {
+    text.split(|c: char| c.is_whitespace() || matches!(c, ',' | '(' | ')' | ';'))
+        .map(|word| word.trim_end_matches(['.', '!', '?']))
+        .filter(|word| !word.is_empty())
+        .collect()
+}
+
+fn month(word: &str) -> Option<u32> {
+    let word = word.get(..3)?;
+    MONTHS
+        .iter()
+        .position(|month| *month == word)
+        .map(|idx| idx as u32 + 1)
+}
+
+fn day(word: &str) -> Option<u32> {
+    let word = word.trim_end_matches(|c: char| c.is_ascii_alphabetic());
+    word.parse().ok().filter(|day| (1..=31).contains(day))
+}
+
+// `14:00`, `2pm`, `2:30 pm`, taking the word after too for a separate am/pm
+fn time(word: &str, next: Option<&str>) -> Option<String> {
+    let (digits, suffix) = match word.find(|c: char| c.is_ascii_alphabetic()) {
+        Some(idx) => word.split_at(idx),
+        None => (
+            word,
+            next.filter(|next| matches!(*next, "am" | "pm"))
+                .unwrap_or(""),
+        ),
+    };
+    let (hour, minute) = match digits.split_once([':', '.']) {
+        Some((hour, minute)) => (hour.parse::<u32>().ok()?, minute.parse::<u32>().ok()?),
+        None if !suffix.is_empty() => (digits.parse::<u32>().ok()?, 0),
+        None => return None,
+    };
+    let hour = match suffix {
+        "am" if hour == 12 => 0,
+        "am" if hour <= 12 => hour,
+        "pm" if hour < 12 => hour + 12,
+        "pm" if hour == 12 => 12,
+        "" if hour < 24 => hour,
+        _ => return None,
+    };
+    (minute < 60).then(|| format!("{hour:02}:{minute:02}"))
+}
+
+fn dates(text: &str, today: &str) -> Vec<String> {
+    let words = words(text);
+    let this_year = today
+        .get(..4)
+        .and_then(|year| year.parse::<u32>().ok())
+        .unwrap_or(2000);
+    let mut dates = Vec::new();
+    let mut idx = 0;
+    while idx < words.len() {
+        let word = words[idx];
+        // `2024-10-20`, `20 October (2024)` or `October 20(th) (2024)`
+        let (date, used) = if word.len() == 10 && word.as_bytes()[4] == b'-' {
+            (Some(word.to_string()), 1)
+        } else {
+            let next = words.get(idx + 1).copied().unwrap_or("");
+            let (day, month) = match (day(word), month(next), month(word), day(next)) {
+                (Some(day), Some(month), _, _) => (day, month),
+                (_, _, Some(month), Some(day)) => (day, month),
+                _ => {
+                    idx += 1;
+                    continue;
+                }
+            };
+            let after = words.get(idx + 2).and_then(|year| year.parse::<u32>().ok());
+            let (year, used) = match after.filter(|year| (2000..2100).contains(year)) {
+                Some(year) => (year, 3),
+                None => {
+                    let date = format!("{this_year:04}-{month:02}-{day:02}");
+                    // a date without a year that has passed is next year's
+                    let year = if date.as_str() < today {
+                        this_year + 1
+                    } else {
+                        this_year
+                    };
+                    (year, 2)
+                }
+            };
+            (Some(format!("{year:04}-{month:02}-{day:02}")), used)
+        };
+        idx += used;
+        let Some(date) =
+            date.filter(|date| dates::parse_datetime(&format!("{date} 00:00")).is_some())
+        else {
+            continue;
+        };
+        // a time within the next few words, e.g. "on October 20 at 2pm"
+        let time = (idx..(idx + 3).min(words.len()))
+            .find_map(|at| time(words[at], words.get(at + 1).copied()));
+        dates.push(match time {
+            Some(time) => format!("{date} {time}"),
+            None => date,
+        });
+    }
+    dates
+}
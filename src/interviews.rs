@@ -0,0 +1,152 @@
+use crate::{database::schema::Interview, dates::parse_datetime};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ConflictKind {
+    Overlap,
+    /// The gap in minutes between the two interviews, shorter than the configured buffer.
+    BackToBack(u32),
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Conflict {
+    pub interview: Interview,
+    pub company: String,
+    pub kind: ConflictKind,
+}
+
+impl Conflict {
+    pub fn message(&self) -> String {
+        let with = format!("{} at {}", self.company, self.interview.starts_at);
+        match self.kind {
+            ConflictKind::Overlap => format!("Overlaps with {with}"),
+            ConflictKind::BackToBack(0) => format!("Back-to-back with {with}"),
+            ConflictKind::BackToBack(gap) => format!("Only {gap} min from {with}"),
+        }
+    }
+}
+
+/// Existing interviews that overlap `interview` or leave less than `buffer_minutes` between
+/// them and it.
+pub fn find_conflicts(
+    interview: &Interview,
+    existing: &[(Interview, String)],
+    buffer_minutes: u32,
+) -> Vec<Conflict> {
+    let Some((start, end)) = span(interview) else {
+        return Vec::new();
+    };
+    existing
+        .iter()
+        .filter(|(other, _)| other.id != interview.id || interview.id == 0)
+        .filter_map(|(other, company)| {
+            let (other_start, other_end) = span(other)?;
+            let kind = if start < other_end && other_start < end {
+                ConflictKind::Overlap
+            } else {
+                let gap = if other_start >= end {
+                    other_start - end
+                } else {
+                    start - other_end
+                };
+                if gap >= buffer_minutes as i64 {
+                    return None;
+                }
+                ConflictKind::BackToBack(gap as u32)
+            };
+            Some(Conflict {
+                interview: other.clone(),
+                company: company.clone(),
+                kind,
+            })
+        })
+        .collect()
+}
+
+// start and end in minutes since the epoch
+fn span(interview: &Interview) -> Option<(i64, i64)> {
+    let start = parse_datetime(&interview.starts_at)?;
+    Some((start, start + interview.duration_minutes as i64))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn interview(id: i32, starts_at: &str, duration_minutes: u32) -> Interview {
+        Interview {
+            id,
+            starts_at: starts_at.to_string(),
+            duration_minutes,
+            ..Interview::default()
+        }
+    }
+
+    // the conflicts of a 10:00 to 11:00 interview with one other
+    fn kinds(other: Interview, buffer_minutes: u32) -> Vec<ConflictKind> {
+        let existing = [(other, "Acme".to_string())];
+        find_conflicts(
+            &interview(0, "2024-03-01 10:00", 60),
+            &existing,
+            buffer_minutes,
+        )
+        .into_iter()
+        .map(|conflict| conflict.kind)
+        .collect()
+    }
+
+    #[test]
+    fn finds_overlaps() {
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 10:30", 60), 0),
+            [ConflictKind::Overlap]
+        );
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 09:00", 180), 0),
+            [ConflictKind::Overlap]
+        );
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 09:30", 31), 0),
+            [ConflictKind::Overlap]
+        );
+    }
+
+    #[test]
+    fn finds_interviews_back_to_back() {
+        // one starting as the other ends doesn't overlap
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 11:00", 30), 15),
+            [ConflictKind::BackToBack(0)]
+        );
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 09:30", 30), 15),
+            [ConflictKind::BackToBack(0)]
+        );
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 08:50", 60), 15),
+            [ConflictKind::BackToBack(10)]
+        );
+        assert_eq!(kinds(interview(1, "2024-03-01 11:00", 30), 0), []);
+    }
+
+    #[test]
+    fn leaves_a_gap_of_the_whole_buffer_alone() {
+        assert_eq!(
+            kinds(interview(1, "2024-03-01 11:14", 30), 15),
+            [ConflictKind::BackToBack(14)]
+        );
+        assert_eq!(kinds(interview(1, "2024-03-01 11:15", 30), 15), []);
+        assert_eq!(kinds(interview(1, "2024-03-02 10:00", 60), 15), []);
+    }
+
+    #[test]
+    fn skips_the_interview_being_edited() {
+        let edited = interview(3, "2024-03-01 10:00", 60);
+        let existing = [(edited.clone(), "Acme".to_string())];
+        assert!(find_conflicts(&edited, &existing, 15).is_empty());
+        let conflicts = find_conflicts(&interview(0, "2024-03-01 10:00", 60), &existing, 15);
+        assert_eq!(
+            conflicts[0].message(),
+            "Overlaps with Acme at 2024-03-01 10:00"
+        );
+    }
+}
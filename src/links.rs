@@ -0,0 +1,89 @@
+//! Wiki-style links in notes: `[[Acme Inc]]` refers to a company and `[[#42]]` to an
+//! application by id. Following a link opens what it refers to, and applications linked to
+//! list the notes that link to them.
+
+use std::ops::Range;
+
+use ratatui::{
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+};
+
+use crate::{action::Action, app::Mode};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum NoteLink {
+    Company(String),
+    Application(i32),
+}
+
+impl NoteLink {
+    fn parse(target: &str) -> Option<Self> {
+        let target = target.trim();
+        if target.contains('\n') {
+            return None;
+        }
+        match target.strip_prefix('#') {
+            Some(id) => id.parse().ok().map(NoteLink::Application),
+            None if !target.is_empty() => Some(NoteLink::Company(target.to_string())),
+            None => None,
+        }
+    }
+
+    /// Actions that open what the link refers to: the application's detail, or the job list
+    /// narrowed to the company.
+    pub fn follow(&self) -> Vec<Action> {
+        match self {
+            NoteLink::Application(id) => vec![Action::OpenJob(id.to_string())],
+            NoteLink::Company(company) => vec![
+                Action::ChangeMode(Mode::Home),
+                Action::ApplySearch(company.clone()),
+            ],
+        }
+    }
+}
+
+/// Every link in `text`, in order, with the byte range of its brackets.
+pub fn find(text: &str) -> Vec<(Range<usize>, NoteLink)> {
+    let mut links = Vec::new();
+    let mut from = 0;
+    while let Some(start) = text[from..].find("[[").map(|idx| from + idx) {
+        let Some(end) = text[start + 2..].find("]]").map(|idx| start + 2 + idx) else {
+            break;
+        };
+        let target = &text[start + 2..end];
+        // an unclosed `[[` before a link shouldn't swallow it
+        if let Some(inner) = target.rfind("[[") {
+            from = start + 2 + inner;
+            continue;
+        }
+        if let Some(link) = NoteLink::parse(target) {
+            links.push((start..end + 2, link));
+        }
+        from = end + 2;
+    }
+    links
+}
+
+/// `text` with its links drawn in `style`, and the `selected`th link of them reversed.
+pub fn highlight(text: &str, style: Style, selected: Option<usize>) -> Text<'static> {
+    let mut index = 0;
+    let lines = text.lines().map(|line| {
+        let mut spans = Vec::new();
+        let mut last = 0;
+        for (range, _) in find(line) {
+            spans.push(Span::raw(line[last..range.start].to_string()));
+            let style = if selected == Some(index) {
+                style.add_modifier(Modifier::REVERSED)
+            } else {
+                style
+            };
+            spans.push(Span::styled(line[range.clone()].to_string(), style));
+            last = range.end;
+            index += 1;
+        }
+        spans.push(Span::raw(line[last..].to_string()));
+        Line::from(spans)
+    });
+    Text::from(lines.collect::<Vec<_>>())
+}
@@ -0,0 +1,79 @@
+//! Company logos drawn beside job cards on terminals speaking the kitty graphics protocol.
+//! Logos are the site's touch icon, fetched once and cached as PNG in the data
+//! directory. Everywhere else, and until a logo arrives, the card shows text alone.
+
+use std::{
+    io::Write,
+    path::{Path, PathBuf},
+};
+
+use base64::{Engine, engine::general_purpose::STANDARD};
+use color_eyre::{Result, eyre::eyre};
+use crossterm::{cursor::MoveTo, queue};
+use ratatui::layout::Rect;
+
+const PNG_SIGNATURE: &[u8] = b"\x89PNG\r\n\x1a\n";
+// largest icon read, far above any touch icon's
+const MAX_ICON_BYTES: u64 = 1_000_000;
+
+/// Whether the terminal is known to display kitty graphics. Terminals that don't would show
+/// the escape sequences as junk, so anything unrecognised gets text only.
+pub fn supported() -> bool {
+    std::env::var_os("KITTY_WINDOW_ID").is_some()
+        || std::env::var("TERM").is_ok_and(|term| term.contains("kitty"))
+        || std::env::var("TERM_PROGRAM")
+            .is_ok_and(|program| matches!(program.as_str(), "WezTerm" | "ghostty"))
+}
+
+/// The host part of a URL, which logos are cached under.
+pub fn host(url: &str) -> Option<&str> {
+    let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+    let host = rest.split(['/', '?', '#', ':']).next()?;
+    (!host.is_empty()).then_some(host)
+}
+
+pub fn cache_path(data_dir: &Path, host: &str) -> PathBuf {
+    data_dir.join("logos").join(format!("{host}.png"))
+}
+
+/// Download the touch icon for `host` into the cache. Only PNGs are kept, since that is what
+/// the terminal is handed.
+pub fn fetch(data_dir: &Path, host: &str) -> Result<PathBuf> {
+    let path = cache_path(data_dir, host);
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    let icon = crate::http::get_ok(
+        &format!("https://{host}/apple-touch-icon.png"),
+        MAX_ICON_BYTES,
+    )
+    .ok()
+    .filter(|bytes| bytes.starts_with(PNG_SIGNATURE))
+    .ok_or_else(|| eyre!("no PNG icon found for {host}"))?;
+    std::fs::write(&path, icon)?;
+    Ok(path)
+}
+
+/// Replace every logo on screen with `placements`, each PNG scaled into its cells.
+pub fn place(out: &mut impl Write, placements: &[(Rect, PathBuf)]) -> Result<()> {
+    clear(out)?;
+    for (area, path) in placements {
+        let path = STANDARD.encode(path.to_string_lossy().as_bytes());
+        queue!(out, MoveTo(area.x, area.y))?;
+        // a=T transmit and show, t=f read from file, f=100 PNG, C=1 leave the cursor be
+        write!(
+            out,
+            "\x1b_Ga=T,t=f,f=100,q=2,C=1,c={},r={};{path}\x1b\\",
+            area.width, area.height
+        )?;
+    }
+    out.flush()?;
+    Ok(())
+}
+
+/// Remove every logo from the screen.
+pub fn clear(out: &mut impl Write) -> Result<()> {
+    write!(out, "\x1b_Ga=d,d=A,q=2\x1b\\")?;
+    out.flush()?;
+    Ok(())
+}
@@ -1,17 +1,53 @@
 use clap::Parser;
-use cli::Cli;
+use cli::{Cli, Command, StartView};
 use color_eyre::Result;
 
-use crate::app::App;
+use crate::{action::Action, app::App, database::db::Database};
 
 mod action;
+mod analysis;
+mod analytics;
 mod app;
+mod categorize;
 mod cli;
+mod clipboard;
 mod components;
 mod config;
+mod cron;
+mod dashboard;
 mod database;
+mod dates;
 mod errors;
+mod export;
+#[cfg(feature = "fixtures")]
+mod fixtures;
+mod http;
+mod import;
+mod inference;
+mod interviews;
+mod links;
+#[cfg(feature = "logos")]
+mod logo;
+mod opener;
+mod perf;
+mod postings;
+mod preset;
+mod priority;
+mod privacy;
+mod session;
+mod spelling;
+mod state;
+mod stats;
+mod statuses;
+mod templates;
+mod theme;
 mod tui;
+mod unlock;
+mod views;
+
+// applications in the throwaway database `--demo` starts with
+#[cfg(feature = "fixtures")]
+const DEMO_APPLICATIONS: usize = 60;
 
 #[tokio::main]
 async fn main() -> Result<()> {
@@ -19,7 +55,68 @@ async fn main() -> Result<()> {
     // crate::logging::init()?;
 
     let args = Cli::parse();
-    let mut app = App::new(args.tick_rate, args.frame_rate)?;
+    match args.command {
+        Some(Command::Dashboard { refresh }) => return dashboard::run(refresh).await,
+        Some(Command::Statuses { command }) => return statuses::run(command),
+        Some(Command::Cron) => return cron::run(),
+        Some(Command::Calendar { output }) => return export::ics::run(output.as_deref()),
+        Some(Command::Sync { path }) => return import::sync::run(&path),
+        Some(Command::Add { text }) => return import::quick::run(&text),
+        #[cfg(feature = "fixtures")]
+        Some(Command::Fixtures {
+            path,
+            count,
+            seed,
+            today,
+        }) => {
+            fixtures::sample_database(&path, count, seed, fixtures::day(today.as_deref())?)?;
+            println!("Wrote {count} sample applications to {}", path.display());
+            return Ok(());
+        }
+        None => {}
+    }
+    let mut app = match &args.replay {
+        Some(path) => {
+            let entries = session::load(path)?;
+            let database = Database::in_memory()?;
+            let mut app = App::with_database(args.tick_rate, args.frame_rate, database)?;
+            app.replay(entries);
+            app
+        }
+        #[cfg(feature = "fixtures")]
+        None if args.demo => {
+            let database = fixtures::demo_database(DEMO_APPLICATIONS, 1, fixtures::day(None)?)?;
+            App::with_database(args.tick_rate, args.frame_rate, database)?
+        }
+        None => App::new(args.tick_rate, args.frame_rate).await?,
+    };
+    if let Some(path) = &args.record {
+        app.record_to(path)?;
+    }
+    if let Some(path) = args.import {
+        app.dispatch(Action::ImportFile(path))?;
+    }
+    if let Some(path) = args.restore {
+        app.dispatch(Action::Restore(path))?;
+    }
+    if args.digest {
+        app.dispatch(Action::Digest)?;
+    }
+    if let Some(name) = args.export_preset {
+        app.dispatch(Action::ExportPreset(name))?;
+    }
+    if let Some(path) = args.import_preset {
+        app.dispatch(Action::ImportPreset(path))?;
+    }
+    match args.view {
+        Some(StartView::List) | None => {}
+        Some(StartView::Stats) => app.dispatch(Action::ShowStats)?,
+        Some(StartView::Reminders) => app.dispatch(Action::ShowReminders)?,
+        Some(StartView::Conflicts) => app.dispatch(Action::ShowConflicts)?,
+    }
+    if let Some(key) = args.open {
+        app.dispatch(Action::OpenJob(key))?;
+    }
     app.run().await?;
     Ok(())
 }
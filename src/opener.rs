@@ -0,0 +1,81 @@
+//! Opening attached files and links outside the app: with the command configured for the
+//! file's extension in `openers`, or the platform's default handler otherwise.
+
+use std::{
+    collections::HashMap,
+    path::Path,
+    process::{Command, Stdio},
+};
+
+use color_eyre::Result;
+use tokio::sync::mpsc::UnboundedSender;
+
+use crate::action::Action;
+
+/// The command configured for `target`'s extension, if it is a file and one is configured.
+/// Extensions are matched without the dot and ignoring case.
+fn configured<'a>(target: &str, openers: &'a HashMap<String, String>) -> Option<&'a str> {
+    if target.contains("://") {
+        return None;
+    }
+    let extension = Path::new(target).extension()?.to_str()?.to_lowercase();
+    openers
+        .iter()
+        .find(|(ext, _)| ext.trim_start_matches('.').to_lowercase() == extension)
+        .map(|(_, command)| command.as_str())
+}
+
+fn command(target: &str, openers: &HashMap<String, String>) -> Command {
+    match configured(target, openers) {
+        // the target is passed as an argument rather than pasted in, so it needs no quoting
+        Some(opener) if !cfg!(windows) => {
+            let mut command = Command::new("sh");
+            command.args(["-c", &format!("{opener} \"$1\""), "sh", target]);
+            command
+        }
+        Some(opener) => {
+            let mut command = Command::new("cmd");
+            command.args(["/C", &format!("{opener} \"{target}\"")]);
+            command
+        }
+        None if cfg!(target_os = "windows") => {
+            let mut command = Command::new("cmd");
+            command.args(["/C", "start", "", target]);
+            command
+        }
+        None if cfg!(target_os = "macos") => {
+            let mut command = Command::new("open");
+            command.arg(target);
+            command
+        }
+        None => {
+            let mut command = Command::new("xdg-open");
+            command.arg(target);
+            command
+        }
+    }
+}
+
+/// Open `target` without waiting for it. Failing to start the opener is returned; an opener
+/// that starts but exits unsuccessfully is reported later as an `Action::Warning` on `tx`.
+pub fn open(
+    target: &str,
+    openers: &HashMap<String, String>,
+    tx: UnboundedSender<Action>,
+) -> Result<()> {
+    let mut child = command(target, openers)
+        .stdin(Stdio::null())
+        .stdout(Stdio::null())
+        .stderr(Stdio::null())
+        .spawn()?;
+    let target = target.to_string();
+    std::thread::spawn(move || {
+        let message = match child.wait() {
+            Ok(status) if status.success() => return,
+            Ok(status) => format!("Failed to open {target}: opener exited with {status}"),
+            Err(err) => format!("Failed to open {target}: {err}"),
+        };
+        let _ = tx.send(Action::Warning(message));
+    });
+    Ok(())
+}
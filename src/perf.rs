@@ -0,0 +1,127 @@
+//! Timings for the debug overlay, to see where the app spends its time when it feels slow.
+//! Only collected while the overlay is shown.
+
+use std::{
+    collections::VecDeque,
+    time::{Duration, Instant},
+};
+
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout, Rect},
+    style::Style,
+    text::{Line, Span},
+    widgets::{Block, BorderType, Clear, Padding, Paragraph},
+};
+
+use crate::theme::Theme;
+
+// frames counted towards the rate, i.e. those drawn in the last second
+const FPS_WINDOW: Duration = Duration::from_secs(1);
+// components listed, slowest first
+const SLOWEST: usize = 8;
+
+#[derive(Debug, Default)]
+pub struct Perf {
+    frames: VecDeque<Instant>,
+    // input waiting for the frame that shows its effect
+    pending_input: Option<Instant>,
+    last_latency: Option<Duration>,
+    queue_depth: usize,
+    peak_queue_depth: usize,
+    frame_time: Duration,
+    // how long each component took to draw in the last frame, by id
+    draw_times: Vec<(String, Duration)>,
+}
+
+impl Perf {
+    pub fn input_received(&mut self) {
+        self.pending_input.get_or_insert_with(Instant::now);
+    }
+
+    /// `depth` is how many actions were waiting when the queue was last drained.
+    pub fn actions_queued(&mut self, depth: usize) {
+        self.queue_depth = depth;
+        self.peak_queue_depth = self.peak_queue_depth.max(depth);
+    }
+
+    pub fn frame_started(&mut self) {
+        self.draw_times.clear();
+    }
+
+    pub fn component_drawn(&mut self, id: String, took: Duration) {
+        self.draw_times.push((id, took));
+    }
+
+    pub fn frame_drawn(&mut self, started: Instant) {
+        let now = Instant::now();
+        self.frame_time = now - started;
+        self.frames.push_back(now);
+        while self
+            .frames
+            .front()
+            .is_some_and(|frame| now - *frame > FPS_WINDOW)
+        {
+            self.frames.pop_front();
+        }
+        if let Some(input) = self.pending_input.take() {
+            self.last_latency = Some(now - input);
+        }
+    }
+
+    fn lines(&self, theme: &Theme) -> Vec<Line<'static>> {
+        let muted = Style::default().fg(theme.muted);
+        let row = |label: &str, value: String| {
+            Line::from(vec![
+                Span::styled(format!("{label:<16}"), muted),
+                Span::raw(value),
+            ])
+        };
+        let millis = |took: Duration| format!("{:.2} ms", took.as_secs_f64() * 1000.0);
+        let mut lines = vec![
+            row("FPS", self.frames.len().to_string()),
+            row("Frame", millis(self.frame_time)),
+            row(
+                "Input latency",
+                self.last_latency.map_or("–".to_string(), millis),
+            ),
+            row(
+                "Action queue",
+                format!("{} (peak {})", self.queue_depth, self.peak_queue_depth),
+            ),
+            Line::raw(""),
+        ];
+        let mut slowest = self.draw_times.clone();
+        slowest.sort_by_key(|(_, took)| std::cmp::Reverse(*took));
+        lines.extend(
+            slowest
+                .into_iter()
+                .take(SLOWEST)
+                .map(|(id, took)| row(&id, millis(took))),
+        );
+        lines
+    }
+
+    /// Draw the figures in the top right corner, over whatever is there.
+    pub fn draw(&self, frame: &mut Frame, area: Rect, theme: &Theme) {
+        let lines = self.lines(theme);
+        let [area] = Layout::vertical([Constraint::Length(lines.len() as u16 + 2)])
+            .flex(Flex::Start)
+            .areas(area);
+        let [area] = Layout::horizontal([Constraint::Length(34)])
+            .flex(Flex::End)
+            .areas(area);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.warning))
+            .padding(Padding::horizontal(1))
+            .title("Performance");
+        frame.render_widget(Clear, area);
+        frame.render_widget(
+            Paragraph::new(lines)
+                .style(Style::default().bg(theme.popup_bg))
+                .block(block),
+            area,
+        );
+    }
+}
@@ -0,0 +1,67 @@
+//! Whether postings on Greenhouse and Lever boards are still up, from the boards' public job
+//! APIs, which answer 404 once a posting is taken down. Other sites give no such signal, so
+//! only links to these two are checked.
+
+use serde::{Deserialize, Serialize};
+
+use crate::http;
+
+// largest API answer read; only its status is wanted
+const MAX_ANSWER_BYTES: u64 = 1_000_000;
+
+/// A posting on a board with a public API, as found in an application's link.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Board {
+    /// `boards.greenhouse.io/<board>/jobs/<id>`, or `job-boards.greenhouse.io`.
+    Greenhouse { board: String, id: String },
+    /// `jobs.lever.co/<company>/<id>`.
+    Lever { company: String, id: String },
+}
+
+impl Board {
+    pub fn from_url(url: &str) -> Option<Self> {
+        let rest = url.split_once("://").map_or(url, |(_, rest)| rest);
+        let rest = rest.split(['?', '#']).next()?;
+        let mut parts = rest.split('/').filter(|part| !part.is_empty());
+        let host = parts.next()?.to_lowercase();
+        match host.as_str() {
+            "boards.greenhouse.io" | "job-boards.greenhouse.io" => {
+                let board = parts.next()?.to_string();
+                let id = match (parts.next()?, parts.next()) {
+                    ("jobs", Some(id)) => id,
+                    _ => return None,
+                };
+                (id.chars().all(|c| c.is_ascii_digit())).then(|| Board::Greenhouse {
+                    board,
+                    id: id.to_string(),
+                })
+            }
+            "jobs.lever.co" | "jobs.eu.lever.co" => Some(Board::Lever {
+                company: parts.next()?.to_string(),
+                id: parts.next()?.to_string(),
+            }),
+            _ => None,
+        }
+    }
+
+    fn api_url(&self) -> String {
+        match self {
+            Board::Greenhouse { board, id } => {
+                format!("https://boards-api.greenhouse.io/v1/boards/{board}/jobs/{id}")
+            }
+            Board::Lever { company, id } => {
+                format!("https://api.lever.co/v0/postings/{company}/{id}")
+            }
+        }
+    }
+
+    /// `Some(true)` once the board no longer lists the posting, `None` when it couldn't be
+    /// told, e.g. offline, so a failed check never flags anything.
+    pub fn is_closed(&self) -> Option<bool> {
+        match http::get(&self.api_url(), MAX_ANSWER_BYTES).ok()?.0 {
+            200 => Some(false),
+            404 | 410 => Some(true),
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,131 @@
+use std::path::{Path, PathBuf};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    config::{Config, KeyBindings, key_sequence_to_string},
+    theme::ThemeConfig,
+};
+
+/// File name of the most recently applied preset, layered over the user's `config.json5`.
+pub const ACTIVE_PRESET_FILE: &str = "active_preset.json5";
+
+/// A shareable bundle of keybindings and theme settings.
+#[derive(Clone, Debug, Serialize, Deserialize)]
+pub struct Preset {
+    pub name: String,
+    #[serde(default)]
+    pub keybindings: KeyBindings,
+    #[serde(default)]
+    pub theme: ThemeConfig,
+}
+
+impl Preset {
+    pub fn from_config(name: &str, config: &Config) -> Self {
+        Preset {
+            name: name.to_string(),
+            keybindings: config.keybindings.clone(),
+            theme: config.theme_config.clone(),
+        }
+    }
+
+    pub fn load(path: &Path) -> Result<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        json5::from_str(&contents).map_err(|err| eyre!("Invalid preset {}: {err}", path.display()))
+    }
+
+    pub fn save(&self, path: &Path) -> Result<()> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Human readable list of what applying this preset would change.
+    pub fn diff(&self, config: &Config) -> Vec<String> {
+        let mut changes = Vec::new();
+        let mut modes = self.keybindings.iter().collect::<Vec<_>>();
+        modes.sort_by_key(|(mode, _)| format!("{mode:?}"));
+        for (mode, bindings) in modes {
+            let current = config.keybindings.get(mode);
+            let mut lines = bindings
+                .iter()
+                .filter_map(|(keys, action)| {
+                    let existing = current.and_then(|current| current.get(keys));
+                    let keys = key_sequence_to_string(keys);
+                    match existing {
+                        Some(current) if current == action => None,
+                        Some(current) => Some(format!("~ {mode:?} {keys}: {current} -> {action}")),
+                        None => Some(format!("+ {mode:?} {keys}: {action}")),
+                    }
+                })
+                .collect::<Vec<_>>();
+            lines.sort();
+            changes.extend(lines);
+        }
+
+        let current = &config.theme_config;
+        let theme = &self.theme;
+        if theme.preset != current.preset {
+            changes.push(format!(
+                "~ theme preset: {:?} -> {:?}",
+                current.preset, theme.preset
+            ));
+        }
+        if theme.min_contrast != current.min_contrast {
+            changes.push(format!(
+                "~ theme min_contrast: {} -> {}",
+                current.min_contrast, theme.min_contrast
+            ));
+        }
+        if theme.contrast != current.contrast {
+            changes.push(format!(
+                "~ theme contrast: {:?} -> {:?}",
+                current.contrast, theme.contrast
+            ));
+        }
+        if theme.terminal_background != current.terminal_background {
+            changes.push(format!(
+                "~ theme terminal_background: {:?} -> {:?}",
+                current.terminal_background, theme.terminal_background
+            ));
+        }
+        let mut colors = theme
+            .colors
+            .iter()
+            .filter(|(role, color)| current.colors.get(role) != Some(color))
+            .map(|(role, color)| format!("~ theme color {role:?}: {color}"))
+            .collect::<Vec<_>>();
+        colors.sort();
+        changes.extend(colors);
+        changes
+    }
+
+    /// Merge the preset into `config`: its bindings are added on top of the current ones and
+    /// its theme replaces the current theme.
+    pub fn apply(&self, config: &mut Config) {
+        for (mode, bindings) in self.keybindings.iter() {
+            config
+                .keybindings
+                .entry(*mode)
+                .or_default()
+                .extend(bindings.iter().map(|(k, a)| (k.clone(), a.clone())));
+        }
+        config.theme_config = self.theme.clone();
+        (config.theme, config.theme_warnings) = config.theme_config.build();
+    }
+}
+
+pub fn preset_path(config: &Config, name: &str) -> PathBuf {
+    config
+        .config
+        .config_dir
+        .join("presets")
+        .join(format!("{name}.json5"))
+}
+
+pub fn active_preset_path(config: &Config) -> PathBuf {
+    config.config.config_dir.join(ACTIVE_PRESET_FILE)
+}
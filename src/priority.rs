@@ -0,0 +1,35 @@
+//! The "what should I do next" order: applications ranked by how soon something needs doing
+//! about them, weighted as configured.
+
+use crate::{config::PriorityConfig, database::schema::PrioritySignals};
+
+/// 1 for something due today or overdue, halving with each day further away.
+fn urgency(days: Option<i64>) -> f64 {
+    days.map_or(0.0, |days| 0.5f64.powi(days.max(0) as i32))
+}
+
+/// How pressing an application is; higher comes first.
+pub fn score(signals: &PrioritySignals, weights: &PriorityConfig, stale_after_days: u32) -> f64 {
+    let staleness = match signals.idle_days {
+        Some(days) if stale_after_days > 0 => (days as f64 / stale_after_days as f64).min(1.0),
+        _ => 0.0,
+    };
+    weights.follow_up * urgency(signals.follow_up_in)
+        + weights.interview * urgency(signals.interview_in)
+        + weights.staleness * staleness
+}
+
+/// Ids of the applications with signals, i.e. active ones, most pressing first. Ties keep the
+/// order they were added in.
+pub fn rank(
+    signals: &[PrioritySignals],
+    weights: &PriorityConfig,
+    stale_after_days: u32,
+) -> Vec<i32> {
+    let mut scored = signals
+        .iter()
+        .map(|signals| (signals.job_id, score(signals, weights, stale_after_days)))
+        .collect::<Vec<_>>();
+    scored.sort_by(|(a_id, a), (b_id, b)| b.total_cmp(a).then(a_id.cmp(b_id)));
+    scored.into_iter().map(|(id, _)| id).collect()
+}
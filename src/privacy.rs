@@ -0,0 +1,51 @@
+use crate::database::schema::{Contact, JobApplication};
+
+/// Render-time masking for screenshots and screen sharing. Nothing stored is changed; views
+/// pass what they are about to draw through here instead.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq)]
+pub struct Privacy {
+    pub enabled: bool,
+}
+
+impl Privacy {
+    pub fn new(enabled: bool) -> Self {
+        Privacy { enabled }
+    }
+
+    /// A copy of `job` with identifying details and pay replaced by placeholders. Companies
+    /// are numbered by id so applications can still be told apart.
+    pub fn job(&self, job: &JobApplication) -> JobApplication {
+        if !self.enabled {
+            return job.clone();
+        }
+        JobApplication {
+            company_name: format!("Company #{}", job.id),
+            contacts: job
+                .contacts
+                .iter()
+                .map(|contact| Contact {
+                    name: "Hidden contact".to_string(),
+                    role: contact.role.clone(),
+                    ..Contact::default()
+                })
+                .collect(),
+            url: job.url.as_ref().map(|_| "Hidden link".to_string()),
+            salary_min: None,
+            salary_max: None,
+            currency: None,
+            compensation_notes: (job.salary_min.is_some()
+                || job.salary_max.is_some()
+                || job.compensation_notes.is_some())
+            .then(|| "Hidden pay".to_string()),
+            ..job.clone()
+        }
+    }
+
+    pub fn text(&self, text: &str, placeholder: &str) -> String {
+        if self.enabled && !text.is_empty() {
+            placeholder.to_string()
+        } else {
+            text.to_string()
+        }
+    }
+}
@@ -0,0 +1,115 @@
+//! Session recordings, for reproducing bugs. A recording holds the input that drove a session
+//! (keys, mouse, pastes and resizes) and the actions dispatched from the command line, one
+//! JSON entry per line. Everything else the app does follows from those, so replaying them
+//! against an empty database walks through the same actions again.
+
+use std::{
+    collections::VecDeque,
+    fs::File,
+    io::{BufWriter, Write},
+    path::Path,
+};
+
+use color_eyre::{Result, eyre::eyre};
+use serde::{Deserialize, Serialize};
+
+use crate::{action::Action, tui::Event};
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(bound(deserialize = "'de: 'static"))]
+pub enum Entry {
+    Event(Event),
+    Action(Box<Action>),
+}
+
+impl Entry {
+    /// Whether an event drives the session, rather than being the app's own ticking.
+    pub fn is_input(event: &Event) -> bool {
+        matches!(
+            event,
+            Event::Key(_) | Event::Mouse(_) | Event::Paste(_) | Event::Resize(..)
+        )
+    }
+}
+
+/// Writes entries to a recording as they happen, so it survives a crash.
+pub struct Recorder {
+    writer: BufWriter<File>,
+}
+
+impl Recorder {
+    pub fn create(path: &Path) -> Result<Self> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        Ok(Self {
+            writer: BufWriter::new(File::create(path)?),
+        })
+    }
+
+    pub fn record(&mut self, entry: &Entry) -> Result<()> {
+        serde_json::to_writer(&mut self.writer, entry)?;
+        writeln!(self.writer)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Read a recording, failing on the first entry that can't be understood.
+pub fn load(path: &Path) -> Result<Vec<Entry>> {
+    // actions name popups with `&'static str`, which can only borrow from input that is never
+    // freed; a recording is read once per run, so it is simply kept
+    let contents: &'static str = Box::leak(std::fs::read_to_string(path)?.into_boxed_str());
+    contents
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(idx, line)| {
+            serde_json::from_str(line).map_err(|err| eyre!("{}:{}: {err}", path.display(), idx + 1))
+        })
+        .collect()
+}
+
+/// A recording being played back, one entry per tick unless paused.
+pub struct Replay {
+    entries: VecDeque<Entry>,
+    total: usize,
+    pub paused: bool,
+    // entries to play while paused
+    steps: usize,
+}
+
+impl Replay {
+    pub fn new(entries: Vec<Entry>) -> Self {
+        Self {
+            total: entries.len(),
+            entries: entries.into(),
+            paused: false,
+            steps: 0,
+        }
+    }
+
+    pub fn step(&mut self) {
+        self.steps += 1;
+    }
+
+    /// The entry to play this tick, if any.
+    pub fn next(&mut self) -> Option<Entry> {
+        if self.paused {
+            if self.steps == 0 {
+                return None;
+            }
+            self.steps -= 1;
+        }
+        self.entries.pop_front()
+    }
+
+    pub fn is_finished(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// How far through the recording playback is, e.g. "12/40".
+    pub fn progress(&self) -> String {
+        format!("{}/{}", self.total - self.entries.len(), self.total)
+    }
+}
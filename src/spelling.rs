@@ -0,0 +1,247 @@
+//! Spell-checking of notes against a Hunspell dictionary, applying its affix rules so that
+//! e.g. "applications" is known from "application/SM". Each text area's component keeps its
+//! own `Speller`. Words are taken as runs of letters, leaving out links, email addresses,
+//! acronyms and names written in mixed case such as GitHub, which no dictionary has.
+
+use std::{collections::HashSet, fs::OpenOptions, io::Write, path::PathBuf};
+
+use color_eyre::{Result, eyre::eyre};
+use ratatui::style::{Modifier, Style};
+use spellbook::Dictionary;
+use tokio::sync::mpsc::UnboundedSender;
+use tui_textarea::{CursorMove, TextArea};
+
+use crate::{action::Action, app::Mode, config::Config};
+
+/// Where dictionaries are usually installed, tried in order when none is configured. A plain
+/// word list is used as it is when there's no `.aff` file beside it.
+const DICTIONARIES: [&str; 4] = [
+    "/usr/share/hunspell/en_US.dic",
+    "/usr/share/hunspell/en_GB.dic",
+    "/usr/share/myspell/en_US.dic",
+    "/usr/share/dict/words",
+];
+
+/// Words added from the suggestions, one a line, in the data directory.
+const PERSONAL_WORDS: &str = "words.txt";
+
+/// How many suggestions are offered for a word.
+const SUGGESTIONS: usize = 8;
+
+/// Read the dictionary, with the config's words and those added before. Fails only for a
+/// configured dictionary that can't be read; when none is found in the usual places,
+/// nothing is checked.
+fn load(config: &Config) -> Result<Option<Dictionary>> {
+    let spelling = &config.spelling;
+    if !spelling.enabled {
+        return Ok(None);
+    }
+    let path = match &spelling.dictionary {
+        Some(path) => path.clone(),
+        None => match DICTIONARIES
+            .iter()
+            .map(PathBuf::from)
+            .find(|path| path.is_file())
+        {
+            Some(path) => path,
+            None => return Ok(None),
+        },
+    };
+    let failed = |err: &dyn std::fmt::Display| {
+        eyre!("Failed to read the dictionary {}: {err}", path.display())
+    };
+    let dic = std::fs::read_to_string(&path).map_err(|err| failed(&err))?;
+    let mut dictionary = match std::fs::read_to_string(path.with_extension("aff")) {
+        Ok(aff) => Dictionary::new(&aff, &dic),
+        // a word list has no affix rules, nor the count of words a `.dic` file starts with
+        Err(_) => Dictionary::new("SET UTF-8", &format!("0\n{dic}")),
+    }
+    .map_err(|err| failed(&err))?;
+    let personal =
+        std::fs::read_to_string(config.config.data_dir.join(PERSONAL_WORDS)).unwrap_or_default();
+    for word in personal
+        .lines()
+        .chain(spelling.words.iter().map(String::as_str))
+        .map(str::trim)
+        .filter(|word| !word.is_empty())
+    {
+        // a word with flags the dictionary doesn't define is left out
+        let _ = dictionary.add(word);
+    }
+    Ok(Some(dictionary))
+}
+
+/// Keep `word` in the personal word list, so it's accepted in later runs too.
+pub fn remember_word(word: &str, config: &Config) -> Result<()> {
+    let path = config.config.data_dir.join(PERSONAL_WORDS);
+    let mut file = OpenOptions::new().create(true).append(true).open(&path)?;
+    writeln!(file, "{word}")?;
+    Ok(())
+}
+
+/// The words checked in `text`.
+fn words(text: &str) -> impl Iterator<Item = &str> {
+    text.split_whitespace()
+        .filter(|chunk| {
+            !["://", "www.", "@", "/", "[["]
+                .iter()
+                .any(|part| chunk.contains(part))
+        })
+        .flat_map(|chunk| chunk.split(|c: char| !is_letter(c)))
+        .map(|word| word.trim_matches(['\'', '’']))
+        .filter(|word| !word.is_empty() && !word.chars().skip(1).any(char::is_uppercase))
+}
+
+fn is_letter(c: char) -> bool {
+    c.is_alphabetic() || c == '\'' || c == '’'
+}
+
+/// A component's spell-checker. The dictionary is read the first time text is checked, so
+/// one that never shows notes doesn't hold it.
+#[derive(Default)]
+pub struct Speller {
+    dictionary: Option<Dictionary>,
+    // whether reading it was tried, so a dictionary that fails is reported once
+    loaded: bool,
+}
+
+impl Speller {
+    fn dictionary(
+        &mut self,
+        config: &Config,
+        tx: Option<&UnboundedSender<Action>>,
+    ) -> Option<&Dictionary> {
+        if !self.loaded {
+            self.loaded = true;
+            match load(config) {
+                Ok(dictionary) => self.dictionary = dictionary,
+                Err(err) => {
+                    if let Some(tx) = tx {
+                        let _ = tx.send(Action::Warning(err.to_string()));
+                    }
+                }
+            }
+        }
+        self.dictionary.as_ref()
+    }
+
+    /// Accept `word` from now on, once it has been remembered with `remember_word`.
+    pub fn add(&mut self, word: &str) {
+        if let Some(dictionary) = self.dictionary.as_mut() {
+            let _ = dictionary.add(word);
+        }
+    }
+
+    /// The distinct words in `text` not in the dictionary, in the order they first appear.
+    /// Nothing is misspelled while there's no dictionary.
+    fn misspelled<'t>(
+        &mut self,
+        text: &'t str,
+        config: &Config,
+        tx: Option<&UnboundedSender<Action>>,
+    ) -> Vec<&'t str> {
+        let Some(dictionary) = self.dictionary(config, tx) else {
+            return Vec::new();
+        };
+        let mut seen = HashSet::new();
+        words(text)
+            .filter(|word| !dictionary.check(&word.replace('’', "'")) && seen.insert(*word))
+            .collect()
+    }
+
+    /// Open the suggestions for the misspelled word at the cursor, or say why there are none.
+    pub fn suggest(
+        &mut self,
+        text_area: &TextArea,
+        config: &Config,
+        tx: &UnboundedSender<Action>,
+    ) -> Result<()> {
+        if self.dictionary(config, Some(tx)).is_none() {
+            tx.send(Action::Warning(
+                "No dictionary found; set one under \"spelling\" in the config".into(),
+            ))?;
+            return Ok(());
+        }
+        match word_at_cursor(text_area) {
+            Some((_, word)) if !self.misspelled(&word, config, Some(tx)).is_empty() => {
+                let mut suggestions = Vec::new();
+                if let Some(dictionary) = &self.dictionary {
+                    dictionary.suggest(&word.replace('’', "'"), &mut suggestions);
+                }
+                suggestions.truncate(SUGGESTIONS);
+                tx.send(Action::ChangeMode(Mode::Popup("spelling")))?;
+                tx.send(Action::ShowSpelling(word, suggestions))?;
+            }
+            Some((_, word)) => tx.send(Action::Notify(format!("\"{word}\" is spelled right")))?,
+            None => tx.send(Action::Notify("No word at the cursor".into()))?,
+        }
+        Ok(())
+    }
+
+    /// Underline the misspelled words in `text_area`, through its search highlighting.
+    pub fn highlight(
+        &mut self,
+        text_area: &mut TextArea,
+        config: &Config,
+        tx: Option<&UnboundedSender<Action>>,
+    ) {
+        let text = text_area.lines().join("\n");
+        let pattern = match self.misspelled(&text, config, tx).as_slice() {
+            [] => String::new(),
+            // words hold only letters and apostrophes, so need no escaping
+            words => format!(r"\b(?:{})\b", words.join("|")),
+        };
+        let current = text_area
+            .search_pattern()
+            .map_or("", |current| current.as_str());
+        if current != pattern {
+            let _ = text_area.set_search_pattern(&pattern);
+            text_area.set_search_style(
+                Style::default()
+                    .add_modifier(Modifier::UNDERLINED)
+                    .underline_color(config.theme.error),
+            );
+        }
+    }
+}
+
+/// The word the cursor is on or just after, with the column it starts at.
+pub fn word_at_cursor(text_area: &TextArea) -> Option<(usize, String)> {
+    let (row, col) = text_area.cursor();
+    let line = text_area.lines().get(row)?;
+    let chars = line.chars().collect::<Vec<_>>();
+    let letter_at = |idx: usize| chars.get(idx).copied().is_some_and(is_letter);
+    let mut start = col;
+    while start > 0 && letter_at(start - 1) {
+        start -= 1;
+    }
+    let mut end = col;
+    while letter_at(end) {
+        end += 1;
+    }
+    let word = chars[start..end]
+        .iter()
+        .collect::<String>()
+        .trim_matches(['\'', '’'])
+        .to_string();
+    let start = start
+        + chars[start..end]
+            .iter()
+            .take_while(|c| !c.is_alphabetic())
+            .count();
+    (!word.is_empty()).then_some((start, word))
+}
+
+/// Put `replacement` in place of `word` at the cursor, if the cursor is still on it.
+pub fn replace_at_cursor(text_area: &mut TextArea, word: &str, replacement: &str) {
+    let Some((start, found)) = word_at_cursor(text_area) else {
+        return;
+    };
+    if found != word {
+        return;
+    }
+    let (row, _) = text_area.cursor();
+    text_area.move_cursor(CursorMove::Jump(row as u16, start as u16));
+    text_area.delete_str(word.chars().count());
+    text_area.insert_str(replacement);
+}
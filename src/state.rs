@@ -0,0 +1,40 @@
+//! What the app remembers between runs that isn't configuration, such as previous search
+//! queries. Kept as JSON beside the database so it never has to be edited by hand.
+
+use std::path::{Path, PathBuf};
+
+use color_eyre::Result;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct State {
+    /// Queries run from the search bar, oldest first.
+    #[serde(default)]
+    pub search_history: Vec<String>,
+    /// Day (`YYYY-MM-DD`) the weekly digest was last sent by `job-tracker cron`.
+    #[serde(default)]
+    pub cron_digest_sent: Option<String>,
+    /// Percentage of the list's width the details beside it were last resized to.
+    #[serde(default)]
+    pub notes_preview_width: Option<u16>,
+}
+
+impl State {
+    pub fn path(data_dir: &Path) -> PathBuf {
+        data_dir.join("state.json")
+    }
+
+    /// The saved state, or a fresh one when there is none yet or it can't be read.
+    pub fn load(data_dir: &Path) -> Self {
+        std::fs::read_to_string(Self::path(data_dir))
+            .ok()
+            .and_then(|contents| serde_json::from_str(&contents).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self, data_dir: &Path) -> Result<()> {
+        std::fs::create_dir_all(data_dir)?;
+        std::fs::write(Self::path(data_dir), serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
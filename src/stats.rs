@@ -0,0 +1,143 @@
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{
+    analytics::Insights,
+    database::{
+        db::Database,
+        query,
+        schema::{ApplicationStatus, Expense},
+    },
+};
+
+/// Figures shown in the stats view, computed from the database on request.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Stats {
+    pub status_counts: Vec<(ApplicationStatus, u32)>,
+    /// `(monday, count)` for every week since the first application.
+    pub weekly_applications: Vec<(String, u32)>,
+    /// Rounded to whole days; `None` until a rejection has been recorded.
+    pub average_days_to_rejection: Option<u32>,
+    /// `(YYYY-MM, cents)` for every month with expenses, oldest first.
+    pub monthly_expenses: Vec<(String, i64)>,
+    pub expenses: Vec<Expense>,
+    pub insights: Insights,
+}
+
+impl Stats {
+    pub fn collect(db: &Database) -> Self {
+        Stats {
+            status_counts: query::get_status_counts(db),
+            weekly_applications: query::get_weekly_application_counts(db),
+            average_days_to_rejection: query::get_average_days_to_rejection(db)
+                .map(|days| days.max(0.0).round() as u32),
+            monthly_expenses: query::get_monthly_expense_totals(db),
+            expenses: query::get_expenses(db),
+            insights: Insights::collect(db),
+        }
+    }
+
+    /// Only the figures compared across profiles: counts, weekly applications and time to
+    /// rejection.
+    pub fn headline(db: &Database) -> Self {
+        Stats {
+            status_counts: query::get_status_counts(db),
+            weekly_applications: query::get_weekly_application_counts(db),
+            average_days_to_rejection: query::get_average_days_to_rejection(db)
+                .map(|days| days.max(0.0).round() as u32),
+            ..Default::default()
+        }
+    }
+
+    /// Saved postings aren't counted, as they haven't been applied to.
+    pub fn total_applications(&self) -> u32 {
+        self.status_counts
+            .iter()
+            .filter(|(status, _)| *status != ApplicationStatus::Saved)
+            .map(|(_, count)| count)
+            .sum()
+    }
+
+    pub fn count(&self, status: &ApplicationStatus) -> u32 {
+        self.status_counts
+            .iter()
+            .find(|(s, _)| s == status)
+            .map_or(0, |(_, count)| *count)
+    }
+
+    /// Share of applications that heard back, i.e. moved past `Applied` other than by
+    /// being withdrawn.
+    pub fn response_rate(&self) -> Option<f64> {
+        let total = self.total_applications();
+        let waiting = self.count(&ApplicationStatus::Applied);
+        let withdrawn = self.count(&ApplicationStatus::Withdrawn);
+        let total = total - withdrawn;
+        (total > 0).then(|| (total - waiting) as f64 / total as f64)
+    }
+
+    /// Share of applications that led to an offer, accepted or not, leaving out withdrawn ones.
+    pub fn offer_rate(&self) -> Option<f64> {
+        let offers =
+            self.count(&ApplicationStatus::Offered) + self.count(&ApplicationStatus::Accepted);
+        let total = self.total_applications() - self.count(&ApplicationStatus::Withdrawn);
+        (total > 0).then(|| offers as f64 / total as f64)
+    }
+
+    pub fn total_expenses(&self) -> i64 {
+        self.monthly_expenses.iter().map(|(_, cents)| cents).sum()
+    }
+}
+
+/// Headline figures of `db`, as "current", followed by each profile's. A profile that can't
+/// be read, e.g. a missing file or one that isn't a tracker database, says why instead.
+pub fn compare_profiles(
+    db: &Database,
+    profiles: &[(String, PathBuf)],
+) -> Vec<(String, Result<Stats, String>)> {
+    let mut rows = vec![("current".to_string(), Ok(Stats::headline(db)))];
+    rows.extend(profiles.iter().map(|(name, path)| {
+        let stats = Database::open_read_only(path)
+            .and_then(|profile| {
+                // the queries panic on a missing table, but older schemas are fine
+                let conn = profile.connection();
+                conn.prepare("SELECT status, application_date FROM job_applications")?;
+                conn.prepare("SELECT job_id, status, changed_at FROM status_history")?;
+                Ok(Stats::headline(&profile))
+            })
+            .map_err(|err| format!("{}: {err}", path.display()));
+        (name.clone(), stats)
+    }));
+    rows
+}
+
+pub fn format_amount(cents: i64) -> String {
+    let sign = if cents < 0 { "-" } else { "" };
+    let cents = cents.abs();
+    format!("{sign}{}.{:02}", cents / 100, cents % 100)
+}
+
+/// Parse an amount such as `12`, `12.5` or `12.50` into cents.
+pub fn parse_amount(value: &str) -> Option<i64> {
+    let value = value.trim().trim_start_matches(['$', '£', '€']);
+    let (whole, fraction) = value.split_once('.').unwrap_or((value, ""));
+    let digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+    if whole.is_empty() && fraction.is_empty()
+        || fraction.len() > 2
+        || !digits(whole)
+        || !digits(fraction)
+    {
+        return None;
+    }
+    let whole = if whole.is_empty() {
+        0
+    } else {
+        whole.parse::<i64>().ok()?
+    };
+    let fraction = match fraction.len() {
+        0 => 0,
+        1 => fraction.parse::<i64>().ok()? * 10,
+        _ => fraction.parse::<i64>().ok()?,
+    };
+    whole.checked_mul(100)?.checked_add(fraction)
+}
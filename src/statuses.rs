@@ -0,0 +1,108 @@
+//! The statuses defined in the database, and the colour each status is drawn in. They are
+//! read once at startup, as statuses are parsed and drawn in places that have no database to
+//! hand.
+
+use std::{str::FromStr, sync::RwLock};
+
+use color_eyre::{Result, eyre::eyre};
+use ratatui::style::Color;
+
+use crate::{
+    cli::StatusCommand,
+    config::Config,
+    database::{
+        db::Database,
+        query,
+        schema::{ApplicationStatus, StatusDefinition},
+    },
+    unlock,
+};
+
+static DEFINITIONS: RwLock<Vec<StatusDefinition>> = RwLock::new(Vec::new());
+
+/// Read the status definitions, replacing any read before.
+pub fn load(db: &Database) {
+    if let Ok(mut definitions) = DEFINITIONS.write() {
+        *definitions = query::get_statuses(db);
+    }
+}
+
+/// The definition with this name, ignoring case.
+pub fn find(name: &str) -> Option<StatusDefinition> {
+    let definitions = DEFINITIONS.read().ok()?;
+    definitions
+        .iter()
+        .find(|status| status.name.eq_ignore_ascii_case(name.trim()))
+        .cloned()
+}
+
+/// Statuses added by the user, in the order they were added.
+pub fn custom() -> Vec<ApplicationStatus> {
+    let Ok(definitions) = DEFINITIONS.read() else {
+        return Vec::new();
+    };
+    definitions
+        .iter()
+        .map(|status| ApplicationStatus::from(status.name.clone()))
+        .filter(|status| matches!(status, ApplicationStatus::Custom(_)))
+        .collect()
+}
+
+/// The colour a status is defined with, or the terminal's own for unknown statuses and
+/// colours that don't parse.
+pub fn colour(status: &ApplicationStatus) -> Color {
+    find(&status.to_string())
+        .and_then(|status| Color::from_str(&status.color).ok())
+        .unwrap_or(Color::Reset)
+}
+
+/// Carry out a `statuses` subcommand against the usual database, listing the statuses when
+/// no subcommand is given.
+pub fn run(command: Option<StatusCommand>) -> Result<()> {
+    let mut database = Database::default();
+    unlock::from_env(&mut database, &Config::new()?)?;
+    database.create()?;
+    load(&database);
+    match command.unwrap_or(StatusCommand::List) {
+        StatusCommand::List => {
+            for status in query::get_statuses(&database) {
+                let closed = if status.is_active { "" } else { "  (closed)" };
+                println!("{:<24}{}{closed}", status.name, status.color);
+            }
+        }
+        StatusCommand::Set {
+            name,
+            color,
+            closed,
+        } => {
+            // recolouring an existing status keeps its name as it was written
+            let name = find(&name).map_or_else(|| name.trim().to_string(), |status| status.name);
+            if name.is_empty() {
+                return Err(eyre!("a status needs a name"));
+            }
+            Color::from_str(&color).map_err(|_| eyre!("unknown colour `{color}`"))?;
+            // built-in statuses keep whether they are closed
+            let is_active = match ApplicationStatus::from(name.clone()) {
+                ApplicationStatus::Custom(_) => !closed,
+                status => status.is_active(),
+            };
+            query::save_status(
+                &StatusDefinition {
+                    name: name.clone(),
+                    color,
+                    is_active,
+                },
+                &database,
+            )?;
+            println!("Saved {name}");
+        }
+        StatusCommand::Remove { name } => {
+            if query::delete_status(&name, &database)? {
+                println!("Removed {name}");
+            } else {
+                return Err(eyre!("there is no status called {name}"));
+            }
+        }
+    }
+    Ok(())
+}
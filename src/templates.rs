@@ -0,0 +1,48 @@
+//! Email and message skeletons from the config's `templates`, filled in for an application.
+//! Placeholders are written in braces, e.g. `{company}`; unknown ones are left as they are so a
+//! typo shows up in the text rather than vanishing.
+
+use crate::database::schema::JobApplication;
+
+fn value(job: &JobApplication, placeholder: &str) -> Option<String> {
+    let contact = job.contacts.first();
+    Some(match placeholder {
+        "company" => job.company_name.clone(),
+        "position" => job.position.clone(),
+        "contact" => contact
+            .map(|contact| contact.name.clone())
+            .unwrap_or_default(),
+        "email" => contact
+            .map(|contact| contact.email.clone())
+            .unwrap_or_default(),
+        "status" => job.status.to_string(),
+        "date" => job.application_date.clone(),
+        "url" => job.url.clone().unwrap_or_default(),
+        _ => return None,
+    })
+}
+
+/// `template` with every known placeholder replaced by `job`'s details.
+pub fn render(template: &str, job: &JobApplication) -> String {
+    let mut rendered = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find('{') {
+        rendered.push_str(&rest[..start]);
+        let after = &rest[start + 1..];
+        match after
+            .find('}')
+            .and_then(|end| Some((end, value(job, after[..end].trim())?)))
+        {
+            Some((end, value)) => {
+                rendered.push_str(&value);
+                rest = &after[end + 1..];
+            }
+            None => {
+                rendered.push('{');
+                rest = after;
+            }
+        }
+    }
+    rendered.push_str(rest);
+    rendered
+}
@@ -0,0 +1,328 @@
+use std::collections::HashMap;
+
+use ratatui::style::Color;
+use serde::{Deserialize, Serialize};
+use tracing::info;
+
+/// Resolved colours for each themed role, used by components at render time.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Theme {
+    pub background: Color,
+    pub text: Color,
+    pub muted: Color,
+    pub focus: Color,
+    pub link: Color,
+    pub selection_bg: Color,
+    /// Borders of unfocused fields and panels.
+    pub border: Color,
+    pub popup_bg: Color,
+    /// Errors and destructive confirmations.
+    pub error: Color,
+    pub warning: Color,
+    pub success: Color,
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::preset(ThemePreset::Default)
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemeRole {
+    Background,
+    Text,
+    Muted,
+    Focus,
+    Link,
+    SelectionBg,
+    Border,
+    PopupBg,
+    Error,
+    Warning,
+    Success,
+}
+
+// (foreground, background) pairs that are drawn on top of each other
+const CONTRAST_PAIRS: [(ThemeRole, ThemeRole); 9] = [
+    (ThemeRole::Text, ThemeRole::Background),
+    (ThemeRole::Muted, ThemeRole::Background),
+    (ThemeRole::Focus, ThemeRole::Background),
+    (ThemeRole::Link, ThemeRole::Background),
+    (ThemeRole::Link, ThemeRole::SelectionBg),
+    (ThemeRole::Text, ThemeRole::SelectionBg),
+    (ThemeRole::Text, ThemeRole::PopupBg),
+    (ThemeRole::Warning, ThemeRole::Background),
+    (ThemeRole::Text, ThemeRole::Error),
+];
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ThemePreset {
+    /// For dark terminals.
+    #[default]
+    #[serde(alias = "dark")]
+    Default,
+    Light,
+    HighContrast,
+}
+
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ContrastMode {
+    Off,
+    /// Report combinations below the minimum contrast.
+    #[default]
+    Warn,
+    /// Lighten or darken foregrounds until they reach the minimum contrast.
+    Adjust,
+}
+
+/// What `Color::Reset` resolves to, since the terminal's own colours can't be queried.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TerminalBackground {
+    #[default]
+    Dark,
+    Light,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ThemeConfig {
+    pub preset: ThemePreset,
+    pub min_contrast: f64,
+    pub contrast: ContrastMode,
+    pub terminal_background: TerminalBackground,
+    pub colors: HashMap<ThemeRole, Color>,
+}
+
+impl Default for ThemeConfig {
+    fn default() -> Self {
+        Self {
+            preset: ThemePreset::default(),
+            min_contrast: 2.0,
+            contrast: ContrastMode::default(),
+            terminal_background: TerminalBackground::default(),
+            colors: HashMap::new(),
+        }
+    }
+}
+
+impl Theme {
+    pub fn preset(preset: ThemePreset) -> Self {
+        match preset {
+            ThemePreset::Default => Theme {
+                background: Color::Reset,
+                text: Color::White,
+                muted: Color::DarkGray,
+                focus: Color::Blue,
+                link: Color::Blue,
+                selection_bg: Color::DarkGray,
+                border: Color::White,
+                popup_bg: Color::Reset,
+                error: Color::Red,
+                warning: Color::Yellow,
+                success: Color::Green,
+            },
+            ThemePreset::Light => Theme {
+                background: Color::Reset,
+                text: Color::Black,
+                muted: Color::DarkGray,
+                focus: Color::Blue,
+                link: Color::Blue,
+                selection_bg: Color::Gray,
+                border: Color::DarkGray,
+                popup_bg: Color::Reset,
+                error: Color::Red,
+                // yellow is unreadable on white
+                warning: Color::Magenta,
+                success: Color::Green,
+            },
+            ThemePreset::HighContrast => Theme {
+                background: Color::Black,
+                text: Color::White,
+                muted: Color::Gray,
+                focus: Color::LightYellow,
+                link: Color::LightCyan,
+                selection_bg: Color::Blue,
+                border: Color::White,
+                popup_bg: Color::Black,
+                error: Color::Red,
+                warning: Color::LightYellow,
+                success: Color::LightGreen,
+            },
+        }
+    }
+
+    pub fn role(&self, role: ThemeRole) -> Color {
+        match role {
+            ThemeRole::Background => self.background,
+            ThemeRole::Text => self.text,
+            ThemeRole::Muted => self.muted,
+            ThemeRole::Focus => self.focus,
+            ThemeRole::Link => self.link,
+            ThemeRole::SelectionBg => self.selection_bg,
+            ThemeRole::Border => self.border,
+            ThemeRole::PopupBg => self.popup_bg,
+            ThemeRole::Error => self.error,
+            ThemeRole::Warning => self.warning,
+            ThemeRole::Success => self.success,
+        }
+    }
+
+    fn role_mut(&mut self, role: ThemeRole) -> &mut Color {
+        match role {
+            ThemeRole::Background => &mut self.background,
+            ThemeRole::Text => &mut self.text,
+            ThemeRole::Muted => &mut self.muted,
+            ThemeRole::Focus => &mut self.focus,
+            ThemeRole::Link => &mut self.link,
+            ThemeRole::SelectionBg => &mut self.selection_bg,
+            ThemeRole::Border => &mut self.border,
+            ThemeRole::PopupBg => &mut self.popup_bg,
+            ThemeRole::Error => &mut self.error,
+            ThemeRole::Warning => &mut self.warning,
+            ThemeRole::Success => &mut self.success,
+        }
+    }
+}
+
+impl ThemeConfig {
+    /// Build the theme from the preset and overrides, then run the contrast pass. Returns the
+    /// theme along with a warning for every pair below `min_contrast` when in `Warn` mode.
+    pub fn build(&self) -> (Theme, Vec<String>) {
+        let mut theme = Theme::preset(self.preset);
+        for (role, color) in &self.colors {
+            *theme.role_mut(*role) = *color;
+        }
+
+        let mut warnings = Vec::new();
+        if self.contrast == ContrastMode::Off {
+            return (theme, warnings);
+        }
+        for (fg_role, bg_role) in CONTRAST_PAIRS {
+            let fg = self.to_rgb(theme.role(fg_role), false);
+            let bg = self.to_rgb(theme.role(bg_role), true);
+            let ratio = contrast_ratio(fg, bg);
+            if ratio >= self.min_contrast {
+                continue;
+            }
+            match self.contrast {
+                ContrastMode::Warn => warnings.push(format!(
+                    "Theme: {fg_role:?} on {bg_role:?} has a contrast of {ratio:.1}:1 (minimum {:.1}:1)",
+                    self.min_contrast
+                )),
+                ContrastMode::Adjust => {
+                    let (r, g, b) = adjust_for_contrast(fg, bg, self.min_contrast);
+                    info!("Theme: adjusted {fg_role:?} on {bg_role:?} from {ratio:.1}:1");
+                    *theme.role_mut(fg_role) = Color::Rgb(r, g, b);
+                }
+                ContrastMode::Off => {}
+            }
+        }
+        (theme, warnings)
+    }
+
+    fn to_rgb(&self, color: Color, is_background: bool) -> (u8, u8, u8) {
+        match color {
+            Color::Reset => match (self.terminal_background, is_background) {
+                (TerminalBackground::Dark, true) | (TerminalBackground::Light, false) => (0, 0, 0),
+                _ => (255, 255, 255),
+            },
+            color => color_to_rgb(color),
+        }
+    }
+}
+
+// Approximations using the default xterm palette for named and indexed colours.
+fn color_to_rgb(color: Color) -> (u8, u8, u8) {
+    const ANSI: [(u8, u8, u8); 16] = [
+        (0, 0, 0),
+        (205, 0, 0),
+        (0, 205, 0),
+        (205, 205, 0),
+        (0, 0, 238),
+        (205, 0, 205),
+        (0, 205, 205),
+        (229, 229, 229),
+        (127, 127, 127),
+        (255, 0, 0),
+        (0, 255, 0),
+        (255, 255, 0),
+        (92, 92, 255),
+        (255, 0, 255),
+        (0, 255, 255),
+        (255, 255, 255),
+    ];
+    match color {
+        Color::Reset | Color::Black => ANSI[0],
+        Color::Red => ANSI[1],
+        Color::Green => ANSI[2],
+        Color::Yellow => ANSI[3],
+        Color::Blue => ANSI[4],
+        Color::Magenta => ANSI[5],
+        Color::Cyan => ANSI[6],
+        Color::Gray => ANSI[7],
+        Color::DarkGray => ANSI[8],
+        Color::LightRed => ANSI[9],
+        Color::LightGreen => ANSI[10],
+        Color::LightYellow => ANSI[11],
+        Color::LightBlue => ANSI[12],
+        Color::LightMagenta => ANSI[13],
+        Color::LightCyan => ANSI[14],
+        Color::White => ANSI[15],
+        Color::Rgb(r, g, b) => (r, g, b),
+        Color::Indexed(i) if i < 16 => ANSI[i as usize],
+        Color::Indexed(i) if i < 232 => {
+            let level = |v: u8| if v == 0 { 0 } else { 55 + v * 40 };
+            let i = i - 16;
+            (level(i / 36), level((i / 6) % 6), level(i % 6))
+        }
+        Color::Indexed(i) => {
+            let v = 8 + (i - 232) * 10;
+            (v, v, v)
+        }
+    }
+}
+
+fn relative_luminance((r, g, b): (u8, u8, u8)) -> f64 {
+    let channel = |c: u8| {
+        let c = c as f64 / 255.0;
+        if c <= 0.03928 {
+            c / 12.92
+        } else {
+            ((c + 0.055) / 1.055).powf(2.4)
+        }
+    };
+    0.2126 * channel(r) + 0.7152 * channel(g) + 0.0722 * channel(b)
+}
+
+/// WCAG contrast ratio between two colours, from 1.0 (identical) to 21.0 (black on white).
+pub fn contrast_ratio(fg: (u8, u8, u8), bg: (u8, u8, u8)) -> f64 {
+    let (a, b) = (relative_luminance(fg), relative_luminance(bg));
+    (a.max(b) + 0.05) / (a.min(b) + 0.05)
+}
+
+// Blend the foreground towards white or black, whichever moves it away from the background,
+// and stop at the first step that is readable enough.
+fn adjust_for_contrast(fg: (u8, u8, u8), bg: (u8, u8, u8), min_contrast: f64) -> (u8, u8, u8) {
+    let towards = if relative_luminance(bg) < 0.5 {
+        (255, 255, 255)
+    } else {
+        (0, 0, 0)
+    };
+    let blend = |a: u8, b: u8, t: f64| (a as f64 + (b as f64 - a as f64) * t).round() as u8;
+    (1..=20)
+        .map(|step| {
+            let t = step as f64 / 20.0;
+            (
+                blend(fg.0, towards.0, t),
+                blend(fg.1, towards.1, t),
+                blend(fg.2, towards.2, t),
+            )
+        })
+        .find(|candidate| contrast_ratio(*candidate, bg) >= min_contrast)
+        .unwrap_or(towards)
+}
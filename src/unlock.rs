@@ -0,0 +1,162 @@
+//! The passphrase for a database encrypted at rest, asked for before anything reads it. It
+//! can be given in `JOB_TRACKER_PASSPHRASE` instead, as it must be for subcommands run
+//! without a terminal, e.g. from cron.
+
+use color_eyre::{Result, eyre::eyre};
+use crossterm::event::{KeyCode, KeyModifiers};
+use ratatui::{
+    Frame,
+    layout::{Constraint, Flex, Layout},
+    style::{Modifier, Style},
+    text::{Line, Span, Text},
+    widgets::{Block, BorderType, Padding, Paragraph},
+};
+
+use crate::{
+    config::Config,
+    database::db::Database,
+    tui::{Event, Tui},
+};
+
+const PASSPHRASE_VAR: &str = "JOB_TRACKER_PASSPHRASE";
+
+/// Whether the config asks for encryption, failing when this build can't provide it rather
+/// than carrying on in plain text.
+fn enabled(config: &Config) -> Result<bool> {
+    if config.encryption.enabled && !cfg!(feature = "encryption") {
+        return Err(eyre!(
+            "Encryption is turned on in the config, but this build lacks the `encryption` feature"
+        ));
+    }
+    Ok(config.encryption.enabled)
+}
+
+/// Unlock `database` with the passphrase from the environment, for subcommands that can't
+/// ask for it.
+pub fn from_env(database: &mut Database, config: &Config) -> Result<()> {
+    if !enabled(config)? {
+        return Ok(());
+    }
+    let passphrase = std::env::var(PASSPHRASE_VAR)
+        .map_err(|_| eyre!("Encryption is turned on; set {PASSPHRASE_VAR} to open the database"))?;
+    database.unlock(&passphrase)
+}
+
+/// Unlock `database` with the passphrase from the environment, or else one typed in. A
+/// database that isn't encrypted yet has one chosen for it, typed twice.
+pub async fn prompt(database: &mut Database, config: &Config) -> Result<()> {
+    if !enabled(config)? {
+        return Ok(());
+    }
+    if std::env::var_os(PASSPHRASE_VAR).is_some() {
+        return from_env(database, config);
+    }
+    let mut prompt = Prompt {
+        choosing: !database.is_encrypted(),
+        input: String::new(),
+        first: None,
+        error: None,
+    };
+    let mut tui = Tui::new()?.mouse(false).tick_rate(1.0).frame_rate(30.0);
+    tui.enter()?;
+    let unlocked = loop {
+        let Some(event) = tui.next_event().await else {
+            break false;
+        };
+        match event {
+            Event::Render | Event::Resize(_, _) => {
+                tui.draw(|frame| prompt.draw(frame, config))?;
+            }
+            Event::Paste(text) => prompt.input.push_str(text.trim_end_matches(['\r', '\n'])),
+            Event::Key(key) => match key.code {
+                KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => {
+                    break false;
+                }
+                KeyCode::Esc => {
+                    prompt.input.clear();
+                    prompt.first = None;
+                }
+                KeyCode::Backspace => {
+                    prompt.input.pop();
+                }
+                KeyCode::Char(c) => prompt.input.push(c),
+                KeyCode::Enter if !prompt.input.is_empty() => {
+                    let input = std::mem::take(&mut prompt.input);
+                    if prompt.choosing && prompt.first.is_none() {
+                        prompt.first = Some(input);
+                        prompt.error = None;
+                        continue;
+                    }
+                    if prompt.first.take().is_some_and(|first| first != input) {
+                        prompt.error = Some("The passphrases don't match".into());
+                        continue;
+                    }
+                    match database.unlock(&input) {
+                        Ok(()) => break true,
+                        Err(err) => prompt.error = Some(err.to_string()),
+                    }
+                }
+                _ => {}
+            },
+            Event::Quit | Event::Closed => break false,
+            _ => {}
+        }
+    };
+    tui.exit()?;
+    if unlocked {
+        Ok(())
+    } else {
+        Err(eyre!("The database was left locked"))
+    }
+}
+
+struct Prompt {
+    // no encrypted database yet, so the passphrase is being chosen rather than checked
+    choosing: bool,
+    input: String,
+    // the passphrase typed the first time, while it's typed again to confirm
+    first: Option<String>,
+    error: Option<String>,
+}
+
+impl Prompt {
+    fn draw(&self, frame: &mut Frame, config: &Config) {
+        let theme = &config.theme;
+        let [area] = Layout::vertical([Constraint::Length(7)])
+            .flex(Flex::Center)
+            .areas(frame.area());
+        let [area] = Layout::horizontal([Constraint::Length(48)])
+            .flex(Flex::Center)
+            .areas(area);
+
+        let (title, label) = match (self.choosing, &self.first) {
+            (false, _) => ("Encrypted database", "Passphrase: "),
+            (true, None) => ("Choose a passphrase", "Passphrase: "),
+            (true, Some(_)) => ("Choose a passphrase", "Again: "),
+        };
+        let status = match &self.error {
+            Some(error) => Line::styled(error.as_str(), Style::default().fg(theme.error)),
+            None => Line::styled(
+                "[enter] unlock   [ctrl-c] quit",
+                Style::default().fg(theme.muted),
+            ),
+        };
+        let text = Text::from(vec![
+            Line::from(vec![
+                Span::raw(label),
+                Span::styled(
+                    "•".repeat(self.input.chars().count()),
+                    Style::default().add_modifier(Modifier::BOLD),
+                ),
+            ]),
+            Line::raw(""),
+            status,
+        ]);
+        let block = Block::bordered()
+            .border_type(BorderType::Rounded)
+            .border_style(Style::default().fg(theme.focus))
+            .padding(Padding::horizontal(1))
+            .title(title);
+        frame.render_widget(Paragraph::new(text).centered().block(block), area);
+    }
+}
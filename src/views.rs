@@ -0,0 +1,771 @@
+//! Views defined in the config: a named filter, order and layout for the job list, shown as
+//! tabs above it. Filters are written in a small expression language, e.g.
+//!
+//! ```text
+//! active and (status = Interviewing or status = "Phone Screen") and company ~ acme
+//! ```
+//!
+//! Comparisons are `field op value`, where `=` and `!=` ignore case, `~` and `!~` test whether
+//! the field contains the value, and `<`, `<=`, `>`, `>=` compare as numbers when the value is
+//! one and as text otherwise, which suits `YYYY-MM-DD` dates; fields left empty never compare.
+//! Values with spaces are quoted, and the value `today` stands for the current date, as in
+//! `follow_up < today`. `active` on its own matches applications still in progress, and `and`,
+//! `or`, `not` and brackets combine the rest.
+//!
+//! Filters are run by the database, as part of the query for each page of the job list, and
+//! against single applications by `Filter::matches`, e.g. for card badges.
+
+use std::fmt;
+
+use serde::{Deserialize, Serialize};
+
+use crate::{database::schema::JobApplication, dates::local_time};
+
+// the value standing for the current date
+const TODAY: &str = "today";
+
+/// A part of an application that views can filter on or show as a column.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Field {
+    Date,
+    Company,
+    Position,
+    Status,
+    Location,
+    Category,
+    WorkType,
+    LocationType,
+    Notes,
+    Url,
+    Currency,
+    SalaryMin,
+    SalaryMax,
+    FollowUp,
+    Deadline,
+    Rating,
+    Created,
+    Updated,
+}
+impl Field {
+    const ALL: [Field; 18] = [
+        Field::Date,
+        Field::Company,
+        Field::Position,
+        Field::Status,
+        Field::Location,
+        Field::Category,
+        Field::WorkType,
+        Field::LocationType,
+        Field::Notes,
+        Field::Url,
+        Field::Currency,
+        Field::SalaryMin,
+        Field::SalaryMax,
+        Field::FollowUp,
+        Field::Deadline,
+        Field::Rating,
+        Field::Created,
+        Field::Updated,
+    ];
+
+    /// As written in filters and config, e.g. `work_type`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Field::Date => "date",
+            Field::Company => "company",
+            Field::Position => "position",
+            Field::Status => "status",
+            Field::Location => "location",
+            Field::Category => "category",
+            Field::WorkType => "work_type",
+            Field::LocationType => "location_type",
+            Field::Notes => "notes",
+            Field::Url => "url",
+            Field::Currency => "currency",
+            Field::SalaryMin => "salary_min",
+            Field::SalaryMax => "salary_max",
+            Field::FollowUp => "follow_up",
+            Field::Deadline => "deadline",
+            Field::Rating => "rating",
+            Field::Created => "created",
+            Field::Updated => "updated",
+        }
+    }
+
+    /// Column heading in the table view.
+    pub fn heading(self) -> &'static str {
+        match self {
+            Field::Date => "Date",
+            Field::Company => "Company",
+            Field::Position => "Position",
+            Field::Status => "Status",
+            Field::Location => "Location",
+            Field::Category => "Category",
+            Field::WorkType => "Work Type",
+            Field::LocationType => "Location Type",
+            Field::Notes => "Notes",
+            Field::Url => "Link",
+            Field::Currency => "Currency",
+            Field::SalaryMin => "Salary Min",
+            Field::SalaryMax => "Salary Max",
+            Field::FollowUp => "Follow-up",
+            Field::Deadline => "Deadline",
+            Field::Rating => "Rating",
+            Field::Created => "Created",
+            Field::Updated => "Updated",
+        }
+    }
+
+    /// The field's value on `job`, empty when it has none.
+    pub fn value(self, job: &JobApplication) -> String {
+        let optional = |value: &Option<String>| value.clone().unwrap_or_default();
+        match self {
+            Field::Date => job.application_date.clone(),
+            Field::Company => job.company_name.clone(),
+            Field::Position => job.position.clone(),
+            Field::Status => job.status.to_string(),
+            Field::Location => job.location.clone(),
+            Field::Category => job.position_category.to_string(),
+            Field::WorkType => job.work_type.to_string(),
+            Field::LocationType => job.location_type.to_string(),
+            Field::Notes => optional(&job.notes),
+            Field::Url => optional(&job.url),
+            Field::Currency => optional(&job.currency),
+            Field::SalaryMin => job.salary_min.map(|s| s.to_string()).unwrap_or_default(),
+            Field::SalaryMax => job.salary_max.map(|s| s.to_string()).unwrap_or_default(),
+            Field::FollowUp => optional(&job.follow_up_date),
+            Field::Deadline => optional(&job.deadline),
+            Field::Rating => job.rating.map(|r| r.to_string()).unwrap_or_default(),
+            Field::Created => job
+                .created_at
+                .as_deref()
+                .map(local_time)
+                .unwrap_or_default(),
+            Field::Updated => job
+                .last_updated
+                .as_deref()
+                .map(local_time)
+                .unwrap_or_default(),
+        }
+    }
+
+    /// The column the field is stored in, as an SQL expression that is never null.
+    fn column(self) -> &'static str {
+        match self {
+            Field::Date => "application_date",
+            Field::Company => "company_name",
+            Field::Position => "position",
+            Field::Status => "status",
+            Field::Location => "location",
+            Field::Category => "position_category",
+            Field::WorkType => "work_type",
+            Field::LocationType => "location_type",
+            Field::Notes => "COALESCE(notes, '')",
+            Field::Url => "COALESCE(url, '')",
+            Field::Currency => "COALESCE(currency, '')",
+            Field::SalaryMin => "COALESCE(salary_min, '')",
+            Field::SalaryMax => "COALESCE(salary_max, '')",
+            Field::FollowUp => "COALESCE(follow_up_date, '')",
+            Field::Deadline => "COALESCE(deadline, '')",
+            Field::Rating => "COALESCE(rating, '')",
+            Field::Created => "COALESCE(created_at, '')",
+            Field::Updated => "COALESCE(last_updated, '')",
+        }
+    }
+
+    fn parse(name: &str) -> Option<Field> {
+        Field::ALL
+            .into_iter()
+            .find(|field| field.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum Op {
+    Eq,
+    Ne,
+    Contains,
+    NotContains,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+impl Op {
+    fn symbol(self) -> &'static str {
+        match self {
+            Op::Eq => "=",
+            Op::Ne => "!=",
+            Op::Contains => "~",
+            Op::NotContains => "!~",
+            Op::Lt => "<",
+            Op::Le => "<=",
+            Op::Gt => ">",
+            Op::Ge => ">=",
+        }
+    }
+}
+
+/// A parsed filter expression.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(try_from = "String", into = "String")]
+pub enum Filter {
+    #[default]
+    All,
+    Active,
+    Compare(Field, Op, String),
+    Not(Box<Filter>),
+    And(Box<Filter>, Box<Filter>),
+    Or(Box<Filter>, Box<Filter>),
+}
+
+impl Filter {
+    /// An SQL condition on `job_applications` for the filter. Values are pushed onto `params`
+    /// and referred to by position, so the condition can follow other parameters.
+    pub fn to_sql(&self, params: &mut Vec<String>) -> String {
+        match self {
+            Filter::All => "1".to_string(),
+            Filter::Active => "is_active".to_string(),
+            Filter::Compare(field, op, value) => {
+                let column = field.column();
+                let numeric = value.parse::<f64>().is_ok();
+                let param = if value.eq_ignore_ascii_case(TODAY) {
+                    "date('now', 'localtime')".to_string()
+                } else {
+                    params.push(value.clone());
+                    format!("?{}", params.len())
+                };
+                match op {
+                    Op::Eq => format!("lower({column}) = lower({param})"),
+                    Op::Ne => format!("lower({column}) != lower({param})"),
+                    Op::Contains => format!("instr(lower({column}), lower({param})) > 0"),
+                    Op::NotContains => format!("instr(lower({column}), lower({param})) = 0"),
+                    _ if numeric => format!(
+                        "({column} != '' AND CAST({column} AS REAL) {} CAST({param} AS REAL))",
+                        op.symbol()
+                    ),
+                    _ => format!(
+                        "({column} != '' AND lower({column}) {} lower({param}))",
+                        op.symbol()
+                    ),
+                }
+            }
+            Filter::Not(filter) => format!("NOT ({})", filter.to_sql(params)),
+            Filter::And(a, b) => format!("({}) AND ({})", a.to_sql(params), b.to_sql(params)),
+            Filter::Or(a, b) => format!("({}) OR ({})", a.to_sql(params), b.to_sql(params)),
+        }
+    }
+
+    /// Whether `job` passes the filter, comparing as `to_sql` does. `today` is the
+    /// `YYYY-MM-DD` date the value `today` stands for.
+    pub fn matches(&self, job: &JobApplication, today: &str) -> bool {
+        match self {
+            Filter::All => true,
+            Filter::Active => job.is_active,
+            Filter::Compare(field, op, value) => {
+                let actual = field.value(job).to_lowercase();
+                let value = if value.eq_ignore_ascii_case(TODAY) {
+                    today.to_string()
+                } else {
+                    value.to_lowercase()
+                };
+                let ordering = || match value.parse::<f64>() {
+                    // text that isn't a number casts to 0, as in SQL
+                    Ok(number) => actual.parse::<f64>().unwrap_or(0.0).partial_cmp(&number),
+                    Err(_) => Some(actual.as_str().cmp(&value)),
+                };
+                match op {
+                    Op::Eq => actual == value,
+                    Op::Ne => actual != value,
+                    Op::Contains => actual.contains(&value),
+                    Op::NotContains => !actual.contains(&value),
+                    _ if actual.is_empty() => false,
+                    Op::Lt => ordering().is_some_and(|o| o.is_lt()),
+                    Op::Le => ordering().is_some_and(|o| o.is_le()),
+                    Op::Gt => ordering().is_some_and(|o| o.is_gt()),
+                    Op::Ge => ordering().is_some_and(|o| o.is_ge()),
+                }
+            }
+            Filter::Not(filter) => !filter.matches(job, today),
+            Filter::And(a, b) => a.matches(job, today) && b.matches(job, today),
+            Filter::Or(a, b) => a.matches(job, today) || b.matches(job, today),
+        }
+    }
+
+    pub fn parse(input: &str) -> Result<Filter, FilterError> {
+        let tokens = tokenize(input)?;
+        if tokens.is_empty() {
+            return Ok(Filter::All);
+        }
+        let mut parser = Parser {
+            tokens,
+            pos: 0,
+            end: input.chars().count() + 1,
+        };
+        let filter = parser.or()?;
+        match parser.tokens.get(parser.pos) {
+            None => Ok(filter),
+            Some(token) => {
+                let hint = match token.kind {
+                    TokenKind::Word(_) => " (values with spaces need quotes)",
+                    _ => "",
+                };
+                Err(FilterError::new(
+                    token.column,
+                    format!(
+                        "expected `and`, `or` or the end, found {}{hint}",
+                        token.kind
+                    ),
+                ))
+            }
+        }
+    }
+}
+
+impl TryFrom<String> for Filter {
+    type Error = FilterError;
+
+    fn try_from(input: String) -> Result<Self, Self::Error> {
+        Filter::parse(&input)
+    }
+}
+
+/// Writes the filter back as an expression, bracketing every combination so it parses the
+/// same way again.
+impl fmt::Display for Filter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Filter::All => Ok(()),
+            Filter::Active => write!(f, "active"),
+            Filter::Compare(field, op, value) => write!(
+                f,
+                "{} {} \"{}\"",
+                field.name(),
+                op.symbol(),
+                value.replace('"', "\\\"")
+            ),
+            Filter::Not(filter) => write!(f, "not ({filter})"),
+            Filter::And(a, b) => write!(f, "({a}) and ({b})"),
+            Filter::Or(a, b) => write!(f, "({a}) or ({b})"),
+        }
+    }
+}
+
+impl From<Filter> for String {
+    fn from(filter: Filter) -> Self {
+        filter.to_string()
+    }
+}
+
+/// Why a filter couldn't be parsed, and the column (from 1) where the problem is.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FilterError {
+    pub column: usize,
+    pub message: String,
+}
+impl FilterError {
+    fn new(column: usize, message: String) -> Self {
+        Self { column, message }
+    }
+}
+impl fmt::Display for FilterError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "column {}: {}", self.column, self.message)
+    }
+}
+impl std::error::Error for FilterError {}
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Word(String),
+    Quoted(String),
+    Op(Op),
+    Open,
+    Close,
+}
+impl fmt::Display for TokenKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            TokenKind::Word(word) => write!(f, "`{word}`"),
+            TokenKind::Quoted(text) => write!(f, "\"{text}\""),
+            TokenKind::Op(_) => write!(f, "an operator"),
+            TokenKind::Open => write!(f, "`(`"),
+            TokenKind::Close => write!(f, "`)`"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    column: usize,
+}
+
+fn tokenize(input: &str) -> Result<Vec<Token>, FilterError> {
+    let chars = input.chars().collect::<Vec<_>>();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+    while i < chars.len() {
+        let column = i + 1;
+        let c = chars[i];
+        let next = chars.get(i + 1).copied();
+        let (kind, len) = match c {
+            c if c.is_whitespace() => {
+                i += 1;
+                continue;
+            }
+            '(' => (TokenKind::Open, 1),
+            ')' => (TokenKind::Close, 1),
+            '=' => (TokenKind::Op(Op::Eq), 1),
+            '~' => (TokenKind::Op(Op::Contains), 1),
+            '!' if next == Some('=') => (TokenKind::Op(Op::Ne), 2),
+            '!' if next == Some('~') => (TokenKind::Op(Op::NotContains), 2),
+            '<' if next == Some('=') => (TokenKind::Op(Op::Le), 2),
+            '<' => (TokenKind::Op(Op::Lt), 1),
+            '>' if next == Some('=') => (TokenKind::Op(Op::Ge), 2),
+            '>' => (TokenKind::Op(Op::Gt), 1),
+            '"' => {
+                let mut text = String::new();
+                let mut j = i + 1;
+                loop {
+                    match chars.get(j) {
+                        None => {
+                            return Err(FilterError::new(column, "unclosed quote".to_string()));
+                        }
+                        Some('"') => break,
+                        Some('\\') if chars.get(j + 1) == Some(&'"') => {
+                            text.push('"');
+                            j += 2;
+                        }
+                        Some(c) => {
+                            text.push(*c);
+                            j += 1;
+                        }
+                    }
+                }
+                (TokenKind::Quoted(text), j + 1 - i)
+            }
+            _ => {
+                let len = chars[i..]
+                    .iter()
+                    .take_while(|c| !c.is_whitespace() && !"()=~!<>\"".contains(**c))
+                    .count();
+                if len == 0 {
+                    return Err(FilterError::new(column, format!("unexpected `{c}`")));
+                }
+                let word = chars[i..i + len].iter().collect();
+                (TokenKind::Word(word), len)
+            }
+        };
+        tokens.push(Token { kind, column });
+        i += len;
+    }
+    Ok(tokens)
+}
+
+struct Parser {
+    tokens: Vec<Token>,
+    pos: usize,
+    // column just past the input, for errors about something missing at the end
+    end: usize,
+}
+
+impl Parser {
+    fn peek_keyword(&self, keyword: &str) -> bool {
+        matches!(
+            self.tokens.get(self.pos),
+            Some(Token { kind: TokenKind::Word(word), .. }) if word.eq_ignore_ascii_case(keyword)
+        )
+    }
+
+    fn next(&mut self, expected: &str) -> Result<Token, FilterError> {
+        let token = self
+            .tokens
+            .get(self.pos)
+            .cloned()
+            .ok_or_else(|| FilterError::new(self.end, format!("expected {expected}")))?;
+        self.pos += 1;
+        Ok(token)
+    }
+
+    fn or(&mut self) -> Result<Filter, FilterError> {
+        let mut filter = self.and()?;
+        while self.peek_keyword("or") {
+            self.pos += 1;
+            filter = Filter::Or(Box::new(filter), Box::new(self.and()?));
+        }
+        Ok(filter)
+    }
+
+    fn and(&mut self) -> Result<Filter, FilterError> {
+        let mut filter = self.not()?;
+        while self.peek_keyword("and") {
+            self.pos += 1;
+            filter = Filter::And(Box::new(filter), Box::new(self.not()?));
+        }
+        Ok(filter)
+    }
+
+    fn not(&mut self) -> Result<Filter, FilterError> {
+        if self.peek_keyword("not") {
+            self.pos += 1;
+            return Ok(Filter::Not(Box::new(self.not()?)));
+        }
+        self.atom()
+    }
+
+    fn atom(&mut self) -> Result<Filter, FilterError> {
+        let token = self.next("a comparison such as `status = Applied`")?;
+        let name = match token.kind {
+            TokenKind::Open => {
+                let filter = self.or()?;
+                let close = self.next("`)`")?;
+                if close.kind != TokenKind::Close {
+                    return Err(FilterError::new(
+                        close.column,
+                        format!("expected `)`, found {}", close.kind),
+                    ));
+                }
+                return Ok(filter);
+            }
+            TokenKind::Word(word) if word.eq_ignore_ascii_case("active") => {
+                return Ok(Filter::Active);
+            }
+            TokenKind::Word(word) => word,
+            kind => {
+                return Err(FilterError::new(
+                    token.column,
+                    format!("expected a field name, found {kind}"),
+                ));
+            }
+        };
+        let field = Field::parse(&name).ok_or_else(|| {
+            let fields = Field::ALL.map(Field::name).join(", ");
+            FilterError::new(
+                token.column,
+                format!("unknown field `{name}`, expected `active` or one of {fields}"),
+            )
+        })?;
+        let op = match self.next(&format!("an operator after `{name}`"))? {
+            Token {
+                kind: TokenKind::Op(op),
+                ..
+            } => op,
+            token => {
+                return Err(FilterError::new(
+                    token.column,
+                    format!(
+                        "expected an operator (=, !=, ~, !~, <, <=, >, >=) after `{name}`, found {}",
+                        token.kind
+                    ),
+                ));
+            }
+        };
+        let value = match self.next(&format!("a value to compare `{name}` with"))? {
+            Token {
+                kind: TokenKind::Word(value) | TokenKind::Quoted(value),
+                ..
+            } => value,
+            token => {
+                return Err(FilterError::new(
+                    token.column,
+                    format!(
+                        "expected a value to compare `{name}` with, found {}",
+                        token.kind
+                    ),
+                ));
+            }
+        };
+        Ok(Filter::Compare(field, op, value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        database::{builder::JobApplicationBuilder, db::Database, query},
+        dates,
+    };
+
+    fn compare(field: Field, op: Op, value: &str) -> Filter {
+        Filter::Compare(field, op, value.to_string())
+    }
+
+    fn error(input: &str) -> FilterError {
+        Filter::parse(input).unwrap_err()
+    }
+
+    #[test]
+    fn reports_errors_at_their_column() {
+        let unclosed = error("status = \"Applied");
+        assert_eq!(
+            (unclosed.column, unclosed.message.as_str()),
+            (10, "unclosed quote")
+        );
+
+        let unknown = error("active and colour = red");
+        assert_eq!(unknown.column, 12);
+        assert!(unknown.message.starts_with("unknown field `colour`"));
+
+        let operator = error("status Applied");
+        assert_eq!(operator.column, 8);
+        assert!(
+            operator
+                .message
+                .ends_with("after `status`, found `Applied`")
+        );
+
+        let unquoted = error("status = Phone Screen");
+        assert_eq!(unquoted.column, 16);
+        assert!(
+            unquoted
+                .message
+                .ends_with("(values with spaces need quotes)")
+        );
+
+        // something missing at the end is reported just past it
+        let value = error("status =");
+        assert_eq!(
+            (value.column, value.message.as_str()),
+            (9, "expected a value to compare `status` with")
+        );
+        let bracket = error("(active");
+        assert_eq!(
+            (bracket.column, bracket.message.as_str()),
+            (8, "expected `)`")
+        );
+
+        assert_eq!(error("rating > 3 )").column, 12);
+    }
+
+    #[test]
+    fn reads_quoted_values_and_escapes() {
+        assert_eq!(
+            Filter::parse("status = \"Phone Screen\"").unwrap(),
+            compare(Field::Status, Op::Eq, "Phone Screen")
+        );
+        assert_eq!(
+            Filter::parse(r#"company ~ "Acme \"Labs\"""#).unwrap(),
+            compare(Field::Company, Op::Contains, "Acme \"Labs\"")
+        );
+        // operators and brackets inside quotes are part of the value
+        assert_eq!(
+            Filter::parse("notes !~ \"a=b (c)\"").unwrap(),
+            compare(Field::Notes, Op::NotContains, "a=b (c)")
+        );
+        assert_eq!(Filter::parse("  ").unwrap(), Filter::All);
+
+        let filter = Filter::parse(r#"not company = "Acme \"Labs\"" or rating >= 4"#).unwrap();
+        assert_eq!(Filter::parse(&filter.to_string()).unwrap(), filter);
+    }
+
+    #[test]
+    fn binds_not_then_and_then_or() {
+        let applied = || compare(Field::Status, Op::Eq, "Applied");
+        let acme = || compare(Field::Company, Op::Eq, "Acme");
+        assert_eq!(
+            Filter::parse("active or status = Applied and company = Acme").unwrap(),
+            Filter::Or(
+                Box::new(Filter::Active),
+                Box::new(Filter::And(Box::new(applied()), Box::new(acme())))
+            )
+        );
+        assert_eq!(
+            Filter::parse("NOT active AND status = Applied").unwrap(),
+            Filter::And(
+                Box::new(Filter::Not(Box::new(Filter::Active))),
+                Box::new(applied())
+            )
+        );
+        assert_eq!(
+            Filter::parse("not (active or status = Applied) and company = Acme").unwrap(),
+            Filter::And(
+                Box::new(Filter::Not(Box::new(Filter::Or(
+                    Box::new(Filter::Active),
+                    Box::new(applied())
+                )))),
+                Box::new(acme())
+            )
+        );
+        // the same operator groups from the left
+        assert_eq!(
+            Filter::parse("active or status = Applied or company = Acme").unwrap(),
+            Filter::Or(
+                Box::new(Filter::Or(Box::new(Filter::Active), Box::new(applied()))),
+                Box::new(acme())
+            )
+        );
+    }
+
+    #[test]
+    fn matches_the_rows_the_database_selects() {
+        let db = Database::in_memory().unwrap();
+        let jobs = [
+            JobApplicationBuilder::new()
+                .company_name("Acme")
+                .position("Backend Engineer")
+                .status("Phone Screen")
+                .location_type("Remote")
+                .salary_min("55000")
+                .rating("4")
+                .follow_up_date("2000-01-01")
+                .notes("Remote first, async"),
+            JobApplicationBuilder::new()
+                .company_name("Globex")
+                .position("Data Analyst")
+                .status("Rejected")
+                .salary_min("9000")
+                .rating("2")
+                .follow_up_date("2999-01-01"),
+            JobApplicationBuilder::new()
+                .company_name("Initech")
+                .position("Designer")
+                .status("Applied")
+                .location_type("On Site"),
+        ];
+        for job in jobs {
+            query::add_application(job.build().unwrap(), &db).unwrap();
+        }
+        let jobs = query::get_all_applications(&db);
+        let today = dates::today();
+
+        for input in [
+            "active",
+            "status = \"phone screen\"",
+            "company ~ AC or company ~ tech",
+            "not notes ~ remote",
+            "location_type != remote",
+            "salary_min >= 50000",
+            "salary_min < 10000",
+            "rating > 3 or rating <= 2",
+            "follow_up < today",
+            "follow_up >= today",
+            "position > d and not status = rejected",
+        ] {
+            let filter = Filter::parse(input).unwrap();
+            let mut params = Vec::new();
+            let sql = format!(
+                "SELECT id FROM job_applications WHERE {} ORDER BY id",
+                filter.to_sql(&mut params)
+            );
+            let selected = db
+                .connection()
+                .prepare(&sql)
+                .unwrap()
+                .query_map(rusqlite::params_from_iter(&params), |row| {
+                    row.get::<_, i32>(0)
+                })
+                .unwrap()
+                .collect::<Result<Vec<_>, _>>()
+                .unwrap();
+            let matched = jobs
+                .iter()
+                .filter(|job| filter.matches(job, &today))
+                .map(|job| job.id)
+                .collect::<Vec<_>>();
+            assert_eq!(selected, matched, "{input}");
+        }
+    }
+}